@@ -1,16 +1,19 @@
 //! CKB chain service.
 #![allow(missing_docs)]
 
-use ckb_channel::{self as channel, select, Sender};
+use ckb_chain_spec::consensus::Consensus;
+use ckb_channel::{self as channel, oneshot, select, Receiver, Sender};
 use ckb_error::{Error, InternalErrorKind};
 use ckb_logger::{self, debug, error, info, log_enabled, trace, warn};
 use ckb_metrics::{metrics, Timer};
 use ckb_proposal_table::ProposalTable;
 #[cfg(debug_assertions)]
 use ckb_rust_unstable_port::IsSorted;
-use ckb_shared::shared::Shared;
+use ckb_shared::{shared::Shared, Snapshot};
 use ckb_stop_handler::{SignalSender, StopHandler};
-use ckb_store::{attach_block_cell, detach_block_cell, ChainStore, StoreTransaction};
+use ckb_store::{
+    attach_block_cell, attach_block_filter, detach_block_cell, ChainStore, StoreTransaction,
+};
 use ckb_types::{
     core::{
         cell::{
@@ -19,21 +22,29 @@ use ckb_types::{
         },
         hardfork::HardForkSwitch,
         service::{Request, DEFAULT_CHANNEL_SIZE, SIGNAL_CHANNEL_SIZE},
-        BlockExt, BlockNumber, BlockView, HeaderView,
+        BlockExt, BlockNumber, BlockView, HeaderView, TransactionView,
     },
-    packed::{Byte32, ProposalShortId},
+    packed::{Byte32, CellOutput, ProposalShortId},
+    prelude::*,
     U256,
 };
-use ckb_verification::{BlockVerifier, InvalidParentError, NonContextualBlockTxsVerifier};
+use ckb_verification::{
+    BlockVerifier, CheckpointError, InvalidParentError, NonContextualBlockTxsVerifier,
+};
 use ckb_verification_contextual::{ContextualBlockVerifier, VerifyContext};
 use ckb_verification_traits::{Switch, Verifier};
 use faketime::unix_time_as_millis;
-use std::collections::{HashSet, VecDeque};
-use std::sync::Arc;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::sync::{mpsc, Arc};
 use std::{cmp, thread};
 
 type ProcessBlockRequest = Request<(Arc<BlockView>, Switch), Result<bool, Error>>;
 type TruncateRequest = Request<Byte32, Result<(), Error>>;
+type InvalidateBlockRequest = Request<Byte32, Result<(), Error>>;
+type ReconsiderBlockRequest = Request<Byte32, Result<(), Error>>;
+
+/// The count of the queued blocks verified ahead of committing during the initial block download
+const PIPELINE_DEPTH: usize = 16;
 
 /// Controller to the chain service.
 ///
@@ -45,6 +56,8 @@ type TruncateRequest = Request<Byte32, Result<(), Error>>;
 pub struct ChainController {
     process_block_sender: Sender<ProcessBlockRequest>,
     truncate_sender: Sender<TruncateRequest>, // Used for testing only
+    invalidate_block_sender: Sender<InvalidateBlockRequest>,
+    reconsider_block_sender: Sender<ReconsiderBlockRequest>,
     stop: Option<StopHandler<()>>,
 }
 
@@ -59,11 +72,15 @@ impl ChainController {
     pub fn new(
         process_block_sender: Sender<ProcessBlockRequest>,
         truncate_sender: Sender<TruncateRequest>,
+        invalidate_block_sender: Sender<InvalidateBlockRequest>,
+        reconsider_block_sender: Sender<ReconsiderBlockRequest>,
         stop: StopHandler<()>,
     ) -> Self {
         ChainController {
             process_block_sender,
             truncate_sender,
+            invalidate_block_sender,
+            reconsider_block_sender,
             stop: Some(stop),
         }
     }
@@ -93,6 +110,23 @@ impl ChainController {
         })
     }
 
+    /// Submits the block without waiting, the result is received from the returned receiver.
+    ///
+    /// The blocks submitted together are verified ahead in a pipeline during the initial block
+    /// download. The parent of a submitted block must be stored or submitted before it.
+    pub fn submit_block(
+        &self,
+        block: Arc<BlockView>,
+        switch: Switch,
+    ) -> mpsc::Receiver<Result<bool, Error>> {
+        let (responder, response) = mpsc::channel();
+        let _ = self.process_block_sender.send(Request {
+            responder,
+            arguments: (block, switch),
+        });
+        response
+    }
+
     /// Truncate chain to specified target
     ///
     /// Should use for testing only
@@ -104,6 +138,26 @@ impl ChainController {
         })
     }
 
+    /// Marks the block as invalid, the main chain switches to the best fork without it
+    ///
+    /// The mark is persisted, until the block is reconsidered.
+    pub fn invalidate_block(&self, block_hash: Byte32) -> Result<(), Error> {
+        Request::call(&self.invalidate_block_sender, block_hash).unwrap_or_else(|| {
+            Err(InternalErrorKind::System
+                .other("Chain service has gone")
+                .into())
+        })
+    }
+
+    /// Removes the invalid mark of the block, the main chain switches to the best fork
+    pub fn reconsider_block(&self, block_hash: Byte32) -> Result<(), Error> {
+        Request::call(&self.reconsider_block_sender, block_hash).unwrap_or_else(|| {
+            Err(InternalErrorKind::System
+                .other("Chain service has gone")
+                .into())
+        })
+    }
+
     pub fn try_stop(&mut self) {
         if let Some(ref mut stop) = self.stop {
             stop.try_send(());
@@ -116,6 +170,8 @@ impl ChainController {
         ChainController {
             stop: None,
             truncate_sender: self.truncate_sender.clone(),
+            invalidate_block_sender: self.invalidate_block_sender.clone(),
+            reconsider_block_sender: self.reconsider_block_sender.clone(),
             process_block_sender: self.process_block_sender.clone(),
         }
     }
@@ -249,6 +305,8 @@ impl ChainService {
         let (signal_sender, signal_receiver) = channel::bounded::<()>(SIGNAL_CHANNEL_SIZE);
         let (process_block_sender, process_block_receiver) = channel::bounded(DEFAULT_CHANNEL_SIZE);
         let (truncate_sender, truncate_receiver) = channel::bounded(1);
+        let (invalidate_block_sender, invalidate_block_receiver) = channel::bounded(1);
+        let (reconsider_block_sender, reconsider_block_receiver) = channel::bounded(1);
 
         // Mainly for test: give an empty thread_name
         let mut thread_builder = thread::Builder::new();
@@ -264,9 +322,9 @@ impl ChainService {
                         break;
                     },
                     recv(process_block_receiver) -> msg => match msg {
-                        Ok(Request { responder, arguments: (block, verify) }) => {
+                        Ok(request) => {
                             let _ = tx_control.suspend_chunk_process();
-                            let _ = responder.send(self.process_block(block, verify));
+                            self.process_block_requests(request, &process_block_receiver);
                             let _ = tx_control.continue_chunk_process();
                         },
                         _ => {
@@ -284,6 +342,28 @@ impl ChainService {
                             error!("truncate_receiver closed");
                             break;
                         },
+                    },
+                    recv(invalidate_block_receiver) -> msg => match msg {
+                        Ok(Request { responder, arguments: block_hash }) => {
+                            let _ = tx_control.suspend_chunk_process();
+                            let _ = responder.send(self.invalidate_block(&block_hash));
+                            let _ = tx_control.continue_chunk_process();
+                        },
+                        _ => {
+                            error!("invalidate_block_receiver closed");
+                            break;
+                        },
+                    },
+                    recv(reconsider_block_receiver) -> msg => match msg {
+                        Ok(Request { responder, arguments: block_hash }) => {
+                            let _ = tx_control.suspend_chunk_process();
+                            let _ = responder.send(self.reconsider_block(&block_hash));
+                            let _ = tx_control.continue_chunk_process();
+                        },
+                        _ => {
+                            error!("reconsider_block_receiver closed");
+                            break;
+                        },
                     }
                 }
             })
@@ -294,7 +374,13 @@ impl ChainService {
             "chain".to_string(),
         );
 
-        ChainController::new(process_block_sender, truncate_sender, stop)
+        ChainController::new(
+            process_block_sender,
+            truncate_sender,
+            invalidate_block_sender,
+            reconsider_block_sender,
+            stop,
+        )
     }
 
     fn make_fork_for_truncate(&self, target: &HeaderView, current_tip: &HeaderView) -> ForkChanges {
@@ -355,9 +441,347 @@ impl ChainService {
         Ok(())
     }
 
+    // Marks the block as invalid. If the block is in the main chain, the main chain is truncated
+    // to its parent, then switches to the best valid fork
+    pub(crate) fn invalidate_block(&mut self, block_hash: &Byte32) -> Result<(), Error> {
+        let snapshot = Arc::clone(&self.shared.snapshot());
+        let header = snapshot
+            .get_block_header(block_hash)
+            .ok_or_else(|| InternalErrorKind::Other.other("block not found"))?;
+        if header.is_genesis() {
+            return Err(InternalErrorKind::Other
+                .other("the genesis block can't be invalidated")
+                .into());
+        }
+        if let Some(freezer) = snapshot.freezer() {
+            if header.number() < freezer.number() {
+                return Err(InternalErrorKind::Other
+                    .other("the frozen block can't be invalidated")
+                    .into());
+            }
+        }
+        let mut ext = snapshot
+            .get_block_ext(block_hash)
+            .expect("block ext stored");
+        ext.verified = Some(false);
+        let mut invalidated = snapshot.get_invalidated_blocks();
+        if !invalidated.contains(block_hash) {
+            invalidated.push(block_hash.to_owned());
+        }
+
+        let db_txn = self.shared.store().begin_transaction();
+        db_txn.insert_block_ext(block_hash, &ext)?;
+        db_txn.insert_invalidated_blocks(invalidated)?;
+        if !snapshot.is_main_chain(block_hash) {
+            db_txn.commit()?;
+            self.shared.refresh_snapshot();
+            info!(
+                "invalidate block: {}, hash: {:#x}",
+                header.number(),
+                block_hash
+            );
+            return Ok(());
+        }
+
+        let parent_header = snapshot
+            .get_block_header(&header.parent_hash())
+            .expect("parent stored");
+        let parent_ext = snapshot
+            .get_block_ext(&header.parent_hash())
+            .expect("parent stored");
+        let parent_epoch_ext = snapshot
+            .get_block_epoch_index(&header.parent_hash())
+            .and_then(|index| snapshot.get_epoch_ext(&index))
+            .expect("parent stored");
+        let origin_proposals = snapshot.proposals();
+        let mut fork = self.make_fork_for_truncate(&parent_header, snapshot.tip_header());
+
+        self.rollback(&fork, &db_txn)?;
+        db_txn.insert_tip_header(&parent_header)?;
+        db_txn.insert_current_epoch_ext(&parent_epoch_ext)?;
+        db_txn.commit()?;
+
+        self.update_proposal_table(&fork);
+        let (detached_proposal_id, new_proposals) = self
+            .proposal_table
+            .finalize(origin_proposals, parent_header.number());
+        fork.detached_proposal_id = detached_proposal_id;
+
+        let new_snapshot = self.shared.new_snapshot(
+            parent_header,
+            parent_ext.total_difficulty,
+            parent_epoch_ext,
+            new_proposals,
+        );
+        self.shared.store_snapshot(Arc::clone(&new_snapshot));
+        self.update_tx_pool_for_reorg(&fork, new_snapshot);
+        info!(
+            "invalidate block: {}, hash: {:#x}, {} blocks detached",
+            header.number(),
+            block_hash,
+            fork.detached_blocks().len()
+        );
+
+        self.activate_best_chain()
+    }
+
+    // Removes the invalid mark of the block, then switches to the best valid fork
+    pub(crate) fn reconsider_block(&mut self, block_hash: &Byte32) -> Result<(), Error> {
+        let mut ext = self
+            .shared
+            .store()
+            .get_block_ext(block_hash)
+            .ok_or_else(|| InternalErrorKind::Other.other("block not found"))?;
+        if ext.verified == Some(false) {
+            // the block is verified again once it is attached to the main chain
+            ext.verified = None;
+            let mut invalidated = self.shared.store().get_invalidated_blocks();
+            invalidated.retain(|hash| hash != block_hash);
+            let db_txn = self.shared.store().begin_transaction();
+            db_txn.insert_block_ext(block_hash, &ext)?;
+            db_txn.insert_invalidated_blocks(invalidated)?;
+            db_txn.commit()?;
+            self.shared.refresh_snapshot();
+            info!("reconsider block: {:#x}", block_hash);
+        }
+
+        self.activate_best_chain()
+    }
+
+    // Switches the main chain to the heaviest stored fork which has no invalid blocks
+    //
+    // The forks are walked down from their tips to the main chain, each fork block is visited
+    // once, it is valid when neither it nor an ancestor out of the main chain is marked invalid
+    fn activate_best_chain(&mut self) -> Result<(), Error> {
+        let snapshot = Arc::clone(&self.shared.snapshot());
+        let mut valid_forks: HashMap<Byte32, bool> = HashMap::new();
+        let mut candidates = Vec::new();
+        for tip in snapshot.get_fork_tips() {
+            // the ancestors of a lighter tip are lighter as well
+            let tip_ext = snapshot
+                .get_block_ext(&tip.hash())
+                .expect("block ext stored");
+            if &tip_ext.total_difficulty <= snapshot.total_difficulty() {
+                continue;
+            }
+            let mut path = Vec::new();
+            let mut hash = tip.hash();
+            let mut valid = loop {
+                if let Some(valid) = valid_forks.get(&hash) {
+                    break *valid;
+                }
+                if snapshot.is_main_chain(&hash) {
+                    break true;
+                }
+                let parent_hash = snapshot
+                    .get_block_header(&hash)
+                    .expect("block header stored")
+                    .parent_hash();
+                path.push(hash);
+                hash = parent_hash;
+            };
+            for hash in path.into_iter().rev() {
+                let ext = snapshot.get_block_ext(&hash).expect("block ext stored");
+                valid = valid && ext.verified != Some(false);
+                valid_forks.insert(hash.clone(), valid);
+                if valid && &ext.total_difficulty > snapshot.total_difficulty() {
+                    candidates.push((hash, ext));
+                }
+            }
+        }
+        candidates.sort_by(|(_, a), (_, b)| b.total_difficulty.cmp(&a.total_difficulty));
+
+        for (hash, ext) in candidates {
+            let block = self.shared.store().get_block(&hash).expect("block stored");
+            match self.switch_to_fork(&block, ext) {
+                Ok(()) => return Ok(()),
+                Err(err) => {
+                    warn!(
+                        "switch to fork {}-{} error: {}",
+                        block.number(),
+                        block.hash(),
+                        err
+                    );
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn switch_to_fork(&mut self, block: &BlockView, ext: BlockExt) -> Result<(), Error> {
+        let snapshot = Arc::clone(&self.shared.snapshot());
+        let origin_proposals = snapshot.proposals();
+        let epoch = snapshot
+            .get_block_epoch_index(&block.hash())
+            .and_then(|index| snapshot.get_epoch_ext(&index))
+            .expect("block epoch stored");
+        let total_difficulty = ext.total_difficulty.clone();
+
+        let mut fork = ForkChanges::default();
+        let db_txn = self.shared.store().begin_transaction();
+        self.find_fork(&mut fork, snapshot.tip_header().number(), block, ext);
+        self.check_checkpoints(&fork)?;
+        self.rollback(&fork, &db_txn)?;
+        self.reconcile_main_chain(&db_txn, &mut fork, Switch::NONE)?;
+        db_txn.insert_tip_header(&block.header())?;
+        db_txn.insert_current_epoch_ext(&epoch)?;
+        db_txn.commit()?;
+
+        self.update_proposal_table(&fork);
+        let (detached_proposal_id, new_proposals) = self
+            .proposal_table
+            .finalize(origin_proposals, block.number());
+        fork.detached_proposal_id = detached_proposal_id;
+
+        let new_snapshot =
+            self.shared
+                .new_snapshot(block.header(), total_difficulty, epoch, new_proposals);
+        self.shared.store_snapshot(Arc::clone(&new_snapshot));
+        self.update_tx_pool_for_reorg(&fork, new_snapshot);
+        self.shared
+            .notify_controller()
+            .notify_new_block(block.clone());
+        info!(
+            "switch to fork: {}, hash: {:#x}, {} blocks attached, {} blocks detached",
+            block.number(),
+            block.hash(),
+            fork.attached_blocks().len(),
+            fork.detached_blocks().len()
+        );
+        Ok(())
+    }
+
+    fn update_tx_pool_for_reorg(&self, fork: &ForkChanges, new_snapshot: Arc<Snapshot>) {
+        let tx_pool_controller = self.shared.tx_pool_controller();
+        if tx_pool_controller.service_started() {
+            if let Err(e) = tx_pool_controller.update_tx_pool_for_reorg(
+                fork.detached_blocks().clone(),
+                fork.attached_blocks().clone(),
+                fork.detached_proposal_id().clone(),
+                new_snapshot,
+            ) {
+                error!("notify update_tx_pool_for_reorg error {}", e);
+            }
+            for detached_block in fork.detached_blocks() {
+                if let Err(e) = tx_pool_controller.notify_new_uncle(detached_block.as_uncle()) {
+                    error!("notify new_uncle error {}", e);
+                }
+            }
+        }
+    }
+
+    // During the initial block download, the queued blocks are taken together, their
+    // non-contextual verification and input cells fetching run concurrently ahead, while the
+    // blocks are still committed one by one in the received order.
+    pub(crate) fn process_block_requests(
+        &mut self,
+        request: ProcessBlockRequest,
+        receiver: &Receiver<ProcessBlockRequest>,
+    ) {
+        let mut requests = vec![request];
+        if self.shared.is_initial_block_download() {
+            requests.extend(receiver.try_iter().take(PIPELINE_DEPTH - 1));
+        }
+        if requests.len() == 1 {
+            let Request {
+                responder,
+                arguments: (block, switch),
+            } = requests.remove(0);
+            let _ = responder.send(self.process_block(block, switch));
+            return;
+        }
+
+        let pre_verified: Vec<_> = requests
+            .iter()
+            .enumerate()
+            .map(|(i, request)| {
+                let (ref block, switch) = request.arguments;
+                let earlier_blocks = requests[..i]
+                    .iter()
+                    .map(|request| Arc::clone(&request.arguments.0))
+                    .collect();
+                self.pre_verify(Arc::clone(block), switch, earlier_blocks)
+            })
+            .collect();
+        for (request, pre_verified) in requests.into_iter().zip(pre_verified) {
+            let Request {
+                responder,
+                arguments: (block, switch),
+            } = request;
+            // fall back to verify in place if the worker is gone
+            let non_contextual = pre_verified.recv().ok();
+            let _ = responder.send(self.process_block_inner(block, switch, non_contextual));
+        }
+    }
+
+    // Runs the non-contextual verification on the rayon pool, then fetches the input cells and
+    // the cell deps into the store cache, so they are cached when the block is resolved. The
+    // cells created by the earlier blocks in the same batch are not stored yet, they are cached
+    // from those blocks directly.
+    fn pre_verify(
+        &self,
+        block: Arc<BlockView>,
+        switch: Switch,
+        earlier_blocks: Vec<Arc<BlockView>>,
+    ) -> oneshot::Receiver<Result<(), Error>> {
+        let (sender, receiver) = oneshot::channel();
+        let shared = self.shared.clone();
+        rayon::spawn(move || {
+            let result = if switch.disable_non_contextual() {
+                Ok(())
+            } else {
+                Self::non_contextual_verify(shared.consensus(), &block)
+            };
+            let _ = sender.send(result);
+
+            let batch_txs: HashMap<Byte32, TransactionView> = earlier_blocks
+                .iter()
+                .flat_map(|block| block.transactions())
+                .map(|tx| (tx.hash(), tx))
+                .collect();
+            let store = shared.store();
+            for tx in block.transactions().iter().skip(1) {
+                for out_point in tx
+                    .input_pts_iter()
+                    .chain(tx.cell_deps_iter().map(|dep| dep.out_point()))
+                {
+                    let index: u32 = out_point.index().unpack();
+                    let batch_output = batch_txs
+                        .get(&out_point.tx_hash())
+                        .and_then(|tx| tx.output_with_data(index as usize));
+                    if let Some((_, data)) = batch_output {
+                        // the data is the same whether the cell is stored later or not, since
+                        // the cell key commits to it
+                        if let Some(cache) = store.cache() {
+                            let key = out_point.to_cell_key();
+                            let data_hash = CellOutput::calc_data_hash(&data);
+                            cache
+                                .cell_data_hash
+                                .lock()
+                                .put(key.clone(), data_hash.clone());
+                            cache.cell_data.lock().put(key, (data, data_hash));
+                        }
+                    } else if store.get_cell(&out_point).is_some() {
+                        let _ = store.get_cell_data(&out_point);
+                    }
+                }
+            }
+        });
+        receiver
+    }
+
     // visible pub just for test
     #[doc(hidden)]
     pub fn process_block(&mut self, block: Arc<BlockView>, switch: Switch) -> Result<bool, Error> {
+        self.process_block_inner(block, switch, None)
+    }
+
+    fn process_block_inner(
+        &mut self,
+        block: Arc<BlockView>,
+        switch: Switch,
+        non_contextual: Option<Result<(), Error>>,
+    ) -> Result<bool, Error> {
         let block_number = block.number();
         let block_hash = block.hash();
 
@@ -367,15 +791,14 @@ impl ChainService {
         }
 
         let timer = Timer::start();
-        self.insert_block(block, switch).map(|ret| {
+        self.insert_block(block, switch, non_contextual).map(|ret| {
             metrics!(timing, "ckb.processed_block", timer.stop());
             debug!("finish processing block");
             ret
         })
     }
 
-    fn non_contextual_verify(&self, block: &BlockView) -> Result<(), Error> {
-        let consensus = self.shared.consensus();
+    fn non_contextual_verify(consensus: &Consensus, block: &BlockView) -> Result<(), Error> {
         BlockVerifier::new(consensus).verify(block).map_err(|e| {
             debug!("[process_block] BlockVerifier error {:?}", e);
             e
@@ -393,7 +816,12 @@ impl ChainService {
             .map(|_| ())
     }
 
-    fn insert_block(&mut self, block: Arc<BlockView>, switch: Switch) -> Result<bool, Error> {
+    fn insert_block(
+        &mut self,
+        block: Arc<BlockView>,
+        switch: Switch,
+        non_contextual: Option<Result<(), Error>>,
+    ) -> Result<bool, Error> {
         let db_txn = self.shared.store().begin_transaction();
         let txn_snapshot = db_txn.get_snapshot();
         let _snapshot_tip_hash = db_txn.get_update_for_tip_hash(&txn_snapshot);
@@ -402,17 +830,23 @@ impl ChainService {
         if txn_snapshot.block_exists(&block.header().hash()) {
             return Ok(false);
         }
-        // non-contextual verify
+        // non-contextual verify, it may be done ahead in the pipeline
         if !switch.disable_non_contextual() {
-            self.non_contextual_verify(&block)?;
+            match non_contextual {
+                Some(result) => result?,
+                None => Self::non_contextual_verify(self.shared.consensus(), &block)?,
+            }
         }
 
         let mut total_difficulty = U256::zero();
         let mut fork = ForkChanges::default();
 
-        let parent_ext = txn_snapshot
-            .get_block_ext(&block.data().header().raw().parent_hash())
-            .expect("parent already store");
+        // the parent may be rejected earlier in the same batch of the submitted blocks
+        let parent_ext =
+            match txn_snapshot.get_block_ext(&block.data().header().raw().parent_hash()) {
+                Some(ext) => ext,
+                None => return Err(self.invalid_parent_error(block.parent_hash())),
+            };
 
         let parent_header = txn_snapshot
             .get_block_header(&block.data().header().raw().parent_hash())
@@ -422,10 +856,7 @@ impl ChainService {
             parent_ext.total_difficulty.to_owned() + block.header().difficulty();
 
         if parent_ext.verified == Some(false) {
-            return Err(InvalidParentError {
-                parent_hash: parent_header.hash(),
-            }
-            .into());
+            return Err(self.invalid_parent_error(parent_header.hash()));
         }
 
         db_txn.insert_block(&block)?;
@@ -475,6 +906,8 @@ impl ChainService {
                 &cannon_total_difficulty - &current_total_difficulty
             );
             self.find_fork(&mut fork, current_tip_header.number(), &block, ext);
+            self.check_checkpoints(&fork)?;
+            self.check_invalidated(&fork)?;
             self.rollback(&fork, &db_txn)?;
 
             // update and verify chain root
@@ -513,23 +946,7 @@ impl ChainService {
                     .new_snapshot(tip_header, total_difficulty, epoch, new_proposals);
 
             self.shared.store_snapshot(Arc::clone(&new_snapshot));
-
-            let tx_pool_controller = self.shared.tx_pool_controller();
-            if tx_pool_controller.service_started() {
-                if let Err(e) = tx_pool_controller.update_tx_pool_for_reorg(
-                    fork.detached_blocks().clone(),
-                    fork.attached_blocks().clone(),
-                    fork.detached_proposal_id().clone(),
-                    new_snapshot,
-                ) {
-                    error!("notify update_tx_pool_for_reorg error {}", e);
-                }
-                for detached_block in fork.detached_blocks() {
-                    if let Err(e) = tx_pool_controller.notify_new_uncle(detached_block.as_uncle()) {
-                        error!("notify new_uncle error {}", e);
-                    }
-                }
-            }
+            self.update_tx_pool_for_reorg(&fork, new_snapshot);
 
             let block_ref: &BlockView = &block;
             self.shared
@@ -610,6 +1027,87 @@ impl ChainService {
         }
     }
 
+    // The chain never switches to a fork which contains an invalidated block
+    fn check_invalidated(&self, fork: &ForkChanges) -> Result<(), Error> {
+        let store = self.shared.store();
+        for block in fork.attached_blocks() {
+            let invalidated = store
+                .get_block_ext(&block.parent_hash())
+                .map(|ext| ext.verified == Some(false))
+                .unwrap_or(false);
+            if invalidated {
+                return Err(self.invalid_parent_error(block.parent_hash()));
+            }
+        }
+        Ok(())
+    }
+
+    // The children of a block invalidated by the local node are not invalid by themselves, so
+    // the peers which relay them are not punished
+    fn invalid_parent_error(&self, parent_hash: Byte32) -> Error {
+        if self
+            .shared
+            .store()
+            .get_invalidated_blocks()
+            .contains(&parent_hash)
+        {
+            InternalErrorKind::Invalidated
+                .other(format!("the parent block {} is invalidated", parent_hash))
+                .into()
+        } else {
+            InvalidParentError { parent_hash }.into()
+        }
+    }
+
+    // The chain never switches to a fork which detaches a checkpoint block, attaches a block
+    // conflicting with a checkpoint, or leaves the chain of a stored checkpoint block
+    fn check_checkpoints(&self, fork: &ForkChanges) -> Result<(), Error> {
+        let consensus = self.shared.consensus();
+        for block in fork.detached_blocks() {
+            if consensus.checkpoint(block.number()) == Some(&block.hash()) {
+                return Err(CheckpointError::ReorgBelowCheckpoint {
+                    number: block.number(),
+                }
+                .into());
+            }
+        }
+        for block in fork.attached_blocks() {
+            if let Some(expected) = consensus.checkpoint(block.number()) {
+                if expected != &block.hash() {
+                    return Err(CheckpointError::Mismatch {
+                        number: block.number(),
+                        expected: expected.clone(),
+                        actual: block.hash(),
+                    }
+                    .into());
+                }
+            }
+        }
+        // The new tip below a known checkpoint block must be its ancestor
+        if let Some(tip) = fork.attached_blocks().back() {
+            if let Some((checkpoint, hash)) = consensus.next_checkpoint(tip.number() + 1) {
+                let store = self.shared.store();
+                let mut ancestor = store.get_block_header(hash);
+                while let Some(header) = &ancestor {
+                    if header.number() <= tip.number() {
+                        break;
+                    }
+                    ancestor = store.get_block_header(&header.parent_hash());
+                }
+                if let Some(header) = ancestor {
+                    if header.hash() != tip.hash() {
+                        return Err(CheckpointError::ForkBelowCheckpoint {
+                            checkpoint,
+                            number: tip.number(),
+                        }
+                        .into());
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
     pub(crate) fn rollback(&self, fork: &ForkChanges, txn: &StoreTransaction) -> Result<(), Error> {
         for block in fork.detached_blocks().iter().rev() {
             txn.detach_block(block)?;
@@ -762,6 +1260,7 @@ impl ChainService {
         for b in fork.attached_blocks().iter().take(verified_len) {
             txn.attach_block(b)?;
             attach_block_cell(txn, b)?;
+            attach_block_filter(txn, b)?;
         }
 
         let verify_context = VerifyContext::new(txn, consensus);
@@ -824,6 +1323,7 @@ impl ChainService {
                                         cache_entries.into_iter().map(|entry| entry.fee).collect();
                                     txn.attach_block(b)?;
                                     attach_block_cell(txn, b)?;
+                                    attach_block_filter(txn, b)?;
                                     let mut mut_ext = ext.clone();
                                     mut_ext.verified = Some(true);
                                     mut_ext.txs_fees = txs_fees;
@@ -868,6 +1368,7 @@ impl ChainService {
             } else {
                 txn.attach_block(b)?;
                 attach_block_cell(txn, b)?;
+                attach_block_filter(txn, b)?;
                 let mut mut_ext = ext.clone();
                 mut_ext.verified = Some(true);
                 txn.insert_block_ext(&b.header().hash(), &mut_ext)?;
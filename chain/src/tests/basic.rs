@@ -1,4 +1,4 @@
-use crate::chain::ChainController;
+use crate::chain::{ChainController, ChainService};
 use crate::tests::util::{
     create_always_success_tx, create_cellbase, create_multi_outputs_transaction,
     create_transaction, create_transaction_with_out_point, dao_data, start_chain, MockChain,
@@ -6,10 +6,12 @@ use crate::tests::util::{
 };
 use ckb_chain_spec::consensus::{Consensus, ConsensusBuilder};
 use ckb_dao_utils::genesis_dao_data;
-use ckb_error::assert_error_eq;
+use ckb_error::{assert_error_eq, InternalError, InternalErrorKind};
+use ckb_launcher::SharedBuilder;
 use ckb_shared::shared::Shared;
 use ckb_store::ChainStore;
 use ckb_types::core::error::OutPointError;
+use ckb_types::core::service::Request;
 use ckb_types::prelude::*;
 use ckb_types::{
     bytes::Bytes,
@@ -22,6 +24,7 @@ use ckb_types::{
     utilities::{compact_to_difficulty, difficulty_to_compact},
     U256,
 };
+use ckb_verification::CheckpointError;
 use ckb_verification_traits::Switch;
 use std::sync::Arc;
 
@@ -425,6 +428,167 @@ fn test_chain_fork_by_total_difficulty() {
     );
 }
 
+#[test]
+fn test_chain_fork_below_checkpoint() {
+    let (_, shared, parent) = start_chain(None);
+    let final_number = 20;
+
+    let mock_store = MockStore::new(&parent, shared.store());
+    let mut chain1 = MockChain::new(parent.clone(), shared.consensus());
+    let mut chain2 = MockChain::new(parent, shared.consensus());
+    for _ in 0..final_number {
+        chain1.gen_empty_block_with_diff(100u64, &mock_store);
+    }
+    for i in 0..final_number {
+        let j = if i > 10 { 110 } else { 99 };
+        chain2.gen_empty_block_with_diff(j, &mock_store);
+    }
+    assert!(chain2.total_difficulty() > chain1.total_difficulty());
+
+    let mut consensus = shared.consensus().clone();
+    consensus
+        .checkpoints
+        .insert(5, chain1.blocks()[4].header().hash());
+    let (chain_controller, shared, _) = start_chain(Some(consensus));
+
+    for block in chain1.blocks() {
+        chain_controller
+            .internal_process_block(Arc::new(block.clone()), Switch::DISABLE_ALL)
+            .expect("process block ok");
+    }
+    let (last, blocks) = chain2.blocks().split_last().unwrap();
+    for block in blocks {
+        chain_controller
+            .internal_process_block(Arc::new(block.clone()), Switch::DISABLE_ALL)
+            .expect("process block ok");
+    }
+    // the heavier fork detaches the checkpoint block
+    assert_error_eq!(
+        chain_controller
+            .internal_process_block(Arc::new(last.clone()), Switch::DISABLE_ALL)
+            .unwrap_err(),
+        CheckpointError::ReorgBelowCheckpoint { number: 5 },
+    );
+    assert_eq!(
+        shared.snapshot().tip_hash(),
+        chain1.blocks().last().unwrap().hash()
+    );
+}
+
+#[test]
+fn test_chain_leaves_stored_checkpoint() {
+    let (_, shared, parent) = start_chain(None);
+
+    let mock_store = MockStore::new(&parent, shared.store());
+    let mut checkpointed = MockChain::new(parent.clone(), shared.consensus());
+    let mut main = MockChain::new(parent.clone(), shared.consensus());
+    let mut fork = MockChain::new(parent, shared.consensus());
+    for _ in 0..20 {
+        checkpointed.gen_empty_block_with_diff(100u64, &mock_store);
+    }
+    for _ in 0..8 {
+        main.gen_empty_block_with_diff(300u64, &mock_store);
+    }
+    for _ in 0..8 {
+        fork.gen_empty_block_with_diff(310u64, &mock_store);
+    }
+    assert!(main.total_difficulty() > checkpointed.total_difficulty());
+    assert!(fork.total_difficulty() > main.total_difficulty());
+
+    let mut consensus = shared.consensus().clone();
+    consensus
+        .checkpoints
+        .insert(15, checkpointed.blocks()[14].header().hash());
+    let (chain_controller, shared, _) = start_chain(Some(consensus));
+
+    // the checkpoint block is stored on a lighter fork
+    for block in main.blocks().iter().chain(checkpointed.blocks()) {
+        chain_controller
+            .internal_process_block(Arc::new(block.clone()), Switch::DISABLE_ALL)
+            .expect("process block ok");
+    }
+    let (last, blocks) = fork.blocks().split_last().unwrap();
+    for block in blocks {
+        chain_controller
+            .internal_process_block(Arc::new(block.clone()), Switch::DISABLE_ALL)
+            .expect("process block ok");
+    }
+    // the heavier fork is not on the chain of the checkpoint block
+    assert_error_eq!(
+        chain_controller
+            .internal_process_block(Arc::new(last.clone()), Switch::DISABLE_ALL)
+            .unwrap_err(),
+        CheckpointError::ForkBelowCheckpoint {
+            checkpoint: 15,
+            number: 8,
+        },
+    );
+    assert_eq!(
+        shared.snapshot().tip_hash(),
+        main.blocks().last().unwrap().hash()
+    );
+}
+
+#[test]
+fn test_invalidate_and_reconsider_block() {
+    let (chain_controller, shared, parent) = start_chain(None);
+
+    let mock_store = MockStore::new(&parent, shared.store());
+    let mut chain1 = MockChain::new(parent.clone(), shared.consensus());
+    let mut chain2 = MockChain::new(parent, shared.consensus());
+    for _ in 0..10 {
+        chain1.gen_empty_block_with_nonce(1u128, &mock_store);
+    }
+    for _ in 0..8 {
+        chain2.gen_empty_block_with_nonce(2u128, &mock_store);
+    }
+    for block in chain1.blocks().iter().chain(chain2.blocks()) {
+        chain_controller
+            .process_block(Arc::new(block.clone()))
+            .expect("process block ok");
+    }
+    let tip1 = chain1.blocks().last().unwrap().hash();
+    let tip2 = chain2.blocks().last().unwrap().hash();
+    assert_eq!(shared.snapshot().tip_hash(), tip1);
+
+    // the heavier fork contains the invalid block, switches to the lighter one
+    let invalid = chain1.blocks()[4].hash();
+    chain_controller
+        .invalidate_block(invalid.clone())
+        .expect("invalidate block ok");
+    assert_eq!(shared.snapshot().tip_hash(), tip2);
+    assert_eq!(
+        shared.store().get_block_ext(&invalid).unwrap().verified,
+        Some(false)
+    );
+    assert_eq!(
+        shared.store().get_invalidated_blocks(),
+        vec![invalid.clone()]
+    );
+
+    // the descendants of the invalidated block are rejected, but they are not invalid
+    chain1.gen_empty_block_with_nonce(1u128, &mock_store);
+    let descendant = chain1.blocks().last().unwrap().clone();
+    let err = chain_controller
+        .process_block(Arc::new(descendant.clone()))
+        .unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<InternalError>().map(InternalError::kind),
+        Some(InternalErrorKind::Invalidated)
+    );
+    assert_eq!(shared.snapshot().tip_hash(), tip2);
+
+    chain_controller
+        .reconsider_block(invalid)
+        .expect("reconsider block ok");
+    assert_eq!(shared.snapshot().tip_hash(), tip1);
+    assert!(shared.store().get_invalidated_blocks().is_empty());
+    chain_controller
+        .process_block(Arc::new(descendant.clone()))
+        .expect("process block ok");
+    assert_eq!(shared.snapshot().tip_hash(), descendant.hash());
+}
+
 #[test]
 fn test_chain_fork_by_first_received() {
     let (chain_controller, shared, parent) = start_chain(None);
@@ -867,3 +1031,59 @@ fn test_next_epoch_ext() {
         );
     }
 }
+
+#[test]
+fn test_pipelined_process_blocks() {
+    let (shared, mut pack) = SharedBuilder::with_temp_db()
+        .consensus(Consensus::default())
+        .build()
+        .unwrap();
+    assert!(shared.is_initial_block_download());
+    let mut chain_service = ChainService::new(shared.clone(), pack.take_proposal_table());
+    let parent = shared.snapshot().tip_header().clone();
+    let mock_store = MockStore::new(&parent, shared.store());
+    let mut chain = MockChain::new(parent, shared.consensus());
+    for i in 0..4 {
+        chain.gen_empty_block_with_nonce(i, &mock_store);
+    }
+    // a sibling of the tip without the cellbase fails the non-contextual verification
+    let invalid = chain
+        .tip()
+        .as_advanced_builder()
+        .set_transactions(vec![])
+        .build();
+    // the child of the rejected block is rejected without panic, though its parent isn't stored
+    let orphan = chain
+        .tip()
+        .as_advanced_builder()
+        .parent_hash(invalid.hash())
+        .build();
+
+    let switch = Switch::DISABLE_ALL - Switch::DISABLE_NON_CONTEXTUAL;
+    let (sender, receiver) = ckb_channel::bounded(16);
+    let mut responses = Vec::new();
+    for block in chain.blocks().iter().chain(vec![&invalid, &orphan]) {
+        let (responder, response) = std::sync::mpsc::channel();
+        sender
+            .send(Request {
+                responder,
+                arguments: (Arc::new(block.clone()), switch),
+            })
+            .unwrap();
+        responses.push(response);
+    }
+    let first = receiver.recv().unwrap();
+    chain_service.process_block_requests(first, &receiver);
+
+    let results: Vec<_> = responses
+        .into_iter()
+        .map(|response| response.recv().unwrap())
+        .collect();
+    for result in &results[..4] {
+        assert!(result.as_ref().expect("process block ok"));
+    }
+    assert!(results[4].is_err());
+    assert!(results[5].is_err());
+    assert_eq!(shared.snapshot().tip_hash(), chain.tip().hash());
+    assert!(shared.store().get_block(&invalid.hash()).is_none());
+}
@@ -67,6 +67,8 @@ pub fn run_app(version: Version) -> Result<(), ExitCode> {
         cli::CMD_RESET_DATA => subcommand::reset_data(setup.reset_data(matches)?),
         cli::CMD_MIGRATE => subcommand::migrate(setup.migrate(matches)?),
         cli::CMD_DB_REPAIR => subcommand::db_repair(setup.db_repair(matches)?),
+        cli::CMD_DB_STATS => subcommand::db_stats(setup.db_stats(matches)?, handle),
+        cli::CMD_DB_VERIFY => subcommand::db_verify(setup.db_verify(matches)?, handle),
         _ => unreachable!(),
     };
 
@@ -84,6 +86,8 @@ fn is_silent_logging(cmd: &str) -> Silent {
             | cli::CMD_STATS
             | cli::CMD_MIGRATE
             | cli::CMD_DB_REPAIR
+            | cli::CMD_DB_STATS
+            | cli::CMD_DB_VERIFY
             | cli::CMD_RESET_DATA
     )
 }
@@ -0,0 +1,73 @@
+use ckb_app_config::{DBStatsArgs, ExitCode};
+use ckb_async_runtime::Handle;
+use ckb_db_schema::ALL_COLUMNS;
+use ckb_launcher::SharedBuilder;
+use ckb_memory_tracker::GatherRocksDBStats;
+use ckb_store::ChainStore;
+
+pub fn db_stats(args: DBStatsArgs, async_handle: Handle) -> Result<(), ExitCode> {
+    let shared_builder = SharedBuilder::new(
+        &args.config.bin_name,
+        args.config.root_dir.as_path(),
+        &args.config.db,
+        Some(args.config.ancient.clone()),
+        async_handle,
+    )?;
+    let (shared, _) = shared_builder
+        .consensus(args.consensus)
+        .store_config(args.config.store)
+        .build()?;
+    let store = shared.store();
+
+    for column in &args.compact {
+        let col = ALL_COLUMNS
+            .iter()
+            .copied()
+            .find(|col| **col == *column)
+            .ok_or_else(|| {
+                eprintln!("column {} does not exist", column);
+                ExitCode::Cli
+            })?;
+        println!("compacting column {} ...", col);
+        store.compact_range(col, None, None).map_err(|err| {
+            eprintln!("compact column {} error: {}", col, err);
+            ExitCode::Failure
+        })?;
+    }
+
+    let format_value = |value: Option<u64>| value.map_or_else(|| "-".to_owned(), |v| v.to_string());
+    println!("columns:");
+    println!(
+        "{0: <6} | {1: <14} | {2: <16} | {3: <16} | {4: <18} | {5: <16}",
+        "column",
+        "estimate_keys",
+        "live_sst_size",
+        "total_sst_size",
+        "pending_compaction",
+        "block_cache_usage"
+    );
+    for cf in store.db().inner().gather_column_family_stats() {
+        println!(
+            "{0: <6} | {1: <14} | {2: <16} | {3: <16} | {4: <18} | {5: <16}",
+            cf.name,
+            format_value(cf.estimate_num_keys),
+            format_value(cf.live_sst_files_size),
+            format_value(cf.total_sst_files_size),
+            format_value(cf.estimate_pending_compaction_bytes),
+            format_value(cf.block_cache_usage),
+        );
+    }
+
+    if let Some(freezer) = store.freezer() {
+        let sizes = freezer.table_sizes().map_err(|err| {
+            eprintln!("freezer error: {}", err);
+            ExitCode::IO
+        })?;
+        println!("freezer:");
+        println!("{0: <10} | {1: <16}", "table", "size");
+        for (table, size) in sizes {
+            println!("{0: <10} | {1: <16}", table.name(), size);
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,82 @@
+use ckb_app_config::{DBVerifyArgs, ExitCode};
+use ckb_async_runtime::Handle;
+use ckb_db::RocksDB;
+use ckb_db_schema::COLUMNS;
+use ckb_launcher::SharedBuilder;
+use ckb_store::{ChainDB, DBVerifier};
+use ckb_types::bytes::Bytes;
+
+pub fn db_verify(args: DBVerifyArgs, async_handle: Handle) -> Result<(), ExitCode> {
+    let shared_builder = SharedBuilder::new(
+        &args.config.bin_name,
+        args.config.root_dir.as_path(),
+        &args.config.db,
+        Some(args.config.ancient.clone()),
+        async_handle,
+    )?;
+    let (shared, _) = shared_builder
+        .consensus(args.consensus)
+        .store_config(args.config.store)
+        .build()?;
+
+    if !args.tmp_target.is_dir() {
+        eprintln!(
+            "db-verify error: {:?}",
+            "The specified path does not exist or not directory"
+        );
+        return Err(ExitCode::Failure);
+    }
+    let tmp_db_dir = tempfile::tempdir_in(args.tmp_target).map_err(|err| {
+        eprintln!("db-verify error: {:?}", err);
+        ExitCode::Failure
+    })?;
+
+    let count = {
+        let scratch = ChainDB::new(
+            RocksDB::open_in(tmp_db_dir.path(), COLUMNS),
+            Default::default(),
+        );
+        let verifier = DBVerifier::new(shared.store(), &scratch);
+
+        println!("rebuilding from the main chain blocks ...");
+        verifier
+            .rebuild(|number| println!("rebuilt up to block {}", number))
+            .map_err(|err| {
+                eprintln!("db-verify error: {}", err);
+                ExitCode::Failure
+            })?;
+
+        verifier
+            .diff(args.fix, |discrepancy| {
+                println!(
+                    "column {} {:?} key {:x}",
+                    discrepancy.column,
+                    discrepancy.kind,
+                    Bytes::from(discrepancy.key.clone())
+                );
+            })
+            .map_err(|err| {
+                eprintln!("db-verify error: {}", err);
+                ExitCode::Failure
+            })?
+    };
+
+    tmp_db_dir.close().map_err(|err| {
+        eprintln!("db-verify error: {:?}", err);
+        ExitCode::Failure
+    })?;
+
+    if count == 0 {
+        println!("no discrepancy found");
+        Ok(())
+    } else if args.fix {
+        println!("{} discrepancies found and fixed", count);
+        Ok(())
+    } else {
+        println!(
+            "{} discrepancies found, run with --fix to overwrite them with the rebuilt entries",
+            count
+        );
+        Err(ExitCode::Failure)
+    }
+}
@@ -1,11 +1,15 @@
 use ckb_app_config::{ExitCode, MigrateArgs};
+use ckb_db_migration::{PendingMigration, ProgressFormat};
 use ckb_launcher::migrate::Migrate;
 use std::cmp::Ordering;
 
 use crate::helper::prompt;
 
 pub fn migrate(args: MigrateArgs) -> Result<(), ExitCode> {
-    let migrate = Migrate::new(&args.config.db.path);
+    let mut migrate = Migrate::new(&args.config.db.path, Some(args.config.ancient.clone()));
+    if args.json_progress {
+        migrate.set_progress_format(ProgressFormat::Json);
+    }
 
     {
         let read_only_db = migrate.open_read_only_db().map_err(|e| {
@@ -32,6 +36,11 @@ pub fn migrate(args: MigrateArgs) -> Result<(), ExitCode> {
                 }
             }
 
+            if args.dry_run {
+                print_pending(&migrate.pending(&db));
+                return Ok(());
+            }
+
             if matches!(db_status, Ordering::Equal) {
                 return Ok(());
             }
@@ -60,6 +69,11 @@ pub fn migrate(args: MigrateArgs) -> Result<(), ExitCode> {
         }
     }
 
+    if args.dry_run {
+        print_pending(&[]);
+        return Ok(());
+    }
+
     let bulk_load_db_db = migrate.open_bulk_load_db().map_err(|e| {
         eprintln!("migrate error {}", e);
         ExitCode::Failure
@@ -73,3 +87,29 @@ pub fn migrate(args: MigrateArgs) -> Result<(), ExitCode> {
     }
     Ok(())
 }
+
+fn print_pending(pending: &[PendingMigration]) {
+    if pending.is_empty() {
+        println!("no pending migrations");
+        return;
+    }
+    println!(
+        "{0: <16} | {1: <9} | {2: <14} | {3: <10}",
+        "version", "expensive", "estimated_work", "checkpoint"
+    );
+    for migration in pending {
+        println!(
+            "{0: <16} | {1: <9} | {2: <14} | {3: <10}",
+            migration.version,
+            migration.expensive,
+            migration
+                .estimated_work
+                .map_or_else(|| "-".to_owned(), |work| work.to_string()),
+            if migration.has_checkpoint {
+                "resume"
+            } else {
+                "-"
+            },
+        );
+    }
+}
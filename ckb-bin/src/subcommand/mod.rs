@@ -1,4 +1,6 @@
 mod db_repair;
+mod db_stats;
+mod db_verify;
 mod export;
 mod import;
 mod init;
@@ -12,6 +14,8 @@ mod run;
 mod stats;
 
 pub use self::db_repair::db_repair;
+pub use self::db_stats::db_stats;
+pub use self::db_verify::db_verify;
 pub use self::export::export;
 pub use self::import::import;
 pub use self::init::init;
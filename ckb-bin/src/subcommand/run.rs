@@ -54,6 +54,13 @@ pub fn run(args: RunArgs, version: Version, async_handle: Handle) -> Result<(),
     let tx_pool_builder = pack.take_tx_pool_builder();
     tx_pool_builder.start(network_controller.non_owning_clone());
 
+    let stratum_server = launcher.start_stratum(
+        &shared,
+        chain_controller.non_owning_clone(),
+        network_controller.non_owning_clone(),
+        miner_enable,
+    )?;
+
     let exit_handler_clone = exit_handler.clone();
     ctrlc::set_handler(move || {
         exit_handler_clone.notify_exit();
@@ -67,6 +74,7 @@ pub fn run(args: RunArgs, version: Version, async_handle: Handle) -> Result<(),
         ExitCode::Failure
     })?;
 
+    drop(stratum_server);
     drop(rpc_server);
     drop(network_controller);
     drop(chain_controller);
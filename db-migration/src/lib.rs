@@ -1,6 +1,8 @@
 //! TODO(doc): @quake
-use ckb_db::{ReadOnlyDB, RocksDB};
-use ckb_db_schema::{COLUMN_META, META_TIP_HEADER_KEY, MIGRATION_VERSION_KEY};
+use ckb_db::{ReadOnlyDB, RocksDB, RocksDBWriteBatch};
+use ckb_db_schema::{
+    COLUMN_META, META_TIP_HEADER_KEY, MIGRATION_CHECKPOINT_KEY_PREFIX, MIGRATION_VERSION_KEY,
+};
 use ckb_error::{Error, InternalErrorKind};
 use ckb_logger::{debug, error, info};
 use console::Term;
@@ -9,17 +11,53 @@ use std::cmp::Ordering;
 use std::collections::BTreeMap;
 use std::sync::Arc;
 
+mod progress;
 #[cfg(test)]
 mod tests;
 
+use progress::JsonProgress;
+
+// AddExtraDataHash - 1, see patch 220464f
+const PATCH_220464F_VERSION: &str = "20210609195048";
+
 fn internal_error(reason: String) -> Error {
     InternalErrorKind::Database.other(reason).into()
 }
 
+/// How the progress of running migrations is reported
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ProgressFormat {
+    /// Progress bars drawn on the terminal
+    Bar,
+    /// One JSON object per line on stdout, for monitoring by other programs
+    Json,
+}
+
+impl Default for ProgressFormat {
+    fn default() -> Self {
+        ProgressFormat::Bar
+    }
+}
+
+/// A migration which has not been applied to the database yet, reported by dry-run
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PendingMigration {
+    /// Migration version
+    pub version: String,
+    /// Whether the migration costs a lot of time
+    pub expensive: bool,
+    /// Estimated amount of work, see `Migration::estimate`
+    pub estimated_work: Option<u64>,
+    /// Whether an interrupted run left a checkpoint to resume from, always false for the
+    /// migrations which don't save checkpoints
+    pub has_checkpoint: bool,
+}
+
 /// TODO(doc): @quake
 #[derive(Default)]
 pub struct Migrations {
     migrations: BTreeMap<String, Box<dyn Migration>>,
+    progress_format: ProgressFormat,
 }
 
 impl Migrations {
@@ -27,9 +65,15 @@ impl Migrations {
     pub fn new() -> Self {
         Migrations {
             migrations: BTreeMap::new(),
+            progress_format: ProgressFormat::default(),
         }
     }
 
+    /// Set how the progress of running migrations is reported
+    pub fn set_progress_format(&mut self, format: ProgressFormat) {
+        self.progress_format = format;
+    }
+
     /// TODO(doc): @quake
     pub fn add_migration(&mut self, migration: Box<dyn Migration>) {
         self.migrations
@@ -45,22 +89,9 @@ impl Migrations {
     /// - Greater: The database version is greater than the matched version of the executable binary.
     ///   Requires upgrade the executable binary.
     pub fn check(&self, db: &ReadOnlyDB) -> Ordering {
-        let db_version = match db
-            .get_pinned_default(MIGRATION_VERSION_KEY)
-            .expect("get the version of database")
-        {
-            Some(version_bytes) => {
-                String::from_utf8(version_bytes.to_vec()).expect("version bytes to utf8")
-            }
-            None => {
-                // if version is none, but db is not empty
-                // patch 220464f
-                if self.is_non_empty_rdb(db) {
-                    return Ordering::Less;
-                } else {
-                    return Ordering::Equal;
-                }
-            }
+        let db_version = match self.read_only_db_version(db) {
+            Some(version) => version,
+            None => return Ordering::Equal,
         };
         debug!("current database version [{}]", db_version);
 
@@ -77,24 +108,58 @@ impl Migrations {
 
     /// Check if the migrations will consume a lot of time.
     pub fn expensive(&self, db: &ReadOnlyDB) -> bool {
-        let db_version = match db
+        self.pending_migrations(db).any(|m| m.expensive())
+    }
+
+    /// Returns the migrations which `migrate` would perform, without touching the database.
+    pub fn pending(&self, db: &ReadOnlyDB) -> Vec<PendingMigration> {
+        self.pending_migrations(db)
+            .map(|m| PendingMigration {
+                version: m.version().to_string(),
+                expensive: m.expensive(),
+                estimated_work: m.estimate(db),
+                has_checkpoint: db
+                    .get_pinned(COLUMN_META, &Checkpoint::new(m.version()).key())
+                    .map(|value| value.is_some())
+                    .unwrap_or(false),
+            })
+            .collect()
+    }
+
+    // The migrations newer than the database version
+    fn pending_migrations<'a>(
+        &'a self,
+        db: &ReadOnlyDB,
+    ) -> impl Iterator<Item = &'a dyn Migration> + 'a {
+        let db_version = self.read_only_db_version(db);
+        self.migrations
+            .values()
+            .map(AsRef::as_ref)
+            .skip_while(move |m| match db_version {
+                Some(ref version) => m.version() <= version.as_str(),
+                None => true,
+            })
+    }
+
+    // Returns `None` for an empty database, which requires no migrations
+    fn read_only_db_version(&self, db: &ReadOnlyDB) -> Option<String> {
+        match db
             .get_pinned_default(MIGRATION_VERSION_KEY)
             .expect("get the version of database")
         {
             Some(version_bytes) => {
-                String::from_utf8(version_bytes.to_vec()).expect("version bytes to utf8")
+                Some(String::from_utf8(version_bytes.to_vec()).expect("version bytes to utf8"))
             }
             None => {
                 // if version is none, but db is not empty
                 // patch 220464f
-                return self.is_non_empty_rdb(db);
+                if self.is_non_empty_rdb(db) {
+                    Some(PATCH_220464F_VERSION.to_string())
+                } else {
+                    None
+                }
             }
-        };
-
-        self.migrations
-            .values()
-            .skip_while(|m| m.version() <= db_version.as_str())
-            .any(|m| m.expensive())
+        }
     }
 
     fn is_non_empty_rdb(&self, db: &ReadOnlyDB) -> bool {
@@ -124,20 +189,33 @@ impl Migrations {
             .collect();
         let migrations_count = migrations.len();
         for (idx, (_, m)) in migrations.iter().enumerate() {
-            let mpbc = Arc::clone(&mpb);
-            let pb = move |count: u64| -> ProgressBar {
-                let pb = mpbc.add(ProgressBar::new(count));
-                pb.set_draw_target(ProgressDrawTarget::term(Term::stdout(), None));
-                pb.set_prefix(format!("[{}/{}]", idx + 1, migrations_count));
-                pb
+            db = match self.progress_format {
+                ProgressFormat::Bar => {
+                    let mpbc = Arc::clone(&mpb);
+                    let pb = move |count: u64| -> ProgressBar {
+                        let pb = mpbc.add(ProgressBar::new(count));
+                        pb.set_draw_target(ProgressDrawTarget::term(Term::stdout(), None));
+                        pb.set_prefix(format!("[{}/{}]", idx + 1, migrations_count));
+                        pb
+                    };
+                    m.migrate(db, Arc::new(pb))?
+                }
+                ProgressFormat::Json => {
+                    let progress = JsonProgress::start(idx + 1, migrations_count, m.version());
+                    let result = m.migrate(db, progress.progress_bar_factory());
+                    progress.finish(result.as_ref().err());
+                    result?
+                }
             };
-            db = m.migrate(db, Arc::new(pb))?;
             db.put_default(MIGRATION_VERSION_KEY, m.version())
                 .map_err(|err| {
                     internal_error(format!("failed to migrate the database: {}", err))
                 })?;
+            Checkpoint::new(m.version()).clear(&db)?;
+        }
+        if self.progress_format == ProgressFormat::Bar {
+            mpb.join_and_clear().expect("MultiProgress join");
         }
-        mpb.join_and_clear().expect("MultiProgress join");
         Ok(db)
     }
 
@@ -202,14 +280,62 @@ impl Migrations {
     }
 
     fn patch_220464f(&self, db: RocksDB) -> Result<RocksDB, Error> {
-        const V: &str = "20210609195048"; // AddExtraDataHash - 1
-        self.run_migrate(db, V)
+        self.run_migrate(db, PATCH_220464F_VERSION)
+    }
+}
+
+/// Progress checkpoint of a migration, persisted in `COLUMN_META`.
+///
+/// Checkpoints are opt-in: a migration which saves a checkpoint as it goes resumes from there
+/// when interrupted, other migrations start over from scratch. `Migrations` clears the
+/// checkpoint once the migration is done.
+#[derive(Copy, Clone, Debug)]
+pub struct Checkpoint<'a> {
+    version: &'a str,
+}
+
+impl<'a> Checkpoint<'a> {
+    /// Checkpoint of the migration with the given version
+    pub const fn new(version: &'a str) -> Self {
+        Checkpoint { version }
+    }
+
+    fn key(&self) -> Vec<u8> {
+        [MIGRATION_CHECKPOINT_KEY_PREFIX, self.version.as_bytes()].concat()
+    }
+
+    /// Returns the saved checkpoint, if any
+    pub fn get(&self, db: &RocksDB) -> Result<Option<Vec<u8>>, Error> {
+        db.get_pinned(COLUMN_META, &self.key())
+            .map(|value| value.map(|value| value.to_vec()))
+    }
+
+    /// Saves the checkpoint
+    pub fn set(&self, db: &RocksDB, value: &[u8]) -> Result<(), Error> {
+        let mut wb = db.new_write_batch();
+        self.put(&mut wb, value)?;
+        db.write(&wb)
+    }
+
+    /// Saves the checkpoint along with the batch, so that it is in line with the migrated data
+    pub fn put(&self, wb: &mut RocksDBWriteBatch, value: &[u8]) -> Result<(), Error> {
+        wb.put(COLUMN_META, &self.key(), value)
+    }
+
+    /// Removes the saved checkpoint
+    pub fn clear(&self, db: &RocksDB) -> Result<(), Error> {
+        let mut wb = db.new_write_batch();
+        wb.delete(COLUMN_META, &self.key())?;
+        db.write(&wb)
     }
 }
 
 /// TODO(doc): @quake
 pub trait Migration {
-    /// TODO(doc): @quake
+    /// Performs the migration.
+    ///
+    /// Save a [`Checkpoint`] along with the migrated data to make an interrupted migration
+    /// resumable, otherwise it is performed again from the beginning.
     fn migrate(
         &self,
         _db: RocksDB,
@@ -225,6 +351,14 @@ pub trait Migration {
     fn expensive(&self) -> bool {
         true
     }
+
+    /// Estimated amount of work to perform this migration, e.g. the number of blocks to process,
+    /// reported by `ckb migrate --dry-run`.
+    ///
+    /// Returns `None` if unknown.
+    fn estimate(&self, _db: &ReadOnlyDB) -> Option<u64> {
+        None
+    }
 }
 
 /// TODO(doc): @quake
@@ -0,0 +1,120 @@
+//! Machine-readable migration progress, printed as one JSON object per line on stdout.
+use ckb_error::Error;
+use indicatif::{ProgressBar, ProgressDrawTarget};
+use serde_json::{json, Value};
+use std::sync::mpsc::{self, RecvTimeoutError, Sender};
+use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+const REPORT_INTERVAL: Duration = Duration::from_secs(5);
+
+type Bars = Arc<Mutex<Vec<ProgressBar>>>;
+
+fn emit(value: Value) {
+    println!("{}", value);
+}
+
+// sum up the bars of all threads of a migration
+fn position_and_length(bars: &Bars) -> (u64, u64) {
+    bars.lock()
+        .expect("progress bars lock")
+        .iter()
+        .fold((0, 0), |(position, length), pb| {
+            (position + pb.position(), length + pb.length())
+        })
+}
+
+/// Reports the progress of a running migration periodically.
+pub(crate) struct JsonProgress {
+    index: usize,
+    total: usize,
+    version: String,
+    bars: Bars,
+    started_at: Instant,
+    stop: Option<Sender<()>>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl JsonProgress {
+    pub(crate) fn start(index: usize, total: usize, version: &str) -> Self {
+        emit(json!({
+            "event": "start",
+            "index": index,
+            "total": total,
+            "version": version,
+        }));
+
+        let bars: Bars = Arc::new(Mutex::new(Vec::new()));
+        let (stop, stop_rx) = mpsc::channel::<()>();
+        let handle = {
+            let bars = Arc::clone(&bars);
+            let version = version.to_string();
+            thread::spawn(move || {
+                while let Err(RecvTimeoutError::Timeout) = stop_rx.recv_timeout(REPORT_INTERVAL) {
+                    let (position, length) = position_and_length(&bars);
+                    emit(json!({
+                        "event": "progress",
+                        "index": index,
+                        "total": total,
+                        "version": version,
+                        "position": position,
+                        "length": length,
+                    }));
+                }
+            })
+        };
+
+        JsonProgress {
+            index,
+            total,
+            version: version.to_string(),
+            bars,
+            started_at: Instant::now(),
+            stop: Some(stop),
+            handle: Some(handle),
+        }
+    }
+
+    /// Hidden progress bars, whose positions are collected by the reporter
+    pub(crate) fn progress_bar_factory(&self) -> Arc<dyn Fn(u64) -> ProgressBar + Send + Sync> {
+        let bars = Arc::clone(&self.bars);
+        Arc::new(move |count: u64| -> ProgressBar {
+            let pb = ProgressBar::with_draw_target(count, ProgressDrawTarget::hidden());
+            bars.lock().expect("progress bars lock").push(pb.clone());
+            pb
+        })
+    }
+
+    pub(crate) fn finish(mut self, error: Option<&Error>) {
+        self.stop();
+        let (position, length) = position_and_length(&self.bars);
+        let mut report = json!({
+            "event": if error.is_some() { "error" } else { "done" },
+            "index": self.index,
+            "total": self.total,
+            "version": self.version,
+            "position": position,
+            "length": length,
+            "elapsed_secs": self.started_at.elapsed().as_secs(),
+        });
+        if let Some(error) = error {
+            report["error"] = json!(error.to_string());
+        }
+        emit(report);
+    }
+
+    fn stop(&mut self) {
+        // dropping the sender wakes the reporter up
+        self.stop.take();
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+impl Drop for JsonProgress {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
@@ -1,11 +1,11 @@
 use ckb_app_config::DBConfig;
-use ckb_db::RocksDB;
-use ckb_db_schema::MIGRATION_VERSION_KEY;
-use ckb_error::Error;
+use ckb_db::{ReadOnlyDB, RocksDB};
+use ckb_db_schema::{COLUMN_META, MIGRATION_VERSION_KEY};
+use ckb_error::{Error, InternalErrorKind};
 use indicatif::ProgressBar;
 use std::sync::Arc;
 
-use crate::{DefaultMigration, Migration, Migrations};
+use crate::{Checkpoint, DefaultMigration, Migration, Migrations, PendingMigration};
 
 #[test]
 fn test_default_migration() {
@@ -120,3 +120,85 @@ fn test_customized_migration() {
         );
     }
 }
+
+#[test]
+fn test_resume_migration_from_checkpoint() {
+    struct InterruptedMigration;
+    const VERSION: &str = "20191127101121";
+
+    impl Migration for InterruptedMigration {
+        fn migrate(
+            &self,
+            db: RocksDB,
+            _pb: Arc<dyn Fn(u64) -> ProgressBar + Send + Sync>,
+        ) -> Result<RocksDB, Error> {
+            let checkpoint = Checkpoint::new(VERSION);
+            match checkpoint.get(&db)? {
+                Some(ref value) if value.as_slice() == b"half" => Ok(db),
+                _ => {
+                    // the first run is interrupted halfway
+                    checkpoint.set(&db, b"half")?;
+                    Err(InternalErrorKind::Database.other("interrupted").into())
+                }
+            }
+        }
+
+        fn version(&self) -> &str {
+            VERSION
+        }
+
+        fn estimate(&self, _db: &ReadOnlyDB) -> Option<u64> {
+            Some(100)
+        }
+    }
+
+    let tmp_dir = tempfile::Builder::new()
+        .prefix("test_resume_migration_from_checkpoint")
+        .tempdir()
+        .unwrap();
+    let config = DBConfig {
+        path: tmp_dir.as_ref().to_path_buf(),
+        ..Default::default()
+    };
+    {
+        let mut migrations = Migrations::default();
+        migrations.add_migration(Box::new(DefaultMigration::new("20191116225943")));
+        let db = RocksDB::open(&config, 5);
+        migrations.init_db_version(&db).unwrap();
+    }
+
+    let mut migrations = Migrations::default();
+    migrations.add_migration(Box::new(DefaultMigration::new("20191116225943")));
+    migrations.add_migration(Box::new(InterruptedMigration));
+    let pending = || {
+        let db = ReadOnlyDB::open_cf(&config.path, vec![COLUMN_META])
+            .unwrap()
+            .unwrap();
+        migrations.pending(&db)
+    };
+    let expected = |has_checkpoint: bool| {
+        vec![PendingMigration {
+            version: VERSION.to_string(),
+            expensive: true,
+            estimated_work: Some(100),
+            has_checkpoint,
+        }]
+    };
+
+    assert_eq!(pending(), expected(false));
+    assert!(migrations.migrate(RocksDB::open(&config, 5)).is_err());
+    assert_eq!(pending(), expected(true));
+
+    let db = migrations.migrate(RocksDB::open(&config, 5)).unwrap();
+    assert_eq!(
+        VERSION.as_bytes(),
+        db.get_pinned_default(MIGRATION_VERSION_KEY)
+            .unwrap()
+            .unwrap()
+            .to_vec()
+            .as_slice()
+    );
+    assert!(Checkpoint::new(VERSION).get(&db).unwrap().is_none());
+    drop(db);
+    assert!(pending().is_empty());
+}
@@ -3,7 +3,7 @@
 /// Column families alias type
 pub type Col = &'static str;
 /// Total column number
-pub const COLUMNS: u32 = 16;
+pub const COLUMNS: u32 = 17;
 /// Column store chain index
 pub const COLUMN_INDEX: Col = "0";
 /// Column store block's header
@@ -38,13 +38,41 @@ pub const COLUMN_NUMBER_HASH: Col = "13";
 pub const COLUMN_CELL_DATA_HASH: Col = "14";
 /// Column store block extension data
 pub const COLUMN_BLOCK_EXTENSION: Col = "15";
+/// Column store block filter header and block filter data
+pub const COLUMN_BLOCK_FILTER: Col = "16";
+
+/// All column families, indexed by their names
+pub const ALL_COLUMNS: [Col; COLUMNS as usize] = [
+    COLUMN_INDEX,
+    COLUMN_BLOCK_HEADER,
+    COLUMN_BLOCK_BODY,
+    COLUMN_BLOCK_UNCLE,
+    COLUMN_META,
+    COLUMN_TRANSACTION_INFO,
+    COLUMN_BLOCK_EXT,
+    COLUMN_BLOCK_PROPOSAL_IDS,
+    COLUMN_BLOCK_EPOCH,
+    COLUMN_EPOCH,
+    COLUMN_CELL,
+    COLUMN_UNCLES,
+    COLUMN_CELL_DATA,
+    COLUMN_NUMBER_HASH,
+    COLUMN_CELL_DATA_HASH,
+    COLUMN_BLOCK_EXTENSION,
+    COLUMN_BLOCK_FILTER,
+];
 
 /// META_TIP_HEADER_KEY tracks the latest known best block header
 pub const META_TIP_HEADER_KEY: &[u8] = b"TIP_HEADER";
 /// META_CURRENT_EPOCH_KEY tracks the latest known epoch
 pub const META_CURRENT_EPOCH_KEY: &[u8] = b"CURRENT_EPOCH";
+/// META_INVALIDATED_BLOCKS_KEY tracks the blocks invalidated by the local node
+pub const META_INVALIDATED_BLOCKS_KEY: &[u8] = b"INVALIDATED_BLOCKS";
 
 /// CHAIN_SPEC_HASH_KEY tracks the hash of chain spec which created current database
 pub const CHAIN_SPEC_HASH_KEY: &[u8] = b"chain-spec-hash";
 /// MIGRATION_VERSION_KEY tracks the current database version.
 pub const MIGRATION_VERSION_KEY: &[u8] = b"db-version";
+/// MIGRATION_CHECKPOINT_KEY_PREFIX + migration version tracks the progress of an unfinished migration,
+/// stored in `COLUMN_META`.
+pub const MIGRATION_CHECKPOINT_KEY_PREFIX: &[u8] = b"migration-checkpoint-";
@@ -6,15 +6,17 @@ use crate::{internal_error, Result};
 use ckb_app_config::DBConfig;
 use ckb_db_schema::Col;
 use ckb_logger::info;
+use once_cell::sync::OnceCell;
 use rocksdb::ops::{
     CompactRangeCF, CreateCF, DropCF, GetColumnFamilys, GetPinned, GetPinnedCF, IterateCF, OpenCF,
     Put, SetOptions, WriteOps,
 };
 use rocksdb::{
-    ffi, ColumnFamily, ColumnFamilyDescriptor, DBPinnableSlice, FullOptions, IteratorMode,
-    OptimisticTransactionDB, OptimisticTransactionOptions, Options, WriteBatch, WriteOptions,
+    ffi, ColumnFamily, ColumnFamilyDescriptor, DBPinnableSlice, Env, FullOptions, IteratorMode,
+    OptimisticTransactionDB, OptimisticTransactionOptions, Options, WriteBatch, WriteOptions, DB,
 };
 use std::path::Path;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
 
 /// RocksDB wrapper base on OptimisticTransactionDB
@@ -23,10 +25,40 @@ use std::sync::Arc;
 #[derive(Clone)]
 pub struct RocksDB {
     pub(crate) inner: Arc<OptimisticTransactionDB>,
+    // declared after `inner`, so that the files are destroyed after the db is closed
+    pub(crate) memory_files: Option<Arc<MemoryFiles>>,
 }
 
 const DEFAULT_CACHE_SIZE: usize = 128 << 20;
 
+static MEMORY_DB_COUNT: AtomicUsize = AtomicUsize::new(0);
+
+// All in-memory databases share one env which lives as long as the process,
+// so that no database could outlive the env it runs on.
+fn memory_env() -> &'static Env {
+    static MEMORY_ENV: OnceCell<Env> = OnceCell::new();
+    MEMORY_ENV.get_or_init(|| Env::mem_env().expect("create rocksdb memory env"))
+}
+
+/// The files of an in-memory database, destroyed once all handles of the database are dropped.
+pub(crate) struct MemoryFiles {
+    path: String,
+}
+
+impl Drop for MemoryFiles {
+    fn drop(&mut self) {
+        let mut opts = Options::default();
+        opts.set_env(memory_env());
+        // fails if the db is still opened by a snapshot or transaction, the files are leaked then
+        if let Err(err) = DB::destroy(&opts, &self.path) {
+            info!(
+                "failed to destroy in-memory database {}: {}",
+                self.path, err
+            );
+        }
+    }
+}
+
 impl RocksDB {
     pub(crate) fn open_with_check(config: &DBConfig, columns: u32) -> Result<Self> {
         let cf_names: Vec<_> = (0..columns).map(|c| c.to_string()).collect();
@@ -80,6 +112,7 @@ impl RocksDB {
 
         Ok(RocksDB {
             inner: Arc::new(db),
+            memory_files: None,
         })
     }
 
@@ -108,6 +141,31 @@ impl RocksDB {
         Self::open_with_check(&config, columns).unwrap_or_else(|err| panic!("{}", err))
     }
 
+    /// Open a database which keeps all its files in memory, with the given columns count.
+    ///
+    /// It supports everything an on-disk database does, snapshots, write batches and
+    /// optimistic transactions included, and is gone once all its handles are dropped.
+    pub fn open_in_memory(columns: u32) -> Self {
+        let path = format!(
+            "/ckb-memory-db-{}",
+            MEMORY_DB_COUNT.fetch_add(1, Ordering::SeqCst)
+        );
+        let cf_names: Vec<_> = (0..columns).map(|c| c.to_string()).collect();
+
+        let mut opts = Options::default();
+        opts.create_if_missing(true);
+        opts.create_missing_column_families(true);
+        opts.set_env(memory_env());
+
+        let db = OptimisticTransactionDB::open_cf(&opts, &path, &cf_names)
+            .unwrap_or_else(|err| panic!("failed to open in-memory database: {}", err));
+
+        RocksDB {
+            inner: Arc::new(db),
+            memory_files: Some(Arc::new(MemoryFiles { path })),
+        }
+    }
+
     /// Set appropriate parameters for bulk loading.
     pub fn prepare_for_bulk_load_open<P: AsRef<Path>>(
         path: P,
@@ -148,6 +206,7 @@ impl RocksDB {
             |db| {
                 Ok(Some(RocksDB {
                     inner: Arc::new(db),
+                    memory_files: None,
                 }))
             },
         )
@@ -137,3 +137,27 @@ fn write_and_partial_read() {
 
     assert!(vec![4u8, 3, 2].as_slice() == &ret.as_ref()[1..4]);
 }
+
+#[test]
+fn in_memory_write_and_read() {
+    let db = RocksDB::open_in_memory(2);
+    let other = RocksDB::open_in_memory(2);
+
+    let snapshot = db.get_snapshot();
+    let txn = db.transaction();
+    txn.put("0", &[0, 0], &[0, 0, 0]).unwrap();
+    txn.put("1", &[1, 1], &[1, 1, 1]).unwrap();
+    txn.commit().unwrap();
+
+    let mut batch = db.new_write_batch();
+    batch.put("1", &[2], &[2, 2]).unwrap();
+    db.write(&batch).unwrap();
+
+    assert!(vec![0u8, 0, 0].as_slice() == db.get_pinned("0", &[0, 0]).unwrap().unwrap().as_ref());
+    assert!(vec![1u8, 1, 1].as_slice() == db.get_pinned("1", &[1, 1]).unwrap().unwrap().as_ref());
+    assert!(vec![2u8, 2].as_slice() == db.get_pinned("1", &[2]).unwrap().unwrap().as_ref());
+    assert!(snapshot.get_pinned("0", &[0, 0]).unwrap().is_none());
+
+    // each in-memory database has its own files
+    assert!(other.get_pinned("0", &[0, 0]).unwrap().is_none());
+}
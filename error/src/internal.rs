@@ -47,6 +47,10 @@ pub enum InternalErrorKind {
     /// The feature is disabled or is conflicted with the configuration
     Config,
 
+    /// The block descends from a block invalidated by the local node, it isn't invalid by
+    /// itself
+    Invalidated,
+
     /// Other system error
     Other,
 }
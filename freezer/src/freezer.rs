@@ -2,7 +2,7 @@ use crate::freezer_files::FreezerFiles;
 use crate::internal_error;
 use ckb_error::Error;
 use ckb_types::{
-    core::{BlockNumber, BlockView, HeaderView},
+    core::{BlockExt, BlockNumber, BlockView, HeaderView},
     packed,
     prelude::*,
 };
@@ -20,16 +20,74 @@ const LOCKNAME: &str = "FLOCK";
 /// sorted blkhash for making ranges for compaction
 type FreezeResult = BTreeMap<packed::Byte32, (BlockNumber, u32)>;
 
+/// Kinds of immutable per-block data, each kind is stored in its own set of freezer files
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum FreezerTable {
+    /// Raw blocks
+    Blocks = 0,
+    /// Block headers, packed as `HeaderView`
+    Headers = 1,
+    /// Block exts
+    BlockExts = 2,
+    /// Block uncles, packed as `UncleBlockVecView`
+    Uncles = 3,
+    /// Block extensions, empty for blocks without extension
+    Extensions = 4,
+}
+
+impl FreezerTable {
+    /// All tables, in the order in which an item is appended
+    pub const ALL: [FreezerTable; 5] = [
+        FreezerTable::Blocks,
+        FreezerTable::Headers,
+        FreezerTable::BlockExts,
+        FreezerTable::Uncles,
+        FreezerTable::Extensions,
+    ];
+
+    /// Table name
+    pub fn name(self) -> &'static str {
+        match self {
+            FreezerTable::Blocks => "blocks",
+            FreezerTable::Headers => "headers",
+            FreezerTable::BlockExts => "block_exts",
+            FreezerTable::Uncles => "uncles",
+            FreezerTable::Extensions => "extensions",
+        }
+    }
+
+    // Blocks are kept at the root of the freezer, compatible with the single-table layout
+    fn path(self, root: &Path) -> PathBuf {
+        match self {
+            FreezerTable::Blocks => root.to_path_buf(),
+            _ => root.join(self.name()),
+        }
+    }
+}
+
 struct Inner {
-    pub(crate) files: FreezerFiles,
+    pub(crate) tables: Vec<FreezerFiles>,
     pub(crate) tip: Option<HeaderView>,
 }
 
+impl Inner {
+    fn table(&mut self, table: FreezerTable) -> &mut FreezerFiles {
+        &mut self.tables[table as usize]
+    }
+
+    fn sync_all(&self) -> Result<(), Error> {
+        for files in &self.tables {
+            files.sync_all().map_err(internal_error)?;
+        }
+        Ok(())
+    }
+}
+
 /// Freezer is an memory mapped append-only database to store immutable chain data into flat files
 #[derive(Clone)]
 pub struct Freezer {
     inner: Arc<Mutex<Inner>>,
-    number: Arc<AtomicU64>,
+    numbers: Vec<Arc<AtomicU64>>,
     /// file lock to prevent double opens
     pub(crate) lock: Arc<File>,
     /// stop flag
@@ -46,12 +104,27 @@ impl Freezer {
             .open(lock_path)
             .map_err(internal_error)?;
         lock.try_lock_exclusive().map_err(internal_error)?;
-        let mut files = FreezerFiles::open(path).map_err(internal_error)?;
-        let freezer_number = files.number();
 
-        let mut tip = None;
+        let tables = FreezerTable::ALL
+            .iter()
+            .map(|table| FreezerFiles::open(table.path(&path)))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(internal_error)?;
+        let mut inner = Inner { tables, tip: None };
+
+        let freezer_number = inner.table(FreezerTable::Blocks).number();
+        for table in &FreezerTable::ALL[1..] {
+            if inner.table(*table).number() > freezer_number {
+                return Err(internal_error(format!(
+                    "freezer table {:?} is ahead of blocks",
+                    table
+                )));
+            }
+        }
+
         if freezer_number > 1 {
-            let raw_block = files
+            let raw_block = inner
+                .table(FreezerTable::Blocks)
                 .retrieve(freezer_number - 1)
                 .map_err(internal_error)?
                 .ok_or_else(|| internal_error("freezer inconsistent"))?;
@@ -61,12 +134,15 @@ impl Freezer {
             if block.count_extra_fields() > 1 {
                 return Err(internal_error("block has more than one extra fields"));
             }
-            tip = Some(block.header().into_view());
+            inner.tip = Some(block.header().into_view());
         }
 
-        let inner = Inner { files, tip };
         Ok(Freezer {
-            number: Arc::clone(&inner.files.number),
+            numbers: inner
+                .tables
+                .iter()
+                .map(|files| Arc::clone(&files.number))
+                .collect(),
             inner: Arc::new(Mutex::new(inner)),
             lock: Arc::new(lock),
             stopped: Arc::new(AtomicBool::new(false)),
@@ -80,78 +156,145 @@ impl Freezer {
 
     /// Freeze background process that periodically checks the chain data for any
     /// import progress and moves ancient data from the kv-db into the freezer.
+    ///
+    /// Tables which fall behind the blocks table, e.g. after upgrading from a freezer
+    /// that only kept blocks, are caught up first. The returned blocks are frozen into
+    /// every table and can be wiped out from the kv-db.
     pub fn freeze<F>(
         &self,
         threshold: BlockNumber,
         get_block_by_number: F,
     ) -> Result<FreezeResult, Error>
     where
-        F: Fn(BlockNumber) -> Option<BlockView>,
+        F: Fn(BlockNumber) -> Option<(BlockView, BlockExt)>,
     {
-        let number = self.number();
-        let mut guard = self.inner.lock();
+        let number = self.min_number();
         let mut ret = BTreeMap::new();
         ckb_logger::trace!("freezer freeze start {} threshold {}", number, threshold);
 
         for number in number..threshold {
             if self.stopped.load(Ordering::SeqCst) {
-                guard.files.sync_all().map_err(internal_error)?;
+                self.inner.lock().sync_all()?;
                 return Ok(ret);
             }
 
-            if let Some(block) = get_block_by_number(number) {
-                if let Some(ref header) = guard.tip {
-                    if header.hash() != block.header().parent_hash() {
-                        return Err(internal_error(format!(
-                            "appending unexpected block expected parent_hash {} have {}",
-                            header.hash(),
-                            block.header().parent_hash()
-                        )));
+            // the lock must not be held here, block getter may read frozen blocks back
+            if let Some((block, ext)) = get_block_by_number(number) {
+                let mut guard = self.inner.lock();
+                if guard.table(FreezerTable::Blocks).number() == number {
+                    if let Some(ref header) = guard.tip {
+                        if header.hash() != block.header().parent_hash() {
+                            return Err(internal_error(format!(
+                                "appending unexpected block expected parent_hash {} have {}",
+                                header.hash(),
+                                block.header().parent_hash()
+                            )));
+                        }
                     }
+                    let raw_block = block.data();
+                    guard
+                        .table(FreezerTable::Blocks)
+                        .append(number, raw_block.as_slice())
+                        .map_err(internal_error)?;
+                    guard.tip = Some(block.header());
+                    ckb_logger::trace!("freezer block append {}", number);
+                }
+
+                for table in &FreezerTable::ALL[1..] {
+                    if guard.table(*table).number() != number {
+                        continue;
+                    }
+                    let data = match table {
+                        FreezerTable::Headers => block.header().pack().as_bytes(),
+                        FreezerTable::BlockExts => ext.pack().as_bytes(),
+                        FreezerTable::Uncles => block.uncles().pack().as_bytes(),
+                        FreezerTable::Extensions => block
+                            .extension()
+                            .map(|extension| extension.as_bytes())
+                            .unwrap_or_default(),
+                        FreezerTable::Blocks => unreachable!(),
+                    };
+                    guard
+                        .table(*table)
+                        .append(number, &data)
+                        .map_err(internal_error)?;
                 }
-                let raw_block = block.data();
-                guard
-                    .files
-                    .append(number, raw_block.as_slice())
-                    .map_err(internal_error)?;
 
                 ret.insert(
                     block.header().hash(),
                     (number, block.transactions().len() as u32),
                 );
-                guard.tip = Some(block.header());
-                ckb_logger::trace!("freezer block append {}", number);
             } else {
                 ckb_logger::error!("freezer block missing {}", number);
                 break;
             }
         }
-        guard.files.sync_all().map_err(internal_error)?;
+        self.inner.lock().sync_all()?;
         Ok(ret)
     }
 
-    /// Retrieve an item with the given number
+    /// Retrieve a block with the given number
     pub fn retrieve(&self, number: BlockNumber) -> Result<Option<Vec<u8>>, Error> {
+        self.retrieve_from(FreezerTable::Blocks, number)
+    }
+
+    /// Retrieve an item with the given number from the specified table
+    pub fn retrieve_from(
+        &self,
+        table: FreezerTable,
+        number: BlockNumber,
+    ) -> Result<Option<Vec<u8>>, Error> {
         self.inner
             .lock()
-            .files
+            .table(table)
             .retrieve(number)
             .map_err(internal_error)
     }
 
-    /// Return total item number in the freezer
+    /// Return total block number in the freezer
     pub fn number(&self) -> BlockNumber {
-        self.number.load(Ordering::SeqCst)
+        self.table_number(FreezerTable::Blocks)
+    }
+
+    /// Return total item number in the specified table
+    pub fn table_number(&self, table: FreezerTable) -> BlockNumber {
+        self.numbers[table as usize].load(Ordering::SeqCst)
+    }
+
+    /// Return the lowest item number among all tables,
+    /// items below it are frozen in every table.
+    pub fn min_number(&self) -> BlockNumber {
+        FreezerTable::ALL
+            .iter()
+            .map(|table| self.table_number(*table))
+            .min()
+            .expect("freezer tables non empty")
+    }
+
+    /// Return the size in bytes of the files of each table
+    pub fn table_sizes(&self) -> Result<Vec<(FreezerTable, u64)>, Error> {
+        let inner = self.inner.lock();
+        FreezerTable::ALL
+            .iter()
+            .map(|table| {
+                inner.tables[*table as usize]
+                    .size()
+                    .map(|size| (*table, size))
+                    .map_err(internal_error)
+            })
+            .collect()
     }
 
     /// Truncate discards any recent data above the provided threshold number.
     pub fn truncate(&self, item: u64) -> Result<(), Error> {
         if item > 0 && ((item + 1) < self.number()) {
             let mut inner = self.inner.lock();
-            inner.files.truncate(item).map_err(internal_error)?;
+            for files in inner.tables.iter_mut() {
+                files.truncate(item).map_err(internal_error)?;
+            }
 
             let raw_block = inner
-                .files
+                .table(FreezerTable::Blocks)
                 .retrieve(item)
                 .map_err(internal_error)?
                 .expect("frozen number sync with files");
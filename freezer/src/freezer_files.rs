@@ -118,6 +118,18 @@ impl FreezerFiles {
         self.number.load(Ordering::SeqCst)
     }
 
+    /// Return the total size in bytes of the index and data files
+    pub fn size(&self) -> Result<u64, IoError> {
+        let mut size = self.index.metadata()?.len();
+        for file_id in self.tail_id..=self.head_id {
+            let path = self.file_path.join(helper::file_name(file_id));
+            if let Ok(metadata) = fs::metadata(path) {
+                size += metadata.len();
+            }
+        }
+        Ok(size)
+    }
+
     /// Append item into freezer files
     pub fn append(&mut self, number: u64, input: &[u8]) -> Result<(), IoError> {
         let expected = self.number.load(Ordering::SeqCst);
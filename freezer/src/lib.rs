@@ -12,5 +12,5 @@ fn internal_error<S: Display + Debug + Sync + Send + 'static>(reason: S) -> Erro
     InternalErrorKind::Database.other(reason).into()
 }
 
-pub use freezer::Freezer;
+pub use freezer::{Freezer, FreezerTable};
 pub use freezer_files::FreezerFilesBuilder;
@@ -3,7 +3,7 @@ use ckb_app_config::MinerClientConfig;
 use ckb_async_runtime::Handle;
 use ckb_channel::Sender;
 use ckb_jsonrpc_types::{Block as JsonBlock, BlockTemplate};
-use ckb_logger::{debug, error};
+use ckb_logger::{debug, error, info, warn};
 use ckb_stop_handler::{SignalSender, StopHandler};
 use ckb_types::{
     packed::{Block, Byte32},
@@ -21,11 +21,22 @@ use jsonrpc_core::{
 };
 use serde_json::error::Error as JsonError;
 use serde_json::{self, json, Value};
-use std::{convert::Into, thread, time};
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc, Weak,
+};
+use std::{convert::Into, io, iter, thread, time};
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::net::TcpStream;
 use tokio::sync::{mpsc, oneshot};
 
 type RpcRequest = (oneshot::Sender<Result<Bytes, RpcError>>, MethodCall);
 
+// The interval to retry the block template subscription while falling back to polling
+const SUBSCRIPTION_RETRY_INTERVAL: time::Duration = time::Duration::from_secs(10);
+// The interval to check whether the RPC endpoints are reachable
+const HEALTH_CHECK_INTERVAL: time::Duration = time::Duration::from_secs(5);
+
 #[derive(Debug)]
 pub enum RpcError {
     Http(HyperError),
@@ -34,6 +45,8 @@ pub enum RpcError {
     Fail(RpcFail),
     SendError,
     NoRespData,
+    Io(io::Error),
+    Timeout,
 }
 
 #[derive(Debug, Clone)]
@@ -128,6 +141,68 @@ impl Drop for Rpc {
     }
 }
 
+#[derive(Debug)]
+struct Endpoint {
+    // the authority without the credentials, only for logging
+    name: String,
+    rpc: Rpc,
+    timeout: time::Duration,
+    healthy: AtomicBool,
+}
+
+impl Endpoint {
+    fn new(url: &str, timeout: time::Duration, handle: Handle) -> Endpoint {
+        let uri: Uri = url.parse().expect("valid rpc url");
+        let name = uri
+            .authority()
+            .and_then(|authority| authority.as_str().rsplit('@').next())
+            .unwrap_or_default()
+            .to_owned();
+        Endpoint {
+            rpc: Rpc::new(uri, handle),
+            name,
+            timeout,
+            healthy: AtomicBool::new(true),
+        }
+    }
+
+    fn request(
+        &self,
+        method: &str,
+        params: Vec<Value>,
+    ) -> impl Future<Output = Result<Output, RpcError>> {
+        let future = self.rpc.request(method.to_owned(), params);
+        let timeout = self.timeout;
+        async move {
+            tokio::time::timeout(timeout, future)
+                .await
+                .unwrap_or(Err(RpcError::Timeout))
+        }
+    }
+
+    fn is_healthy(&self) -> bool {
+        self.healthy.load(Ordering::Acquire)
+    }
+
+    // Any response, even a failure one, means the node is reachable
+    fn update_health<T>(&self, result: &Result<T, RpcError>) {
+        let healthy = !matches!(
+            result,
+            Err(RpcError::Http(_))
+                | Err(RpcError::Canceled)
+                | Err(RpcError::SendError)
+                | Err(RpcError::Timeout)
+        );
+        if self.healthy.swap(healthy, Ordering::AcqRel) != healthy {
+            if healthy {
+                info!("rpc endpoint {} is reachable again", self.name);
+            } else {
+                warn!("rpc endpoint {} is unreachable", self.name);
+            }
+        }
+    }
+}
+
 pub enum Works {
     New(Work),
     FailSubmit(Byte32),
@@ -142,51 +217,112 @@ pub struct Client {
     pub new_work_tx: Sender<Works>,
     /// TODO(doc): @quake
     pub config: MinerClientConfig,
-    /// TODO(doc): @quake
-    pub rpc: Rpc,
+    // The endpoint or subscription address which the current work comes from, the work ids of
+    // different nodes are unrelated
+    current_work_source: Option<String>,
+    endpoints: Arc<Vec<Endpoint>>,
     handle: Handle,
 }
 
 impl Client {
     /// TODO(doc): @quake
     pub fn new(new_work_tx: Sender<Works>, config: MinerClientConfig, handle: Handle) -> Client {
-        let uri: Uri = config.rpc_url.parse().expect("valid rpc url");
+        let timeout = time::Duration::from_millis(config.rpc_timeout);
+        let endpoints: Arc<Vec<_>> = Arc::new(
+            iter::once(&config.rpc_url)
+                .chain(config.backup_rpc_urls.iter())
+                .map(|url| Endpoint::new(url, timeout, handle.clone()))
+                .collect(),
+        );
+        if endpoints.len() > 1 {
+            handle.spawn(check_health(Arc::downgrade(&endpoints)));
+        }
 
         Client {
             current_work_id: None,
-            rpc: Rpc::new(uri, handle.clone()),
+            current_work_source: None,
+            endpoints,
             new_work_tx,
             config,
             handle,
         }
     }
 
-    fn send_submit_block_request(
+    // The healthy endpoints in the configured order, or all of them when none is healthy,
+    // since a node may become reachable before the next health check.
+    fn candidates(&self) -> Vec<usize> {
+        let healthy: Vec<usize> = (0..self.endpoints.len())
+            .filter(|&index| self.endpoints[index].is_healthy())
+            .collect();
+        if healthy.is_empty() {
+            (0..self.endpoints.len()).collect()
+        } else {
+            healthy
+        }
+    }
+
+    fn request<T: serde::de::DeserializeOwned>(
+        &self,
+        index: usize,
+        method: &str,
+        params: Vec<Value>,
+    ) -> impl Future<Output = Result<T, RpcError>> + 'static + Send {
+        let endpoints = Arc::clone(&self.endpoints);
+        let future = endpoints[index].request(method, params);
+        async move {
+            let output = future.await;
+            endpoints[index].update_health(&output);
+            parse_response(output?).await
+        }
+    }
+
+    // Submits the block to all the healthy endpoints, it succeeds if any of them accepts it.
+    fn send_submit_block_requests(
         &self,
         work_id: &str,
         block: Block,
-    ) -> impl Future<Output = Result<Output, RpcError>> + 'static + Send {
+    ) -> impl Future<Output = Result<(), RpcError>> + 'static + Send {
         let block: JsonBlock = block.into();
-        let method = "submit_block".to_owned();
         let params = vec![json!(work_id), json!(block)];
+        let (names, requests): (Vec<_>, Vec<_>) = self
+            .candidates()
+            .into_iter()
+            .map(|index| {
+                (
+                    self.endpoints[index].name.clone(),
+                    self.request::<H256>(index, "submit_block", params.clone()),
+                )
+            })
+            .unzip();
 
-        self.rpc.clone().request(method, params)
+        async move {
+            let mut result = Err(RpcError::NoRespData);
+            for (name, response) in names.into_iter().zip(future::join_all(requests).await) {
+                match response {
+                    Ok(_) => result = Ok(()),
+                    Err(err) => {
+                        error!("rpc call submit_block to {} error: {:?}", name, err);
+                        if result.is_err() {
+                            result = Err(err);
+                        }
+                    }
+                }
+            }
+            result
+        }
     }
 
     /// TODO(doc): @quake
     pub fn submit_block(&self, work_id: &str, block: Block) -> Result<(), RpcError> {
         let parent = block.header().raw().parent_hash();
-        let future = self
-            .send_submit_block_request(work_id, block)
-            .and_then(parse_response::<H256>);
+        let future = self.send_submit_block_requests(work_id, block);
 
         if self.config.block_on_submit {
-            self.handle.block_on(future).map(|_| ())
+            self.handle.block_on(future)
         } else {
             let sender = self.new_work_tx.clone();
             self.handle.spawn(async move {
-                if let Err(e) = future.await {
-                    error!("rpc call submit_block error: {:?}", e);
+                if future.await.is_err() {
                     sender.send(Works::FailSubmit(parent)).unwrap()
                 }
             });
@@ -194,9 +330,22 @@ impl Client {
         }
     }
 
-    /// TODO(doc): @quake
+    /// Gets the new work via the block template subscription if `subscription_address` is set,
+    /// and polls the block template while the subscription is unavailable.
     pub fn poll_block_template(&mut self) {
+        let mut last_subscribed_at: Option<time::Instant> = None;
         loop {
+            if self.config.subscription_address.is_some()
+                && last_subscribed_at
+                    .map(|at| at.elapsed() >= SUBSCRIPTION_RETRY_INTERVAL)
+                    .unwrap_or(true)
+            {
+                last_subscribed_at = Some(time::Instant::now());
+                self.try_subscribe_block_template(time::Duration::from_secs(
+                    self.config.subscription_read_timeout,
+                ));
+                error!("no block_template subscription is available, fall back to polling");
+            }
             debug!("poll block template...");
             self.try_update_block_template();
             thread::sleep(time::Duration::from_millis(self.config.poll_interval));
@@ -205,9 +354,91 @@ impl Client {
 
     /// TODO(doc): @quake
     pub fn try_update_block_template(&mut self) {
-        match self.handle.block_on(self.get_block_template()) {
+        let (source, block_template) = self.handle.block_on(self.get_block_template());
+        self.update_block_template(&source, block_template);
+    }
+
+    // Subscribes via `subscription_address` followed by `backup_subscription_addresses`, fails
+    // over to the next one when the subscription is broken, returns once all of them are
+    pub(crate) fn try_subscribe_block_template(&mut self, read_timeout: time::Duration) {
+        let addresses: Vec<String> = self
+            .config
+            .subscription_address
+            .iter()
+            .chain(self.config.backup_subscription_addresses.iter())
+            .cloned()
+            .collect();
+        let handle = self.handle.clone();
+        for address in addresses {
+            if let Err(err) = handle.block_on(self.subscribe_block_template(&address, read_timeout))
+            {
+                error!("subscribe block_template via {} error: {:?}", address, err);
+            }
+        }
+    }
+
+    // Returns when the subscription is closed or nothing is pushed within `read_timeout`
+    pub(crate) async fn subscribe_block_template(
+        &mut self,
+        address: &str,
+        read_timeout: time::Duration,
+    ) -> Result<(), RpcError> {
+        let stream = TcpStream::connect(address).await.map_err(RpcError::Io)?;
+        let (reader, mut writer) = stream.into_split();
+        let request = json!({
+            "id": 0,
+            "jsonrpc": "2.0",
+            "method": "subscribe",
+            "params": ["block_template"],
+        });
+        writer
+            .write_all(format!("{}\n", request).as_bytes())
+            .await
+            .map_err(RpcError::Io)?;
+
+        let mut lines = BufReader::new(reader).lines();
+        while let Some(line) = tokio::time::timeout(read_timeout, lines.next_line())
+            .await
+            .map_err(|_| {
+                RpcError::Io(io::Error::new(
+                    io::ErrorKind::TimedOut,
+                    "no block template is pushed",
+                ))
+            })?
+            .map_err(RpcError::Io)?
+        {
+            let message: Value = serde_json::from_str(&line).map_err(RpcError::Json)?;
+            if let Some(result) = message["params"]["result"].as_str() {
+                let block_template = serde_json::from_str(result).map_err(RpcError::Json);
+                self.update_block_template(address, block_template);
+            } else if !message["error"].is_null() {
+                let error =
+                    serde_json::from_value(message["error"].clone()).map_err(RpcError::Json)?;
+                return Err(RpcError::Fail(error));
+            } else {
+                // the template may have changed before the subscription
+                debug!("subscribed block_template");
+                let (source, block_template) = self.get_block_template().await;
+                self.update_block_template(&source, block_template);
+            }
+        }
+        Err(RpcError::Io(io::Error::new(
+            io::ErrorKind::UnexpectedEof,
+            "subscription closed",
+        )))
+    }
+
+    fn update_block_template(
+        &mut self,
+        source: &str,
+        block_template: Result<BlockTemplate, RpcError>,
+    ) {
+        match block_template {
             Ok(block_template) => {
-                if self.current_work_id != Some(block_template.work_id.into()) {
+                if self.current_work_source.as_deref() != Some(source)
+                    || self.current_work_id != Some(block_template.work_id.into())
+                {
+                    self.current_work_source = Some(source.to_owned());
                     self.current_work_id = Some(block_template.work_id.into());
                     let work: Work = block_template.into();
                     if let Err(e) = self.new_work_tx.send(Works::New(work)) {
@@ -236,14 +467,44 @@ impl Client {
         }
     }
 
-    async fn get_block_template(&self) -> Result<BlockTemplate, RpcError> {
-        let method = "get_block_template".to_owned();
-        let params = vec![];
+    // Gets the block template from the first healthy endpoint, and fails over to the next one
+    // when the endpoint is unreachable or fails the request. Returns the name of the endpoint
+    // which the result comes from.
+    async fn get_block_template(&self) -> (String, Result<BlockTemplate, RpcError>) {
+        let candidates = self.candidates();
+        let mut output = (String::new(), Err(RpcError::NoRespData));
+        for (i, index) in candidates.iter().enumerate() {
+            let name = self.endpoints[*index].name.clone();
+            let result = self.request(*index, "get_block_template", vec![]).await;
+            match result {
+                Err(ref err) if i + 1 < candidates.len() => {
+                    warn!("rpc call get_block_template to {} error: {:?}", name, err);
+                }
+                _ => {
+                    output = (name, result);
+                    break;
+                }
+            }
+        }
+        output
+    }
+}
 
-        self.rpc
-            .request(method, params)
-            .and_then(parse_response)
-            .await
+// Checks all the endpoints periodically, so the preferred ones are used again once they are
+// reachable. Exits when the client is dropped.
+async fn check_health(endpoints: Weak<Vec<Endpoint>>) {
+    loop {
+        tokio::time::sleep(HEALTH_CHECK_INTERVAL).await;
+        let endpoints = match endpoints.upgrade() {
+            Some(endpoints) => endpoints,
+            None => break,
+        };
+        let requests = endpoints.iter().map(|endpoint| {
+            endpoint
+                .request("get_tip_block_number", vec![])
+                .map(move |output| endpoint.update_health(&output))
+        });
+        future::join_all(requests).await;
     }
 }
 
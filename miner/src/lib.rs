@@ -3,6 +3,9 @@ mod client;
 mod miner;
 mod worker;
 
+#[cfg(test)]
+mod tests;
+
 pub use crate::client::Client;
 pub use crate::miner::Miner;
 
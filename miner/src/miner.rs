@@ -4,6 +4,7 @@ use crate::Work;
 use ckb_app_config::MinerWorkerConfig;
 use ckb_channel::{select, unbounded, Receiver};
 use ckb_logger::{debug, error, info};
+use ckb_metrics::{global_meter, internal::KeyValue, metrics};
 use ckb_pow::PowEngine;
 use ckb_types::{
     packed::{Byte32, Header},
@@ -31,6 +32,8 @@ pub struct Miner {
     pub work_rx: Receiver<Works>,
     /// TODO(doc): @quake
     pub nonce_rx: Receiver<(Byte32, Work, u128)>,
+    /// Receives the hash rate reported by each worker thread
+    pub hash_rate_rx: Receiver<(String, f64)>,
     /// TODO(doc): @quake
     pub pb: ProgressBar,
     /// TODO(doc): @quake
@@ -51,11 +54,20 @@ impl Miner {
         limit: u128,
     ) -> Miner {
         let (nonce_tx, nonce_rx) = unbounded();
+        let (hash_rate_tx, hash_rate_rx) = unbounded();
         let mp = MultiProgress::new();
 
         let worker_controllers = workers
             .iter()
-            .map(|config| start_worker(Arc::clone(&pow), config, nonce_tx.clone(), &mp))
+            .map(|config| {
+                start_worker(
+                    Arc::clone(&pow),
+                    config,
+                    nonce_tx.clone(),
+                    hash_rate_tx.clone(),
+                    &mp,
+                )
+            })
             .collect();
 
         let pb = mp.add(ProgressBar::new(100));
@@ -75,13 +87,15 @@ impl Miner {
             worker_controllers,
             work_rx,
             nonce_rx,
+            hash_rate_rx,
             pb,
             stderr_is_tty,
             limit,
         }
     }
 
-    /// TODO(doc): @quake
+    /// Runs the miner, the hash rate of each worker, the found and rejected blocks, and the stale
+    /// works are reported through the metrics service.
     pub fn run(&mut self) {
         loop {
             select! {
@@ -90,6 +104,7 @@ impl Miner {
                         match work {
                             Works::FailSubmit(hash) => {
                                 self.legacy_work.pop(&hash);
+                                metrics!(counter, "ckb.miner.rejected_blocks", 1);
                             },
                             Works::New(work) => self.notify_new_work(work),
                         }
@@ -110,6 +125,19 @@ impl Miner {
                         error!("nonce_rx closed");
                         break;
                     },
+                },
+                recv(self.hash_rate_rx) -> msg => match msg {
+                    Ok((worker, hash_rate)) => {
+                        // the rate in H/s is fractional, which the integer gauge would truncate
+                        global_meter()
+                            .f64_value_recorder("ckb.miner.hash_rate")
+                            .init()
+                            .record(hash_rate, &[KeyValue::new("worker", worker)]);
+                    },
+                    _ => {
+                        error!("hash_rate_rx closed");
+                        break;
+                    },
                 }
             };
         }
@@ -151,6 +179,7 @@ impl Miner {
                 pow_hash,
                 block.header()
             );
+            metrics!(counter, "ckb.miner.stale_works", 1);
             self.notify_workers(WorkerMessage::Start);
             return;
         } else {
@@ -163,6 +192,7 @@ impl Miner {
         }
 
         self.legacy_work.put(parent_hash, ());
+        metrics!(counter, "ckb.miner.found_blocks", 1);
         if self.stderr_is_tty {
             debug!("Found! #{} {:#x}", block.number(), block_hash);
         } else {
@@ -177,6 +207,7 @@ impl Miner {
             {
                 self.legacy_work.pop(&block.parent_hash());
                 error!("rpc call submit_block error: {:?}", e);
+                metrics!(counter, "ckb.miner.rejected_blocks", 1);
             }
             self.client.try_update_block_template();
             self.notify_workers(WorkerMessage::Start);
@@ -0,0 +1,304 @@
+use crate::client::{Client, RpcError, Works};
+use crate::tests::{block_template, closed_address, Reply, StubEndpoint};
+use ckb_app_config::MinerClientConfig;
+use ckb_async_runtime::new_global_runtime;
+use ckb_channel::{unbounded, Receiver};
+use ckb_types::{packed, H256};
+use serde_json::json;
+use std::io::{self, BufRead, BufReader, Write};
+use std::net::TcpListener;
+use std::sync::{
+    atomic::{AtomicBool, AtomicU64, Ordering},
+    Arc,
+};
+use std::thread;
+use std::time::{Duration, Instant};
+
+fn client_config(rpc_url: &str, backup_rpc_urls: Vec<String>) -> MinerClientConfig {
+    MinerClientConfig {
+        rpc_url: rpc_url.to_owned(),
+        backup_rpc_urls,
+        poll_interval: 100,
+        block_on_submit: true,
+        subscription_address: None,
+        backup_subscription_addresses: vec![],
+        rpc_timeout: 500,
+        subscription_read_timeout: 60,
+    }
+}
+
+// A stub endpoint which serves the block template of the current work id
+fn template_endpoint(work_id: &Arc<AtomicU64>) -> StubEndpoint {
+    let work_id = Arc::clone(work_id);
+    StubEndpoint::start(move |method| match method {
+        "get_block_template" => {
+            Reply::Result(json!(block_template(work_id.load(Ordering::SeqCst))))
+        }
+        _ => Reply::Result(json!("0x0")),
+    })
+}
+
+fn new_work_id(work_rx: &Receiver<Works>) -> u64 {
+    match work_rx
+        .recv_timeout(Duration::from_secs(10))
+        .expect("new work")
+    {
+        Works::New(work) => work.work_id,
+        Works::FailSubmit(_) => panic!("unexpected failed submission"),
+    }
+}
+
+// A subscription server which acknowledges the subscription, then pushes the given templates
+// and keeps the connection open
+fn subscription_server(pushed_work_ids: Vec<u64>) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let address = listener.local_addr().unwrap().to_string();
+    thread::spawn(move || {
+        let (stream, _) = listener.accept().unwrap();
+        let mut writer = stream.try_clone().unwrap();
+        let mut line = String::new();
+        BufReader::new(stream).read_line(&mut line).unwrap();
+        writeln!(
+            writer,
+            "{}",
+            json!({"jsonrpc": "2.0", "result": "0x0", "id": 0})
+        )
+        .unwrap();
+        for work_id in pushed_work_ids {
+            let template = serde_json::to_string(&block_template(work_id)).unwrap();
+            let message = json!({
+                "jsonrpc": "2.0",
+                "method": "subscribe",
+                "params": {"result": template, "subscription": "0x0"},
+            });
+            writeln!(writer, "{}", message).unwrap();
+        }
+        loop {
+            thread::park();
+        }
+    });
+    address
+}
+
+fn is_timeout(result: &Result<(), RpcError>) -> bool {
+    matches!(result, Err(RpcError::Io(err)) if err.kind() == io::ErrorKind::TimedOut)
+}
+
+#[test]
+fn test_subscribe_block_template() {
+    let (handle, _stop) = new_global_runtime();
+    let work_id = Arc::new(AtomicU64::new(1));
+    let endpoint = template_endpoint(&work_id);
+    let address = subscription_server(vec![2, 3]);
+    let (work_tx, work_rx) = unbounded();
+    let mut client = Client::new(
+        work_tx,
+        client_config(&endpoint.url, vec![]),
+        handle.clone(),
+    );
+
+    let subscription = client.subscribe_block_template(&address, Duration::from_secs(1));
+    let result = handle.block_on(subscription);
+    // the template is fetched once subscribed, then the pushed ones are used
+    assert_eq!(new_work_id(&work_rx), 1);
+    assert_eq!(new_work_id(&work_rx), 2);
+    assert_eq!(new_work_id(&work_rx), 3);
+    assert_eq!(endpoint.calls("get_block_template"), 1);
+    assert!(is_timeout(&result));
+}
+
+#[test]
+fn test_same_work_id_from_another_source() {
+    let (handle, _stop) = new_global_runtime();
+    let work_id = Arc::new(AtomicU64::new(1));
+    let endpoint = template_endpoint(&work_id);
+    // the subscribed node has its own work ids, which may collide with the polled ones
+    let address = subscription_server(vec![1, 1, 2]);
+    let (work_tx, work_rx) = unbounded();
+    let mut client = Client::new(
+        work_tx,
+        client_config(&endpoint.url, vec![]),
+        handle.clone(),
+    );
+
+    let subscription = client.subscribe_block_template(&address, Duration::from_millis(500));
+    let result = handle.block_on(subscription);
+    assert!(is_timeout(&result));
+    assert_eq!(new_work_id(&work_rx), 1);
+    assert_eq!(new_work_id(&work_rx), 1);
+    assert_eq!(new_work_id(&work_rx), 2);
+    assert!(work_rx.try_recv().is_err());
+}
+
+#[test]
+fn test_half_open_subscription_falls_back_to_polling() {
+    let (handle, _stop) = new_global_runtime();
+    let work_id = Arc::new(AtomicU64::new(1));
+    let endpoint = template_endpoint(&work_id);
+    // nothing is pushed after the subscription is acknowledged
+    let address = subscription_server(vec![]);
+    let (work_tx, work_rx) = unbounded();
+    let mut client = Client::new(
+        work_tx,
+        client_config(&endpoint.url, vec![]),
+        handle.clone(),
+    );
+
+    let subscription = client.subscribe_block_template(&address, Duration::from_millis(500));
+    let result = handle.block_on(subscription);
+    assert!(is_timeout(&result));
+    assert_eq!(new_work_id(&work_rx), 1);
+
+    // the new work is polled
+    work_id.store(2, Ordering::SeqCst);
+    client.try_update_block_template();
+    assert_eq!(new_work_id(&work_rx), 2);
+}
+
+#[test]
+fn test_refused_subscription() {
+    let (handle, _stop) = new_global_runtime();
+    let work_id = Arc::new(AtomicU64::new(1));
+    let endpoint = template_endpoint(&work_id);
+    let (work_tx, _work_rx) = unbounded();
+    let mut client = Client::new(
+        work_tx,
+        client_config(&endpoint.url, vec![]),
+        handle.clone(),
+    );
+
+    let address = closed_address();
+    let subscription = client.subscribe_block_template(&address, Duration::from_secs(1));
+    let result = handle.block_on(subscription);
+    assert!(matches!(result, Err(RpcError::Io(_))));
+}
+
+#[test]
+fn test_subscription_failover() {
+    let (handle, _stop) = new_global_runtime();
+    let work_id = Arc::new(AtomicU64::new(1));
+    let endpoint = template_endpoint(&work_id);
+    let (work_tx, work_rx) = unbounded();
+    let mut config = client_config(&endpoint.url, vec![]);
+    config.subscription_address = Some(closed_address());
+    config.backup_subscription_addresses = vec![subscription_server(vec![2])];
+    let mut client = Client::new(work_tx, config, handle);
+
+    // returns once the backup subscription times out as well
+    client.try_subscribe_block_template(Duration::from_millis(500));
+    assert_eq!(new_work_id(&work_rx), 1);
+    assert_eq!(new_work_id(&work_rx), 2);
+}
+
+#[test]
+fn test_get_block_template_failover() {
+    let (handle, _stop) = new_global_runtime();
+    let work_id = Arc::new(AtomicU64::new(1));
+    let primary = StubEndpoint::start(|_| Reply::Hang);
+    let backup = template_endpoint(&work_id);
+    let (work_tx, work_rx) = unbounded();
+    let mut client = Client::new(
+        work_tx,
+        client_config(&primary.url, vec![backup.url.clone()]),
+        handle,
+    );
+
+    // the primary times out and the backup serves the template
+    client.try_update_block_template();
+    assert_eq!(new_work_id(&work_rx), 1);
+    // the unhealthy primary is skipped
+    work_id.store(2, Ordering::SeqCst);
+    client.try_update_block_template();
+    assert_eq!(new_work_id(&work_rx), 2);
+    assert_eq!(primary.calls("get_block_template"), 1);
+    assert_eq!(backup.calls("get_block_template"), 2);
+}
+
+#[test]
+fn test_get_block_template_failover_on_error() {
+    let (handle, _stop) = new_global_runtime();
+    let work_id = Arc::new(AtomicU64::new(1));
+    let primary = StubEndpoint::start(|_| {
+        Reply::Error(json!({"code": -32601, "message": "Method not found"}))
+    });
+    let backup = template_endpoint(&work_id);
+    let (work_tx, work_rx) = unbounded();
+    let mut client = Client::new(
+        work_tx,
+        client_config(&primary.url, vec![backup.url.clone()]),
+        handle,
+    );
+
+    // the primary is reachable but fails the request, so the backup serves the template
+    client.try_update_block_template();
+    assert_eq!(new_work_id(&work_rx), 1);
+    assert_eq!(primary.calls("get_block_template"), 1);
+    assert_eq!(backup.calls("get_block_template"), 1);
+}
+
+#[test]
+fn test_health_recovery() {
+    let (handle, _stop) = new_global_runtime();
+    let work_id = Arc::new(AtomicU64::new(1));
+    let hanging = Arc::new(AtomicBool::new(true));
+    let primary = {
+        let work_id = Arc::clone(&work_id);
+        let hanging = Arc::clone(&hanging);
+        StubEndpoint::start(move |method| match method {
+            _ if hanging.load(Ordering::SeqCst) => Reply::Hang,
+            "get_block_template" => {
+                Reply::Result(json!(block_template(work_id.load(Ordering::SeqCst))))
+            }
+            _ => Reply::Result(json!("0x0")),
+        })
+    };
+    let backup = template_endpoint(&work_id);
+    let (work_tx, work_rx) = unbounded();
+    let mut client = Client::new(
+        work_tx,
+        client_config(&primary.url, vec![backup.url.clone()]),
+        handle,
+    );
+    client.try_update_block_template();
+    assert_eq!(new_work_id(&work_rx), 1);
+    assert_eq!(backup.calls("get_block_template"), 1);
+
+    // the primary is used again once the health check reaches it
+    hanging.store(false, Ordering::SeqCst);
+    let deadline = Instant::now() + Duration::from_secs(20);
+    while primary.calls("get_block_template") < 2 {
+        assert!(Instant::now() < deadline, "primary should recover");
+        thread::sleep(Duration::from_millis(200));
+        client.try_update_block_template();
+    }
+}
+
+#[test]
+fn test_submit_block_to_all() {
+    let (handle, _stop) = new_global_runtime();
+    let primary = StubEndpoint::start(|_| Reply::Hang);
+    let backup = StubEndpoint::start(|_| Reply::Result(json!(H256::default())));
+    let (work_tx, _work_rx) = unbounded();
+    let client = Client::new(
+        work_tx,
+        client_config(&primary.url, vec![backup.url.clone()]),
+        handle,
+    );
+
+    // it succeeds if any endpoint accepts the block
+    assert!(client.submit_block("0", packed::Block::default()).is_ok());
+    assert_eq!(primary.calls("submit_block"), 1);
+    assert_eq!(backup.calls("submit_block"), 1);
+
+    // it fails if none accepts the block
+    let rejecting = StubEndpoint::start(|_| Reply::Result(json!("invalid hash")));
+    let (work_tx, _work_rx) = unbounded();
+    let (handle, _stop) = new_global_runtime();
+    let client = Client::new(
+        work_tx,
+        client_config(&primary.url, vec![rejecting.url.clone()]),
+        handle,
+    );
+    assert!(client.submit_block("0", packed::Block::default()).is_err());
+    assert_eq!(rejecting.calls("submit_block"), 1);
+}
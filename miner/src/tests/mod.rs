@@ -0,0 +1,136 @@
+use ckb_jsonrpc_types::{BlockTemplate, CellbaseTemplate};
+use ckb_types::{packed, H256};
+use serde_json::{json, Value};
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+mod client;
+
+// The reply of a stub endpoint to a call
+pub(crate) enum Reply {
+    Result(Value),
+    // a JSON-RPC error, like a node which has not set `block_assembler`
+    Error(Value),
+    // accepts the call but never replies, like a node which is restarting
+    Hang,
+}
+
+// A stub RPC endpoint which answers the JSON-RPC calls over HTTP
+pub(crate) struct StubEndpoint {
+    pub(crate) url: String,
+    pub(crate) calls: Arc<Mutex<Vec<String>>>,
+}
+
+impl StubEndpoint {
+    pub(crate) fn start<F>(reply: F) -> StubEndpoint
+    where
+        F: Fn(&str) -> Reply + Send + Sync + 'static,
+    {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/", listener.local_addr().unwrap());
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        let reply = Arc::new(reply);
+        let endpoint_calls = Arc::clone(&calls);
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let stream = match stream {
+                    Ok(stream) => stream,
+                    Err(_) => break,
+                };
+                let calls = Arc::clone(&endpoint_calls);
+                let reply = Arc::clone(&reply);
+                thread::spawn(move || serve(stream, &calls, reply.as_ref()));
+            }
+        });
+        StubEndpoint { url, calls }
+    }
+
+    pub(crate) fn calls(&self, method: &str) -> usize {
+        self.calls
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|call| call.as_str() == method)
+            .count()
+    }
+}
+
+// Serves the requests of a keep-alive HTTP connection
+fn serve(stream: TcpStream, calls: &Mutex<Vec<String>>, reply: &dyn Fn(&str) -> Reply) {
+    let mut writer = stream.try_clone().unwrap();
+    let mut reader = BufReader::new(stream);
+    loop {
+        let mut content_length = 0;
+        loop {
+            let mut line = String::new();
+            if reader.read_line(&mut line).unwrap_or(0) == 0 {
+                return;
+            }
+            let line = line.trim_end();
+            if line.is_empty() {
+                break;
+            }
+            let mut parts = line.splitn(2, ':');
+            let name = parts.next().unwrap_or_default();
+            if name.eq_ignore_ascii_case("content-length") {
+                content_length = parts.next().unwrap_or_default().trim().parse().unwrap();
+            }
+        }
+        let mut body = vec![0; content_length];
+        reader.read_exact(&mut body).unwrap();
+        let request: Value = serde_json::from_slice(&body).unwrap();
+        let method = request["method"].as_str().unwrap_or_default().to_owned();
+        calls.lock().unwrap().push(method.clone());
+        let response = match reply(&method) {
+            Reply::Result(result) => {
+                json!({"jsonrpc": "2.0", "result": result, "id": request["id"]})
+            }
+            Reply::Error(error) => json!({"jsonrpc": "2.0", "error": error, "id": request["id"]}),
+            Reply::Hang => loop {
+                thread::park();
+            },
+        }
+        .to_string();
+        let message = format!(
+            "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\n\r\n{}",
+            response.len(),
+            response
+        );
+        if writer.write_all(message.as_bytes()).is_err() {
+            return;
+        }
+    }
+}
+
+// An address which refuses the connections
+pub(crate) fn closed_address() -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    listener.local_addr().unwrap().to_string()
+}
+
+pub(crate) fn block_template(work_id: u64) -> BlockTemplate {
+    BlockTemplate {
+        version: 0u32.into(),
+        compact_target: 0x2000_0000u32.into(),
+        current_time: 0u64.into(),
+        number: 1u64.into(),
+        epoch: 0u64.into(),
+        parent_hash: H256::default(),
+        cycles_limit: 0u64.into(),
+        bytes_limit: 0u64.into(),
+        uncles_count_limit: 0u64.into(),
+        uncles: vec![],
+        transactions: vec![],
+        proposals: vec![],
+        cellbase: CellbaseTemplate {
+            hash: H256::default(),
+            cycles: None,
+            data: packed::Transaction::default().into(),
+        },
+        work_id: work_id.into(),
+        dao: packed::Byte32::default().into(),
+        extension: None,
+    }
+}
@@ -10,12 +10,15 @@ use rand_distr::{self as dist, Distribution as _};
 use std::thread;
 use std::time::Duration;
 
+pub(crate) const WORKER_NAME: &str = "Dummy-Worker";
+
 pub struct Dummy {
     delay: Delay,
     start: bool,
     pow_work: Option<(Byte32, Work)>,
     nonce_tx: Sender<(Byte32, Work, u128)>,
     worker_rx: Receiver<WorkerMessage>,
+    hash_rate_tx: Sender<(String, f64)>,
 }
 
 pub enum Delay {
@@ -68,6 +71,7 @@ impl Dummy {
         config: &DummyConfig,
         nonce_tx: Sender<(Byte32, Work, u128)>,
         worker_rx: Receiver<WorkerMessage>,
+        hash_rate_tx: Sender<(String, f64)>,
     ) -> Result<Self, Box<dyn std::error::Error>> {
         Delay::try_from(config).map(|delay| Self {
             start: true,
@@ -75,6 +79,7 @@ impl Dummy {
             delay,
             nonce_tx,
             worker_rx,
+            hash_rate_tx,
         })
     }
 
@@ -95,10 +100,18 @@ impl Dummy {
     }
 
     fn solve(&self, pow_hash: Byte32, work: Work, nonce: u128) {
-        thread::sleep(self.delay.duration());
+        let delay = self.delay.duration();
+        thread::sleep(delay);
         if let Err(err) = self.nonce_tx.send((pow_hash, work, nonce)) {
             error!("nonce_tx send error {:?}", err);
         }
+        // every answer counts as one hash
+        if !delay.is_zero() {
+            let hash_rate = (WORKER_NAME.to_string(), 1.0 / delay.as_secs_f64());
+            if let Err(err) = self.hash_rate_tx.send(hash_rate) {
+                error!("hash_rate_tx send error {:?}", err);
+            }
+        }
     }
 }
 
@@ -12,27 +12,33 @@ use std::thread;
 use std::time::{Duration, Instant};
 
 pub struct EaglesongSimple {
+    name: String,
     start: bool,
     pow_work: Option<(Byte32, Work)>,
     target: U256,
     nonce_tx: Sender<(Byte32, Work, u128)>,
     worker_rx: Receiver<WorkerMessage>,
+    hash_rate_tx: Sender<(String, f64)>,
     nonces_found: u128,
     pub(crate) extra_hash_function: Option<ExtraHashFunction>,
 }
 
 impl EaglesongSimple {
     pub fn new(
+        name: String,
         nonce_tx: Sender<(Byte32, Work, u128)>,
         worker_rx: Receiver<WorkerMessage>,
+        hash_rate_tx: Sender<(String, f64)>,
         extra_hash_function: Option<ExtraHashFunction>,
     ) -> Self {
         Self {
+            name,
             start: true,
             pow_work: None,
             target: U256::zero(),
             nonce_tx,
             worker_rx,
+            hash_rate_tx,
             nonces_found: 0,
             extra_hash_function,
         }
@@ -102,11 +108,14 @@ impl Worker for EaglesongSimple {
                             + u64::from(elapsed.subsec_nanos()))
                             as f64
                             / 1_000_000_000.0;
+                        let hash_rate = state_update_counter as f64 / elapsed_nanos;
                         progress_bar.set_message(format!(
                             "hash rate: {:>10.3} / nonces found: {:>10}",
-                            state_update_counter as f64 / elapsed_nanos,
-                            self.nonces_found,
+                            hash_rate, self.nonces_found,
                         ));
+                        if let Err(err) = self.hash_rate_tx.send((self.name.clone(), hash_rate)) {
+                            error!("hash_rate_tx send error {:?}", err);
+                        }
                         progress_bar.inc(1);
                         state_update_counter = 0;
                         start = Instant::now();
@@ -67,18 +67,19 @@ pub fn start_worker(
     pow: Arc<dyn PowEngine>,
     config: &MinerWorkerConfig,
     nonce_tx: Sender<(Byte32, Work, u128)>,
+    hash_rate_tx: Sender<(String, f64)>,
     mp: &MultiProgress,
 ) -> WorkerController {
     match config {
         MinerWorkerConfig::Dummy(config) => {
             if pow.as_any().downcast_ref::<DummyPowEngine>().is_some() {
-                let worker_name = "Dummy-Worker";
+                let worker_name = dummy::WORKER_NAME;
                 let pb = mp.add(ProgressBar::new(100));
                 pb.set_style(ProgressStyle::default_bar().template(PROGRESS_BAR_TEMPLATE));
                 pb.set_prefix(worker_name);
 
                 let (worker_tx, worker_rx) = unbounded();
-                let mut worker = Dummy::try_new(config, nonce_tx, worker_rx)
+                let mut worker = Dummy::try_new(config, nonce_tx, worker_rx, hash_rate_tx)
                     .expect("valid distribution parameters");
 
                 thread::Builder::new()
@@ -113,11 +114,17 @@ pub fn start_worker(
 
                         let (worker_tx, worker_rx) = unbounded();
                         let nonce_tx = nonce_tx.clone();
+                        let hash_rate_tx = hash_rate_tx.clone();
                         thread::Builder::new()
-                            .name(worker_name)
+                            .name(worker_name.clone())
                             .spawn(move || {
-                                let mut worker =
-                                    EaglesongSimple::new(nonce_tx, worker_rx, extra_hash_function);
+                                let mut worker = EaglesongSimple::new(
+                                    worker_name,
+                                    nonce_tx,
+                                    worker_rx,
+                                    hash_rate_tx,
+                                    extra_hash_function,
+                                );
                                 let rng = nonce_generator(nonce_range);
                                 worker.run(rng, pb);
                             })
@@ -0,0 +1,189 @@
+//! Bandwidth accounting per session and protocol, and the upload limits
+use crate::{PeerIndex, ProtocolId};
+use ckb_app_config::BandwidthConfig;
+use ckb_metrics::metrics;
+use ckb_util::Mutex;
+use p2p::service::TargetSession;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// Bytes sent to and received from peers through a protocol
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ProtocolBandwidth {
+    /// Bytes sent
+    pub sent: u64,
+    /// Bytes received
+    pub received: u64,
+}
+
+#[derive(Default)]
+struct SessionBandwidth {
+    protocols: HashMap<ProtocolId, ProtocolBandwidth>,
+    // bytes sent in the current window
+    window_sent: u64,
+}
+
+struct Accounts {
+    sessions: HashMap<PeerIndex, SessionBandwidth>,
+    protocols: HashMap<ProtocolId, ProtocolBandwidth>,
+    window_started: Instant,
+    // bytes sent to all sessions in the current window
+    window_sent: u64,
+}
+
+impl Accounts {
+    fn roll_window(&mut self, window: Duration) {
+        if self.window_started.elapsed() >= window {
+            self.window_started = Instant::now();
+            self.window_sent = 0;
+            for session in self.sessions.values_mut() {
+                session.window_sent = 0;
+            }
+        }
+    }
+}
+
+/// Counts the bytes of the messages of every session and protocol
+///
+/// The counters of a session are dropped once it is closed, the protocol counters are kept
+/// for the whole run.
+pub(crate) struct BandwidthMonitor {
+    config: BandwidthConfig,
+    accounts: Mutex<Accounts>,
+}
+
+impl BandwidthMonitor {
+    pub(crate) fn new(config: BandwidthConfig) -> Self {
+        BandwidthMonitor {
+            config,
+            accounts: Mutex::new(Accounts {
+                sessions: HashMap::default(),
+                protocols: HashMap::default(),
+                window_started: Instant::now(),
+                window_sent: 0,
+            }),
+        }
+    }
+
+    fn window(&self) -> Duration {
+        Duration::from_secs(self.config.window_secs)
+    }
+
+    pub(crate) fn record_sent(&self, session: PeerIndex, proto_id: ProtocolId, bytes: usize) {
+        let bytes = bytes as u64;
+        {
+            let mut accounts = self.accounts.lock();
+            accounts.roll_window(self.window());
+            accounts.window_sent += bytes;
+            accounts.protocols.entry(proto_id).or_default().sent += bytes;
+            let session = accounts.sessions.entry(session).or_default();
+            session.window_sent += bytes;
+            session.protocols.entry(proto_id).or_default().sent += bytes;
+        }
+        metrics!(
+            counter,
+            "ckb.network.bandwidth_bytes",
+            bytes,
+            "direction" => "out",
+            "protocol_id" => proto_id.value().to_string(),
+        );
+    }
+
+    pub(crate) fn record_received(&self, session: PeerIndex, proto_id: ProtocolId, bytes: usize) {
+        let bytes = bytes as u64;
+        {
+            let mut accounts = self.accounts.lock();
+            accounts.protocols.entry(proto_id).or_default().received += bytes;
+            accounts
+                .sessions
+                .entry(session)
+                .or_default()
+                .protocols
+                .entry(proto_id)
+                .or_default()
+                .received += bytes;
+        }
+        metrics!(
+            counter,
+            "ckb.network.bandwidth_bytes",
+            bytes,
+            "direction" => "in",
+            "protocol_id" => proto_id.value().to_string(),
+        );
+    }
+
+    /// Counts a broadcast for every session it is sent to
+    pub(crate) fn record_broadcast(
+        self: &Arc<Self>,
+        target: TargetSession,
+        proto_id: ProtocolId,
+        bytes: usize,
+    ) -> TargetSession {
+        match target {
+            TargetSession::Single(session) => {
+                self.record_sent(session, proto_id, bytes);
+                TargetSession::Single(session)
+            }
+            TargetSession::All => {
+                let monitor = Arc::clone(self);
+                TargetSession::Filter(Box::new(move |session| {
+                    monitor.record_sent(*session, proto_id, bytes);
+                    true
+                }))
+            }
+            TargetSession::Filter(mut filter) => {
+                let monitor = Arc::clone(self);
+                TargetSession::Filter(Box::new(move |session| {
+                    let selected = filter(session);
+                    if selected {
+                        monitor.record_sent(*session, proto_id, bytes);
+                    }
+                    selected
+                }))
+            }
+            target => target,
+        }
+    }
+
+    pub(crate) fn remove_session(&self, session: PeerIndex) {
+        self.accounts.lock().sessions.remove(&session);
+    }
+
+    pub(crate) fn session_bandwidth(
+        &self,
+        session: PeerIndex,
+    ) -> HashMap<ProtocolId, ProtocolBandwidth> {
+        self.accounts
+            .lock()
+            .sessions
+            .get(&session)
+            .map(|session| session.protocols.clone())
+            .unwrap_or_default()
+    }
+
+    pub(crate) fn protocol_bandwidth(&self) -> HashMap<ProtocolId, ProtocolBandwidth> {
+        self.accounts.lock().protocols.clone()
+    }
+
+    /// Whether the global or the session upload limit of the current window is reached
+    pub(crate) fn upload_limit_reached(&self, session: PeerIndex) -> bool {
+        if self.config.max_upload_bytes.is_none() && self.config.max_peer_upload_bytes.is_none() {
+            return false;
+        }
+        let mut accounts = self.accounts.lock();
+        accounts.roll_window(self.window());
+        let global_reached = self
+            .config
+            .max_upload_bytes
+            .map(|limit| accounts.window_sent >= limit)
+            .unwrap_or(false);
+        let session_reached = self
+            .config
+            .max_peer_upload_bytes
+            .zip(accounts.sessions.get(&session))
+            .map(|(limit, session)| session.window_sent >= limit)
+            .unwrap_or(false);
+        global_reached || session_reached
+    }
+}
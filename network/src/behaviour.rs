@@ -4,9 +4,17 @@ use crate::Score;
 /// we maintain a score to each peer
 /// report peer behaviour will affects peer's score
 ///
-/// Currently this feature is disabled, maybe someday we will add it back or totally remove it.
+/// A peer is banned once its score drops below the configured `ban_score`.
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
 pub enum Behaviour {
+    /// The peer sent an invalid block, compact block or header
+    InvalidBlock,
+    /// The peer relayed a transaction which failed verification
+    InvalidTransaction,
+    /// The peer sent data we never requested
+    UnrequestedData,
+    /// The peer did not respond to our requests in time
+    Timeout,
     #[cfg(test)]
     TestGood,
     #[cfg(test)]
@@ -16,12 +24,21 @@ pub enum Behaviour {
 impl Behaviour {
     /// Behaviour score
     pub fn score(self) -> Score {
-        #[cfg(test)]
         match self {
+            Behaviour::InvalidBlock => -70,
+            Behaviour::InvalidTransaction => -20,
+            Behaviour::UnrequestedData => -10,
+            Behaviour::Timeout => -20,
+            #[cfg(test)]
             Behaviour::TestGood => 10,
+            #[cfg(test)]
             Behaviour::TestBad => -10,
         }
-        #[cfg(not(test))]
-        0
+    }
+
+    /// Whether the behaviour may get the peer banned, a timeout may be caused by a slow link
+    /// rather than misbehaviour, so it only lowers the score
+    pub fn can_ban(self) -> bool {
+        !matches!(self, Behaviour::Timeout)
     }
 }
@@ -6,6 +6,7 @@
 //! And implemented several basic protocols: identify, discovery, ping, feeler, disconnect_message
 //!
 
+mod bandwidth;
 mod behaviour;
 /// compress module
 pub mod compress;
@@ -16,19 +17,22 @@ mod peer;
 pub mod peer_registry;
 pub mod peer_store;
 mod protocols;
+mod proxy;
 mod services;
 
 #[cfg(test)]
 mod tests;
 
 pub use crate::{
+    bandwidth::ProtocolBandwidth,
     behaviour::Behaviour,
     errors::Error,
     network::{
         DefaultExitHandler, EventHandler, ExitHandler, NetworkController, NetworkService,
         NetworkState,
     },
-    peer::{Peer, PeerIdentifyInfo},
+    network_group::{AsMap, Group},
+    peer::{AtomicTimestamp, Peer, PeerIdentifyInfo},
     peer_registry::PeerRegistry,
     peer_store::Score,
     protocols::{
@@ -1,9 +1,11 @@
 //! Global state struct and start function
+use crate::bandwidth::{BandwidthMonitor, ProtocolBandwidth};
 use crate::errors::{Error, P2PError};
+use crate::network_group::{AsMap, Group};
 use crate::peer_registry::{ConnectionStatus, PeerRegistry};
 use crate::peer_store::{
     types::{AddrInfo, BannedAddr},
-    PeerStore,
+    PeerScoreConfig, PeerStore,
 };
 use crate::protocols::{
     disconnect_message::DisconnectMessageProtocol,
@@ -13,11 +15,12 @@ use crate::protocols::{
     ping::PingHandler,
     support_protocols::SupportProtocols,
 };
+use crate::proxy::{is_valid_onion_host, onion_address, ProxyDialer};
 use crate::services::{
     dump_peer_store::DumpPeerStoreService, outbound_peer::OutboundPeerService,
     protocol_type_checker::ProtocolTypeCheckerService,
 };
-use crate::{Behaviour, CKBProtocol, Peer, PeerIndex, ProtocolId, ServiceControl};
+use crate::{Behaviour, CKBProtocol, Peer, PeerIndex, ProtocolId, Score, ServiceControl};
 use ckb_app_config::{default_support_all_protocols, NetworkConfig, SupportProtocol};
 use ckb_logger::{debug, error, info, trace, warn};
 use ckb_spawn::Spawn;
@@ -83,6 +86,10 @@ pub struct NetworkState {
     pub(crate) protocols: RwLock<Vec<(ProtocolId, String, Vec<String>)>>,
 
     pub(crate) ckb2021: AtomicBool,
+    /// Dials through the SOCKS5 proxy if it is configured
+    proxy: Option<ProxyDialer>,
+    /// Bytes sent and received per session and protocol
+    pub(crate) bandwidth: Arc<BandwidthMonitor>,
 }
 
 impl NetworkState {
@@ -91,34 +98,77 @@ impl NetworkState {
         config.create_dir_if_not_exists()?;
         let local_private_key = config.fetch_private_key()?;
         let local_peer_id = local_private_key.peer_id();
+        // never announce the real address when proxying
+        let announced_addrs: Vec<Multiaddr> = if config.proxy.is_some() {
+            config
+                .public_addresses
+                .iter()
+                .filter(|addr| {
+                    onion_address(addr)
+                        .map(|(host, _)| is_valid_onion_host(&host))
+                        .unwrap_or(false)
+                })
+                .cloned()
+                .collect()
+        } else {
+            config
+                .listen_addresses
+                .iter()
+                .chain(config.public_addresses.iter())
+                .cloned()
+                .collect()
+        };
         // set max score to public addresses
-        let public_addrs: HashSet<Multiaddr> = config
-            .listen_addresses
-            .iter()
-            .chain(config.public_addresses.iter())
-            .cloned()
+        let public_addrs: HashSet<Multiaddr> = announced_addrs
+            .into_iter()
             .filter_map(|mut addr| {
                 multiaddr_to_socketaddr(&addr)
-                    .filter(|addr| is_reachable(addr.ip()))
-                    .and({
+                    .map(|socket_addr| is_reachable(socket_addr.ip()))
+                    .unwrap_or_else(|| onion_address(&addr).is_some())
+                    .then(|| {
                         if extract_peer_id(&addr).is_none() {
                             addr.push(Protocol::P2P(Cow::Borrowed(local_peer_id.as_bytes())));
                         }
-                        Some(addr)
+                        addr
                     })
             })
             .collect();
-        let peer_store = Mutex::new(PeerStore::load_from_dir_or_default(
-            config.peer_store_path(),
-        ));
+        let proxy = config.proxy.clone().map(ProxyDialer::new);
+        let mut peer_store = PeerStore::load_from_dir_or_default(config.peer_store_path());
+        let default_score_config = PeerScoreConfig::default();
+        peer_store.set_onion_reachable(config.proxy.is_some());
+        peer_store.set_score_config(PeerScoreConfig {
+            ban_score: config.ban_score.unwrap_or(default_score_config.ban_score),
+            ban_timeout_ms: config
+                .ban_timeout_secs
+                .map(|secs| secs.saturating_mul(1000))
+                .unwrap_or(default_score_config.ban_timeout_ms),
+            ..default_score_config
+        });
+        if let Some(ref path) = config.asmap {
+            let asmap = AsMap::load_from_file(path).map_err(|err| {
+                error!("Failed to load AS map, file: {:?}, error: {}", path, err);
+                err
+            })?;
+            info!(
+                "Loaded AS map with {} networks from {:?}",
+                asmap.len(),
+                path
+            );
+            peer_store.set_asmap(asmap);
+        }
+        let peer_store = Mutex::new(peer_store);
         let bootnodes = config.bootnodes();
 
-        let peer_registry = PeerRegistry::new(
+        let mut peer_registry = PeerRegistry::new(
             config.max_inbound_peers(),
             config.max_outbound_peers(),
             config.whitelist_only,
             config.whitelist_peers(),
         );
+        peer_registry.set_max_outbound_block_relay(config.max_outbound_block_relay_peers);
+
+        let bandwidth = Arc::new(BandwidthMonitor::new(config.bandwidth.clone()));
 
         Ok(NetworkState {
             peer_store,
@@ -134,6 +184,8 @@ impl NetworkState {
             active: AtomicBool::new(true),
             protocols: RwLock::new(Vec::new()),
             ckb2021: AtomicBool::new(false),
+            proxy,
+            bandwidth,
         })
     }
 
@@ -280,6 +332,14 @@ impl NetworkState {
 
     /// Get local node's listen address list
     pub fn public_urls(&self, max_urls: usize) -> Vec<(String, u8)> {
+        if self.proxy.is_some() {
+            // the listen addresses are not reachable through the proxy
+            return self
+                .public_addrs(max_urls)
+                .into_iter()
+                .map(|addr| (addr.to_string(), 1))
+                .collect();
+        }
         let listened_addrs = self.listened_addrs.read();
         self.public_addrs(max_urls.saturating_sub(listened_addrs.len()))
             .into_iter()
@@ -308,6 +368,11 @@ impl NetworkState {
             .collect::<Vec<_>>()
     }
 
+    /// Whether onion addresses can be dialed, i.e. through the proxy
+    pub(crate) fn onion_reachable(&self) -> bool {
+        self.proxy.is_some()
+    }
+
     pub(crate) fn can_dial(&self, addr: &Multiaddr) -> bool {
         let peer_id = extract_peer_id(addr);
         if peer_id.is_none() {
@@ -320,6 +385,16 @@ impl NetworkState {
             trace!("Do not dial self: {:?}, {}", peer_id, addr);
             return false;
         }
+        if let Some((host, _)) = onion_address(addr) {
+            if !self.onion_reachable() {
+                trace!("Do not dial onion address without proxy: {}", addr);
+                return false;
+            }
+            if !is_valid_onion_host(&host) {
+                trace!("Do not dial invalid onion address: {}", addr);
+                return false;
+            }
+        }
         if self.public_addrs.read().contains(addr) {
             trace!(
                 "Do not dial listened address(self): {:?}, {}",
@@ -376,6 +451,7 @@ impl NetworkState {
     pub(crate) fn dial_failed(&self, addr: &Multiaddr) {
         self.with_peer_registry_mut(|reg| {
             reg.remove_feeler(addr);
+            reg.remove_block_relay_only(addr);
         });
 
         if let Some(peer_id) = extract_peer_id(addr) {
@@ -396,7 +472,10 @@ impl NetworkState {
         }
 
         debug!("dialing {}", addr);
-        p2p_control.dial(addr.clone(), target)?;
+        match self.proxy {
+            Some(ref proxy) => proxy.dial(addr.clone(), target)?,
+            None => p2p_control.dial(addr.clone(), target)?,
+        }
         self.dialing_addrs.write().insert(
             extract_peer_id(&addr).expect("verified addr"),
             Instant::now(),
@@ -430,8 +509,40 @@ impl NetworkState {
         }
     }
 
+    /// Dial a peer which only relays blocks
+    pub fn dial_block_relay_only(&self, p2p_control: &ServiceControl, addr: Multiaddr) {
+        if let Err(err) = self.dial_inner(
+            p2p_control,
+            addr.clone(),
+            TargetProtocol::Single(SupportProtocols::Identify.protocol_id()),
+        ) {
+            debug!("dial_block_relay_only error {}", err);
+        } else {
+            self.with_peer_registry_mut(|reg| {
+                reg.add_block_relay_only(&addr);
+            });
+        }
+    }
+
+    /// Remember the long-lived and well-behaved outbound peers as anchors
+    ///
+    /// The previous anchors are kept if there is no such peer, e.g. when all peers are
+    /// disconnected before shutdown.
+    pub(crate) fn update_anchors(&self) {
+        // NOTE: lock peer_store before peer_registry, the same order as `accept_peer`
+        self.with_peer_store_mut(|peer_store| {
+            let anchors = self.with_peer_registry(|reg| reg.anchors(peer_store));
+            if !anchors.is_empty() {
+                peer_store.set_anchors(anchors);
+            }
+        });
+    }
+
     /// this method is intent to check observed addr by dial to self
     pub(crate) fn try_dial_observed_addrs(&self, p2p_control: &ServiceControl) {
+        if self.proxy.is_some() {
+            return;
+        }
         let mut pending_observed_addrs = self.pending_observed_addrs.write();
         if pending_observed_addrs.is_empty() {
             let addrs = self.public_addrs.read();
@@ -462,6 +573,10 @@ impl NetworkState {
 
     /// add observed address for identify protocol
     pub(crate) fn add_observed_addrs(&self, iter: impl Iterator<Item = Multiaddr>) {
+        // the observed addresses are the proxy's ones or the real one
+        if self.proxy.is_some() {
+            return;
+        }
         let mut pending_observed_addrs = self.pending_observed_addrs.write();
         pending_observed_addrs.extend(iter)
     }
@@ -722,8 +837,12 @@ impl<T: ExitHandler> ServiceHandle for EventHandler<T> {
                 let peer_exists = self.network_state.with_peer_registry_mut(|reg| {
                     // should make sure feelers is clean
                     reg.remove_feeler(&session_context.address);
+                    reg.remove_block_relay_only(&session_context.address);
                     reg.remove_peer(session_context.id).is_some()
                 });
+                self.network_state
+                    .bandwidth
+                    .remove_session(session_context.id);
                 if peer_exists {
                     debug!(
                         "{} closed, remove {} from peer_registry",
@@ -976,6 +1095,12 @@ impl<T: ExitHandler> NetworkService<T> {
             bg_services.push(Box::pin(outbound_peer_service) as Pin<Box<_>>);
         };
 
+        if let Some(proxy_dial_service) = network_state.proxy.as_ref().and_then(|proxy| {
+            proxy.service(Arc::clone(&network_state), p2p_service.control().to_owned())
+        }) {
+            bg_services.push(Box::pin(proxy_dial_service) as Pin<Box<_>>);
+        };
+
         #[cfg(feature = "with_dns_seeding")]
         if config.dns_seeding_service_enabled() {
             let dns_seeding_service = crate::services::dns_seeding::DnsSeedingService::new(
@@ -1005,6 +1130,21 @@ impl<T: ExitHandler> NetworkService<T> {
                 .dial_identify(self.p2p_service.control(), addr);
         }
 
+        // reconnect anchors first, they take the block-relay-only slots if there are any
+        let anchors = self
+            .network_state
+            .with_peer_store_mut(|peer_store| peer_store.anchors().to_vec());
+        for (index, addr) in anchors.into_iter().enumerate() {
+            debug!("dial anchor {:?}", addr);
+            if index < config.max_outbound_block_relay_peers as usize {
+                self.network_state
+                    .dial_block_relay_only(self.p2p_service.control(), addr);
+            } else {
+                self.network_state
+                    .dial_identify(self.p2p_service.control(), addr);
+            }
+        }
+
         // get bootnodes
         // try get addrs from peer_store, if peer_store have no enough addrs then use bootnodes
         let bootnodes = self.network_state.with_peer_store_mut(|peer_store| {
@@ -1227,6 +1367,26 @@ impl NetworkController {
             .cloned()
     }
 
+    /// Get the misbehaviour score of a connected peer
+    pub fn peer_score(&self, peer_id: &PeerId) -> Option<Score> {
+        self.network_state.peer_store.lock().peer_score(peer_id)
+    }
+
+    /// Get the network group of an address, by ASN if an AS map is loaded
+    pub fn network_group(&self, addr: &Multiaddr) -> Group {
+        self.network_state.peer_store.lock().network_group(addr)
+    }
+
+    /// Get the bytes sent to and received from a connected peer, by protocol
+    pub fn peer_bandwidth(&self, peer_index: PeerIndex) -> HashMap<ProtocolId, ProtocolBandwidth> {
+        self.network_state.bandwidth.session_bandwidth(peer_index)
+    }
+
+    /// Get the bytes sent and received since the node started, by protocol
+    pub fn protocol_bandwidth(&self) -> HashMap<ProtocolId, ProtocolBandwidth> {
+        self.network_state.bandwidth.protocol_bandwidth()
+    }
+
     /// Ban an ip
     pub fn ban(&self, address: IpNetwork, ban_until: u64, ban_reason: String) {
         self.network_state
@@ -1254,6 +1414,12 @@ impl NetworkController {
         })
     }
 
+    /// Report the behaviour of a peer through peer index
+    pub fn report_peer(&self, peer_index: PeerIndex, behaviour: Behaviour) {
+        self.network_state
+            .report_session(&self.p2p_control, peer_index, behaviour);
+    }
+
     /// Ban an peer through peer index
     pub fn ban_peer(&self, peer_index: PeerIndex, duration: Duration, reason: String) {
         self.network_state
@@ -1268,19 +1434,26 @@ impl NetworkController {
         data: Bytes,
     ) -> Result<(), SendErrorKind> {
         let now = Instant::now();
+        let bandwidth = &self.network_state.bandwidth;
         loop {
-            let target = target
-                .map(TargetSession::Single)
-                .unwrap_or(TargetSession::All);
+            // the broadcast filter only counts the sessions once tentacle applies it, which it
+            // never does for a send that would block
+            let session_target = match target {
+                Some(session) => TargetSession::Single(session),
+                None => bandwidth.record_broadcast(TargetSession::All, proto_id, data.len()),
+            };
             let result = if quick {
                 self.p2p_control
-                    .quick_filter_broadcast(target, proto_id, data.clone())
+                    .quick_filter_broadcast(session_target, proto_id, data.clone())
             } else {
                 self.p2p_control
-                    .filter_broadcast(target, proto_id, data.clone())
+                    .filter_broadcast(session_target, proto_id, data.clone())
             };
             match result {
                 Ok(()) => {
+                    if let Some(session) = target {
+                        bandwidth.record_sent(session, proto_id, data.len());
+                    }
                     return Ok(());
                 }
                 Err(SendErrorKind::WouldBlock) => {
@@ -1,12 +1,56 @@
+//! Network groups, used to keep the connected peers diverse
+//!
+//! An address is grouped by its IP prefix, /16 for IPv4 and /32 for IPv6. When an AS map is
+//! loaded, an address covered by the map is grouped by its autonomous system number instead,
+//! so the peers hosted by one provider fall into one group whatever their prefixes are.
+//!
+//! The AS map file is a text file, every line maps an IP network to an ASN, the longest
+//! matching network wins:
+//!
+//! ```text
+//! # comments and blank lines are ignored
+//! 1.0.0.0/24 13335
+//! 2606:4700::/32 13335
+//! ```
 use crate::{multiaddr::Multiaddr, multiaddr_to_socketaddr};
+use ipnetwork::IpNetwork;
+use std::collections::HashMap;
+use std::fmt;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader};
 use std::net::IpAddr;
+use std::path::Path;
 
-#[derive(Hash, Eq, PartialEq, Debug)]
+/// Network group of an address
+#[derive(Clone, Hash, Eq, PartialEq, Debug)]
 pub enum Group {
+    /// The address can not be grouped
     None,
+    /// Loopback address
     LocalNetwork,
+    /// IPv4 /16 prefix
     IP4([u8; 2]),
+    /// IPv6 /32 prefix
     IP6([u8; 4]),
+    /// Autonomous system number from the AS map
+    Asn(u32),
+}
+
+impl fmt::Display for Group {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Group::None => write!(f, "none"),
+            Group::LocalNetwork => write!(f, "local"),
+            Group::IP4(bits) => write!(f, "{}.{}.0.0/16", bits[0], bits[1]),
+            Group::IP6(bits) => write!(
+                f,
+                "{:x}:{:x}::/32",
+                u16::from_be_bytes([bits[0], bits[1]]),
+                u16::from_be_bytes([bits[2], bits[3]])
+            ),
+            Group::Asn(asn) => write!(f, "AS{}", asn),
+        }
+    }
 }
 
 impl From<&Multiaddr> for Group {
@@ -41,3 +85,110 @@ impl From<&Multiaddr> for Group {
         Group::None
     }
 }
+
+// (is IPv6, network bits, prefix length)
+type NetworkKey = (bool, u128, u8);
+
+fn network_key(ip: IpAddr, prefix: u8) -> NetworkKey {
+    match ip {
+        IpAddr::V4(ipv4) => {
+            let mask = u32::MAX.checked_shl(32 - u32::from(prefix)).unwrap_or(0);
+            (false, u128::from(u32::from(ipv4) & mask), prefix)
+        }
+        IpAddr::V6(ipv6) => {
+            let mask = u128::MAX.checked_shl(128 - u32::from(prefix)).unwrap_or(0);
+            (true, u128::from(ipv6) & mask, prefix)
+        }
+    }
+}
+
+/// Map from IP networks to autonomous system numbers
+#[derive(Default, Debug)]
+pub struct AsMap {
+    networks: HashMap<NetworkKey, u32>,
+    // the prefix lengths in the map, longest first
+    prefixes: Vec<(bool, u8)>,
+}
+
+impl AsMap {
+    /// Load the map from a file
+    pub fn load_from_file<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        Self::load(BufReader::new(File::open(path)?))
+    }
+
+    /// Load the map from a reader
+    pub fn load<R: BufRead>(r: R) -> io::Result<Self> {
+        let mut asmap = AsMap::default();
+        for (index, line) in r.lines().enumerate() {
+            let line = line?;
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let invalid_line = || {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("invalid AS map line {}: {}", index + 1, line),
+                )
+            };
+            let mut fields = line.split_whitespace();
+            let network = fields
+                .next()
+                .and_then(|field| field.parse::<IpNetwork>().ok())
+                .ok_or_else(invalid_line)?;
+            let asn = fields
+                .next()
+                .and_then(|field| field.parse::<u32>().ok())
+                .ok_or_else(invalid_line)?;
+            if fields.next().is_some() {
+                return Err(invalid_line());
+            }
+            asmap.insert(network, asn);
+        }
+        Ok(asmap)
+    }
+
+    /// Map an IP network to an ASN
+    pub fn insert(&mut self, network: IpNetwork, asn: u32) {
+        let key = network_key(network.network(), network.prefix());
+        self.networks.insert(key, asn);
+        let prefix = (key.0, key.2);
+        if !self.prefixes.contains(&prefix) {
+            self.prefixes.push(prefix);
+            self.prefixes.sort_unstable_by(|a, b| b.1.cmp(&a.1));
+        }
+    }
+
+    /// Get the ASN of the longest network containing the IP
+    pub fn lookup(&self, ip: IpAddr) -> Option<u32> {
+        let ip = match ip {
+            IpAddr::V6(ipv6) => ipv6.to_ipv4().map(IpAddr::V4).unwrap_or(ip),
+            _ => ip,
+        };
+        self.prefixes
+            .iter()
+            .filter(|(is_ipv6, _)| *is_ipv6 == ip.is_ipv6())
+            .find_map(|(_, prefix)| self.networks.get(&network_key(ip, *prefix)).copied())
+    }
+
+    /// Get the group of an address, by ASN if the map covers it, by IP prefix otherwise
+    pub fn group(&self, addr: &Multiaddr) -> Group {
+        match multiaddr_to_socketaddr(addr) {
+            Some(socket_addr) if !socket_addr.ip().is_loopback() => self
+                .lookup(socket_addr.ip())
+                .map(Group::Asn)
+                .unwrap_or_else(|| addr.into()),
+            _ => addr.into(),
+        }
+    }
+
+    /// The number of networks in the map
+    pub fn len(&self) -> usize {
+        self.networks.len()
+    }
+
+    /// Whether the map is empty
+    pub fn is_empty(&self) -> bool {
+        self.networks.is_empty()
+    }
+}
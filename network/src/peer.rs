@@ -2,6 +2,7 @@ use crate::network_group::Group;
 use crate::{multiaddr::Multiaddr, ProtocolId, ProtocolVersion, SessionType};
 use p2p::SessionId;
 use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::time::{Duration, Instant};
 
 /// Peer info from identify protocol message
@@ -11,6 +12,32 @@ pub struct PeerIdentifyInfo {
     pub client_version: String,
 }
 
+/// A unix timestamp in milliseconds which can be updated through a shared reference,
+/// so frequent updates only take the read lock of the peer registry
+#[derive(Debug, Default)]
+pub struct AtomicTimestamp(AtomicU64);
+
+impl AtomicTimestamp {
+    /// Get the timestamp, none if it was never set
+    pub fn get(&self) -> Option<u64> {
+        match self.0.load(Ordering::Relaxed) {
+            0 => None,
+            timestamp => Some(timestamp),
+        }
+    }
+
+    /// Set the timestamp
+    pub fn set(&self, timestamp: u64) {
+        self.0.store(timestamp, Ordering::Relaxed);
+    }
+}
+
+impl Clone for AtomicTimestamp {
+    fn clone(&self) -> Self {
+        AtomicTimestamp(AtomicU64::new(self.0.load(Ordering::Relaxed)))
+    }
+}
+
 /// Peer info
 #[derive(Clone, Debug)]
 pub struct Peer {
@@ -24,6 +51,10 @@ pub struct Peer {
     pub last_ping_protocol_message_received_at: Option<Instant>,
     /// ping pong rtt
     pub ping_rtt: Option<Duration>,
+    /// The last time this peer delivered a new valid block
+    pub last_block_delivered_at: AtomicTimestamp,
+    /// The last time this peer delivered a new valid transaction
+    pub last_transaction_delivered_at: AtomicTimestamp,
     /// Indicates whether it is a probe connection of the fleer protocol
     pub is_feeler: bool,
     /// Peer connected time
@@ -36,6 +67,9 @@ pub struct Peer {
     pub protocols: HashMap<ProtocolId, ProtocolVersion>,
     /// Whether a whitelist
     pub is_whitelist: bool,
+    /// Whether an outbound peer which only relays blocks, it takes part in neither
+    /// transaction relay nor address gossip
+    pub is_block_relay_only: bool,
 }
 
 impl Peer {
@@ -51,6 +85,8 @@ impl Peer {
             listened_addrs: Vec::new(),
             identify_info: None,
             ping_rtt: None,
+            last_block_delivered_at: AtomicTimestamp::default(),
+            last_transaction_delivered_at: AtomicTimestamp::default(),
             last_ping_protocol_message_received_at: None,
             connected_time: Instant::now(),
             is_feeler: false,
@@ -58,6 +94,7 @@ impl Peer {
             session_type,
             protocols: HashMap::with_capacity_and_hasher(1, Default::default()),
             is_whitelist,
+            is_block_relay_only: false,
         }
     }
 
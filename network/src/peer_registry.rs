@@ -8,9 +8,17 @@ use ckb_logger::debug;
 use p2p::{multiaddr::Multiaddr, SessionId};
 use rand::seq::SliceRandom;
 use rand::thread_rng;
-use std::collections::{HashMap, HashSet};
+use std::collections::{hash_map::DefaultHasher, HashMap, HashSet};
+use std::hash::{Hash, Hasher};
+use std::time::Duration;
 
 pub(crate) const EVICTION_PROTECT_PEERS: usize = 8;
+pub(crate) const EVICTION_PROTECT_GROUPS: usize = 4;
+pub(crate) const EVICTION_PROTECT_DELIVERY_PEERS: usize = 4;
+/// Maximum number of peers persisted as anchors
+pub(crate) const MAX_ANCHORS: usize = 2;
+/// Outbound peers connected for less than this are not anchors
+const ANCHOR_MIN_CONNECTED_TIME: Duration = Duration::from_secs(30 * 60);
 
 /// Memory records of opened session information
 pub struct PeerRegistry {
@@ -19,10 +27,17 @@ pub struct PeerRegistry {
     max_inbound: u32,
     // max outbound limitation
     max_outbound: u32,
+    // max block-relay-only outbound limitation, not counted in `max_outbound`
+    max_outbound_block_relay: u32,
+    // allow one extra outbound beyond `max_outbound`, to find new peers when the tip is stale
+    try_new_outbound_peer: bool,
     // Only whitelist peers or allow all peers.
     whitelist_only: bool,
     whitelist_peers: HashSet<PeerId>,
     feeler_peers: HashSet<PeerId>,
+    block_relay_only_peers: HashSet<PeerId>,
+    // secret key to order the network groups in eviction
+    eviction_key: u64,
 }
 
 /// Global network connection status
@@ -32,12 +47,16 @@ pub struct ConnectionStatus {
     pub total: u32,
     /// Not whitelist inbound number
     pub non_whitelist_inbound: u32,
-    /// Not whitelist outbound number
+    /// Not whitelist outbound number, block-relay-only sessions excluded
     pub non_whitelist_outbound: u32,
+    /// Block-relay-only outbound number
+    pub block_relay_only_outbound: u32,
     /// Maximum number of inbound session
     pub max_inbound: u32,
     /// Maximum number of outbound session
     pub max_outbound: u32,
+    /// Maximum number of block-relay-only outbound session
+    pub max_outbound_block_relay: u32,
 }
 
 fn sort_then_drop<T, F>(list: &mut Vec<T>, n: usize, compare: F)
@@ -50,6 +69,20 @@ where
     }
 }
 
+// Drop the n peers which most recently delivered, peers which never delivered are kept
+fn drop_recent_delivery<F>(list: &mut Vec<&Peer>, n: usize, delivered_at: F)
+where
+    F: Fn(&Peer) -> Option<u64>,
+{
+    let delivered = list
+        .iter()
+        .filter(|peer| delivered_at(peer).is_some())
+        .count();
+    sort_then_drop(list, n.min(delivered), |peer1, peer2| {
+        delivered_at(peer1).cmp(&delivered_at(peer2))
+    });
+}
+
 impl PeerRegistry {
     /// Init registry from config
     pub fn new(
@@ -62,12 +95,37 @@ impl PeerRegistry {
             peers: HashMap::with_capacity_and_hasher(20, Default::default()),
             whitelist_peers: whitelist_peers.iter().filter_map(extract_peer_id).collect(),
             feeler_peers: HashSet::default(),
+            block_relay_only_peers: HashSet::default(),
+            eviction_key: rand::random(),
             max_inbound,
             max_outbound,
+            max_outbound_block_relay: 0,
+            try_new_outbound_peer: false,
             whitelist_only,
         }
     }
 
+    /// Set the number of block-relay-only outbound slots
+    pub fn set_max_outbound_block_relay(&mut self, max_outbound_block_relay: u32) {
+        self.max_outbound_block_relay = max_outbound_block_relay;
+    }
+
+    /// Allow or disallow one extra outbound connection beyond the limit
+    pub fn set_try_new_outbound_peer(&mut self, enable: bool) {
+        self.try_new_outbound_peer = enable;
+    }
+
+    /// The count of the outbound connections beyond the limit
+    pub fn extra_outbound_count(&self) -> u32 {
+        self.connection_status()
+            .non_whitelist_outbound
+            .saturating_sub(self.max_outbound)
+    }
+
+    fn max_outbound(&self) -> u32 {
+        self.max_outbound + u32::from(self.try_new_outbound_peer)
+    }
+
     pub(crate) fn accept_peer(
         &mut self,
         remote_addr: Multiaddr,
@@ -84,6 +142,8 @@ impl PeerRegistry {
         }
 
         let is_whitelist = self.whitelist_peers.contains(&peer_id);
+        let is_block_relay_only =
+            session_type.is_outbound() && self.block_relay_only_peers.remove(&peer_id);
         let mut evicted_peer: Option<Peer> = None;
 
         if !is_whitelist {
@@ -104,18 +164,23 @@ impl PeerRegistry {
                         return Err(PeerError::ReachMaxInboundLimit.into());
                     }
                 }
-            } else if connection_status.non_whitelist_outbound >= self.max_outbound {
+            } else if is_block_relay_only {
+                if connection_status.block_relay_only_outbound >= self.max_outbound_block_relay {
+                    return Err(PeerError::ReachMaxOutboundLimit.into());
+                }
+            } else if connection_status.non_whitelist_outbound >= self.max_outbound() {
                 return Err(PeerError::ReachMaxOutboundLimit.into());
             }
         }
         peer_store.add_connected_peer(remote_addr.clone(), session_type);
-        let peer = Peer::new(session_id, session_type, remote_addr, is_whitelist);
+        let mut peer = Peer::new(session_id, session_type, remote_addr, is_whitelist);
+        peer.is_block_relay_only = is_block_relay_only;
         self.peers.insert(session_id, peer);
         Ok(evicted_peer)
     }
 
     // try to evict an inbound peer
-    fn try_evict_inbound_peer(&self, _peer_store: &PeerStore) -> Option<SessionId> {
+    fn try_evict_inbound_peer(&self, peer_store: &PeerStore) -> Option<SessionId> {
         let mut candidate_peers = {
             self.peers
                 .values()
@@ -123,6 +188,36 @@ impl PeerRegistry {
                 .collect::<Vec<_>>()
         };
         // Protect peers based on characteristics that an attacker hard to simulate or manipulate
+        // Protect the longest connected peer of a few network groups, the groups are ordered
+        // by a secret key, so an attacker can not tell which groups to avoid
+        let mut keyed_peers = candidate_peers
+            .into_iter()
+            .map(|peer| {
+                let group = peer_store.network_group(&peer.connected_addr);
+                let mut hasher = DefaultHasher::new();
+                self.eviction_key.hash(&mut hasher);
+                group.hash(&mut hasher);
+                (hasher.finish(), group, peer)
+            })
+            .collect::<Vec<_>>();
+        keyed_peers.sort_by(|(key1, _, peer1), (key2, _, peer2)| {
+            key1.cmp(key2)
+                .then(peer1.connected_time.cmp(&peer2.connected_time))
+        });
+        let mut protected_groups = HashSet::new();
+        candidate_peers = keyed_peers
+            .into_iter()
+            .filter_map(|(_, group, peer)| {
+                if protected_groups.len() < EVICTION_PROTECT_GROUPS
+                    && protected_groups.insert(group)
+                {
+                    None
+                } else {
+                    Some(peer)
+                }
+            })
+            .collect();
+
         // Protect peers which has the lowest ping
         sort_then_drop(
             &mut candidate_peers,
@@ -156,6 +251,19 @@ impl PeerRegistry {
                 peer2_last_message.cmp(&peer1_last_message)
             },
         );
+        // Protect peers which most recently delivered new blocks
+        drop_recent_delivery(
+            &mut candidate_peers,
+            EVICTION_PROTECT_DELIVERY_PEERS,
+            |peer| peer.last_block_delivered_at.get(),
+        );
+        // Protect peers which most recently delivered new transactions
+        drop_recent_delivery(
+            &mut candidate_peers,
+            EVICTION_PROTECT_DELIVERY_PEERS,
+            |peer| peer.last_transaction_delivered_at.get(),
+        );
+
         // Protect half peers which have the longest connection time
         let protect_peers = candidate_peers.len() >> 1;
         sort_then_drop(&mut candidate_peers, protect_peers, |peer1, peer2| {
@@ -167,7 +275,7 @@ impl PeerRegistry {
             .into_iter()
             .fold(HashMap::new(), |mut groups, peer| {
                 groups
-                    .entry(peer.network_group())
+                    .entry(peer_store.network_group(&peer.connected_addr))
                     .or_insert_with(Vec::new)
                     .push(peer);
                 groups
@@ -206,6 +314,47 @@ impl PeerRegistry {
             .unwrap_or_default()
     }
 
+    /// Add block-relay-only dial task
+    pub fn add_block_relay_only(&mut self, addr: &Multiaddr) {
+        if let Some(peer_id) = extract_peer_id(addr) {
+            self.block_relay_only_peers.insert(peer_id);
+        }
+    }
+
+    /// Remove block-relay-only dial task on dial fails
+    pub fn remove_block_relay_only(&mut self, addr: &Multiaddr) {
+        if let Some(peer_id) = extract_peer_id(addr) {
+            self.block_relay_only_peers.remove(&peer_id);
+        }
+    }
+
+    /// Pick the long-lived and well-behaved outbound peers, block-relay-only peers first
+    pub(crate) fn anchors(&self, peer_store: &PeerStore) -> Vec<Multiaddr> {
+        let mut candidate_peers = self
+            .peers
+            .values()
+            .filter(|peer| {
+                peer.is_outbound()
+                    && !peer.is_whitelist
+                    && peer.connected_time.elapsed() >= ANCHOR_MIN_CONNECTED_TIME
+                    && extract_peer_id(&peer.connected_addr)
+                        .map(|peer_id| peer_store.is_well_behaved(&peer_id))
+                        .unwrap_or(false)
+            })
+            .collect::<Vec<_>>();
+        candidate_peers.sort_by(|peer1, peer2| {
+            peer2
+                .is_block_relay_only
+                .cmp(&peer1.is_block_relay_only)
+                .then(peer1.connected_time.cmp(&peer2.connected_time))
+        });
+        candidate_peers
+            .into_iter()
+            .take(MAX_ANCHORS)
+            .map(|peer| peer.connected_addr.clone())
+            .collect()
+    }
+
     /// Get peer info
     pub fn get_peer(&self, session_id: SessionId) -> Option<&Peer> {
         self.peers.get(&session_id)
@@ -247,8 +396,11 @@ impl PeerRegistry {
         let total = self.peers.len() as u32;
         let mut non_whitelist_inbound: u32 = 0;
         let mut non_whitelist_outbound: u32 = 0;
+        let mut block_relay_only_outbound: u32 = 0;
         for peer in self.peers.values().filter(|peer| !peer.is_whitelist) {
-            if peer.is_outbound() {
+            if peer.is_block_relay_only {
+                block_relay_only_outbound += 1;
+            } else if peer.is_outbound() {
                 non_whitelist_outbound += 1;
             } else {
                 non_whitelist_inbound += 1;
@@ -258,8 +410,10 @@ impl PeerRegistry {
             total,
             non_whitelist_inbound,
             non_whitelist_outbound,
+            block_relay_only_outbound,
             max_inbound: self.max_inbound,
-            max_outbound: self.max_outbound,
+            max_outbound: self.max_outbound(),
+            max_outbound_block_relay: self.max_outbound_block_relay,
         }
     }
 }
@@ -1,15 +1,28 @@
 //! Address manager
-use crate::peer_store::types::AddrInfo;
+use crate::{peer_store::types::AddrInfo, proxy::onion_address};
 use p2p::{multiaddr::Multiaddr, utils::multiaddr_to_socketaddr};
 use rand::Rng;
 use std::collections::{HashMap, HashSet};
 use std::net::SocketAddr;
 
+/// Addresses are identified by ip and port, or by host and port for onion services
+#[derive(Clone, Debug, Hash, Eq, PartialEq)]
+enum AddrKey {
+    Socket(SocketAddr),
+    Onion(String, u16),
+}
+
+fn addr_key(addr: &Multiaddr) -> Option<AddrKey> {
+    multiaddr_to_socketaddr(addr)
+        .map(AddrKey::Socket)
+        .or_else(|| onion_address(addr).map(|(host, port)| AddrKey::Onion(host, port)))
+}
+
 /// Address manager
 #[derive(Default)]
 pub struct AddrManager {
     next_id: u64,
-    addr_to_id: HashMap<SocketAddr, u64>,
+    addr_to_id: HashMap<AddrKey, u64>,
     id_to_info: HashMap<u64, AddrInfo>,
     random_ids: Vec<u64>,
 }
@@ -17,7 +30,7 @@ pub struct AddrManager {
 impl AddrManager {
     /// Add an address information to address manager
     pub fn add(&mut self, mut addr_info: AddrInfo) {
-        if let Some(key) = multiaddr_to_socketaddr(&addr_info.addr) {
+        if let Some(key) = addr_key(&addr_info.addr) {
             if let Some(exists_last_connected_at_ms) = self
                 .get(&addr_info.addr)
                 .map(|addr| addr.last_connected_at_ms)
@@ -54,16 +67,18 @@ impl AddrManager {
             let j = rng.gen_range(i, self.random_ids.len());
             self.swap_random_id(j, i);
             let addr_info: AddrInfo = self.id_to_info[&self.random_ids[i]].to_owned();
-            if let Some(socket_addr) = multiaddr_to_socketaddr(&addr_info.addr) {
-                let ip = socket_addr.ip();
-                let is_unique_ip = duplicate_ips.insert(ip);
-                // A trick to make our tests work
-                // TODO remove this after fix the network tests.
-                let is_test_ip = ip.is_unspecified() || ip.is_loopback();
-                if (is_test_ip || is_unique_ip)
-                    && addr_info.is_connectable(now_ms)
-                    && filter(&addr_info)
-                {
+            if let Some(key) = addr_key(&addr_info.addr) {
+                let is_unique = match key {
+                    AddrKey::Socket(socket_addr) => {
+                        let ip = socket_addr.ip();
+                        // A trick to make our tests work
+                        // TODO remove this after fix the network tests.
+                        let is_test_ip = ip.is_unspecified() || ip.is_loopback();
+                        duplicate_ips.insert(ip) || is_test_ip
+                    }
+                    AddrKey::Onion(..) => true,
+                };
+                if is_unique && addr_info.is_connectable(now_ms) && filter(&addr_info) {
                     addr_infos.push(addr_info);
                 }
                 if addr_infos.len() == count {
@@ -86,8 +101,8 @@ impl AddrManager {
 
     /// Remove an address by ip and port
     pub fn remove(&mut self, addr: &Multiaddr) -> Option<AddrInfo> {
-        multiaddr_to_socketaddr(addr).and_then(|addr| {
-            self.addr_to_id.remove(&addr).and_then(|id| {
+        addr_key(addr).and_then(|key| {
+            self.addr_to_id.remove(&key).and_then(|id| {
                 let random_id_pos = self.id_to_info.get(&id).expect("exists").random_id_pos;
                 // swap with last index, then remove the last index
                 self.swap_random_id(random_id_pos, self.random_ids.len() - 1);
@@ -99,17 +114,17 @@ impl AddrManager {
 
     /// Get an address information by ip and port
     pub fn get(&self, addr: &Multiaddr) -> Option<&AddrInfo> {
-        multiaddr_to_socketaddr(addr).and_then(|addr| {
+        addr_key(addr).and_then(|key| {
             self.addr_to_id
-                .get(&addr)
+                .get(&key)
                 .and_then(|id| self.id_to_info.get(id))
         })
     }
 
     /// Get a mutable address information by ip and port
     pub fn get_mut(&mut self, addr: &Multiaddr) -> Option<&mut AddrInfo> {
-        if let Some(addr) = multiaddr_to_socketaddr(addr) {
-            if let Some(id) = self.addr_to_id.get(&addr) {
+        if let Some(key) = addr_key(addr) {
+            if let Some(id) = self.addr_to_id.get(&key) {
                 self.id_to_info.get_mut(id)
             } else {
                 None
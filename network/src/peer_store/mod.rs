@@ -42,6 +42,8 @@ pub struct PeerScoreConfig {
     pub ban_score: Score,
     /// Ban time
     pub ban_timeout_ms: u64,
+    /// A score below the default score recovers by one point per this interval
+    pub recovery_interval_ms: u64,
 }
 
 impl Default for PeerScoreConfig {
@@ -50,6 +52,7 @@ impl Default for PeerScoreConfig {
             default_score: 100,
             ban_score: 40,
             ban_timeout_ms: 24 * 3600 * 1000, // 1 day
+            recovery_interval_ms: 60 * 1000,  // 1 minute
         }
     }
 }
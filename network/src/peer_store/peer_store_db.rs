@@ -4,7 +4,7 @@ use crate::{
         addr_manager::AddrManager,
         ban_list::BanList,
         types::{AddrInfo, BannedAddr},
-        PeerStore,
+        Multiaddr, PeerStore,
     },
 };
 use ckb_logger::{debug, error};
@@ -16,6 +16,7 @@ use std::{
 
 const DEFAULT_ADDR_MANAGER_DB: &str = "addr_manager.db";
 const DEFAULT_BAN_LIST_DB: &str = "ban_list.db";
+const DEFAULT_ANCHORS_DB: &str = "anchors.db";
 
 impl AddrManager {
     /// Load address list from disk
@@ -64,6 +65,22 @@ impl BanList {
     }
 }
 
+/// Load anchor addresses from disk
+fn load_anchors<R: Read>(r: R) -> Result<Vec<Multiaddr>, Error> {
+    serde_json::from_reader(r).map_err(|err| PeerStoreError::Serde(err).into())
+}
+
+/// Dump anchor addresses to disk
+fn dump_anchors(anchors: &[Multiaddr], mut file: File) -> Result<(), Error> {
+    debug!("dump {} anchors", anchors.len());
+    // empty file and dump the json string to it
+    file.set_len(0)
+        .and_then(|_| serde_json::to_string(anchors).map_err(Into::into))
+        .and_then(|json_string| file.write_all(json_string.as_bytes()))
+        .and_then(|_| file.sync_all())
+        .map_err(Into::into)
+}
+
 impl PeerStore {
     /// Init peer store from disk
     pub fn load_from_dir_or_default<P: AsRef<Path>>(path: P) -> Self {
@@ -104,7 +121,27 @@ impl PeerStore {
             })
             .unwrap_or_default();
 
-        PeerStore::new(addr_manager, ban_list)
+        let anchors_path = path.as_ref().join(DEFAULT_ANCHORS_DB);
+        let anchors = File::open(&anchors_path)
+            .map_err(|err| {
+                debug!(
+                    "Failed to open anchors db, file: {:?}, error: {:?}",
+                    anchors_path, err
+                )
+            })
+            .and_then(|file| {
+                load_anchors(file).map_err(|err| {
+                    error!(
+                        "Failed to load anchors db, file: {:?}, error: {:?}",
+                        anchors_path, err
+                    )
+                })
+            })
+            .unwrap_or_default();
+
+        let mut peer_store = PeerStore::new(addr_manager, ban_list);
+        peer_store.set_anchors(anchors);
+        peer_store
     }
 
     /// Dump all info to disk
@@ -135,6 +172,16 @@ impl PeerStore {
                 .open(&tmp_ban_list)?,
         )?;
         move_file(tmp_ban_list, path.as_ref().join(DEFAULT_BAN_LIST_DB))?;
+        let tmp_anchors = tmp_dir.join(DEFAULT_ANCHORS_DB);
+        dump_anchors(
+            self.anchors(),
+            OpenOptions::new()
+                .write(true)
+                .create(true)
+                .append(false)
+                .open(&tmp_anchors)?,
+        )?;
+        move_file(tmp_anchors, path.as_ref().join(DEFAULT_ANCHORS_DB))?;
         Ok(())
     }
 }
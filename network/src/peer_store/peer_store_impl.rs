@@ -1,19 +1,25 @@
 use crate::{
     errors::{PeerStoreError, Result},
     extract_peer_id, multiaddr_to_socketaddr,
-    network_group::Group,
+    network_group::{AsMap, Group},
     peer_store::{
         addr_manager::AddrManager,
         ban_list::BanList,
         types::{ip_to_network, AddrInfo, BannedAddr, PeerInfo},
-        Behaviour, Multiaddr, PeerScoreConfig, ReportResult, Status, ADDR_COUNT_LIMIT,
+        Behaviour, Multiaddr, PeerScoreConfig, ReportResult, Score, Status, ADDR_COUNT_LIMIT,
         ADDR_TIMEOUT_MS, ADDR_TRY_TIMEOUT_MS, DIAL_INTERVAL,
     },
+    proxy::onion_address,
     PeerId, SessionType,
 };
 use ipnetwork::IpNetwork;
 use rand::prelude::IteratorRandom;
-use std::collections::{hash_map::Entry, HashMap};
+use std::cell::RefCell;
+use std::collections::{hash_map::Entry, HashMap, HashSet};
+use std::net::IpAddr;
+
+// The max number of disconnected addresses whose penalised score is remembered
+const PENALISED_ADDRS_LIMIT: usize = 4096;
 
 /// Peer store
 ///
@@ -24,7 +30,13 @@ pub struct PeerStore {
     addr_manager: AddrManager,
     ban_list: BanList,
     connected_peers: HashMap<PeerId, PeerInfo>,
+    // penalised scores of the disconnected peers with the update time, carried to reconnections
+    penalised_addrs: HashMap<IpAddr, (Score, u64)>,
     score_config: PeerScoreConfig,
+    anchors: Vec<Multiaddr>,
+    asmap: Option<AsMap>,
+    // onion addresses are only reachable through the proxy
+    onion_reachable: bool,
 }
 
 impl PeerStore {
@@ -34,13 +46,53 @@ impl PeerStore {
             addr_manager,
             ban_list,
             connected_peers: Default::default(),
+            penalised_addrs: Default::default(),
             score_config: Default::default(),
+            anchors: Vec::new(),
+            asmap: None,
+            onion_reachable: false,
+        }
+    }
+
+    /// Set scoring configuration
+    pub fn set_score_config(&mut self, score_config: PeerScoreConfig) {
+        self.score_config = score_config;
+    }
+
+    /// Set the AS map, addresses are grouped by ASN once it is set
+    pub fn set_asmap(&mut self, asmap: AsMap) {
+        self.asmap = Some(asmap);
+    }
+
+    /// Set whether the onion addresses are reachable, i.e. a proxy is configured,
+    /// unreachable onion addresses are neither stored nor returned for dialing
+    pub fn set_onion_reachable(&mut self, reachable: bool) {
+        self.onion_reachable = reachable;
+    }
+
+    fn is_reachable(&self, addr: &Multiaddr) -> bool {
+        self.onion_reachable || onion_address(addr).is_none()
+    }
+
+    /// Get the network group of an address
+    pub fn network_group(&self, addr: &Multiaddr) -> Group {
+        match self.asmap {
+            Some(ref asmap) => asmap.group(addr),
+            None => addr.into(),
         }
     }
 
     /// this method will assume peer is connected, which implies address is "verified".
     pub fn add_connected_peer(&mut self, addr: Multiaddr, session_type: SessionType) {
         let now_ms = faketime::unix_time_as_millis();
+        // a peer reconnecting, even from another port or with another peer id,
+        // starts with the score it had
+        let score = multiaddr_to_socketaddr(&addr)
+            .and_then(|socket_addr| self.penalised_addrs.get(&socket_addr.ip()))
+            .map(|(score, updated_at_ms)| {
+                recovered_score(&self.score_config, *score, *updated_at_ms, now_ms)
+            })
+            .unwrap_or(self.score_config.default_score);
         match self
             .connected_peers
             .entry(extract_peer_id(&addr).expect("connected addr should have peer id"))
@@ -52,7 +104,7 @@ impl PeerStore {
                 peer.session_type = session_type;
             }
             Entry::Vacant(entry) => {
-                let peer = PeerInfo::new(addr, session_type, now_ms);
+                let peer = PeerInfo::new(addr, session_type, now_ms, score);
                 entry.insert(peer);
             }
         }
@@ -61,7 +113,7 @@ impl PeerStore {
     /// Add discovered peer address
     /// this method will assume peer and addr is untrust since we have not connected to it.
     pub fn add_addr(&mut self, addr: Multiaddr) -> Result<()> {
-        if self.ban_list.is_addr_banned(&addr) {
+        if self.ban_list.is_addr_banned(&addr) || !self.is_reachable(&addr) {
             return Ok(());
         }
         self.check_purge()?;
@@ -72,7 +124,7 @@ impl PeerStore {
 
     /// Add outbound peer address
     pub fn add_outbound_addr(&mut self, addr: Multiaddr) {
-        if self.ban_list.is_addr_banned(&addr) {
+        if self.ban_list.is_addr_banned(&addr) || !self.is_reachable(&addr) {
             return;
         }
         let score = self.score_config.default_score;
@@ -91,25 +143,114 @@ impl PeerStore {
     }
 
     /// Report peer behaviours
+    ///
+    /// The score of a connected peer is tracked even if its address is not in the address
+    /// manager, e.g. an inbound peer, the addr is banned once the score drops below `ban_score`
+    /// on a behaviour which `can_ban`. A penalised score recovers by one point per `recovery_interval_ms`.
     pub fn report(&mut self, addr: &Multiaddr, behaviour: Behaviour) -> ReportResult {
-        if let Some(peer_addr) = self.addr_manager.get_mut(addr) {
-            let score = peer_addr.score.saturating_add(behaviour.score());
-            peer_addr.score = score;
-            if score < self.score_config.ban_score {
+        let now_ms = faketime::unix_time_as_millis();
+        let score_config = &self.score_config;
+        let peer_score = extract_peer_id(addr)
+            .and_then(|peer_id| self.connected_peers.get_mut(&peer_id))
+            .map(|peer| {
+                let score =
+                    recovered_score(score_config, peer.score, peer.score_updated_at_ms, now_ms);
+                peer.score = score.saturating_add(behaviour.score());
+                peer.score_updated_at_ms = now_ms;
+                peer.score
+            });
+        let score = peer_score.or_else(|| {
+            self.addr_manager.get_mut(addr).map(|peer_addr| {
+                peer_addr.score = peer_addr.score.saturating_add(behaviour.score());
+                peer_addr.score
+            })
+        });
+        match score {
+            Some(score) if score < self.score_config.ban_score && behaviour.can_ban() => {
                 self.ban_addr(
                     addr,
                     self.score_config.ban_timeout_ms,
                     format!("report behaviour {:?}", behaviour),
                 );
-                return ReportResult::Banned;
+                ReportResult::Banned
             }
+            _ => ReportResult::Ok,
         }
-        ReportResult::Ok
+    }
+
+    /// Get the score of a connected peer
+    pub fn peer_score(&self, peer_id: &PeerId) -> Option<Score> {
+        let now_ms = faketime::unix_time_as_millis();
+        self.connected_peers.get(peer_id).map(|peer| {
+            recovered_score(
+                &self.score_config,
+                peer.score,
+                peer.score_updated_at_ms,
+                now_ms,
+            )
+        })
+    }
+
+    /// Whether a connected peer has never been reported for misbehaviour, or has made up for it
+    pub fn is_well_behaved(&self, peer_id: &PeerId) -> bool {
+        self.peer_score(peer_id)
+            .map(|score| score >= self.score_config.default_score)
+            .unwrap_or(false)
+    }
+
+    /// Get the outbound peers to reconnect first on startup
+    pub fn anchors(&self) -> &[Multiaddr] {
+        &self.anchors
+    }
+
+    /// Set the outbound peers to reconnect first on startup
+    pub fn set_anchors(&mut self, anchors: Vec<Multiaddr>) {
+        self.anchors = anchors;
     }
 
     /// Remove peer id
     pub fn remove_disconnected_peer(&mut self, addr: &Multiaddr) -> Option<PeerInfo> {
-        extract_peer_id(addr).and_then(|peer_id| self.connected_peers.remove(&peer_id))
+        let peer = extract_peer_id(addr).and_then(|peer_id| self.connected_peers.remove(&peer_id));
+        if let Some(ref peer) = peer {
+            self.remember_penalised_score(peer);
+        }
+        peer
+    }
+
+    fn remember_penalised_score(&mut self, peer: &PeerInfo) {
+        let ip = match multiaddr_to_socketaddr(&peer.connected_addr) {
+            Some(socket_addr) => socket_addr.ip(),
+            None => return,
+        };
+        let now_ms = faketime::unix_time_as_millis();
+        let score = recovered_score(
+            &self.score_config,
+            peer.score,
+            peer.score_updated_at_ms,
+            now_ms,
+        );
+        if score >= self.score_config.default_score {
+            return;
+        }
+        if self.penalised_addrs.len() >= PENALISED_ADDRS_LIMIT {
+            let score_config = &self.score_config;
+            // forget the recovered scores first, then the least penalised one
+            self.penalised_addrs.retain(|_, (score, updated_at_ms)| {
+                recovered_score(score_config, *score, *updated_at_ms, now_ms)
+                    < score_config.default_score
+            });
+            if self.penalised_addrs.len() >= PENALISED_ADDRS_LIMIT {
+                if let Some(ip) = self
+                    .penalised_addrs
+                    .iter()
+                    .max_by_key(|(_, (score, _))| *score)
+                    .map(|(ip, _)| *ip)
+                {
+                    self.penalised_addrs.remove(&ip);
+                }
+            }
+        }
+        self.penalised_addrs.insert(ip, (score, now_ms));
     }
 
     /// Get peer status
@@ -126,19 +267,41 @@ impl PeerStore {
         // Get info:
         // 1. Not already connected
         // 2. Connected within 3 days
+        // 3. With an AS map, not in the network group of an outbound peer or of another
+        //    returned addr
 
         let now_ms = faketime::unix_time_as_millis();
         let peers = &self.connected_peers;
         let addr_expired_ms = now_ms.saturating_sub(ADDR_TRY_TIMEOUT_MS);
+        let onion_reachable = self.onion_reachable;
+        // without an AS map the addresses are picked regardless of their groups, as before
+        let used_groups: Option<(&AsMap, RefCell<HashSet<Group>>)> =
+            self.asmap.as_ref().map(|asmap| {
+                let groups = peers
+                    .values()
+                    .filter(|peer| peer.session_type.is_outbound())
+                    .map(|peer| asmap.group(&peer.connected_addr))
+                    .collect();
+                (asmap, RefCell::new(groups))
+            });
         // get addrs that can attempt.
         self.addr_manager
             .fetch_random(count, |peer_addr: &AddrInfo| {
                 extract_peer_id(&peer_addr.addr)
                     .map(|peer_id| !peers.contains_key(&peer_id))
                     .unwrap_or_default()
+                    && (onion_reachable || onion_address(&peer_addr.addr).is_none())
                     && peer_addr.connected(|t| {
                         t > addr_expired_ms && t <= now_ms.saturating_sub(DIAL_INTERVAL)
                     })
+                    && used_groups
+                        .as_ref()
+                        .map(|(asmap, used_groups)| match asmap.group(&peer_addr.addr) {
+                            // local or ungrouped addresses say nothing about diversity
+                            Group::None | Group::LocalNetwork => true,
+                            group => used_groups.borrow_mut().insert(group),
+                        })
+                        .unwrap_or(true)
             })
     }
 
@@ -153,11 +316,13 @@ impl PeerStore {
         let now_ms = faketime::unix_time_as_millis();
         let addr_expired_ms = now_ms.saturating_sub(ADDR_TRY_TIMEOUT_MS);
         let peers = &self.connected_peers;
+        let onion_reachable = self.onion_reachable;
         self.addr_manager
             .fetch_random(count, |peer_addr: &AddrInfo| {
                 extract_peer_id(&peer_addr.addr)
                     .map(|peer_id| !peers.contains_key(&peer_id))
                     .unwrap_or_default()
+                    && (onion_reachable || onion_address(&peer_addr.addr).is_none())
                     && !peer_addr.tried_in_last_minute(now_ms)
                     && !peer_addr.connected(|t| t > addr_expired_ms)
             })
@@ -258,7 +423,7 @@ impl PeerStore {
                 let mut peers_by_network_group: HashMap<Group, Vec<_>> = HashMap::default();
                 for addr in self.addr_manager.addrs_iter() {
                     peers_by_network_group
-                        .entry((&addr.addr).into())
+                        .entry(self.network_group(&addr.addr))
                         .or_default()
                         .push(addr);
                 }
@@ -302,3 +467,20 @@ impl PeerStore {
         Ok(())
     }
 }
+
+// A penalised score recovers over time, up to the default score
+fn recovered_score(
+    score_config: &PeerScoreConfig,
+    score: Score,
+    updated_at_ms: u64,
+    now_ms: u64,
+) -> Score {
+    if score >= score_config.default_score {
+        return score;
+    }
+    let recovered = now_ms.saturating_sub(updated_at_ms) / score_config.recovery_interval_ms.max(1);
+    let recovered = Score::try_from(recovered).unwrap_or(Score::MAX);
+    score
+        .saturating_add(recovered)
+        .min(score_config.default_score)
+}
@@ -14,6 +14,10 @@ pub struct PeerInfo {
     pub session_type: SessionType,
     /// Connected time
     pub last_connected_at_ms: u64,
+    /// Misbehaviour score of the session
+    pub score: Score,
+    /// When the score was last updated
+    pub score_updated_at_ms: u64,
 }
 
 impl PeerInfo {
@@ -22,11 +26,14 @@ impl PeerInfo {
         connected_addr: Multiaddr,
         session_type: SessionType,
         last_connected_at_ms: u64,
+        score: Score,
     ) -> Self {
         PeerInfo {
             connected_addr,
             session_type,
             last_connected_at_ms,
+            score,
+            score_updated_at_ms: last_connected_at_ms,
         }
     }
 }
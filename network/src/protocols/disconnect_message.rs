@@ -22,6 +22,9 @@ impl ServiceProtocol for DisconnectMessageProtocol {
 
     fn received(&mut self, context: ProtocolContextMutRef, data: Bytes) {
         let session_id = context.session.id;
+        self.0
+            .bandwidth
+            .record_received(session_id, context.proto_id, data.len());
         if let Ok(message) = String::from_utf8(data.to_vec()) {
             info!(
                 "Received disconnect message from peer={}: {}",
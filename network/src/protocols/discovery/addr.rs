@@ -43,6 +43,10 @@ pub trait AddressManager {
     fn add_new_addrs(&mut self, session_id: SessionId, addrs: Vec<Multiaddr>);
     fn misbehave(&mut self, session: &SessionContext, kind: &Misbehavior) -> MisbehaveResult;
     fn get_random(&mut self, n: usize) -> Vec<Multiaddr>;
+    /// Count the bytes of a message sent to a session
+    fn record_sent(&self, id: SessionId, pid: ProtocolId, bytes: usize);
+    /// Count the bytes of a message received from a session
+    fn record_received(&self, id: SessionId, pid: ProtocolId, bytes: usize);
 }
 
 // bitcoin: bloom.h, bloom.cpp => CRollingBloomFilter
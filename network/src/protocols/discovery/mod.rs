@@ -24,7 +24,7 @@ use self::{
     protocol::{decode, encode},
     state::RemoteAddress,
 };
-use crate::{NetworkState, ProtocolId};
+use crate::{proxy::onion_address, NetworkState, ProtocolId};
 
 mod addr;
 pub(crate) mod protocol;
@@ -94,6 +94,8 @@ impl<M: AddressManager> ServiceProtocol for DiscoveryProtocol<M> {
     fn received(&mut self, context: ProtocolContextMutRef, data: bytes::Bytes) {
         let session = context.session;
         trace!("[received message]: length={}", data.len());
+        self.addr_mgr
+            .record_received(session.id, context.proto_id, data.len());
 
         let mgr = &mut self.addr_mgr;
         let mut check = |behavior: Misbehavior| -> bool {
@@ -169,6 +171,8 @@ impl<M: AddressManager> ServiceProtocol for DiscoveryProtocol<M> {
                             };
 
                             let msg = encode(DiscoveryMessage::Nodes(nodes), v2);
+                            self.addr_mgr
+                                .record_sent(session.id, context.proto_id, msg.len());
                             if context.send_message(msg).is_err() {
                                 debug!("{:?} send discovery msg Nodes fail", session.id)
                             }
@@ -228,7 +232,7 @@ impl<M: AddressManager> ServiceProtocol for DiscoveryProtocol<M> {
             .iter_mut()
             .filter_map(|(id, state)| {
                 // send all announce addr to remote
-                state.send_messages(context, *id);
+                state.send_messages(context, addr_mgr, *id);
                 // check timer
                 state
                     .check_timer(now, ANNOUNCE_INTERVAL)
@@ -324,7 +328,9 @@ impl AddressManager for DiscoveryAddressManager {
         if !self.discovery_local_address {
             let local_or_invalid = multiaddr_to_socketaddr(addr)
                 .map(|socket_addr| !is_reachable(socket_addr.ip()))
-                .unwrap_or(true);
+                .unwrap_or_else(|| {
+                    onion_address(addr).is_none() || !self.network_state.onion_reachable()
+                });
             !local_or_invalid
         } else {
             true
@@ -379,4 +385,12 @@ impl AddressManager for DiscoveryAddressManager {
         trace!("discovery send random addrs: {:?}", addrs);
         addrs
     }
+
+    fn record_sent(&self, id: SessionId, pid: ProtocolId, bytes: usize) {
+        self.network_state.bandwidth.record_sent(id, pid, bytes);
+    }
+
+    fn record_received(&self, id: SessionId, pid: ProtocolId, bytes: usize) {
+        self.network_state.bandwidth.record_received(id, pid, bytes);
+    }
 }
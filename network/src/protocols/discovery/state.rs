@@ -69,6 +69,7 @@ impl SessionState {
                 v2,
             );
 
+            addr_manager.record_sent(context.session.id, context.proto_id, msg.len());
             if context.send_message(msg).is_err() {
                 debug!("{:?} send discovery msg GetNode fail", context.session.id)
             }
@@ -108,7 +109,12 @@ impl SessionState {
         }
     }
 
-    pub(crate) fn send_messages(&mut self, cx: &mut ProtocolContext, id: SessionId) {
+    pub(crate) fn send_messages<M: AddressManager>(
+        &mut self,
+        cx: &mut ProtocolContext,
+        addr_manager: &M,
+        id: SessionId,
+    ) {
         if !self.announce_multiaddrs.is_empty() {
             let items = self
                 .announce_multiaddrs
@@ -124,6 +130,7 @@ impl SessionState {
                 }),
                 self.v2,
             );
+            addr_manager.record_sent(id, cx.proto_id, msg.len());
             if cx.send_message_to(id, cx.proto_id, msg).is_err() {
                 debug!("{:?} send discovery msg Nodes fail", id)
             }
@@ -11,12 +11,12 @@ use p2p::{
     service::{SessionType, TargetProtocol},
     traits::ServiceProtocol,
     utils::{extract_peer_id, is_reachable, multiaddr_to_socketaddr},
-    SessionId,
+    ProtocolId, SessionId,
 };
 
 mod protocol;
 
-use crate::{NetworkState, PeerIdentifyInfo, SupportProtocols};
+use crate::{proxy::onion_address, NetworkState, PeerIdentifyInfo, SupportProtocols};
 use ckb_types::{packed, prelude::*};
 use std::sync::atomic::Ordering;
 
@@ -79,6 +79,10 @@ pub trait Callback: Clone + Send {
     fn add_observed_addr(&mut self, addr: Multiaddr, ty: SessionType) -> MisbehaveResult;
     /// Report misbehavior
     fn misbehave(&mut self, session: &SessionContext, kind: Misbehavior) -> MisbehaveResult;
+    /// Count the bytes of a message sent to a session
+    fn record_sent(&self, session: SessionId, proto_id: ProtocolId, bytes: usize);
+    /// Count the bytes of a message received from a session
+    fn record_received(&self, session: SessionId, proto_id: ProtocolId, bytes: usize);
 }
 
 /// Identify protocol
@@ -140,7 +144,7 @@ impl<T: Callback> IdentifyProtocol<T> {
                 .filter(|addr| {
                     multiaddr_to_socketaddr(addr)
                         .map(|socket_addr| !global_ip_only || is_reachable(socket_addr.ip()))
-                        .unwrap_or(false)
+                        .unwrap_or_else(|| onion_address(addr).is_some())
                 })
                 .collect::<Vec<_>>();
             self.callback
@@ -223,7 +227,7 @@ impl<T: Callback> ServiceProtocol for IdentifyProtocol<T> {
             .filter(|addr| {
                 multiaddr_to_socketaddr(addr)
                     .map(|socket_addr| !self.global_ip_only || is_reachable(socket_addr.ip()))
-                    .unwrap_or(false)
+                    .unwrap_or_else(|| onion_address(addr).is_some())
             })
             .take(MAX_ADDRS)
             .cloned()
@@ -231,6 +235,8 @@ impl<T: Callback> ServiceProtocol for IdentifyProtocol<T> {
 
         let identify = self.callback.identify();
         let data = IdentifyMessage::new(listen_addrs, session.address.clone(), identify).encode();
+        self.callback
+            .record_sent(session.id, context.proto_id, data.len());
         let _ = context
             .quick_send_message(data)
             .map_err(|err| error!("IdentifyProtocol quick_send_message, error: {:?}", err));
@@ -249,6 +255,8 @@ impl<T: Callback> ServiceProtocol for IdentifyProtocol<T> {
 
     fn received(&mut self, mut context: ProtocolContextMutRef, data: Bytes) {
         let session = context.session;
+        self.callback
+            .record_received(session.id, context.proto_id, data.len());
         match IdentifyMessage::decode(&data) {
             Some(message) => {
                 trace!(
@@ -445,10 +453,21 @@ impl Callback for IdentifyCallback {
                             .network_state
                             .ckb2021
                             .load(std::sync::atomic::Ordering::SeqCst);
+                        let block_relay_only = self.network_state.with_peer_registry(|reg| {
+                            reg.get_peer(context.session.id)
+                                .map(|peer| peer.is_block_relay_only)
+                                .unwrap_or(false)
+                        });
                         // The remote end can support all local protocols.
+                        // Block-relay-only peers take no part in address gossip.
                         let _ = context.open_protocols(
                             context.session.id,
                             TargetProtocol::Filter(Box::new(move |id| {
+                                if block_relay_only
+                                    && id == &SupportProtocols::Discovery.protocol_id()
+                                {
+                                    return false;
+                                }
                                 if ckb2021 {
                                     id != &SupportProtocols::Feeler.protocol_id()
                                         && id != &SupportProtocols::Relay.protocol_id()
@@ -542,6 +561,18 @@ impl Callback for IdentifyCallback {
         );
         MisbehaveResult::Disconnect
     }
+
+    fn record_sent(&self, session: SessionId, proto_id: ProtocolId, bytes: usize) {
+        self.network_state
+            .bandwidth
+            .record_sent(session, proto_id, bytes);
+    }
+
+    fn record_received(&self, session: SessionId, proto_id: ProtocolId, bytes: usize) {
+        self.network_state
+            .bandwidth
+            .record_received(session, proto_id, bytes);
+    }
 }
 
 #[derive(Clone)]
@@ -90,6 +90,21 @@ pub trait CKBProtocolContext: Send {
     fn p2p_control(&self) -> Option<&ServiceControl> {
         None
     }
+    /// Whether the global or the peer upload limit of the current window is reached
+    fn upload_limit_reached(&self, _peer_index: PeerIndex) -> bool {
+        false
+    }
+    /// Record that the peer delivered a new valid block, such peers are protected from eviction
+    fn block_delivered(&self, _peer_index: PeerIndex) {}
+    /// Record that the peer delivered a new valid transaction, such peers are protected from
+    /// eviction
+    fn transaction_delivered(&self, _peer_index: PeerIndex) {}
+    /// Allow or disallow the outbound peer service to dial one extra outbound peer beyond the limit
+    fn set_try_new_outbound_peer(&self, _enable: bool) {}
+    /// The count of the outbound connections beyond the limit
+    fn extra_outbound_count(&self) -> u32 {
+        0
+    }
 }
 
 /// Abstract protocol handle base on tentacle service handle
@@ -194,6 +209,13 @@ impl CKBProtocol {
         self.supported_versions.contains(&version)
     }
 
+    /// Drops the supported versions newer than the given one
+    pub fn max_version(mut self, version: u32) -> Self {
+        self.supported_versions
+            .retain(|v| v.parse::<u32>().map(|v| v <= version).unwrap_or(true));
+        self
+    }
+
     /// Build to tentacle protocol meta
     pub fn build(self) -> ProtocolMeta {
         let protocol_name = self.protocol_name();
@@ -299,6 +321,9 @@ impl ServiceProtocol for CKBHandler {
     }
 
     fn received(&mut self, context: ProtocolContextMutRef, data: Bytes) {
+        self.network_state
+            .bandwidth
+            .record_received(context.session.id, self.proto_id, data.len());
         if !self.network_state.is_active() {
             return;
         }
@@ -378,8 +403,12 @@ impl CKBProtocolContext for DefaultCKBProtocolContext {
             peer_index,
             data.len()
         );
+        let bytes = data.len();
         self.p2p_control
             .quick_send_message_to(peer_index, proto_id, data)?;
+        self.network_state
+            .bandwidth
+            .record_sent(peer_index, proto_id, bytes);
         Ok(())
     }
     fn quick_send_message_to(&self, peer_index: PeerIndex, data: Bytes) -> Result<(), Error> {
@@ -389,11 +418,19 @@ impl CKBProtocolContext for DefaultCKBProtocolContext {
             peer_index,
             data.len()
         );
+        let bytes = data.len();
         self.p2p_control
             .quick_send_message_to(peer_index, self.proto_id, data)?;
+        self.network_state
+            .bandwidth
+            .record_sent(peer_index, self.proto_id, bytes);
         Ok(())
     }
     fn quick_filter_broadcast(&self, target: TargetSession, data: Bytes) -> Result<(), Error> {
+        let target =
+            self.network_state
+                .bandwidth
+                .record_broadcast(target, self.proto_id, data.len());
         self.p2p_control
             .quick_filter_broadcast(target, self.proto_id, data)?;
         Ok(())
@@ -419,8 +456,12 @@ impl CKBProtocolContext for DefaultCKBProtocolContext {
             peer_index,
             data.len()
         );
+        let bytes = data.len();
         self.p2p_control
             .send_message_to(peer_index, proto_id, data)?;
+        self.network_state
+            .bandwidth
+            .record_sent(peer_index, proto_id, bytes);
         Ok(())
     }
     fn send_message_to(&self, peer_index: PeerIndex, data: Bytes) -> Result<(), Error> {
@@ -430,11 +471,19 @@ impl CKBProtocolContext for DefaultCKBProtocolContext {
             peer_index,
             data.len()
         );
+        let bytes = data.len();
         self.p2p_control
             .send_message_to(peer_index, self.proto_id, data)?;
+        self.network_state
+            .bandwidth
+            .record_sent(peer_index, self.proto_id, bytes);
         Ok(())
     }
     fn filter_broadcast(&self, target: TargetSession, data: Bytes) -> Result<(), Error> {
+        let target =
+            self.network_state
+                .bandwidth
+                .record_broadcast(target, self.proto_id, data.len());
         self.p2p_control
             .filter_broadcast(target, self.proto_id, data)?;
         Ok(())
@@ -486,6 +535,35 @@ impl CKBProtocolContext for DefaultCKBProtocolContext {
     fn p2p_control(&self) -> Option<&ServiceControl> {
         Some(&self.p2p_control)
     }
+    fn upload_limit_reached(&self, peer_index: PeerIndex) -> bool {
+        self.network_state
+            .bandwidth
+            .upload_limit_reached(peer_index)
+    }
+    fn block_delivered(&self, peer_index: PeerIndex) {
+        self.network_state.with_peer_registry(|reg| {
+            if let Some(peer) = reg.get_peer(peer_index) {
+                peer.last_block_delivered_at
+                    .set(faketime::unix_time_as_millis());
+            }
+        });
+    }
+    fn transaction_delivered(&self, peer_index: PeerIndex) {
+        self.network_state.with_peer_registry(|reg| {
+            if let Some(peer) = reg.get_peer(peer_index) {
+                peer.last_transaction_delivered_at
+                    .set(faketime::unix_time_as_millis());
+            }
+        });
+    }
+    fn set_try_new_outbound_peer(&self, enable: bool) {
+        self.network_state
+            .with_peer_registry_mut(|reg| reg.set_try_new_outbound_peer(enable));
+    }
+    fn extra_outbound_count(&self) -> u32 {
+        self.network_state
+            .with_peer_registry(|reg| reg.extra_outbound_count())
+    }
 }
 
 pub(crate) struct BlockingFutureTask {
@@ -100,6 +100,11 @@ impl PingHandler {
             debug!("start ping peers: {:?}", peers);
             let ping_msg = PingMessage::build_ping(send_nonce);
             let proto_id = context.proto_id;
+            for session_id in &peers {
+                self.network_state
+                    .bandwidth
+                    .record_sent(*session_id, proto_id, ping_msg.len());
+            }
             if context
                 .filter_broadcast(
                     TargetSession::Filter(Box::new(move |id| peers.contains(id))),
@@ -197,6 +202,9 @@ impl ServiceProtocol for PingHandler {
 
     fn received(&mut self, context: ProtocolContextMutRef, data: Bytes) {
         let session = context.session;
+        self.network_state
+            .bandwidth
+            .record_received(session.id, context.proto_id, data.len());
         match PingMessage::decode(data.as_ref()) {
             None => {
                 error!("decode message error");
@@ -210,10 +218,13 @@ impl ServiceProtocol for PingHandler {
                 match msg {
                     PingPayload::Ping(nonce) => {
                         self.ping_received(session.id);
-                        if context
-                            .send_message(PingMessage::build_pong(nonce))
-                            .is_err()
-                        {
+                        let pong_msg = PingMessage::build_pong(nonce);
+                        self.network_state.bandwidth.record_sent(
+                            session.id,
+                            context.proto_id,
+                            pong_msg.len(),
+                        );
+                        if context.send_message(pong_msg).is_err() {
                             debug!("send message fail");
                         }
                     }
@@ -42,13 +42,18 @@ pub enum SupportProtocols {
     ///
     /// [RFC](https://github.com/nervosnetwork/rfcs/blob/master/rfcs/0004-ckb-block-sync/0004-ckb-block-sync.md#new-block-announcement)
     Relay,
-    /// New Relay
+    /// New Relay, the version 3 supports the Dandelion++ stem transactions, the version 4
+    /// supports the set reconciliation of the transaction announcements
     RelayV2,
     /// Time: A protocol used for node pairing that warns if there is a large gap between the local time and the remote node.
     Time,
     /// Alert: A protocol reserved by the Nervos Foundation to publish network-wide announcements.
     /// Any information sent from the protocol is verified by multi-signature
     Alert,
+    /// BlockFilter: serves the compact block filters and filter hashes to light clients.
+    BlockFilter,
+    /// LightClient: serves the verified headers, the chain tip and the transaction proofs to light clients.
+    LightClient,
 }
 
 impl SupportProtocols {
@@ -65,6 +70,8 @@ impl SupportProtocols {
             SupportProtocols::Time => 102,
             SupportProtocols::RelayV2 => 103,
             SupportProtocols::Alert => 110,
+            SupportProtocols::BlockFilter => 120,
+            SupportProtocols::LightClient => 121,
         }
         .into()
     }
@@ -82,6 +89,8 @@ impl SupportProtocols {
             SupportProtocols::RelayV2 => "/ckb/relay",
             SupportProtocols::Time => "/ckb/tim",
             SupportProtocols::Alert => "/ckb/alt",
+            SupportProtocols::BlockFilter => "/ckb/filter",
+            SupportProtocols::LightClient => "/ckb/lightclient",
         }
         .to_owned()
     }
@@ -101,7 +110,11 @@ impl SupportProtocols {
             SupportProtocols::Relay => vec!["1".to_owned()],
             SupportProtocols::Time => vec!["1".to_owned(), "2".to_owned()],
             SupportProtocols::Alert => vec!["1".to_owned(), "2".to_owned()],
-            SupportProtocols::RelayV2 => vec!["2".to_owned()],
+            SupportProtocols::RelayV2 => {
+                vec!["2".to_owned(), "3".to_owned(), "4".to_owned()]
+            }
+            SupportProtocols::BlockFilter => vec!["2".to_owned()],
+            SupportProtocols::LightClient => vec!["2".to_owned()],
         }
     }
 
@@ -117,6 +130,8 @@ impl SupportProtocols {
             SupportProtocols::Relay | SupportProtocols::RelayV2 => 4 * 1024 * 1024, // 4   MB
             SupportProtocols::Time => 1024,              // 1   KB
             SupportProtocols::Alert => 128 * 1024,       // 128 KB
+            SupportProtocols::BlockFilter => 2 * 1024 * 1024, // 2   MB
+            SupportProtocols::LightClient => 2 * 1024 * 1024, // 2   MB
         }
     }
 
@@ -134,7 +149,11 @@ impl SupportProtocols {
                 no_blocking_flag.disable_all();
                 no_blocking_flag
             }
-            SupportProtocols::Sync | SupportProtocols::Relay | SupportProtocols::RelayV2 => {
+            SupportProtocols::Sync
+            | SupportProtocols::Relay
+            | SupportProtocols::RelayV2
+            | SupportProtocols::BlockFilter
+            | SupportProtocols::LightClient => {
                 let mut blocking_recv_flag = BlockingFlag::default();
                 blocking_recv_flag.disable_connected();
                 blocking_recv_flag.disable_disconnected();
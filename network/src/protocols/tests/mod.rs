@@ -370,6 +370,36 @@ fn test_identify_behavior() {
     );
 }
 
+#[test]
+fn test_builtin_protocols_bandwidth() {
+    let node1 = net_service_start("/test/1".to_string(), false);
+    let node2 = net_service_start("/test/1".to_string(), false);
+
+    node1.dial(
+        &node2,
+        TargetProtocol::Single(SupportProtocols::Identify.protocol_id()),
+    );
+
+    wait_connect_state(&node1, 1);
+    wait_connect_state(&node2, 1);
+
+    let counted = |node: &Node, protocol: &SupportProtocols| {
+        node.network_state
+            .bandwidth
+            .protocol_bandwidth()
+            .get(&protocol.protocol_id())
+            .map(|bandwidth| bandwidth.sent > 0 && bandwidth.received > 0)
+            .unwrap_or(false)
+    };
+    for protocol in &[SupportProtocols::Identify, SupportProtocols::Discovery] {
+        if !wait_until(10, || {
+            counted(&node1, protocol) && counted(&node2, protocol)
+        }) {
+            panic!("{} messages are not counted", protocol.name())
+        }
+    }
+}
+
 #[test]
 fn test_feeler_behavior() {
     let node1 = net_service_start("/test/1".to_string(), true);
@@ -0,0 +1,246 @@
+//! SOCKS5 proxy for outbound connections
+//!
+//! When `proxy` is set in the network config, every outbound dial is sent to a background
+//! service which connects to the target through the proxy, then hands the stream over to
+//! tentacle as a raw session.
+//!
+//! The pinned multiaddr (tentacle-multiaddr 0.3.2) does not know `/onion3`, the first release
+//! which does requires a newer rustc than the one in `rust-toolchain`. Until then an onion service
+//! is addressed by its host name, e.g. `/dns4/<service id>.onion/tcp/8115/p2p/<peer id>`. Any
+//! host name ending with `.onion`, in any case, is taken as an onion service: it is only dialed
+//! through the proxy, which resolves it, and never passed to the system resolver.
+use crate::{errors::Error, NetworkState};
+use ckb_app_config::ProxyConfig;
+use ckb_logger::debug;
+use ckb_util::Mutex;
+use futures::{
+    channel::mpsc::{self, UnboundedReceiver, UnboundedSender},
+    stream::FuturesUnordered,
+    Future, StreamExt,
+};
+use p2p::{
+    multiaddr::{Multiaddr, Protocol},
+    service::{RawSessionInfo, ServiceControl, TargetProtocol},
+    utils::multiaddr_to_socketaddr,
+};
+use std::io;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpStream;
+
+const PROXY_CONNECT_TIMEOUT: Duration = Duration::from_secs(30);
+
+const SOCKS_VERSION: u8 = 5;
+const METHOD_NO_AUTH: u8 = 0;
+const METHOD_USERNAME_PASSWORD: u8 = 2;
+const USERNAME_PASSWORD_VERSION: u8 = 1;
+const CMD_CONNECT: u8 = 1;
+const ATYP_IPV4: u8 = 1;
+const ATYP_DOMAIN: u8 = 3;
+const ATYP_IPV6: u8 = 4;
+const REPLY_SUCCEEDED: u8 = 0;
+// The base32 encoded length of the v3 onion service id
+const ONION_SERVICE_ID_LEN: usize = 56;
+
+/// Returns the lowercased host and the port of an onion service address
+pub(crate) fn onion_address(addr: &Multiaddr) -> Option<(String, u16)> {
+    let (host, port) = host_and_port(addr)?;
+    let host = host.trim_end_matches('.').to_ascii_lowercase();
+    if host.ends_with(".onion") {
+        Some((host, port))
+    } else {
+        None
+    }
+}
+
+/// Whether the onion host is a v3 onion service, i.e. its service id is 56 base32 characters
+pub(crate) fn is_valid_onion_host(host: &str) -> bool {
+    host.strip_suffix(".onion")
+        .map(|id| {
+            id.len() == ONION_SERVICE_ID_LEN
+                && id.bytes().all(|b| matches!(b, b'a'..=b'z' | b'2'..=b'7'))
+        })
+        .unwrap_or(false)
+}
+
+fn host_and_port(addr: &Multiaddr) -> Option<(String, u16)> {
+    let mut host = None;
+    let mut port = None;
+    for proto in addr.iter() {
+        match proto {
+            Protocol::Dns4(name) | Protocol::Dns6(name) => host = Some(name.to_string()),
+            Protocol::Tcp(tcp_port) => port = Some(tcp_port),
+            _ => {}
+        }
+    }
+    host.zip(port)
+}
+
+fn proxy_error<S: Into<String>>(message: S) -> io::Error {
+    io::Error::new(io::ErrorKind::Other, message.into())
+}
+
+/// Connects to `addr` through the SOCKS5 proxy, host names are sent to the proxy unresolved.
+pub(crate) async fn socks5_connect(
+    config: &ProxyConfig,
+    addr: &Multiaddr,
+) -> io::Result<TcpStream> {
+    let mut request = vec![SOCKS_VERSION, CMD_CONNECT, 0];
+    let port = match multiaddr_to_socketaddr(addr) {
+        Some(SocketAddr::V4(socket_addr)) => {
+            request.push(ATYP_IPV4);
+            request.extend_from_slice(&socket_addr.ip().octets());
+            socket_addr.port()
+        }
+        Some(SocketAddr::V6(socket_addr)) => {
+            request.push(ATYP_IPV6);
+            request.extend_from_slice(&socket_addr.ip().octets());
+            socket_addr.port()
+        }
+        None => {
+            let (host, port) = host_and_port(addr).ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("address {} can not be dialed through proxy", addr),
+                )
+            })?;
+            if host.len() > u8::MAX as usize {
+                return Err(proxy_error(format!("host name {} is too long", host)));
+            }
+            request.push(ATYP_DOMAIN);
+            request.push(host.len() as u8);
+            request.extend_from_slice(host.as_bytes());
+            port
+        }
+    };
+    request.extend_from_slice(&port.to_be_bytes());
+
+    let mut stream = TcpStream::connect(config.address).await?;
+
+    let method = if config.username.is_some() {
+        METHOD_USERNAME_PASSWORD
+    } else {
+        METHOD_NO_AUTH
+    };
+    stream.write_all(&[SOCKS_VERSION, 1, method]).await?;
+    let mut reply = [0u8; 2];
+    stream.read_exact(&mut reply).await?;
+    if reply != [SOCKS_VERSION, method] {
+        return Err(proxy_error("no acceptable authentication method"));
+    }
+
+    if let Some(ref username) = config.username {
+        let password = config.password.as_deref().unwrap_or_default();
+        if username.len() > u8::MAX as usize || password.len() > u8::MAX as usize {
+            return Err(proxy_error("username or password is too long"));
+        }
+        let mut auth = vec![USERNAME_PASSWORD_VERSION, username.len() as u8];
+        auth.extend_from_slice(username.as_bytes());
+        auth.push(password.len() as u8);
+        auth.extend_from_slice(password.as_bytes());
+        stream.write_all(&auth).await?;
+        stream.read_exact(&mut reply).await?;
+        if reply[1] != REPLY_SUCCEEDED {
+            return Err(proxy_error("authentication failed"));
+        }
+    }
+
+    stream.write_all(&request).await?;
+    let mut reply = [0u8; 4];
+    stream.read_exact(&mut reply).await?;
+    if reply[0] != SOCKS_VERSION {
+        return Err(proxy_error("invalid reply"));
+    }
+    if reply[1] != REPLY_SUCCEEDED {
+        return Err(proxy_error(format!(
+            "connect to {} failed, reply code {}",
+            addr, reply[1]
+        )));
+    }
+    // skip the bound address and port
+    let bound_len = match reply[3] {
+        ATYP_IPV4 => 4,
+        ATYP_IPV6 => 16,
+        ATYP_DOMAIN => {
+            let mut len = [0u8; 1];
+            stream.read_exact(&mut len).await?;
+            len[0] as usize
+        }
+        atyp => return Err(proxy_error(format!("invalid address type {}", atyp))),
+    };
+    let mut bound = vec![0u8; bound_len + 2];
+    stream.read_exact(&mut bound).await?;
+
+    Ok(stream)
+}
+
+type ProxyDial = (Multiaddr, TargetProtocol);
+
+/// Dials through the SOCKS5 proxy
+pub(crate) struct ProxyDialer {
+    config: ProxyConfig,
+    sender: UnboundedSender<ProxyDial>,
+    receiver: Mutex<Option<UnboundedReceiver<ProxyDial>>>,
+}
+
+impl ProxyDialer {
+    pub(crate) fn new(config: ProxyConfig) -> Self {
+        let (sender, receiver) = mpsc::unbounded();
+        ProxyDialer {
+            config,
+            sender,
+            receiver: Mutex::new(Some(receiver)),
+        }
+    }
+
+    pub(crate) fn dial(&self, addr: Multiaddr, target: TargetProtocol) -> Result<(), Error> {
+        self.sender
+            .unbounded_send((addr, target))
+            .map_err(|err| Error::Dial(format!("proxy dialer is stopped: {}", err)))
+    }
+
+    /// The background service connecting the dials, it can only be taken once
+    pub(crate) fn service(
+        &self,
+        network_state: Arc<NetworkState>,
+        p2p_control: ServiceControl,
+    ) -> Option<impl Future<Output = ()> + Send + 'static> {
+        let mut receiver = self.receiver.lock().take()?;
+        let config = self.config.clone();
+        Some(async move {
+            let mut connecting = FuturesUnordered::new();
+            loop {
+                tokio::select! {
+                    Some((addr, target)) = receiver.next() => {
+                        let config = config.clone();
+                        connecting.push(async move {
+                            let result = tokio::time::timeout(
+                                PROXY_CONNECT_TIMEOUT,
+                                socks5_connect(&config, &addr),
+                            )
+                            .await
+                            .unwrap_or_else(|_| {
+                                Err(io::Error::new(io::ErrorKind::TimedOut, "proxy connect timeout"))
+                            });
+                            (addr, target, result)
+                        });
+                    }
+                    Some((addr, target, result)) = connecting.next(), if !connecting.is_empty() => {
+                        let opened = result.map_err(|err| err.to_string()).and_then(|stream| {
+                            p2p_control
+                                .raw_session(stream, addr.clone(), RawSessionInfo::outbound(target))
+                                .map_err(|err| format!("{:?}", err))
+                        });
+                        if let Err(err) = opened {
+                            debug!("dial {} through proxy failed: {}", addr, err);
+                            network_state.dial_failed(&addr);
+                        }
+                    }
+                    else => break,
+                }
+            }
+        })
+    }
+}
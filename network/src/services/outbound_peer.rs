@@ -135,6 +135,38 @@ impl OutboundPeerService {
         }
     }
 
+    fn try_dial_block_relay_only_peers(&mut self) {
+        let status = self.network_state.connection_status();
+        let count = status
+            .max_outbound_block_relay
+            .saturating_sub(status.block_relay_only_outbound) as usize;
+        if count == 0 {
+            return;
+        }
+        let now_ms = unix_time_as_millis();
+        let attempt_peers = self.network_state.with_peer_store_mut(|peer_store| {
+            let paddrs = peer_store.fetch_addrs_to_attempt(count);
+            for paddr in paddrs.iter() {
+                // mark addr as tried
+                if let Some(paddr) = peer_store.mut_addr_manager().get_mut(&paddr.addr) {
+                    paddr.mark_tried(now_ms);
+                }
+            }
+            paddrs
+        });
+
+        trace!(
+            "block relay only dial count={}, attempt_peers: {:?}",
+            attempt_peers.len(),
+            attempt_peers,
+        );
+
+        for addr in attempt_peers.into_iter().map(|info| info.addr) {
+            self.network_state
+                .dial_block_relay_only(&self.p2p_control, addr);
+        }
+    }
+
     fn try_dial_whitelist(&self) {
         for addr in self.network_state.config.whitelist_peers() {
             self.network_state.dial_identify(&self.p2p_control, addr);
@@ -167,6 +199,8 @@ impl Future for OutboundPeerService {
             self.dial_feeler();
             // keep outbound peer is enough
             self.try_dial_peers();
+            // keep block-relay-only outbound peer is enough
+            self.try_dial_block_relay_only_peers();
             // try dial observed addrs
             self.try_dial_observed();
         }
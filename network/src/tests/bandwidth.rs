@@ -0,0 +1,81 @@
+use crate::{
+    bandwidth::{BandwidthMonitor, ProtocolBandwidth},
+    PeerIndex, SupportProtocols,
+};
+use ckb_app_config::BandwidthConfig;
+use p2p::service::TargetSession;
+use std::sync::Arc;
+
+#[test]
+fn test_bandwidth_accounting() {
+    let monitor = Arc::new(BandwidthMonitor::new(Default::default()));
+    let sync = SupportProtocols::Sync.protocol_id();
+    let relay = SupportProtocols::Relay.protocol_id();
+    let peer1: PeerIndex = 1.into();
+    let peer2: PeerIndex = 2.into();
+
+    monitor.record_sent(peer1, sync, 100);
+    monitor.record_received(peer1, sync, 10);
+    monitor.record_received(peer2, relay, 20);
+    let target = monitor.record_broadcast(
+        TargetSession::Filter(Box::new(move |id| *id == peer2)),
+        relay,
+        5,
+    );
+    if let TargetSession::Filter(mut filter) = target {
+        assert!(!filter(&peer1));
+        assert!(filter(&peer2));
+    } else {
+        panic!("a filter stays a filter");
+    }
+
+    let peer1_bandwidth = monitor.session_bandwidth(peer1);
+    assert_eq!(
+        peer1_bandwidth.get(&sync),
+        Some(&ProtocolBandwidth {
+            sent: 100,
+            received: 10
+        })
+    );
+    assert!(peer1_bandwidth.get(&relay).is_none());
+    assert_eq!(
+        monitor.session_bandwidth(peer2).get(&relay),
+        Some(&ProtocolBandwidth {
+            sent: 5,
+            received: 20
+        })
+    );
+
+    // the protocol counters outlive the sessions
+    monitor.remove_session(peer1);
+    assert!(monitor.session_bandwidth(peer1).is_empty());
+    assert_eq!(monitor.protocol_bandwidth()[&sync].sent, 100);
+}
+
+#[test]
+fn test_upload_limit() {
+    let monitor = BandwidthMonitor::new(BandwidthConfig {
+        window_secs: 3600,
+        max_upload_bytes: Some(1000),
+        max_peer_upload_bytes: Some(600),
+    });
+    let sync = SupportProtocols::Sync.protocol_id();
+    let peer1: PeerIndex = 1.into();
+    let peer2: PeerIndex = 2.into();
+
+    assert!(!monitor.upload_limit_reached(peer1));
+    monitor.record_sent(peer1, sync, 600);
+    assert!(monitor.upload_limit_reached(peer1));
+    assert!(!monitor.upload_limit_reached(peer2));
+    monitor.record_sent(peer2, sync, 400);
+    assert!(monitor.upload_limit_reached(peer2));
+
+    // a new window starts
+    let monitor = BandwidthMonitor::new(BandwidthConfig {
+        window_secs: 0,
+        max_upload_bytes: Some(1000),
+        max_peer_upload_bytes: None,
+    });
+    monitor.record_sent(peer1, sync, 1000);
+    assert!(!monitor.upload_limit_reached(peer1));
+}
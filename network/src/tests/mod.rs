@@ -1,8 +1,11 @@
 mod addr_manager;
+mod bandwidth;
 mod compress;
+mod network_group;
 mod peer_registry;
 mod peer_store;
 mod peer_store_db;
+mod proxy;
 
 fn random_addr() -> crate::multiaddr::Multiaddr {
     format!(
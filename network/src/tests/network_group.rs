@@ -0,0 +1,91 @@
+use crate::{
+    multiaddr::Multiaddr,
+    network_group::{AsMap, Group},
+    peer_store::PeerStore,
+    PeerId, SessionType,
+};
+
+const ASMAP: &str = "
+# test map
+1.0.0.0/8 100
+1.2.0.0/16 200
+2.1.0.0/16 100
+2606:4700::/32 300
+";
+
+fn addr(ip: &str) -> Multiaddr {
+    format!("{}/tcp/8115/p2p/{}", ip, PeerId::random().to_base58())
+        .parse()
+        .unwrap()
+}
+
+#[test]
+fn test_asmap_group() {
+    let asmap = AsMap::load(ASMAP.as_bytes()).unwrap();
+    assert_eq!(asmap.len(), 4);
+
+    // the longest network wins
+    assert_eq!(asmap.group(&addr("/ip4/1.3.4.5")), Group::Asn(100));
+    assert_eq!(asmap.group(&addr("/ip4/1.2.4.5")), Group::Asn(200));
+    assert_eq!(asmap.group(&addr("/ip6/::ffff:1.2.4.5")), Group::Asn(200));
+    assert_eq!(asmap.group(&addr("/ip6/2606:4700::1")), Group::Asn(300));
+    // not in the map
+    assert_eq!(asmap.group(&addr("/ip4/3.4.5.6")), Group::IP4([3, 4]));
+    assert_eq!(asmap.group(&addr("/ip4/127.0.0.1")), Group::LocalNetwork);
+    assert_eq!(Group::Asn(100).to_string(), "AS100");
+    assert_eq!(Group::IP4([3, 4]).to_string(), "3.4.0.0/16");
+
+    assert!(AsMap::load("1.0.0.0/8".as_bytes()).is_err());
+    assert!(AsMap::load("1.0.0.0/8 AS100".as_bytes()).is_err());
+}
+
+#[cfg(not(disable_faketime))]
+#[test]
+fn test_fetch_addrs_to_attempt_in_distinct_groups() {
+    let faketime_file = faketime::millis_tempfile(0).expect("create faketime file");
+    faketime::enable(&faketime_file);
+    faketime::write_millis(&faketime_file, 1).expect("write millis");
+
+    let mut peer_store = PeerStore::default();
+    peer_store.set_asmap(AsMap::load(ASMAP.as_bytes()).unwrap());
+    // different /16 prefixes in one autonomous system
+    let addrs = vec![
+        addr("/ip4/1.3.0.1"),
+        addr("/ip4/1.4.0.1"),
+        addr("/ip4/2.1.0.1"),
+    ];
+    for addr in addrs.iter() {
+        peer_store.add_outbound_addr(addr.clone());
+    }
+    faketime::write_millis(&faketime_file, 100_000).expect("write millis");
+
+    assert_eq!(peer_store.fetch_addrs_to_attempt(3).len(), 1);
+
+    // no more addrs once an outbound peer is in the group
+    peer_store.add_connected_peer(addrs[0].clone(), SessionType::Outbound);
+    assert!(peer_store.fetch_addrs_to_attempt(3).is_empty());
+}
+
+#[cfg(not(disable_faketime))]
+#[test]
+fn test_fetch_addrs_to_attempt_without_asmap() {
+    let faketime_file = faketime::millis_tempfile(0).expect("create faketime file");
+    faketime::enable(&faketime_file);
+    faketime::write_millis(&faketime_file, 1).expect("write millis");
+
+    let mut peer_store = PeerStore::default();
+    // one /16 prefix
+    let addrs = vec![
+        addr("/ip4/1.3.0.1"),
+        addr("/ip4/1.3.0.2"),
+        addr("/ip4/1.3.0.3"),
+    ];
+    for addr in addrs.iter() {
+        peer_store.add_outbound_addr(addr.clone());
+    }
+    faketime::write_millis(&faketime_file, 100_000).expect("write millis");
+
+    // groups are only taken into account with an AS map
+    peer_store.add_connected_peer(addrs[0].clone(), SessionType::Outbound);
+    assert_eq!(peer_store.fetch_addrs_to_attempt(3).len(), 2);
+}
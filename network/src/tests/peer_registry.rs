@@ -3,9 +3,11 @@ use crate::{
     errors::{Error, PeerError},
     extract_peer_id,
     multiaddr::Multiaddr,
-    peer_registry::{PeerRegistry, EVICTION_PROTECT_PEERS},
+    peer_registry::{
+        PeerRegistry, EVICTION_PROTECT_DELIVERY_PEERS, EVICTION_PROTECT_PEERS, MAX_ANCHORS,
+    },
     peer_store::PeerStore,
-    PeerId, SessionType,
+    Behaviour, PeerId, SessionId, SessionType,
 };
 use std::time::{Duration, Instant};
 
@@ -219,3 +221,161 @@ fn test_accept_inbound_peer_eviction() {
     // should evict from one of evict_targets
     assert_eq!(len_after_eviction, evict_targets.len() - 1);
 }
+
+#[test]
+fn test_inbound_eviction_protects_delivering_peers() {
+    let mut peer_store = PeerStore::default();
+    let max_inbound = 30;
+    let mut peers_registry = PeerRegistry::new(max_inbound, 3, false, Vec::new());
+    for session_id in 0..max_inbound as usize {
+        peers_registry
+            .accept_peer(
+                random_addr(),
+                session_id.into(),
+                SessionType::Inbound,
+                &mut peer_store,
+            )
+            .expect("accept");
+    }
+
+    // peers which recently delivered blocks or transactions
+    let now = faketime::unix_time_as_millis();
+    let delivering_peers: Vec<SessionId> = (0..2 * EVICTION_PROTECT_DELIVERY_PEERS)
+        .map(|session_id| session_id.into())
+        .collect();
+    for (index, session_id) in delivering_peers.iter().enumerate() {
+        let peer = peers_registry.get_peer(*session_id).unwrap();
+        if index < EVICTION_PROTECT_DELIVERY_PEERS {
+            peer.last_block_delivered_at.set(now);
+        } else {
+            peer.last_transaction_delivered_at.set(now);
+        }
+    }
+
+    // every new inbound peer evicts one
+    for session_id in 1000..1010 {
+        let evicted_peer = peers_registry
+            .accept_peer(
+                random_addr(),
+                session_id.into(),
+                SessionType::Inbound,
+                &mut peer_store,
+            )
+            .expect("accept")
+            .expect("evict");
+        assert!(!delivering_peers.contains(&evicted_peer.session_id));
+    }
+    assert_eq!(peers_registry.peers().len(), max_inbound as usize);
+}
+
+#[test]
+fn test_block_relay_only_slots_and_anchors() {
+    let mut peer_store = PeerStore::default();
+    let mut peers = PeerRegistry::new(3, 1, false, Vec::new());
+    peers.set_max_outbound_block_relay(2);
+
+    // fill the full relay outbound slot
+    let full_relay_addr = random_addr();
+    peers
+        .accept_peer(
+            full_relay_addr.clone(),
+            1.into(),
+            SessionType::Outbound,
+            &mut peer_store,
+        )
+        .expect("accept");
+
+    // block-relay-only peers take their own slots
+    let block_relay_addrs = vec![random_addr(), random_addr(), random_addr()];
+    for (session_id, addr) in (2..=4).zip(block_relay_addrs.iter()) {
+        peers.add_block_relay_only(addr);
+        let result = peers.accept_peer(
+            addr.clone(),
+            session_id.into(),
+            SessionType::Outbound,
+            &mut peer_store,
+        );
+        if session_id <= 3 {
+            result.expect("accept");
+            assert!(
+                peers
+                    .get_peer(session_id.into())
+                    .unwrap()
+                    .is_block_relay_only
+            );
+        } else {
+            assert_eq!(
+                format!("{}", result.unwrap_err()),
+                format!("{}", Error::Peer(PeerError::ReachMaxOutboundLimit)),
+            );
+        }
+    }
+    let status = peers.connection_status();
+    assert_eq!(status.non_whitelist_outbound, 1);
+    assert_eq!(status.block_relay_only_outbound, 2);
+
+    // short-lived peers are not anchors
+    assert!(peers.anchors(&peer_store).is_empty());
+
+    // long-lived and well-behaved peers are anchors, block-relay-only peers first
+    for session_id in 1..=3 {
+        peers
+            .get_peer_mut(session_id.into())
+            .unwrap()
+            .connected_time = Instant::now() - Duration::from_secs(3600);
+    }
+    let anchors = peers.anchors(&peer_store);
+    assert_eq!(anchors.len(), MAX_ANCHORS);
+    assert!(!anchors.contains(&full_relay_addr));
+
+    // misbehaving peers are not anchors
+    peer_store.report(&block_relay_addrs[0], Behaviour::UnrequestedData);
+    let anchors = peers.anchors(&peer_store);
+    assert!(!anchors.contains(&block_relay_addrs[0]));
+    assert!(anchors.contains(&full_relay_addr));
+}
+
+#[test]
+fn test_extra_outbound_slot() {
+    let mut peer_store = PeerStore::default();
+    let mut peers = PeerRegistry::new(3, 1, false, vec![]);
+    peers
+        .accept_peer(
+            random_addr(),
+            1.into(),
+            SessionType::Outbound,
+            &mut peer_store,
+        )
+        .expect("accept");
+    let err = peers
+        .accept_peer(
+            random_addr(),
+            2.into(),
+            SessionType::Outbound,
+            &mut peer_store,
+        )
+        .unwrap_err();
+    assert_eq!(
+        format!("{}", err),
+        format!("{}", Error::Peer(PeerError::ReachMaxOutboundLimit))
+    );
+
+    // one more outbound peer is allowed when the tip is stale
+    peers.set_try_new_outbound_peer(true);
+    assert_eq!(peers.connection_status().max_outbound, 2);
+    peers
+        .accept_peer(
+            random_addr(),
+            2.into(),
+            SessionType::Outbound,
+            &mut peer_store,
+        )
+        .expect("accept");
+    assert_eq!(peers.extra_outbound_count(), 1);
+
+    // the extra peer stays until an outbound peer is evicted
+    peers.set_try_new_outbound_peer(false);
+    assert_eq!(peers.extra_outbound_count(), 1);
+    peers.remove_peer(1.into());
+    assert_eq!(peers.extra_outbound_count(), 0);
+}
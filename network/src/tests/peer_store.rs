@@ -3,8 +3,8 @@ use crate::{
     extract_peer_id,
     multiaddr::Multiaddr,
     peer_store::{
-        ban_list::CLEAR_INTERVAL_COUNTER, types::multiaddr_to_ip_network, PeerStore, Status,
-        ADDR_COUNT_LIMIT, ADDR_TRY_TIMEOUT_MS,
+        ban_list::CLEAR_INTERVAL_COUNTER, types::multiaddr_to_ip_network, PeerScoreConfig,
+        PeerStore, Status, ADDR_COUNT_LIMIT, ADDR_TRY_TIMEOUT_MS,
     },
     Behaviour, PeerId, SessionType,
 };
@@ -48,6 +48,100 @@ fn test_report() {
     assert!(peer_store.addr_manager().get(&addr).is_none())
 }
 
+#[test]
+fn test_report_connected_peer() {
+    let mut peer_store: PeerStore = Default::default();
+    peer_store.set_score_config(PeerScoreConfig {
+        ban_score: 60,
+        ..Default::default()
+    });
+    // inbound peers are not in the address manager
+    let addr = random_addr();
+    let peer_id = extract_peer_id(&addr).unwrap();
+    peer_store.add_connected_peer(addr.clone(), SessionType::Inbound);
+    assert!(peer_store.addr_manager().get(&addr).is_none());
+    assert_eq!(peer_store.peer_score(&peer_id), Some(100));
+
+    assert!(peer_store
+        .report(&addr, Behaviour::InvalidTransaction)
+        .is_ok());
+    assert_eq!(peer_store.peer_score(&peer_id), Some(80));
+    assert!(peer_store
+        .report(&addr, Behaviour::InvalidTransaction)
+        .is_ok());
+    assert!(peer_store
+        .report(&addr, Behaviour::UnrequestedData)
+        .is_banned());
+    assert!(peer_store.is_addr_banned(&addr));
+}
+
+#[test]
+fn test_report_timeout() {
+    let mut peer_store: PeerStore = Default::default();
+    peer_store.set_score_config(PeerScoreConfig {
+        ban_score: 60,
+        ..Default::default()
+    });
+    let addr = random_addr();
+    let peer_id = extract_peer_id(&addr).unwrap();
+    peer_store.add_connected_peer(addr.clone(), SessionType::Outbound);
+
+    // timeouts lower the score but never ban the peer by themselves
+    for _ in 0..3 {
+        assert!(peer_store.report(&addr, Behaviour::Timeout).is_ok());
+    }
+    assert_eq!(peer_store.peer_score(&peer_id), Some(40));
+    assert!(!peer_store.is_addr_banned(&addr));
+    assert!(peer_store
+        .report(&addr, Behaviour::UnrequestedData)
+        .is_banned());
+}
+
+#[cfg(not(disable_faketime))]
+#[test]
+fn test_peer_score_recovery_and_reconnect() {
+    let faketime_file = faketime::millis_tempfile(0).expect("create faketime file");
+    faketime::enable(&faketime_file);
+
+    let mut peer_store: PeerStore = Default::default();
+    peer_store.set_score_config(PeerScoreConfig {
+        ban_score: 20,
+        recovery_interval_ms: 1_000,
+        ..Default::default()
+    });
+    let addr = random_addr();
+    let peer_id = extract_peer_id(&addr).unwrap();
+    peer_store.add_connected_peer(addr.clone(), SessionType::Inbound);
+    assert!(peer_store.report(&addr, Behaviour::InvalidBlock).is_ok());
+    assert_eq!(peer_store.peer_score(&peer_id), Some(30));
+
+    faketime::write_millis(&faketime_file, 10_000).expect("write millis");
+    assert_eq!(peer_store.peer_score(&peer_id), Some(40));
+    assert!(!peer_store.is_well_behaved(&peer_id));
+
+    // reconnecting from the same ip with another port and peer id keeps the score
+    peer_store.remove_disconnected_peer(&addr);
+    let addr: Multiaddr = format!("/ip4/127.0.0.1/tcp/43/p2p/{}", PeerId::random().to_base58())
+        .parse()
+        .unwrap();
+    let peer_id = extract_peer_id(&addr).unwrap();
+    peer_store.add_connected_peer(addr.clone(), SessionType::Inbound);
+    assert_eq!(peer_store.peer_score(&peer_id), Some(40));
+    assert!(peer_store
+        .report(&addr, Behaviour::InvalidBlock)
+        .is_banned());
+
+    // the penalised score recovers up to the default score
+    peer_store.remove_disconnected_peer(&addr);
+    faketime::write_millis(&faketime_file, 1_000_000).expect("write millis");
+    let addr = random_addr();
+    peer_store.add_connected_peer(addr.clone(), SessionType::Inbound);
+    assert_eq!(
+        peer_store.peer_score(&extract_peer_id(&addr).unwrap()),
+        Some(100)
+    );
+}
+
 #[test]
 fn test_update_status() {
     let mut peer_store: PeerStore = Default::default();
@@ -207,6 +301,30 @@ fn test_fetch_addrs_to_attempt_in_last_minutes() {
     assert_eq!(peer_store.fetch_addrs_to_attempt(1).len(), 1);
 }
 
+#[test]
+fn test_onion_addr_requires_proxy() {
+    let onion_addr: Multiaddr = format!(
+        "/dns4/vww6ybal4bd7szmgncyruucpgfkqahzddi37ktceo3ah7ngmcopnpyyd.onion/tcp/8115/p2p/{}",
+        PeerId::random().to_base58()
+    )
+    .parse()
+    .unwrap();
+
+    let mut peer_store: PeerStore = Default::default();
+    peer_store.add_addr(onion_addr.clone()).unwrap();
+    peer_store.add_outbound_addr(onion_addr.clone());
+    assert!(peer_store.addr_manager().get(&onion_addr).is_none());
+    assert!(peer_store.fetch_addrs_to_feeler(1).is_empty());
+
+    peer_store.set_onion_reachable(true);
+    peer_store.add_addr(onion_addr.clone()).unwrap();
+    assert_eq!(peer_store.fetch_addrs_to_feeler(1).len(), 1);
+
+    // addresses stored before the proxy is dropped are not dialed
+    peer_store.set_onion_reachable(false);
+    assert!(peer_store.fetch_addrs_to_feeler(1).is_empty());
+}
+
 #[test]
 fn test_fetch_addrs_to_feeler() {
     let mut peer_store: PeerStore = Default::default();
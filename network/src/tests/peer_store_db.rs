@@ -62,6 +62,10 @@ fn test_peer_store_persistent() {
     ban_list.ban(ban2.clone());
     ban_list.ban(ban3.clone());
 
+    // set anchors
+    let anchors = vec![addr1.addr.clone(), addr2.addr.clone()];
+    peer_store.set_anchors(anchors.clone());
+
     // dump and load
     let dir = tempfile::tempdir().unwrap();
     peer_store.dump_to_dir(&dir.path()).unwrap();
@@ -88,6 +92,9 @@ fn test_peer_store_persistent() {
             .collect::<HashSet<_>>(),
         vec![ban1, ban2, ban3].into_iter().collect::<HashSet<_>>()
     );
+
+    // check anchors
+    assert_eq!(peer_store2.anchors(), anchors.as_slice());
 }
 
 #[test]
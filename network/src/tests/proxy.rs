@@ -0,0 +1,99 @@
+use crate::{
+    multiaddr::Multiaddr,
+    proxy::{is_valid_onion_host, onion_address, socks5_connect},
+};
+use ckb_app_config::ProxyConfig;
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::TcpListener,
+};
+
+const ONION_HOST: &str = "vww6ybal4bd7szmgncyruucpgfkqahzddi37ktceo3ah7ngmcopnpyyd.onion";
+
+// A minimal SOCKS5 server accepting one connection, it returns the requested host and port
+// after echoing a message back
+async fn serve_socks5(listener: TcpListener, credentials: (&str, &str)) -> (String, u16) {
+    let (mut stream, _) = listener.accept().await.unwrap();
+
+    let mut greeting = [0u8; 3];
+    stream.read_exact(&mut greeting).await.unwrap();
+    assert_eq!(greeting, [5, 1, 2]);
+    stream.write_all(&[5, 2]).await.unwrap();
+
+    let mut auth = [0u8; 2];
+    stream.read_exact(&mut auth).await.unwrap();
+    let mut username = vec![0u8; auth[1] as usize];
+    stream.read_exact(&mut username).await.unwrap();
+    let mut password_len = [0u8; 1];
+    stream.read_exact(&mut password_len).await.unwrap();
+    let mut password = vec![0u8; password_len[0] as usize];
+    stream.read_exact(&mut password).await.unwrap();
+    assert_eq!(username, credentials.0.as_bytes());
+    assert_eq!(password, credentials.1.as_bytes());
+    stream.write_all(&[1, 0]).await.unwrap();
+
+    let mut request = [0u8; 5];
+    stream.read_exact(&mut request).await.unwrap();
+    assert_eq!(request[..4], [5, 1, 0, 3]);
+    let mut host = vec![0u8; request[4] as usize];
+    stream.read_exact(&mut host).await.unwrap();
+    let mut port = [0u8; 2];
+    stream.read_exact(&mut port).await.unwrap();
+    stream
+        .write_all(&[5, 0, 0, 1, 127, 0, 0, 1, 0, 0])
+        .await
+        .unwrap();
+
+    let mut message = [0u8; 4];
+    stream.read_exact(&mut message).await.unwrap();
+    stream.write_all(&message).await.unwrap();
+
+    (String::from_utf8(host).unwrap(), u16::from_be_bytes(port))
+}
+
+#[test]
+fn test_onion_address() {
+    let addr: Multiaddr = format!("/dns4/{}/tcp/8115", ONION_HOST).parse().unwrap();
+    assert_eq!(onion_address(&addr), Some((ONION_HOST.to_string(), 8115)));
+
+    assert!(is_valid_onion_host(ONION_HOST));
+    // the onion hosts in other cases are never taken as clearnet names
+    let addr: Multiaddr = format!("/dns6/{}./tcp/8115", ONION_HOST.to_uppercase())
+        .parse()
+        .unwrap();
+    assert_eq!(onion_address(&addr), Some((ONION_HOST.to_string(), 8115)));
+    let addr: Multiaddr = "/dns4/example.onion/tcp/8115".parse().unwrap();
+    let (host, _) = onion_address(&addr).unwrap();
+    assert!(!is_valid_onion_host(&host));
+
+    let addr: Multiaddr = "/dns4/example.com/tcp/8115".parse().unwrap();
+    assert_eq!(onion_address(&addr), None);
+    let addr: Multiaddr = "/ip4/127.0.0.1/tcp/8115".parse().unwrap();
+    assert_eq!(onion_address(&addr), None);
+}
+
+#[test]
+fn test_socks5_connect() {
+    let rt = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .unwrap();
+    rt.block_on(async {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let config = ProxyConfig {
+            address: listener.local_addr().unwrap(),
+            username: Some("ckb".to_string()),
+            password: Some("secret".to_string()),
+        };
+        let server = tokio::spawn(serve_socks5(listener, ("ckb", "secret")));
+
+        let addr: Multiaddr = format!("/dns4/{}/tcp/8115", ONION_HOST).parse().unwrap();
+        let mut stream = socks5_connect(&config, &addr).await.unwrap();
+        stream.write_all(b"ping").await.unwrap();
+        let mut echo = [0u8; 4];
+        stream.read_exact(&mut echo).await.unwrap();
+        assert_eq!(&echo, b"ping");
+
+        assert_eq!(server.await.unwrap(), (ONION_HOST.to_string(), 8115));
+    });
+}
@@ -1,10 +1,11 @@
 //! TODO(doc): @quake
 use ckb_app_config::NotifyConfig;
 use ckb_channel::{bounded, select, Receiver, RecvError, Sender};
+use ckb_jsonrpc_types::BlockTemplate;
 use ckb_logger::{debug, error, trace};
 use ckb_stop_handler::{SignalSender, StopHandler};
 use ckb_types::{
-    core::{service::Request, tx_pool::Reject, BlockView},
+    core::{service::Request, tx_pool::Reject, BlockView, HeaderView},
     packed::Alert,
 };
 use std::collections::HashMap;
@@ -37,6 +38,10 @@ pub struct NotifyController {
     reject_transaction_notifier: Sender<(PoolTransactionEntry, Reject)>,
     network_alert_register: NotifyRegister<Alert>,
     network_alert_notifier: Sender<Alert>,
+    block_template_register: NotifyRegister<BlockTemplate>,
+    block_template_notifier: Sender<BlockTemplate>,
+    stale_tip_register: NotifyRegister<HeaderView>,
+    stale_tip_notifier: Sender<HeaderView>,
 }
 
 impl Drop for NotifyController {
@@ -53,6 +58,8 @@ pub struct NotifyService {
     proposed_transaction_subscribers: HashMap<String, Sender<PoolTransactionEntry>>,
     reject_transaction_subscribers: HashMap<String, Sender<(PoolTransactionEntry, Reject)>>,
     network_alert_subscribers: HashMap<String, Sender<Alert>>,
+    block_template_subscribers: HashMap<String, Sender<BlockTemplate>>,
+    stale_tip_subscribers: HashMap<String, Sender<HeaderView>>,
 }
 
 impl NotifyService {
@@ -65,6 +72,8 @@ impl NotifyService {
             proposed_transaction_subscribers: HashMap::default(),
             reject_transaction_subscribers: HashMap::default(),
             network_alert_subscribers: HashMap::default(),
+            block_template_subscribers: HashMap::default(),
+            stale_tip_subscribers: HashMap::default(),
         }
     }
 
@@ -92,6 +101,13 @@ impl NotifyService {
             bounded(REGISTER_CHANNEL_SIZE);
         let (network_alert_sender, network_alert_receiver) = bounded(NOTIFY_CHANNEL_SIZE);
 
+        let (block_template_register, block_template_register_receiver) =
+            bounded(REGISTER_CHANNEL_SIZE);
+        let (block_template_sender, block_template_receiver) = bounded(NOTIFY_CHANNEL_SIZE);
+
+        let (stale_tip_register, stale_tip_register_receiver) = bounded(REGISTER_CHANNEL_SIZE);
+        let (stale_tip_sender, stale_tip_receiver) = bounded(NOTIFY_CHANNEL_SIZE);
+
         let mut thread_builder = thread::Builder::new();
         if let Some(name) = thread_name {
             thread_builder = thread_builder.name(name.to_string());
@@ -112,6 +128,10 @@ impl NotifyService {
                     recv(reject_transaction_receiver) -> msg => self.handle_notify_reject_transaction(msg),
                     recv(network_alert_register_receiver) -> msg => self.handle_register_network_alert(msg),
                     recv(network_alert_receiver) -> msg => self.handle_notify_network_alert(msg),
+                    recv(block_template_register_receiver) -> msg => self.handle_register_block_template(msg),
+                    recv(block_template_receiver) -> msg => self.handle_notify_block_template(msg),
+                    recv(stale_tip_register_receiver) -> msg => self.handle_register_stale_tip(msg),
+                    recv(stale_tip_receiver) -> msg => self.handle_notify_stale_tip(msg),
                 }
             })
             .expect("Start notify service failed");
@@ -127,6 +147,10 @@ impl NotifyService {
             reject_transaction_notifier: reject_transaction_sender,
             network_alert_register,
             network_alert_notifier: network_alert_sender,
+            block_template_register,
+            block_template_notifier: block_template_sender,
+            stale_tip_register,
+            stale_tip_notifier: stale_tip_sender,
             stop: StopHandler::new(
                 SignalSender::Crossbeam(signal_sender),
                 Some(join_handle),
@@ -318,6 +342,78 @@ impl NotifyService {
             _ => debug!("network alert channel is closed"),
         }
     }
+
+    fn handle_register_block_template(
+        &mut self,
+        msg: Result<Request<String, Receiver<BlockTemplate>>, RecvError>,
+    ) {
+        match msg {
+            Ok(Request {
+                responder,
+                arguments: name,
+            }) => {
+                debug!("Register block_template {:?}", name);
+                let (sender, receiver) = bounded(NOTIFY_CHANNEL_SIZE);
+                self.block_template_subscribers.insert(name, sender);
+                let _ = responder.send(receiver);
+            }
+            _ => debug!("Register block_template channel is closed"),
+        }
+    }
+
+    fn handle_notify_block_template(&mut self, msg: Result<BlockTemplate, RecvError>) {
+        match msg {
+            Ok(template) => {
+                trace!("event block template {:?}", template.work_id);
+                // notify all subscribers
+                for subscriber in self.block_template_subscribers.values() {
+                    let _ = subscriber.send(template.clone());
+                }
+            }
+            _ => debug!("block template channel is closed"),
+        }
+    }
+
+    fn handle_register_stale_tip(
+        &mut self,
+        msg: Result<Request<String, Receiver<HeaderView>>, RecvError>,
+    ) {
+        match msg {
+            Ok(Request {
+                responder,
+                arguments: name,
+            }) => {
+                debug!("Register stale_tip {:?}", name);
+                let (sender, receiver) = bounded(NOTIFY_CHANNEL_SIZE);
+                self.stale_tip_subscribers.insert(name, sender);
+                let _ = responder.send(receiver);
+            }
+            _ => debug!("Register stale_tip channel is closed"),
+        }
+    }
+
+    fn handle_notify_stale_tip(&mut self, msg: Result<HeaderView, RecvError>) {
+        match msg {
+            Ok(tip) => {
+                trace!("event stale tip {:?}", tip.hash());
+                // notify all subscribers
+                for subscriber in self.stale_tip_subscribers.values() {
+                    let _ = subscriber.send(tip.clone());
+                }
+                // notify script
+                if let Some(script) = self.config.stale_tip_notify_script.as_ref() {
+                    let args = [format!("{:#x}", tip.hash())];
+                    if let Err(err) = Command::new(script).args(&args).status() {
+                        error!(
+                            "failed to run stale_tip_notify_script: {} {}, error: {}",
+                            script, args[0], err
+                        );
+                    }
+                }
+            }
+            _ => debug!("stale tip channel is closed"),
+        }
+    }
 }
 
 impl NotifyController {
@@ -384,4 +480,26 @@ impl NotifyController {
     pub fn notify_network_alert(&self, alert: Alert) {
         let _ = self.network_alert_notifier.send(alert);
     }
+
+    /// Subscribes the block templates which are changed by tx-pool.
+    pub fn subscribe_block_template<S: ToString>(&self, name: S) -> Receiver<BlockTemplate> {
+        Request::call(&self.block_template_register, name.to_string())
+            .expect("Subscribe block template should be OK")
+    }
+
+    /// Notifies the subscribers that the block template is changed.
+    pub fn notify_block_template(&self, template: BlockTemplate) {
+        let _ = self.block_template_notifier.send(template);
+    }
+
+    /// Subscribes the tips which have not changed for a long time.
+    pub fn subscribe_stale_tip<S: ToString>(&self, name: S) -> Receiver<HeaderView> {
+        Request::call(&self.stale_tip_register, name.to_string())
+            .expect("Subscribe stale tip should be OK")
+    }
+
+    /// Notifies the subscribers that the tip has become stale.
+    pub fn notify_stale_tip(&self, tip: HeaderView) {
+        let _ = self.stale_tip_notifier.send(tip);
+    }
 }
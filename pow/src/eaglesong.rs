@@ -59,4 +59,13 @@ impl PowEngine for EaglesongPowEngine {
 
         true
     }
+
+    fn verify_target(&self, header: &Header, target: &U256) -> bool {
+        let input =
+            crate::pow_message(&header.as_reader().calc_pow_hash(), header.nonce().unpack());
+        let mut output = [0u8; 32];
+        eaglesong(&input, &mut output);
+
+        U256::from_big_endian(&output[..]).expect("bound checked") <= *target
+    }
 }
@@ -71,4 +71,16 @@ impl PowEngine for EaglesongBlake2bPowEngine {
 
         true
     }
+
+    fn verify_target(&self, header: &Header, target: &U256) -> bool {
+        let input =
+            crate::pow_message(&header.as_reader().calc_pow_hash(), header.nonce().unpack());
+        let output = {
+            let mut output_tmp = [0u8; 32];
+            eaglesong(&input, &mut output_tmp);
+            blake2b_256(&output_tmp)
+        };
+
+        U256::from_big_endian(&output[..]).expect("bound checked") <= *target
+    }
 }
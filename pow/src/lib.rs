@@ -3,6 +3,7 @@ use byteorder::{ByteOrder, LittleEndian};
 use ckb_types::{
     packed::{Byte32, Header},
     prelude::*,
+    U256,
 };
 use serde::{Deserialize, Serialize};
 use std::any::Any;
@@ -70,6 +71,14 @@ pub fn pow_message(pow_hash: &Byte32, nonce: u128) -> [u8; 48] {
 pub trait PowEngine: Send + Sync + AsAny {
     /// TODO(doc): @quake
     fn verify(&self, header: &Header) -> bool;
+
+    /// Verifies the proof of work of the header against `target` instead of the target of its
+    /// compact target, e.g., to check the shares submitted to a mining pool.
+    ///
+    /// The engines whose proof doesn't depend on the target fall back to `verify`.
+    fn verify_target(&self, header: &Header, _target: &U256) -> bool {
+        self.verify(header)
+    }
 }
 
 /// TODO(doc): @quake
@@ -17,3 +17,14 @@ fn test_pow_message() {
         .to_vec()
     );
 }
+
+#[test]
+fn test_verify_target() {
+    use crate::{EaglesongPowEngine, PowEngine};
+    use ckb_types::{packed::Header, U256};
+
+    let header = Header::default();
+    let engine = EaglesongPowEngine;
+    assert!(engine.verify_target(&header, &U256::max_value()));
+    assert!(!engine.verify_target(&header, &U256::zero()));
+}
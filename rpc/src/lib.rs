@@ -3,6 +3,7 @@
 pub(crate) mod error;
 pub(crate) mod server;
 pub(crate) mod service_builder;
+pub(crate) mod stratum;
 
 pub mod module;
 
@@ -12,6 +13,7 @@ mod tests;
 pub use crate::error::RPCError;
 pub use crate::server::RpcServer;
 pub use crate::service_builder::ServiceBuilder;
+pub use crate::stratum::StratumServer;
 
 #[doc(hidden)]
 pub type IoHandler = jsonrpc_pubsub::PubSubHandler<Option<crate::module::SubscriptionSession>>;
@@ -1,8 +1,8 @@
 use crate::error::RPCError;
 use ckb_jsonrpc_types::{
-    BlockEconomicState, BlockNumber, BlockView, CellWithStatus, Consensus, EpochNumber, EpochView,
-    HeaderView, MerkleProof as JsonMerkleProof, OutPoint, ResponseFormat, Timestamp,
-    TransactionProof, TransactionWithStatus, Uint32,
+    BlockEconomicState, BlockFilter, BlockNumber, BlockView, CellWithStatus, Consensus,
+    EpochNumber, EpochView, HeaderView, JsonBytes, MerkleProof as JsonMerkleProof, OutPoint,
+    ResponseFormat, Timestamp, TransactionProof, TransactionWithStatus, Uint32,
 };
 use ckb_logger::error;
 use ckb_reward_calculator::RewardCalculator;
@@ -1207,6 +1207,99 @@ pub trait ChainRpc {
     /// ```
     #[rpc(name = "get_block_median_time")]
     fn get_block_median_time(&self, block_hash: H256) -> Result<Option<Timestamp>>;
+
+    /// Returns the compact block filter of a block.
+    ///
+    /// The filter is a Golomb-coded set of the lock and type script hashes of the cells created
+    /// and consumed by the block, light wallets use it to find the blocks touching their scripts.
+    ///
+    /// ## Params
+    ///
+    /// * `block_hash` - the block hash.
+    ///
+    /// ## Returns
+    ///
+    /// The RPC returns null when the block is not in the [canonical chain](#canonical-chain).
+    ///
+    /// ## Examples
+    ///
+    /// Request
+    ///
+    /// ```json
+    /// {
+    ///   "id": 42,
+    ///   "jsonrpc": "2.0",
+    ///   "method": "get_block_filter",
+    ///   "params": [
+    ///     "0x7978ec7ce5b507cfb52e149e36b1a23f6062ed150503c85bbf825da3599095ed"
+    ///   ]
+    /// }
+    /// ```
+    ///
+    /// Response
+    ///
+    /// ```json
+    /// {
+    ///   "id": 42,
+    ///   "jsonrpc": "2.0",
+    ///   "result": {
+    ///     "data": "0x01000000000000002856b0",
+    ///     "hash": "0x938893e5d440e5fa65acc22eea75e51d2defaebf7260cb0259af5c875ad036d0",
+    ///     "header": "0x8d9a1654cdc58f17ed068fcf84bc39cf29e6b67e137908fcb92f657b899ffafc"
+    ///   }
+    /// }
+    /// ```
+    #[rpc(name = "get_block_filter")]
+    fn get_block_filter(&self, block_hash: H256) -> Result<Option<BlockFilter>>;
+
+    /// Returns the filter headers of the consecutive blocks in the [canonical chain](#canonical-chain).
+    ///
+    /// The filter header of a block is the hash of its filter hash and the filter header of its
+    /// parent, so a filter header commits to all the filters up to the block.
+    ///
+    /// ## Params
+    ///
+    /// * `start_number` - the number of the first block.
+    /// * `limit` - the max number of the filter headers to return, which can not exceed 2000.
+    ///
+    /// ## Returns
+    ///
+    /// The filter headers of the blocks from `start_number`, it stops at the tip block.
+    ///
+    /// ## Examples
+    ///
+    /// Request
+    ///
+    /// ```json
+    /// {
+    ///   "id": 42,
+    ///   "jsonrpc": "2.0",
+    ///   "method": "get_block_filter_headers",
+    ///   "params": [
+    ///     "0x0",
+    ///     "0x2"
+    ///   ]
+    /// }
+    /// ```
+    ///
+    /// Response
+    ///
+    /// ```json
+    /// {
+    ///   "id": 42,
+    ///   "jsonrpc": "2.0",
+    ///   "result": [
+    ///     "0x8d9a1654cdc58f17ed068fcf84bc39cf29e6b67e137908fcb92f657b899ffafc",
+    ///     "0xc70129084d8c108b73fcac56a4025114684cdae3e27667af3c1c12de92776152"
+    ///   ]
+    /// }
+    /// ```
+    #[rpc(name = "get_block_filter_headers")]
+    fn get_block_filter_headers(
+        &self,
+        start_number: BlockNumber,
+        limit: Uint32,
+    ) -> Result<Vec<H256>>;
 }
 
 pub(crate) struct ChainRpcImpl {
@@ -1216,6 +1309,7 @@ pub(crate) struct ChainRpcImpl {
 const DEFAULT_BLOCK_VERBOSITY_LEVEL: u32 = 2;
 const DEFAULT_HEADER_VERBOSITY_LEVEL: u32 = 1;
 const DEFAULT_GET_TRANSACTION_VERBOSITY_LEVEL: u32 = 2;
+const MAX_BLOCK_FILTER_HEADERS: u32 = 2000;
 
 impl ChainRpc for ChainRpcImpl {
     fn get_block(
@@ -1561,10 +1655,9 @@ impl ChainRpc for ChainRpcImpl {
                 RPCError::custom(RPCError::ChainIndexIsInconsistent, message)
             })
             .map(|block| {
-                let proof = CBMT::build_merkle_proof(
-                    &block.transactions().iter().map(|tx| tx.hash()).collect::<Vec<_>>(),
-                    &tx_indices.into_iter().collect::<Vec<_>>())
-                .expect("build proof with verified inputs should be OK");
+                let proof = block
+                    .transactions_proof(&tx_indices.into_iter().collect::<Vec<_>>())
+                    .expect("build proof with verified inputs should be OK");
                 TransactionProof {
                     block_hash: block.hash().unpack(),
                     witnesses_root: block.calc_witnesses_root().unpack(),
@@ -1665,6 +1758,55 @@ impl ChainRpc for ChainRpcImpl {
         );
         Ok(Some(median_time.into()))
     }
+
+    fn get_block_filter(&self, block_hash: H256) -> Result<Option<BlockFilter>> {
+        let block_hash = block_hash.pack();
+        let snapshot = self.shared.snapshot();
+        if !snapshot.is_main_chain(&block_hash) {
+            return Ok(None);
+        }
+
+        Ok(snapshot
+            .get_block_filter(&block_hash)
+            .and_then(|filter| {
+                snapshot
+                    .get_block_filter_header(&block_hash)
+                    .map(|header| (filter, header))
+            })
+            .map(|(filter, header)| BlockFilter {
+                hash: filter.calc_hash().unpack(),
+                data: JsonBytes::from_bytes(filter.data()),
+                header: header.unpack(),
+            }))
+    }
+
+    fn get_block_filter_headers(
+        &self,
+        start_number: BlockNumber,
+        limit: Uint32,
+    ) -> Result<Vec<H256>> {
+        let limit: u32 = limit.into();
+        if limit > MAX_BLOCK_FILTER_HEADERS {
+            return Err(RPCError::invalid_params(format!(
+                "limit {} exceeds the max {}",
+                limit, MAX_BLOCK_FILTER_HEADERS
+            )));
+        }
+
+        let snapshot = self.shared.snapshot();
+        let start_number: core::BlockNumber = start_number.into();
+        Ok((start_number..=snapshot.tip_number())
+            .take(limit as usize)
+            .map(|number| {
+                snapshot
+                    .get_block_hash(number)
+                    .and_then(|hash| snapshot.get_block_filter_header(&hash))
+            })
+            .take_while(Option::is_some)
+            .flatten()
+            .map(|header| header.unpack())
+            .collect())
+    }
 }
 
 impl ChainRpcImpl {
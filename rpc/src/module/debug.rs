@@ -1,7 +1,15 @@
+use crate::error::RPCError;
+use ckb_chain::chain::ChainController;
+use ckb_db_schema::ALL_COLUMNS;
 use ckb_jsonrpc_types::{ExtraLoggerConfig, MainLoggerConfig};
 use ckb_logger_service::Logger;
+use ckb_shared::shared::Shared;
+use ckb_store::ChainStore;
+use ckb_sync::SyncShared;
+use ckb_types::{prelude::*, H256};
 use jsonrpc_core::{Error, ErrorCode::InternalError, Result};
 use jsonrpc_derive::rpc;
+use std::sync::Arc;
 use std::time;
 
 /// RPC Module Debug for internal RPC methods.
@@ -33,9 +41,98 @@ pub trait DebugRpc {
     /// Removes the logger when this is null.
     #[rpc(name = "set_extra_logger")]
     fn set_extra_logger(&self, name: String, config_opt: Option<ExtraLoggerConfig>) -> Result<()>;
+    /// Compacts the whole key range of a RocksDB column family.
+    ///
+    /// The RPC blocks until the manual compaction is finished.
+    ///
+    /// ## Params
+    ///
+    /// * `column` - The column family name, as listed in the RPC `get_db_stats`.
+    #[rpc(name = "compact_db_column")]
+    fn compact_db_column(&self, column: String) -> Result<()>;
+
+    /// Marks a block as invalid, so it and its descendants are never in the [canonical chain](#canonical-chain).
+    ///
+    /// If the block is in the canonical chain, the chain is reverted to its parent, then
+    /// switches to the stored chain which has accumulated the most work without invalid blocks.
+    ///
+    /// The mark is persisted in the database until the block is reconsidered by the RPC
+    /// `reconsider_block`.
+    ///
+    /// ## Params
+    ///
+    /// * `block_hash` - the block hash, the genesis block and the frozen blocks can't be invalidated.
+    ///
+    /// ## Examples
+    ///
+    /// Request
+    ///
+    /// ```text
+    /// {
+    ///   "id": 42,
+    ///   "jsonrpc": "2.0",
+    ///   "method": "invalidate_block",
+    ///   "params": [
+    ///     "0xa5f5c85987a15de25661e5a214f2c1449cd803f071acc7999820f25246471f40"
+    ///   ]
+    /// }
+    /// ```
+    ///
+    /// Response
+    ///
+    /// ```text
+    /// {
+    ///   "id": 42,
+    ///   "jsonrpc": "2.0",
+    ///   "result": null
+    /// }
+    /// ```
+    #[rpc(name = "invalidate_block")] // noexample
+    fn invalidate_block(&self, block_hash: H256) -> Result<()>;
+
+    /// Removes the invalid mark of a block set by the RPC `invalidate_block`.
+    ///
+    /// The [canonical chain](#canonical-chain) switches to the stored chain which has accumulated
+    /// the most work without invalid blocks, the reconsidered block is verified again when it is
+    /// attached.
+    ///
+    /// ## Params
+    ///
+    /// * `block_hash` - the block hash.
+    ///
+    /// ## Examples
+    ///
+    /// Request
+    ///
+    /// ```text
+    /// {
+    ///   "id": 42,
+    ///   "jsonrpc": "2.0",
+    ///   "method": "reconsider_block",
+    ///   "params": [
+    ///     "0xa5f5c85987a15de25661e5a214f2c1449cd803f071acc7999820f25246471f40"
+    ///   ]
+    /// }
+    /// ```
+    ///
+    /// Response
+    ///
+    /// ```text
+    /// {
+    ///   "id": 42,
+    ///   "jsonrpc": "2.0",
+    ///   "result": null
+    /// }
+    /// ```
+    #[rpc(name = "reconsider_block")] // noexample
+    fn reconsider_block(&self, block_hash: H256) -> Result<()>;
 }
 
-pub(crate) struct DebugRpcImpl {}
+pub(crate) struct DebugRpcImpl {
+    pub shared: Shared,
+    pub chain: ChainController,
+    pub sync_shared: Arc<SyncShared>,
+}
 
 impl DebugRpc for DebugRpcImpl {
     fn jemalloc_profiling_dump(&self) -> Result<String> {
@@ -90,4 +187,45 @@ impl DebugRpc for DebugRpcImpl {
             data: None,
         })
     }
+
+    fn compact_db_column(&self, column: String) -> Result<()> {
+        let col = ALL_COLUMNS
+            .iter()
+            .copied()
+            .find(|col| **col == column)
+            .ok_or_else(|| RPCError::invalid_params(format!("column {} does not exist", column)))?;
+        self.shared
+            .store()
+            .compact_range(col, None, None)
+            .map_err(RPCError::ckb_internal_error)
+    }
+
+    fn invalidate_block(&self, block_hash: H256) -> Result<()> {
+        let block_hash = block_hash.pack();
+        let header = self
+            .shared
+            .snapshot()
+            .get_block_header(&block_hash)
+            .ok_or_else(|| RPCError::invalid_params("block not found"))?;
+        if header.is_genesis() {
+            return Err(RPCError::invalid_params(
+                "the genesis block can't be invalidated",
+            ));
+        }
+
+        self.sync_shared
+            .invalidate_block(&self.chain, block_hash)
+            .map_err(|err| RPCError::custom(RPCError::Invalid, err.to_string()))
+    }
+
+    fn reconsider_block(&self, block_hash: H256) -> Result<()> {
+        let block_hash = block_hash.pack();
+        if self.shared.snapshot().get_block_ext(&block_hash).is_none() {
+            return Err(RPCError::invalid_params("block not found"));
+        }
+
+        self.sync_shared
+            .reconsider_block(&self.chain, block_hash)
+            .map_err(|err| RPCError::custom(RPCError::Invalid, err.to_string()))
+    }
 }
@@ -1,13 +1,17 @@
 use crate::error::RPCError;
 use ckb_jsonrpc_types::{
-    BannedAddr, LocalNode, LocalNodeProtocol, NodeAddress, PeerSyncState, RemoteNode,
-    RemoteNodeProtocol, SyncState, Timestamp,
+    BannedAddr, ChainTip, ChainTipStatus, HeadersSyncProgress, HeadersSyncState, InflightBlock,
+    LocalNode, LocalNodeProtocol, NodeAddress, PeerSyncState, RemoteNode, RemoteNodeProtocol,
+    SyncPeer, SyncState, Timestamp,
 };
 use ckb_network::{extract_peer_id, multiaddr::Multiaddr, NetworkController};
-use ckb_sync::SyncShared;
+use ckb_store::ChainStore;
+use ckb_sync::{BlockStatus, HeadersSyncState as SyncHeadersSyncState, SyncShared};
+use ckb_types::prelude::*;
 use faketime::unix_time_as_millis;
 use jsonrpc_core::Result;
 use jsonrpc_derive::rpc;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 
 const MAX_ADDRS: usize = 50;
@@ -112,37 +116,54 @@ pub trait NetRpc {
     ///       "connected_duration": "0x2f",
     ///       "is_outbound": true,
     ///       "last_ping_duration": "0x1a",
+    ///       "network_group": "AS16509",
     ///       "node_id": "QmXwUgF48ULy6hkgfqrEwEfuHW7WyWyWauueRDAYQHNDfN",
     ///       "protocols": [
     ///         {
+    ///           "bytes_received": "0x5e",
+    ///           "bytes_sent": "0x60",
     ///           "id": "0x4",
     ///           "version": "0.0.1"
     ///         },
     ///         {
+    ///           "bytes_received": "0x1f",
+    ///           "bytes_sent": "0x1f",
     ///           "id": "0x2",
     ///           "version": "0.0.1"
     ///         },
     ///         {
+    ///           "bytes_received": "0x2c0",
+    ///           "bytes_sent": "0x3a1",
     ///           "id": "0x1",
     ///           "version": "0.0.1"
     ///         },
     ///         {
+    ///           "bytes_received": "0x4b3e1",
+    ///           "bytes_sent": "0x19a2c7",
     ///           "id": "0x64",
     ///           "version": "1"
     ///         },
     ///         {
+    ///           "bytes_received": "0x40",
+    ///           "bytes_sent": "0x40",
     ///           "id": "0x6e",
     ///           "version": "1"
     ///         },
     ///         {
+    ///           "bytes_received": "0x1f4",
+    ///           "bytes_sent": "0x1f4",
     ///           "id": "0x66",
     ///           "version": "1"
     ///         },
     ///         {
+    ///           "bytes_received": "0x2d14",
+    ///           "bytes_sent": "0x3a0b",
     ///           "id": "0x65",
     ///           "version": "1"
     ///         },
     ///         {
+    ///           "bytes_received": "0x6a",
+    ///           "bytes_sent": "0x6a",
     ///           "id": "0x0",
     ///           "version": "0.0.1"
     ///         }
@@ -168,37 +189,54 @@ pub trait NetRpc {
     ///       "connected_duration": "0x95",
     ///       "is_outbound": true,
     ///       "last_ping_duration": "0x41",
+    ///       "network_group": "174.80.0.0/16",
     ///       "node_id": "QmSrkzhdBMmfCGx8tQGwgXxzBg8kLtX8qMcqECMuKWsxDV",
     ///       "protocols": [
     ///         {
+    ///           "bytes_received": "0x6a",
+    ///           "bytes_sent": "0x6a",
     ///           "id": "0x0",
     ///           "version": "0.0.1"
     ///         },
     ///         {
+    ///           "bytes_received": "0x1f",
+    ///           "bytes_sent": "0x1f",
     ///           "id": "0x2",
     ///           "version": "0.0.1"
     ///         },
     ///         {
+    ///           "bytes_received": "0x40",
+    ///           "bytes_sent": "0x40",
     ///           "id": "0x6e",
     ///           "version": "1"
     ///         },
     ///         {
+    ///           "bytes_received": "0x1f4",
+    ///           "bytes_sent": "0x1f4",
     ///           "id": "0x66",
     ///           "version": "1"
     ///         },
     ///         {
+    ///           "bytes_received": "0x2c0",
+    ///           "bytes_sent": "0x3a1",
     ///           "id": "0x1",
     ///           "version": "0.0.1"
     ///         },
     ///         {
+    ///           "bytes_received": "0x2d14",
+    ///           "bytes_sent": "0x3a0b",
     ///           "id": "0x65",
     ///           "version": "1"
     ///         },
     ///         {
+    ///           "bytes_received": "0x4b3e1",
+    ///           "bytes_sent": "0x19a2c7",
     ///           "id": "0x64",
     ///           "version": "1"
     ///         },
     ///         {
+    ///           "bytes_received": "0x5e",
+    ///           "bytes_sent": "0x60",
     ///           "id": "0x4",
     ///           "version": "0.0.1"
     ///         }
@@ -373,6 +411,116 @@ pub trait NetRpc {
     #[rpc(name = "sync_state")]
     fn sync_state(&self) -> Result<SyncState>;
 
+    /// Returns the chain synchronization details of each connected remote node.
+    ///
+    /// It tells which remote nodes the blocks are being downloaded from and how fast they
+    /// respond, so the slow remote nodes which stall the synchronization can be found and
+    /// disconnected by `remove_node` or `set_ban`.
+    ///
+    /// ## Examples
+    ///
+    /// Request
+    ///
+    /// ```json
+    /// {
+    ///   "id": 42,
+    ///   "jsonrpc": "2.0",
+    ///   "method": "get_sync_peers",
+    ///   "params": []
+    /// }
+    /// ```
+    ///
+    /// Response
+    ///
+    /// ```json
+    /// {
+    ///   "id": 42,
+    ///   "jsonrpc": "2.0",
+    ///   "result": [
+    ///     {
+    ///       "address": "/ip4/192.168.0.3/tcp/8115",
+    ///       "best_known_header_hash": "0xa5f5c85987a15de25661e5a214f2c1449cd803f071acc7999820f25246471f40",
+    ///       "best_known_header_number": "0x400",
+    ///       "chain_sync_timeout": null,
+    ///       "download_task_count": "0x20",
+    ///       "headers_sync_progress": null,
+    ///       "headers_sync_state": {
+    ///         "state": "tip_synced",
+    ///         "until": "0x17c7b1b0a10"
+    ///       },
+    ///       "inflight_blocks": [
+    ///         {
+    ///           "hash": "0xb5f5c85987a15de25661e5a214f2c1449cd803f071acc7999820f25246471f40",
+    ///           "number": "0x3f1",
+    ///           "requested_at": "0x17c7b1a9c10"
+    ///         }
+    ///       ],
+    ///       "is_2021edition": true,
+    ///       "is_outbound": true,
+    ///       "is_protect": true,
+    ///       "is_whitelist": false,
+    ///       "last_common_header_hash": "0xa5f5c85987a15de25661e5a214f2c1449cd803f071acc7999820f25246471f40",
+    ///       "last_common_header_number": "0x3f0",
+    ///       "node_id": "QmaaaLB4uPyDpZwTQGhV63zuYrKm4reyN2tF1j2ain4oE7",
+    ///       "peer_index": "0x1",
+    ///       "sent_getheaders": false
+    ///     }
+    ///   ]
+    /// }
+    /// ```
+    #[rpc(name = "get_sync_peers")]
+    fn get_sync_peers(&self) -> Result<Vec<SyncPeer>>;
+
+    /// Returns the tips of all the chains known by the node, including the canonical chain and
+    /// the forks.
+    ///
+    /// The forks of the stored blocks are found in the database, and the forks of the headers
+    /// are tracked from the headers received since the node started.
+    ///
+    /// ## Returns
+    ///
+    /// The chain tips ordered by the block numbers descending.
+    ///
+    /// ## Examples
+    ///
+    /// Request
+    ///
+    /// ```json
+    /// {
+    ///   "id": 42,
+    ///   "jsonrpc": "2.0",
+    ///   "method": "get_chain_tips",
+    ///   "params": []
+    /// }
+    /// ```
+    ///
+    /// Response
+    ///
+    /// ```json
+    /// {
+    ///   "id": 42,
+    ///   "jsonrpc": "2.0",
+    ///   "result": [
+    ///     {
+    ///       "branch_len": "0x0",
+    ///       "hash": "0xa5f5c85987a15de25661e5a214f2c1449cd803f071acc7999820f25246471f40",
+    ///       "number": "0x400",
+    ///       "status": "active",
+    ///       "total_difficulty": "0x800"
+    ///     },
+    ///     {
+    ///       "branch_len": "0x2",
+    ///       "hash": "0xb5f5c85987a15de25661e5a214f2c1449cd803f071acc7999820f25246471f40",
+    ///       "number": "0x3ff",
+    ///       "status": "headers_only",
+    ///       "total_difficulty": "0x7fe"
+    ///     }
+    ///   ]
+    /// }
+    /// ```
+    #[rpc(name = "get_chain_tips")]
+    fn get_chain_tips(&self) -> Result<Vec<ChainTip>>;
+
     /// Disable/enable all p2p network activity
     ///
     /// ## Params
@@ -573,14 +721,20 @@ impl NetRpc for NetRpcImpl {
             .map(|(peer_index, peer)| {
                 let mut addresses = vec![&peer.connected_addr];
                 addresses.extend(peer.listened_addrs.iter());
+                // the connected address carries the misbehaviour score of the session
+                let peer_score = extract_peer_id(&peer.connected_addr)
+                    .and_then(|peer_id| self.network_controller.peer_score(&peer_id));
 
                 let node_addresses = addresses
                     .iter()
                     .map(|addr| {
-                        let score = self
-                            .network_controller
-                            .addr_info(addr)
-                            .map(|addr_info| addr_info.score)
+                        let score = peer_score
+                            .filter(|_| *addr == &peer.connected_addr)
+                            .or_else(|| {
+                                self.network_controller
+                                    .addr_info(addr)
+                                    .map(|addr_info| addr_info.score)
+                            })
                             .unwrap_or(1);
                         let non_negative_score = if score > 0 { score as u64 } else { 0 };
                         NodeAddress {
@@ -591,6 +745,7 @@ impl NetRpc for NetRpcImpl {
                     .collect();
 
                 let inflight_blocks = self.sync_shared.state().read_inflight_blocks();
+                let bandwidth = self.network_controller.peer_bandwidth(*peer_index);
                 RemoteNode {
                     is_outbound: peer.is_outbound(),
                     version: peer
@@ -606,6 +761,10 @@ impl NetRpc for NetRpcImpl {
                     last_ping_duration: peer
                         .ping_rtt
                         .map(|duration| (duration.as_millis() as u64).into()),
+                    network_group: self
+                        .network_controller
+                        .network_group(&peer.connected_addr)
+                        .to_string(),
                     sync_state: self.sync_shared.state().peers().state.get(peer_index).map(
                         |state| PeerSyncState {
                             best_known_header_hash: state
@@ -637,9 +796,14 @@ impl NetRpc for NetRpcImpl {
                     protocols: peer
                         .protocols
                         .iter()
-                        .map(|(protocol_id, protocol_version)| RemoteNodeProtocol {
-                            id: (protocol_id.value() as u64).into(),
-                            version: protocol_version.clone(),
+                        .map(|(protocol_id, protocol_version)| {
+                            let bandwidth = bandwidth.get(protocol_id).copied().unwrap_or_default();
+                            RemoteNodeProtocol {
+                                id: (protocol_id.value() as u64).into(),
+                                version: protocol_version.clone(),
+                                bytes_sent: bandwidth.sent.into(),
+                                bytes_received: bandwidth.received.into(),
+                            }
                         })
                         .collect(),
                 }
@@ -728,6 +892,190 @@ impl NetRpc for NetRpcImpl {
         Ok(sync_state)
     }
 
+    fn get_sync_peers(&self) -> Result<Vec<SyncPeer>> {
+        let connected_peers: HashMap<_, _> = self
+            .network_controller
+            .connected_peers()
+            .into_iter()
+            .collect();
+        let state = self.sync_shared.state();
+        let inflight_blocks = state.read_inflight_blocks();
+        let mut peers: Vec<SyncPeer> = state
+            .peers()
+            .state
+            .iter()
+            .filter_map(|kv_pair| {
+                let (peer_index, peer_state) = kv_pair.pair();
+                let peer = connected_peers.get(peer_index)?;
+
+                let mut blocks: Vec<InflightBlock> = inflight_blocks
+                    .inflight_block_by_peer(*peer_index)
+                    .map(|blocks| {
+                        blocks
+                            .iter()
+                            .map(|block| InflightBlock {
+                                number: block.number.into(),
+                                hash: block.hash.clone().into(),
+                                requested_at: inflight_blocks
+                                    .inflight_state_by_block(block)
+                                    .map(|state| state.timestamp())
+                                    .unwrap_or_default()
+                                    .into(),
+                            })
+                            .collect()
+                    })
+                    .unwrap_or_default();
+                blocks.sort_by_key(|block| block.number.value());
+
+                let headers_sync_state = match peer_state.chain_sync.headers_sync_state() {
+                    SyncHeadersSyncState::Initialized => HeadersSyncState::Initialized,
+                    SyncHeadersSyncState::SyncProtocolConnected => {
+                        HeadersSyncState::SyncProtocolConnected
+                    }
+                    SyncHeadersSyncState::Started => HeadersSyncState::Started,
+                    SyncHeadersSyncState::Suspend(until) => {
+                        HeadersSyncState::Suspended(until.into())
+                    }
+                    SyncHeadersSyncState::TipSynced(until) => {
+                        HeadersSyncState::TipSynced(until.into())
+                    }
+                };
+                let headers_sync_progress =
+                    peer_state
+                        .headers_sync_controller
+                        .map(|controller| HeadersSyncProgress {
+                            started_at: controller.started_ts.into(),
+                            started_tip_timestamp: controller.started_tip_ts.into(),
+                            last_updated_at: controller.last_updated_ts.into(),
+                            last_updated_tip_timestamp: controller.last_updated_tip_ts.into(),
+                            is_close_to_the_end: controller.is_close_to_the_end,
+                        });
+
+                Some(SyncPeer {
+                    peer_index: (peer_index.value() as u64).into(),
+                    node_id: extract_peer_id(&peer.connected_addr)
+                        .map(|peer_id| peer_id.to_base58())
+                        .unwrap_or_default(),
+                    address: peer.connected_addr.to_string(),
+                    is_outbound: peer_state.peer_flags.is_outbound,
+                    is_protect: peer_state.peer_flags.is_protect,
+                    is_whitelist: peer_state.peer_flags.is_whitelist,
+                    is_2021edition: peer_state.peer_flags.is_2021edition,
+                    best_known_header_hash: peer_state
+                        .best_known_header
+                        .as_ref()
+                        .map(|header| header.hash().into()),
+                    best_known_header_number: peer_state
+                        .best_known_header
+                        .as_ref()
+                        .map(|header| header.number().into()),
+                    last_common_header_hash: peer_state
+                        .last_common_header
+                        .as_ref()
+                        .map(|header| header.hash().into()),
+                    last_common_header_number: peer_state
+                        .last_common_header
+                        .as_ref()
+                        .map(|header| header.number().into()),
+                    inflight_blocks: blocks,
+                    download_task_count: (inflight_blocks.peer_task_count(*peer_index) as u64)
+                        .into(),
+                    headers_sync_state,
+                    headers_sync_progress,
+                    chain_sync_timeout: Some(peer_state.chain_sync.timeout)
+                        .filter(|timeout| *timeout != 0)
+                        .map(Into::into),
+                    sent_getheaders: peer_state.chain_sync.sent_getheaders,
+                })
+            })
+            .collect();
+        peers.sort_by_key(|peer| peer.peer_index.value());
+
+        Ok(peers)
+    }
+
+    fn get_chain_tips(&self) -> Result<Vec<ChainTip>> {
+        let active_chain = self.sync_shared.active_chain();
+        let main_tip = active_chain.tip_header();
+        // the tracked tips which are stored are either extended, or found in the store
+        let mut hashes: Vec<_> = self
+            .sync_shared
+            .state()
+            .chain_tips()
+            .into_iter()
+            .filter(|hash| !active_chain.contains_block_status(hash, BlockStatus::BLOCK_STORED))
+            .collect();
+        hashes.extend(
+            self.sync_shared
+                .store()
+                .get_fork_tips()
+                .into_iter()
+                .map(|header| header.hash()),
+        );
+        hashes.push(main_tip.hash());
+        let headers: HashMap<_, _> = hashes
+            .into_iter()
+            .filter_map(|hash| {
+                let header = self.sync_shared.get_header_view(&hash, None)?;
+                Some((hash, header))
+            })
+            .collect();
+
+        // a tracked tip may be extended by another one, or be in the main chain
+        let parents: HashSet<_> = headers
+            .values()
+            .map(|header| header.parent_hash())
+            .collect();
+        let mut tips: Vec<ChainTip> = headers
+            .into_iter()
+            .filter(|(hash, _)| {
+                hash == &main_tip.hash()
+                    || (!parents.contains(hash) && !active_chain.is_main_chain(hash))
+            })
+            .filter_map(|(hash, header)| {
+                let (branch_len, status) = if hash == main_tip.hash() {
+                    (0, ChainTipStatus::Active)
+                } else {
+                    let fork_point =
+                        active_chain.last_common_ancestor(header.inner(), &main_tip)?;
+                    let block_status = active_chain.get_block_status(&hash);
+                    let status = if block_status.contains(BlockStatus::BLOCK_INVALID) {
+                        ChainTipStatus::Invalid
+                    } else if block_status.contains(BlockStatus::BLOCK_VALID) {
+                        // the verified blocks may be detached by an invalid ancestor
+                        let mut current = header.clone();
+                        let mut status = ChainTipStatus::Valid;
+                        while current.number() > fork_point.number() + 1 {
+                            current = self
+                                .sync_shared
+                                .get_header_view(&current.parent_hash(), None)?;
+                            if active_chain
+                                .contains_block_status(&current.hash(), BlockStatus::BLOCK_INVALID)
+                            {
+                                status = ChainTipStatus::Invalid;
+                                break;
+                            }
+                        }
+                        status
+                    } else {
+                        ChainTipStatus::HeadersOnly
+                    };
+                    (header.number() - fork_point.number(), status)
+                };
+                Some(ChainTip {
+                    hash: hash.unpack(),
+                    number: header.number().into(),
+                    branch_len: branch_len.into(),
+                    total_difficulty: header.total_difficulty().clone(),
+                    status,
+                })
+            })
+            .collect();
+        tips.sort_by_key(|tip| std::cmp::Reverse(tip.number.value()));
+
+        Ok(tips)
+    }
+
     fn set_network_active(&self, state: bool) -> Result<()> {
         self.network_controller.set_active(state);
         Ok(())
@@ -1,6 +1,9 @@
-use ckb_jsonrpc_types::{AlertMessage, ChainInfo};
+use crate::error::RPCError;
+use ckb_jsonrpc_types::{AlertMessage, ChainInfo, ColumnStats, DBStats, FreezerTableStats};
+use ckb_memory_tracker::GatherRocksDBStats;
 use ckb_network_alert::notifier::Notifier as AlertNotifier;
 use ckb_shared::shared::Shared;
+use ckb_store::ChainStore;
 use ckb_traits::HeaderProvider;
 use ckb_util::Mutex;
 use jsonrpc_core::Result;
@@ -50,6 +53,53 @@ pub trait StatsRpc {
     /// ```
     #[rpc(name = "get_blockchain_info")]
     fn get_blockchain_info(&self) -> Result<ChainInfo>;
+
+    /// Returns statistics about the database.
+    ///
+    /// It reports the RocksDB properties of each column family and the size of each freezer
+    /// table, which helps to find out which kind of data grows the disk usage.
+    ///
+    /// ## Examples
+    ///
+    /// Request
+    ///
+    /// ```json
+    /// {
+    ///   "id": 42,
+    ///   "jsonrpc": "2.0",
+    ///   "method": "get_db_stats",
+    ///   "params": []
+    /// }
+    /// ```
+    ///
+    /// Response
+    ///
+    /// ```json
+    /// {
+    ///   "id": 42,
+    ///   "jsonrpc": "2.0",
+    ///   "result": {
+    ///     "columns": [
+    ///       {
+    ///         "block_cache_usage": "0x2c40",
+    ///         "column": "0",
+    ///         "estimate_num_keys": "0x2a",
+    ///         "estimate_pending_compaction_bytes": "0x0",
+    ///         "live_sst_files_size": "0x4d2",
+    ///         "total_sst_files_size": "0x4d2"
+    ///       }
+    ///     ],
+    ///     "freezer": [
+    ///       {
+    ///         "size": "0xc",
+    ///         "table": "blocks"
+    ///       }
+    ///     ]
+    ///   }
+    /// }
+    /// ```
+    #[rpc(name = "get_db_stats")]
+    fn get_db_stats(&self) -> Result<DBStats>;
 }
 
 pub(crate) struct StatsRpcImpl {
@@ -99,4 +149,38 @@ impl StatsRpc for StatsRpcImpl {
             alerts,
         })
     }
+
+    fn get_db_stats(&self) -> Result<DBStats> {
+        let store = self.shared.store();
+        let columns = store
+            .db()
+            .inner()
+            .gather_column_family_stats()
+            .into_iter()
+            .map(|cf| ColumnStats {
+                column: cf.name,
+                estimate_num_keys: cf.estimate_num_keys.map(Into::into),
+                live_sst_files_size: cf.live_sst_files_size.map(Into::into),
+                total_sst_files_size: cf.total_sst_files_size.map(Into::into),
+                estimate_pending_compaction_bytes: cf
+                    .estimate_pending_compaction_bytes
+                    .map(Into::into),
+                block_cache_usage: cf.block_cache_usage.map(Into::into),
+            })
+            .collect();
+        let freezer = match store.freezer() {
+            Some(freezer) => freezer
+                .table_sizes()
+                .map_err(RPCError::ckb_internal_error)?
+                .into_iter()
+                .map(|(table, size)| FreezerTableStats {
+                    table: table.name().to_owned(),
+                    size: size.into(),
+                })
+                .collect(),
+            None => Vec::new(),
+        };
+
+        Ok(DBStats { columns, freezer })
+    }
 }
@@ -86,7 +86,7 @@ pub trait SubscriptionRpc {
     ///
     /// ## Params
     ///
-    /// * `topic` - Subscription topic (enum: new_tip_header | new_tip_block | new_transaction | proposed_transaction | rejected_transaction)
+    /// * `topic` - Subscription topic (enum: new_tip_header | new_tip_block | new_transaction | proposed_transaction | rejected_transaction | block_template)
     ///
     /// ## Returns
     ///
@@ -146,6 +146,13 @@ pub trait SubscriptionRpc {
     /// -   the first item type is [`PoolTransactionEntry`](../../ckb_jsonrpc_types/struct.PoolTransactionEntry.html), and
     /// -   the second item type is [`PoolTransactionReject`](../../ckb_jsonrpc_types/struct.PoolTransactionReject.html).
     ///
+    /// ### `block_template`
+    ///
+    /// Subscribers will get notified when the block template is changed by a new tip block, new
+    /// transactions or new uncles. It requires the block assembler.
+    ///
+    /// The type of the `params.result` in the push message is [`BlockTemplate`](../../ckb_jsonrpc_types/struct.BlockTemplate.html).
+    ///
     /// ## Examples
     ///
     /// Request
@@ -282,6 +289,7 @@ impl SubscriptionRpcImpl {
             notify_controller.subscribe_proposed_transaction(name.to_string());
         let reject_transaction_receiver =
             notify_controller.subscribe_reject_transaction(name.to_string());
+        let block_template_receiver = notify_controller.subscribe_block_template(name.to_string());
 
         let subscription_rpc_impl = SubscriptionRpcImpl::default();
         let subscribers = Arc::clone(&subscription_rpc_impl.subscribers);
@@ -363,6 +371,21 @@ impl SubscriptionRpcImpl {
                             break;
                         },
                     },
+                    recv(block_template_receiver) -> msg => match msg {
+                        Ok(template) => {
+                            let subscribers = subscribers.read().expect("acquiring subscribers read lock");
+                            if let Some(block_template_subscribers) = subscribers.get(&Topic::BlockTemplate) {
+                                let json_string = Ok(serde_json::to_string(&template).expect("serialization should be ok"));
+                                for sink in block_template_subscribers.values() {
+                                    let _ = sink.notify(json_string.clone());
+                                }
+                            }
+                        },
+                        _ => {
+                            error!("block_template_receiver closed");
+                            break;
+                        },
+                    },
                 }
             })
             .expect("Start SubscriptionRpc thread failed");
@@ -181,9 +181,20 @@ impl<'a> ServiceBuilder<'a> {
     }
 
     /// Mounts methods from module Debug if it is enabled in the config.
-    pub fn enable_debug(mut self) -> Self {
+    pub fn enable_debug(
+        mut self,
+        shared: Shared,
+        chain: ChainController,
+        sync_shared: Arc<SyncShared>,
+    ) -> Self {
         if self.config.debug_enable() {
-            self.io_handler.extend_with(DebugRpcImpl {}.to_delegate());
+            let rpc_methods = DebugRpcImpl {
+                shared,
+                chain,
+                sync_shared,
+            }
+            .to_delegate();
+            self.io_handler.extend_with(rpc_methods);
         }
         self
     }
@@ -0,0 +1,610 @@
+//! The stratum mining server.
+//!
+//! Instead of polling `get_block_template`, the miners keep a connection to the stratum server,
+//! which pushes a new job whenever the tx-pool notifies a new block template. Every authorized
+//! connection is allocated a distinct extranonce which takes the high bytes of the header nonce,
+//! so the miners never search the same nonce space. The shares are checked against the share
+//! target, the ones which also meet the block target are submitted through `submit_block`.
+//!
+//! The messages are line-delimited JSON:
+//!
+//! * `mining.subscribe` returns `[null, extranonce1, extranonce2_size]`, `extranonce1` is null
+//!   until the connection is authorized.
+//! * `mining.authorize` with the params `[worker, password]` returns `true` if the password matches
+//!   the configured one, or no password is configured.
+//! * `mining.submit` with the params `[worker, job_id, extranonce2]` returns `true` if the share
+//!   is accepted.
+//! * The server notifies `mining.set_extranonce` with `[extranonce1, extranonce2_size]` once a
+//!   subscribed connection is authorized, then `mining.set_target` with `[target]` and
+//!   `mining.notify` with `[job_id, pow_hash, number, parent_hash, clean_jobs]`.
+//!
+//! The nonce of a share is the big-endian concatenation of extranonce1 and extranonce2, and all
+//! the binaries are hex encoded without the `0x` prefix.
+use crate::module::{MinerRpc, MinerRpcImpl};
+use ckb_app_config::StratumConfig;
+use ckb_chain::chain::ChainController;
+use ckb_channel::Receiver;
+use ckb_jsonrpc_types::BlockTemplate;
+use ckb_logger::{debug, error, info};
+use ckb_network::NetworkController;
+use ckb_pow::PowEngine;
+use ckb_shared::shared::Shared;
+use ckb_stop_handler::{SignalSender, StopHandler, WATCH_INIT};
+use ckb_types::{core::BlockNumber, packed, prelude::*, utilities::compact_to_target, H256, U256};
+use ckb_util::Mutex;
+use futures::StreamExt;
+use serde_json::{json, Value};
+use std::collections::{HashSet, VecDeque};
+use std::io;
+use std::net::SocketAddr;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::thread;
+use tokio::{
+    io::AsyncWriteExt,
+    net::{TcpListener, TcpStream},
+    sync::{watch, OwnedSemaphorePermit, Semaphore},
+};
+use tokio_util::codec::{FramedRead, LinesCodec};
+
+const NONCE_SIZE: usize = 16;
+// The recent jobs which still accept shares
+const MAX_JOBS: usize = 16;
+// The accepted shares recorded by a job to reject the duplicates
+pub(crate) const MAX_JOB_SHARES: usize = 1 << 16;
+// The connection is closed once a request line is longer
+pub(crate) const MAX_LINE_LENGTH: usize = 4096;
+
+const ERROR_OTHER: u32 = 20;
+const ERROR_STALE_JOB: u32 = 21;
+const ERROR_DUPLICATE_SHARE: u32 = 22;
+const ERROR_LOW_DIFFICULTY: u32 = 23;
+const ERROR_UNAUTHORIZED: u32 = 24;
+const ERROR_NOT_SUBSCRIBED: u32 = 25;
+
+/// The result of checking a share.
+#[derive(Debug)]
+pub(crate) enum Share {
+    /// The share meets the share target.
+    Accepted,
+    /// The share meets the block target, carries the solved block.
+    Block(packed::Block),
+    /// The share doesn't meet the share target.
+    LowDifficulty,
+    /// The nonce has been submitted.
+    Duplicate,
+    /// The job has recorded `MAX_JOB_SHARES` shares, the miner should wait for a new job.
+    Full,
+}
+
+/// A mining job built from a block template.
+pub(crate) struct Job {
+    pub(crate) id: String,
+    work_id: u64,
+    block: packed::Block,
+    pow_hash: packed::Byte32,
+    target: U256,
+    clean: bool,
+    nonces: Mutex<HashSet<u128>>,
+}
+
+impl Job {
+    /// Builds a job from the template, the shares must meet the easier one of the share target
+    /// and the block target.
+    pub(crate) fn new(
+        id: String,
+        template: BlockTemplate,
+        share_target: Option<&U256>,
+        clean: bool,
+    ) -> Job {
+        let work_id = template.work_id.into();
+        let block: packed::Block = template.into();
+        let (block_target, _) = compact_to_target(block.header().raw().compact_target().unpack());
+        let target = match share_target {
+            Some(share_target) if *share_target > block_target => share_target.clone(),
+            _ => block_target,
+        };
+        Job {
+            id,
+            work_id,
+            pow_hash: block.header().calc_pow_hash(),
+            block,
+            target,
+            clean,
+            nonces: Mutex::new(HashSet::new()),
+        }
+    }
+
+    fn number(&self) -> BlockNumber {
+        self.block.header().raw().number().unpack()
+    }
+
+    fn parent_hash(&self) -> packed::Byte32 {
+        self.block.header().raw().parent_hash()
+    }
+
+    /// Checks the share with the given nonce.
+    ///
+    /// Only the shares which meet the target are recorded, so a job records at most
+    /// `MAX_JOB_SHARES` nonces.
+    pub(crate) fn check(&self, engine: &dyn PowEngine, nonce: u128) -> Share {
+        let header = self.block.header().as_builder().nonce(nonce.pack()).build();
+        if !engine.verify_target(&header, &self.target) {
+            return Share::LowDifficulty;
+        }
+        {
+            let mut nonces = self.nonces.lock();
+            if nonces.contains(&nonce) {
+                return Share::Duplicate;
+            }
+            if nonces.len() >= MAX_JOB_SHARES {
+                return Share::Full;
+            }
+            nonces.insert(nonce);
+        }
+        if engine.verify(&header) {
+            Share::Block(self.block.clone().as_builder().header(header).build())
+        } else {
+            Share::Accepted
+        }
+    }
+
+    fn set_target(&self) -> Value {
+        let target = H256::from_slice(&self.target.to_be_bytes()).expect("U256 is 32 bytes");
+        json!({
+            "id": null,
+            "method": "mining.set_target",
+            "params": [format!("{:x}", target)],
+        })
+    }
+
+    fn notify(&self) -> Value {
+        json!({
+            "id": null,
+            "method": "mining.notify",
+            "params": [
+                self.id,
+                format!("{:x}", self.pow_hash),
+                self.number(),
+                format!("{:x}", self.parent_hash()),
+                self.clean,
+            ],
+        })
+    }
+}
+
+/// Composes the nonce from the extranonce of the connection and the hex encoded extranonce2
+/// searched by the miner.
+pub(crate) fn compose_nonce(
+    extranonce1: u64,
+    extranonce_size: usize,
+    extranonce2: &str,
+) -> Option<u128> {
+    let extranonce2_size = NONCE_SIZE - extranonce_size;
+    if extranonce2.len() != extranonce2_size * 2 {
+        return None;
+    }
+    let extranonce2 = u128::from_str_radix(extranonce2, 16).ok()?;
+    Some((u128::from(extranonce1) << (extranonce2_size * 8)) | extranonce2)
+}
+
+struct Stratum {
+    miner: Arc<MinerRpcImpl>,
+    engine: Arc<dyn PowEngine>,
+    share_target: Option<U256>,
+    password: Option<String>,
+    extranonce_size: usize,
+    next_extranonce: AtomicU64,
+    // the extranonces allocated to the open sessions
+    extranonces: Mutex<HashSet<u64>>,
+    sessions: Arc<Semaphore>,
+    jobs: Mutex<VecDeque<Arc<Job>>>,
+    next_job_id: AtomicU64,
+    job_sender: watch::Sender<Option<Arc<Job>>>,
+    job_receiver: watch::Receiver<Option<Arc<Job>>>,
+}
+
+#[derive(Default)]
+struct Session {
+    subscribed: bool,
+    extranonce1: Option<u64>,
+    authorized: bool,
+    job_id: Option<String>,
+}
+
+impl Stratum {
+    fn update_job(&self, template: BlockTemplate) {
+        let latest = self.jobs.lock().front().cloned();
+        if let Some(ref latest) = latest {
+            if latest.work_id == u64::from(template.work_id) {
+                return;
+            }
+        }
+        let clean = latest
+            .map(|latest| latest.parent_hash() != template.parent_hash.pack())
+            .unwrap_or(true);
+        let id = format!("{:x}", self.next_job_id.fetch_add(1, Ordering::SeqCst));
+        let job = Arc::new(Job::new(id, template, self.share_target.as_ref(), clean));
+        debug!(
+            "stratum new job {} for block #{}, clean: {}",
+            job.id,
+            job.number(),
+            clean
+        );
+        {
+            let mut jobs = self.jobs.lock();
+            if clean {
+                jobs.clear();
+            }
+            jobs.push_front(Arc::clone(&job));
+            jobs.truncate(MAX_JOBS);
+        }
+        let _ = self.job_sender.send(Some(job));
+    }
+
+    fn find_job(&self, id: &str) -> Option<Arc<Job>> {
+        self.jobs.lock().iter().find(|job| job.id == id).cloned()
+    }
+
+    fn current_job(&self) -> Option<Arc<Job>> {
+        self.job_receiver.borrow().clone()
+    }
+
+    fn job_messages(job: &Job, session: &mut Session) -> Vec<Value> {
+        session.job_id = Some(job.id.clone());
+        vec![job.set_target(), job.notify()]
+    }
+
+    // Allocates the next extranonce which is not in use, wraps around within the extranonce
+    // bytes, returns none if all of them are in use
+    fn allocate_extranonce(&self) -> Option<u64> {
+        let mask = u64::max_value() >> (64 - self.extranonce_size * 8);
+        let mut extranonces = self.extranonces.lock();
+        if extranonces.len() as u64 > mask {
+            return None;
+        }
+        loop {
+            let extranonce = self.next_extranonce.fetch_add(1, Ordering::SeqCst) & mask;
+            if extranonces.insert(extranonce) {
+                return Some(extranonce);
+            }
+        }
+    }
+
+    fn release_extranonce(&self, extranonce: u64) {
+        self.extranonces.lock().remove(&extranonce);
+    }
+
+    fn format_extranonce(&self, extranonce: Option<u64>) -> Value {
+        extranonce
+            .map(|extranonce| json!(format!("{:01$x}", extranonce, self.extranonce_size * 2)))
+            .unwrap_or(Value::Null)
+    }
+
+    // Sends the extranonce and the current job to a session which is both subscribed and
+    // authorized
+    fn start_mining(&self, session: &mut Session) -> Vec<Value> {
+        let mut messages = vec![json!({
+            "id": null,
+            "method": "mining.set_extranonce",
+            "params": [
+                self.format_extranonce(session.extranonce1),
+                NONCE_SIZE - self.extranonce_size,
+            ],
+        })];
+        if let Some(job) = self.current_job() {
+            messages.extend(Self::job_messages(&job, session));
+        }
+        messages
+    }
+
+    async fn handle(&self, session: &mut Session, line: &str) -> Vec<Value> {
+        let request: Value = match serde_json::from_str(line) {
+            Ok(request) => request,
+            Err(err) => {
+                debug!("stratum invalid request {}: {}", line, err);
+                return vec![error_response(Value::Null, ERROR_OTHER, "invalid request")];
+            }
+        };
+        let id = request["id"].clone();
+        let params = &request["params"];
+        match request["method"].as_str().unwrap_or_default() {
+            "mining.subscribe" => {
+                let mut messages = vec![json!({
+                    "id": id,
+                    "result": [
+                        null,
+                        self.format_extranonce(session.extranonce1),
+                        NONCE_SIZE - self.extranonce_size,
+                    ],
+                    "error": null,
+                })];
+                if !session.subscribed && session.extranonce1.is_some() {
+                    if let Some(job) = self.current_job() {
+                        messages.extend(Self::job_messages(&job, session));
+                    }
+                }
+                session.subscribed = true;
+                messages
+            }
+            "mining.authorize" => {
+                if let Some(ref password) = self.password {
+                    if params[1].as_str() != Some(password.as_str()) {
+                        return vec![error_response(
+                            id,
+                            ERROR_UNAUTHORIZED,
+                            "unauthorized worker",
+                        )];
+                    }
+                }
+                if session.extranonce1.is_none() {
+                    session.extranonce1 = self.allocate_extranonce();
+                }
+                if session.extranonce1.is_none() {
+                    return vec![error_response(id, ERROR_OTHER, "no extranonce available")];
+                }
+                let first = !session.authorized;
+                session.authorized = true;
+                let mut messages = vec![json!({ "id": id, "result": true, "error": null })];
+                if first && session.subscribed {
+                    messages.extend(self.start_mining(session));
+                }
+                messages
+            }
+            "mining.submit" => {
+                if !session.subscribed {
+                    return vec![error_response(id, ERROR_NOT_SUBSCRIBED, "not subscribed")];
+                }
+                let extranonce1 = match session.extranonce1 {
+                    Some(extranonce1) if session.authorized => extranonce1,
+                    _ => {
+                        return vec![error_response(
+                            id,
+                            ERROR_UNAUTHORIZED,
+                            "unauthorized worker",
+                        )]
+                    }
+                };
+                let job = match params[1].as_str().and_then(|job_id| self.find_job(job_id)) {
+                    Some(job) => job,
+                    None => return vec![error_response(id, ERROR_STALE_JOB, "job not found")],
+                };
+                let nonce = match params[2].as_str().and_then(|extranonce2| {
+                    compose_nonce(extranonce1, self.extranonce_size, extranonce2)
+                }) {
+                    Some(nonce) => nonce,
+                    None => return vec![error_response(id, ERROR_OTHER, "invalid extranonce2")],
+                };
+                match job.check(self.engine.as_ref(), nonce) {
+                    Share::Accepted => vec![json!({ "id": id, "result": true, "error": null })],
+                    Share::Block(block) => vec![self.submit(id, job.work_id, block).await],
+                    Share::LowDifficulty => {
+                        vec![error_response(
+                            id,
+                            ERROR_LOW_DIFFICULTY,
+                            "low difficulty share",
+                        )]
+                    }
+                    Share::Duplicate => {
+                        vec![error_response(id, ERROR_DUPLICATE_SHARE, "duplicate share")]
+                    }
+                    Share::Full => vec![error_response(id, ERROR_STALE_JOB, "job is full")],
+                }
+            }
+            method => {
+                debug!("stratum unknown method {}", method);
+                vec![error_response(id, ERROR_OTHER, "unknown method")]
+            }
+        }
+    }
+
+    async fn submit(&self, id: Value, work_id: u64, block: packed::Block) -> Value {
+        let miner = Arc::clone(&self.miner);
+        let result = tokio::task::spawn_blocking(move || {
+            miner.submit_block(work_id.to_string(), block.into())
+        })
+        .await;
+        match result {
+            Ok(Ok(hash)) => {
+                info!("stratum submitted block {:#x}", hash);
+                json!({ "id": id, "result": true, "error": null })
+            }
+            Ok(Err(err)) => error_response(id, ERROR_OTHER, &err.message),
+            Err(err) => {
+                error!("stratum submit block error: {}", err);
+                error_response(id, ERROR_OTHER, "internal error")
+            }
+        }
+    }
+
+    // Builds the jobs from the current block template and the ones notified by the tx-pool, it
+    // exits once the notify service is stopped
+    fn receive_templates(self: Arc<Self>, templates: Receiver<BlockTemplate>) {
+        match self.miner.get_block_template(None, None, None) {
+            Ok(template) => self.update_job(template),
+            Err(err) => debug!("stratum get_block_template error: {}", err.message),
+        }
+        while let Ok(template) = templates.recv() {
+            self.update_job(template);
+        }
+    }
+
+    async fn accept(self: Arc<Self>, listener: TcpListener, mut stop: watch::Receiver<u8>) {
+        loop {
+            tokio::select! {
+                _ = stop.changed() => break,
+                accepted = listener.accept() => match accepted {
+                    Ok((stream, peer)) => match Arc::clone(&self.sessions).try_acquire_owned() {
+                        Ok(permit) => {
+                            debug!("stratum accepted {}", peer);
+                            tokio::spawn(Arc::clone(&self).serve(stream, permit, stop.clone()));
+                        }
+                        Err(_) => debug!("stratum rejected {}, too many sessions", peer),
+                    },
+                    Err(err) => error!("stratum accept error: {}", err),
+                },
+            }
+        }
+    }
+
+    // The session ends when the connection is closed, a line exceeds `MAX_LINE_LENGTH`, or the
+    // server stops, the permit and the extranonce of the session are released then
+    async fn serve(
+        self: Arc<Self>,
+        stream: TcpStream,
+        _permit: OwnedSemaphorePermit,
+        stop: watch::Receiver<u8>,
+    ) {
+        let mut session = Session::default();
+        self.serve_session(stream, &mut session, stop).await;
+        if let Some(extranonce) = session.extranonce1 {
+            self.release_extranonce(extranonce);
+        }
+    }
+
+    async fn serve_session(
+        &self,
+        stream: TcpStream,
+        session: &mut Session,
+        mut stop: watch::Receiver<u8>,
+    ) {
+        let (reader, mut writer) = stream.into_split();
+        let mut lines = FramedRead::new(reader, LinesCodec::new_with_max_length(MAX_LINE_LENGTH));
+        let mut jobs = self.job_receiver.clone();
+        loop {
+            let mining = session.subscribed && session.authorized;
+            let messages = tokio::select! {
+                _ = stop.changed() => break,
+                line = lines.next() => match line {
+                    Some(Ok(line)) => self.handle(session, &line).await,
+                    Some(Err(err)) => {
+                        debug!("stratum read error: {}", err);
+                        break;
+                    }
+                    None => break,
+                },
+                changed = jobs.changed(), if mining => {
+                    if changed.is_err() {
+                        break;
+                    }
+                    match self.current_job() {
+                        Some(job) if session.job_id.as_ref() != Some(&job.id) => {
+                            Self::job_messages(&job, session)
+                        }
+                        _ => Vec::new(),
+                    }
+                }
+            };
+            for message in messages {
+                let mut line = message.to_string();
+                line.push('\n');
+                if let Err(err) = writer.write_all(line.as_bytes()).await {
+                    debug!("stratum write error: {}", err);
+                    return;
+                }
+            }
+        }
+    }
+}
+
+fn error_response(id: Value, code: u32, message: &str) -> Value {
+    json!({ "id": id, "result": null, "error": [code, message, null] })
+}
+
+/// The stratum mining server.
+pub struct StratumServer {
+    stop: StopHandler<()>,
+    listen_address: SocketAddr,
+}
+
+impl StratumServer {
+    /// Starts a stratum server.
+    ///
+    /// ## Parameters
+    ///
+    /// * `config` - Stratum config options.
+    /// * `shared` - The shared state, the block assembler must be enabled.
+    /// * `chain` - The chain controller which processes the solved blocks.
+    /// * `network_controller` - The network controller which relays the solved blocks.
+    pub fn start(
+        config: StratumConfig,
+        shared: Shared,
+        chain: ChainController,
+        network_controller: NetworkController,
+    ) -> io::Result<StratumServer> {
+        if config.extranonce_size == 0 || config.extranonce_size > 8 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "stratum extranonce_size should be in the range 1..=8",
+            ));
+        }
+        let handle = shared.async_handle().clone();
+        let listener = std::net::TcpListener::bind(&config.listen_address)?;
+        listener.set_nonblocking(true)?;
+        let listen_address = listener.local_addr()?;
+        if !listen_address.ip().is_loopback() && config.password.is_none() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "stratum password is required unless listen_address is a loopback address",
+            ));
+        }
+        let listener = handle.enter(|| TcpListener::from_std(listener))?;
+
+        let (job_sender, job_receiver) = watch::channel(None);
+        let stratum = Arc::new(Stratum {
+            engine: shared.consensus().pow_engine(),
+            share_target: config
+                .share_target
+                .map(|target| U256::from_big_endian(target.as_bytes()).expect("bound checked")),
+            password: config.password,
+            extranonce_size: config.extranonce_size,
+            next_extranonce: AtomicU64::new(0),
+            extranonces: Mutex::new(HashSet::new()),
+            sessions: Arc::new(Semaphore::new(config.max_sessions)),
+            jobs: Mutex::new(VecDeque::new()),
+            next_job_id: AtomicU64::new(0),
+            job_sender,
+            job_receiver,
+            miner: Arc::new(MinerRpcImpl {
+                shared,
+                chain,
+                network_controller,
+            }),
+        });
+
+        // subscribes before fetching the current template, so no change is missed
+        let templates = stratum
+            .miner
+            .shared
+            .notify_controller()
+            .subscribe_block_template("stratum");
+        let receiver = Arc::clone(&stratum);
+        thread::Builder::new()
+            .name("stratum".to_string())
+            .spawn(move || receiver.receive_templates(templates))?;
+
+        let (signal_sender, signal_receiver) = watch::channel(WATCH_INIT);
+        handle.spawn(stratum.accept(listener, signal_receiver));
+        info!("Listen stratum server on address {}", listen_address);
+
+        Ok(StratumServer {
+            stop: StopHandler::new(
+                SignalSender::Watch(signal_sender),
+                None,
+                "stratum".to_string(),
+            ),
+            listen_address,
+        })
+    }
+
+    /// Returns the address the stratum server listens on.
+    pub fn listen_address(&self) -> SocketAddr {
+        self.listen_address
+    }
+}
+
+impl Drop for StratumServer {
+    fn drop(&mut self) {
+        self.stop.try_send(());
+    }
+}
@@ -240,7 +240,7 @@ fn setup_rpc_test_suite(height: u64) -> RpcTestSuite {
             chain_controller.clone(),
             true,
         )
-        .enable_net(network_controller.clone(), sync_shared)
+        .enable_net(network_controller.clone(), Arc::clone(&sync_shared))
         .enable_stats(shared.clone(), Arc::clone(&alert_notifier))
         .enable_experiment(shared.clone())
         .enable_integration_test(
@@ -248,8 +248,8 @@ fn setup_rpc_test_suite(height: u64) -> RpcTestSuite {
             network_controller.clone(),
             chain_controller.clone(),
         )
-        .enable_debug()
-        .enable_alert(alert_verifier, alert_notifier, network_controller);
+        .enable_debug(shared.clone(), chain_controller.clone(), sync_shared)
+        .enable_alert(alert_verifier, alert_notifier, network_controller.clone());
     let io_handler = builder.build();
 
     let rpc_server = RpcServer::new(rpc_config, io_handler, shared.notify_controller());
@@ -263,6 +263,7 @@ fn setup_rpc_test_suite(height: u64) -> RpcTestSuite {
     let suite = RpcTestSuite {
         shared,
         chain_controller,
+        network_controller,
         rpc_server,
         rpc_uri,
         rpc_client,
@@ -558,13 +559,17 @@ where
 // * Use replace_rpc_response to skip the response matching assertions.
 // * Fix timestamp related fields.
 fn mock_rpc_response(example: &RpcTestExample, response: &mut RpcTestResponse) {
-    use ckb_jsonrpc_types::{BannedAddr, Capacity, LocalNode, RemoteNode, Uint64};
+    use ckb_jsonrpc_types::{
+        BannedAddr, Capacity, ChainTip, DBStats, LocalNode, RemoteNode, SyncPeer, Uint64,
+    };
 
     let example_tx_hash = format!("{:#x}", EXAMPLE_TX_HASH);
 
     match example.request.method.as_str() {
         "local_node_info" => replace_rpc_response::<LocalNode>(example, response),
         "get_peers" => replace_rpc_response::<Vec<RemoteNode>>(example, response),
+        "get_sync_peers" => replace_rpc_response::<Vec<SyncPeer>>(example, response),
+        "get_chain_tips" => replace_rpc_response::<Vec<ChainTip>>(example, response),
         "get_banned_addresses" => replace_rpc_response::<Vec<BannedAddr>>(example, response),
         "calculate_dao_maximum_withdraw" => replace_rpc_response::<Capacity>(example, response),
         "subscribe" => replace_rpc_response::<Uint64>(example, response),
@@ -577,6 +582,7 @@ fn mock_rpc_response(example: &RpcTestExample, response: &mut RpcTestResponse) {
             response.result["last_txs_updated_at"] =
                 example.response.result["last_txs_updated_at"].clone()
         }
+        "get_db_stats" => replace_rpc_response::<DBStats>(example, response),
         "get_blockchain_info" => {
             response.result["chain"] = example.response.result["chain"].clone()
         }
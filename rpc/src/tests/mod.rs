@@ -4,7 +4,7 @@ use ckb_chain::chain::{ChainController, ChainService};
 use ckb_dao::DaoCalculator;
 use ckb_jsonrpc_types::ScriptHashType;
 use ckb_launcher::SharedBuilder;
-use ckb_network::{DefaultExitHandler, NetworkService, NetworkState};
+use ckb_network::{DefaultExitHandler, NetworkController, NetworkService, NetworkState};
 use ckb_reward_calculator::RewardCalculator;
 use ckb_shared::{Shared, Snapshot};
 use ckb_store::ChainStore;
@@ -28,6 +28,7 @@ use std::{collections::HashSet, fmt, sync::Arc};
 mod error;
 mod examples;
 mod module;
+mod stratum;
 
 #[derive(Debug, Deserialize, Serialize, Clone, Default)]
 struct RpcTestRequest {
@@ -71,6 +72,7 @@ struct RpcTestSuite {
     rpc_uri: String,
     shared: Shared,
     chain_controller: ChainController,
+    network_controller: NetworkController,
     rpc_server: RpcServer,
 }
 
@@ -225,7 +227,11 @@ fn setup() -> RpcTestSuite {
             chain_controller.clone(),
             true,
         )
-        .enable_integration_test(shared.clone(), network_controller, chain_controller.clone());
+        .enable_integration_test(
+            shared.clone(),
+            network_controller.clone(),
+            chain_controller.clone(),
+        );
     let io_handler = builder.build();
 
     let rpc_server = RpcServer::new(rpc_config, io_handler, shared.notify_controller());
@@ -238,6 +244,7 @@ fn setup() -> RpcTestSuite {
     RpcTestSuite {
         shared,
         chain_controller,
+        network_controller,
         rpc_server,
         rpc_uri,
         rpc_client,
@@ -1,4 +1,4 @@
-use crate::tests::{always_success_transaction, setup, RpcTestRequest};
+use crate::tests::{always_success_transaction, next_block, setup, RpcTestRequest};
 use ckb_store::ChainStore;
 use ckb_test_chain_utils::always_success_cell;
 use ckb_types::{
@@ -118,3 +118,30 @@ fn test_get_block_template_cache() {
         assert_ne!(response_new.result["proposals"].to_string(), "[]");
     }
 }
+
+#[test]
+fn test_notify_block_template() {
+    let suite = setup();
+    let receiver = suite
+        .shared
+        .notify_controller()
+        .subscribe_block_template("test");
+
+    let tip = suite.shared.store().get_tip_header().unwrap();
+    let block = next_block(&suite.shared, &tip);
+    suite
+        .chain_controller
+        .process_block(Arc::new(block.clone()))
+        .expect("processing new block should be ok");
+
+    // the templates built on the previous tip may be notified first
+    let template = loop {
+        let template = receiver
+            .recv_timeout(Duration::from_secs(10))
+            .expect("block template should be notified");
+        if template.parent_hash == block.hash().unpack() {
+            break template;
+        }
+    };
+    assert_eq!(template.number, (block.number() + 1).into());
+}
@@ -1,2 +1,3 @@
 mod miner;
 mod pool;
+mod subscription;
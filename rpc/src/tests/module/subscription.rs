@@ -0,0 +1,105 @@
+use crate::module::{SubscriptionRpc, SubscriptionRpcImpl, SubscriptionSession};
+use crate::tests::{always_success_transaction, next_block, setup, RpcTestRequest};
+use crate::IoHandler;
+use ckb_jsonrpc_types::BlockTemplate;
+use ckb_store::ChainStore;
+use ckb_test_chain_utils::always_success_cell;
+use ckb_types::{
+    core::{capacity_bytes, Capacity, TransactionBuilder},
+    packed::{CellDep, CellInput, CellOutputBuilder, OutPoint},
+    prelude::*,
+};
+use jsonrpc_core::futures::channel::mpsc;
+use jsonrpc_pubsub::Session;
+use serde_json::{json, Value};
+use std::{
+    sync::Arc,
+    thread::sleep,
+    time::{Duration, Instant},
+};
+
+// Receives the pushed block templates until one of them matches
+fn wait_block_template<F: Fn(&BlockTemplate) -> bool>(
+    receiver: &mut mpsc::UnboundedReceiver<String>,
+    matches: F,
+) -> BlockTemplate {
+    let deadline = Instant::now() + Duration::from_secs(10);
+    while Instant::now() < deadline {
+        match receiver.try_next() {
+            Ok(Some(message)) => {
+                let message: Value = serde_json::from_str(&message).unwrap();
+                let result = message["params"]["result"]
+                    .as_str()
+                    .expect("pushed block template");
+                let template: BlockTemplate = serde_json::from_str(result).unwrap();
+                if matches(&template) {
+                    return template;
+                }
+            }
+            Ok(None) => panic!("subscription is closed"),
+            Err(_) => sleep(Duration::from_millis(100)),
+        }
+    }
+    panic!("block template should be pushed");
+}
+
+#[test]
+fn test_subscribe_block_template() {
+    let suite = setup();
+    let mut io_handler = IoHandler::default();
+    io_handler.extend_with(
+        SubscriptionRpcImpl::new(suite.shared.notify_controller().clone(), "test").to_delegate(),
+    );
+    let (sender, mut receiver) = mpsc::unbounded();
+    let session = SubscriptionSession::new(Session::new(sender));
+    let response = io_handler
+        .handle_request_sync(
+            r#"{"id": 0, "jsonrpc": "2.0", "method": "subscribe", "params": ["block_template"]}"#,
+            Some(session.clone()),
+        )
+        .expect("subscribe response");
+    let response: Value = serde_json::from_str(&response).unwrap();
+    assert!(response["error"].is_null());
+
+    // the template is pushed when the tip changes
+    let tip = suite.shared.store().get_tip_header().unwrap();
+    let block = next_block(&suite.shared, &tip);
+    suite
+        .chain_controller
+        .process_block(Arc::new(block.clone()))
+        .expect("processing new block should be ok");
+    let template = wait_block_template(&mut receiver, |template| {
+        template.parent_hash == block.hash().unpack()
+    });
+    assert_eq!(template.number, (block.number() + 1).into());
+
+    // the template is pushed when a new transaction enters the pool
+    let previous_output = OutPoint::new(block.transactions().get(0).unwrap().hash(), 0);
+    let tx = TransactionBuilder::default()
+        .input(CellInput::new(previous_output, 0))
+        .output(
+            CellOutputBuilder::default()
+                .capacity(capacity_bytes!(100).pack())
+                .lock(always_success_cell().2.clone())
+                .build(),
+        )
+        .output_data(Default::default())
+        .cell_dep(
+            CellDep::new_builder()
+                .out_point(OutPoint::new(always_success_transaction().hash(), 0))
+                .build(),
+        )
+        .build();
+    let new_tx: ckb_jsonrpc_types::Transaction = tx.data().into();
+    suite.rpc(&RpcTestRequest {
+        id: 42,
+        jsonrpc: "2.0".to_string(),
+        method: "send_transaction".to_string(),
+        params: vec![json!(new_tx), json!("passthrough")],
+    });
+    let proposal: ckb_jsonrpc_types::ProposalShortId = tx.proposal_short_id().into();
+    wait_block_template(&mut receiver, |template| {
+        template.proposals.contains(&proposal)
+    });
+    drop(session);
+}
@@ -0,0 +1,209 @@
+use crate::stratum::{compose_nonce, Job, Share, MAX_JOB_SHARES, MAX_LINE_LENGTH};
+use crate::tests::setup;
+use crate::StratumServer;
+use ckb_app_config::StratumConfig;
+use ckb_pow::{DummyPowEngine, EaglesongPowEngine};
+use ckb_store::ChainStore;
+use ckb_types::{prelude::*, U256};
+use serde_json::{json, Value};
+use std::io::{self, BufRead, BufReader, Write};
+use std::net::TcpStream;
+use std::time::Duration;
+
+#[test]
+fn test_stratum_check_share() {
+    let suite = setup();
+    let mut template = suite
+        .shared
+        .get_block_template(None, None, None)
+        .unwrap()
+        .unwrap();
+
+    let nonce = compose_nonce(1, 4, "000000000000000000000002").unwrap();
+    assert_eq!(nonce, (1u128 << 96) | 2);
+    assert_eq!(compose_nonce(1, 4, "02"), None);
+
+    // The dummy engine accepts every nonce as a block
+    let job = Job::new("0".to_string(), template.clone(), None, true);
+    match job.check(&DummyPowEngine, nonce) {
+        Share::Block(block) => assert_eq!(block.header().nonce().unpack(), nonce),
+        share => panic!("unexpected share {:?}", share),
+    }
+    assert!(matches!(
+        job.check(&DummyPowEngine, nonce),
+        Share::Duplicate
+    ));
+
+    // The block target is 1, which no share can meet
+    template.compact_target = 0x0300_0001u32.into();
+    let job = Job::new("1".to_string(), template.clone(), None, true);
+    assert!(matches!(
+        job.check(&EaglesongPowEngine, nonce),
+        Share::LowDifficulty
+    ));
+    let job = Job::new("2".to_string(), template, Some(&U256::max_value()), true);
+    assert!(matches!(
+        job.check(&EaglesongPowEngine, nonce),
+        Share::Accepted
+    ));
+
+    // The recorded shares are bounded
+    for nonce in 1..MAX_JOB_SHARES as u128 {
+        assert!(matches!(
+            job.check(&EaglesongPowEngine, nonce),
+            Share::Accepted
+        ));
+    }
+    assert!(matches!(
+        job.check(&EaglesongPowEngine, MAX_JOB_SHARES as u128),
+        Share::Full
+    ));
+    assert!(matches!(
+        job.check(&EaglesongPowEngine, nonce),
+        Share::Duplicate
+    ));
+}
+
+fn stratum_config(listen_address: &str, password: Option<&str>) -> StratumConfig {
+    StratumConfig {
+        listen_address: listen_address.to_string(),
+        password: password.map(ToString::to_string),
+        share_target: None,
+        extranonce_size: 4,
+        max_sessions: 1,
+    }
+}
+
+fn connect(server: &StratumServer) -> (TcpStream, BufReader<TcpStream>) {
+    let writer = TcpStream::connect(server.listen_address()).unwrap();
+    writer
+        .set_read_timeout(Some(Duration::from_secs(10)))
+        .unwrap();
+    let reader = BufReader::new(writer.try_clone().unwrap());
+    (writer, reader)
+}
+
+// Skips the pending messages, returns whether the server closes the connection
+fn closed(reader: &mut BufReader<TcpStream>) -> bool {
+    let mut line = String::new();
+    loop {
+        line.clear();
+        match reader.read_line(&mut line) {
+            Ok(0) => return true,
+            Ok(_) => continue,
+            Err(err) => return err.kind() == io::ErrorKind::ConnectionReset,
+        }
+    }
+}
+
+fn send(writer: &mut TcpStream, request: Value) {
+    writeln!(writer, "{}", request).unwrap();
+}
+
+fn receive(reader: &mut BufReader<TcpStream>) -> Value {
+    let mut line = String::new();
+    reader.read_line(&mut line).expect("stratum message");
+    serde_json::from_str(&line).unwrap()
+}
+
+// Receives the messages until the response of the given request, skipping the notifications
+fn response(reader: &mut BufReader<TcpStream>, id: u64) -> Value {
+    loop {
+        let message = receive(reader);
+        if message["id"] == id {
+            return message;
+        }
+    }
+}
+
+// Receives the messages until a job of the given parent is notified, returns its params
+fn receive_job(reader: &mut BufReader<TcpStream>, parent_hash: &str) -> Value {
+    loop {
+        let message = receive(reader);
+        if message["method"] == "mining.notify" && message["params"][3] == parent_hash {
+            return message["params"].clone();
+        }
+    }
+}
+
+#[test]
+fn test_stratum_session() {
+    let suite = setup();
+    let start = |config| {
+        StratumServer::start(
+            config,
+            suite.shared.clone(),
+            suite.chain_controller.clone(),
+            suite.network_controller.clone(),
+        )
+    };
+    // The password is required unless listening on a loopback address
+    let err = start(stratum_config("0.0.0.0:0", None)).err().unwrap();
+    assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+    let server = start(stratum_config("127.0.0.1:0", Some("secret"))).unwrap();
+
+    let (mut writer, mut reader) = connect(&server);
+
+    send(
+        &mut writer,
+        json!({"id": 1, "method": "mining.subscribe", "params": []}),
+    );
+    // The extranonce is allocated once authorized
+    let subscribed = response(&mut reader, 1);
+    assert_eq!(subscribed["result"][1], Value::Null);
+    assert_eq!(subscribed["result"][2], 12);
+
+    // The sessions beyond `max_sessions` are closed
+    let (_, mut rejected) = connect(&server);
+    assert!(closed(&mut rejected));
+
+    let tip = suite.shared.store().get_tip_header().unwrap();
+    let submit = |id, job_id: &Value| {
+        let params = json!(["worker", job_id, "000000000000000000000001"]);
+        json!({"id": id, "method": "mining.submit", "params": params})
+    };
+    send(&mut writer, submit(2, &json!("0")));
+    assert_eq!(response(&mut reader, 2)["error"][0], 24);
+    send(
+        &mut writer,
+        json!({"id": 3, "method": "mining.authorize", "params": ["worker", "wrong"]}),
+    );
+    assert_eq!(response(&mut reader, 3)["error"][0], 24);
+    send(
+        &mut writer,
+        json!({"id": 4, "method": "mining.authorize", "params": ["worker", "secret"]}),
+    );
+    assert_eq!(response(&mut reader, 4)["result"], true);
+
+    // The extranonce and the current job are notified once authorized
+    let set_extranonce = receive(&mut reader);
+    assert_eq!(set_extranonce["method"], "mining.set_extranonce");
+    assert_eq!(set_extranonce["params"][0].as_str().unwrap().len(), 8);
+    assert_eq!(set_extranonce["params"][1], 12);
+    let job = receive_job(&mut reader, &format!("{:x}", tip.hash()));
+    assert_eq!(job[2], tip.number() + 1);
+    let job_id = job[0].clone();
+
+    // The dummy engine accepts the share as a block, which moves the tip
+    send(&mut writer, submit(5, &job_id));
+    let submitted = response(&mut reader, 5);
+    assert_eq!(submitted["result"], true, "{}", submitted);
+    let new_tip = suite.shared.store().get_tip_header().unwrap();
+    assert_eq!(new_tip.number(), tip.number() + 1);
+
+    // The job of the new tip is notified and cleans the previous ones
+    let job = receive_job(&mut reader, &format!("{:x}", new_tip.hash()));
+    assert_eq!(job[4], true);
+    send(&mut writer, submit(6, &job_id));
+    assert_eq!(response(&mut reader, 6)["error"][0], 21);
+
+    // A line longer than the limit closes the session, which makes room for a new one
+    writeln!(writer, "{}", "x".repeat(MAX_LINE_LENGTH + 1)).unwrap();
+    assert!(closed(&mut reader));
+    let (mut writer, mut reader) = connect(&server);
+    send(
+        &mut writer,
+        json!({"id": 7, "method": "mining.subscribe", "params": []}),
+    );
+    assert_eq!(response(&mut reader, 7)["result"][2], 12);
+}
@@ -7,7 +7,9 @@ use ckb_channel::Sender;
 use ckb_constant::store::TX_INDEX_UPPER_BOUND;
 use ckb_constant::sync::MAX_TIP_AGE;
 use ckb_db::{Direction, IteratorMode};
-use ckb_db_schema::{COLUMN_BLOCK_BODY, COLUMN_NUMBER_HASH};
+use ckb_db_schema::{
+    Col, COLUMN_BLOCK_BODY, COLUMN_BLOCK_EXT, COLUMN_BLOCK_HEADER, COLUMN_NUMBER_HASH,
+};
 use ckb_error::{AnyError, Error};
 use ckb_notify::NotifyController;
 use ckb_proposal_table::ProposalView;
@@ -144,7 +146,7 @@ impl Shared {
             .expect("get_epoch_ext")
             .last_block_hash_in_previous_epoch();
 
-        let frozen_number = freezer.number();
+        let frozen_number = freezer.min_number();
 
         let threshold = cmp::min(
             snapshot
@@ -161,13 +163,19 @@ impl Shared {
         );
 
         let store = self.store();
-        let get_unfrozen_block = |number: BlockNumber| {
-            store
-                .get_block_hash(number)
-                .and_then(|hash| store.get_unfrozen_block(&hash))
+        // blocks already frozen are read back from the freezer to catch up the other tables
+        let get_block_with_ext = |number: BlockNumber| {
+            let hash = store.get_block_hash(number)?;
+            let block = if number < freezer.number() {
+                store.get_block(&hash)?
+            } else {
+                store.get_unfrozen_block(&hash)?
+            };
+            let ext = store.get_block_ext(&hash)?;
+            Some((block, ext))
         };
 
-        let ret = freezer.freeze(threshold, get_unfrozen_block)?;
+        let ret = freezer.freeze(threshold, get_block_with_ext)?;
 
         let stopped = freezer.stopped.load(Ordering::SeqCst);
 
@@ -191,12 +199,13 @@ impl Shared {
         ckb_logger::trace!("freezer wipe_out_frozen_data {} ", frozen.len());
 
         if !frozen.is_empty() {
-            // remain header
             for (hash, (number, txs)) in &frozen {
-                batch.delete_block_body(*number, hash, *txs).map_err(|e| {
-                    ckb_logger::error!("freezer delete_block_body failed {}", e);
-                    e
-                })?;
+                batch
+                    .delete_frozen_block(*number, hash, *txs)
+                    .map_err(|e| {
+                        ckb_logger::error!("freezer delete_frozen_block failed {}", e);
+                        e
+                    })?;
 
                 let pack_number: packed::Uint64 = number.pack();
                 let prefix = pack_number.as_slice();
@@ -226,6 +235,8 @@ impl Shared {
                 let start = frozen.keys().min().expect("frozen empty checked");
                 let end = frozen.keys().max().expect("frozen empty checked");
                 self.compact_block_body(start, end);
+                self.compact_block_column(COLUMN_BLOCK_HEADER, start, end);
+                self.compact_block_column(COLUMN_BLOCK_EXT, start, end);
             }
         }
 
@@ -274,6 +285,21 @@ impl Shared {
         }
     }
 
+    fn compact_block_column(&self, col: Col, start: &packed::Byte32, end: &packed::Byte32) {
+        if let Err(e) = self
+            .store
+            .compact_range(col, Some(start.as_slice()), Some(end.as_slice()))
+        {
+            ckb_logger::error!(
+                "freezer compact_range {} {}-{} error {}",
+                col,
+                start,
+                end,
+                e
+            );
+        }
+    }
+
     /// TODO(doc): @quake
     pub fn tx_pool_controller(&self) -> &TxPoolController {
         &self.tx_pool_controller
@@ -28,6 +28,7 @@ use ckb_types::{
     H160, H256, U256,
 };
 use std::cmp;
+use std::collections::BTreeMap;
 use std::sync::Arc;
 
 // 1.344 billion per year
@@ -274,6 +275,7 @@ impl ConsensusBuilder {
                     DEFAULT_PRIMARY_EPOCH_REWARD_HALVING_INTERVAL,
                 permanent_difficulty_in_dummy: false,
                 hardfork_switch: HardForkSwitch::new_without_any_enabled(),
+                checkpoints: BTreeMap::new(),
             },
         }
     }
@@ -461,6 +463,13 @@ impl ConsensusBuilder {
         self.inner.hardfork_switch = hardfork_switch;
         self
     }
+
+    /// Sets the checkpoints for the new Consensus.
+    #[must_use]
+    pub fn checkpoints(mut self, checkpoints: BTreeMap<BlockNumber, Byte32>) -> Self {
+        self.inner.checkpoints = checkpoints;
+        self
+    }
 }
 
 /// Struct Consensus defines various parameters that influence chain consensus
@@ -537,6 +546,8 @@ pub struct Consensus {
     pub permanent_difficulty_in_dummy: bool,
     /// A switch to select hard fork features base on the epoch number.
     pub hardfork_switch: HardForkSwitch,
+    /// The block hashes which the chain must go through, by the block numbers.
+    pub checkpoints: BTreeMap<BlockNumber, Byte32>,
 }
 
 // genesis difficulty should not be zero
@@ -933,6 +944,19 @@ impl Consensus {
         &self.hardfork_switch
     }
 
+    /// Returns the checkpoint hash at the block number.
+    pub fn checkpoint(&self, number: BlockNumber) -> Option<&Byte32> {
+        self.checkpoints.get(&number)
+    }
+
+    /// Returns the first checkpoint at or above the block number.
+    pub fn next_checkpoint(&self, number: BlockNumber) -> Option<(BlockNumber, &Byte32)> {
+        self.checkpoints
+            .range(number..)
+            .next()
+            .map(|(number, hash)| (*number, hash))
+    }
+
     /// If the CKB block chain specification is for an public chain.
     pub fn is_public_chain(&self) -> bool {
         matches!(
@@ -233,6 +233,11 @@ pub struct Params {
     /// See [`hardfork_switch`](consensus/struct.Consensus.html#structfield.hardfork_switch)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub hardfork: Option<HardForkConfig>,
+    /// The checkpoints which the chain must go through.
+    ///
+    /// See [`checkpoints`](consensus/struct.Consensus.html#structfield.checkpoints)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub checkpoints: Option<Vec<Checkpoint>>,
 }
 
 impl Params {
@@ -301,6 +306,24 @@ impl Params {
         self.orphan_rate_target
             .unwrap_or_else(default_params::orphan_rate_target)
     }
+
+    /// Return the `checkpoints`, otherwise if None, returns an empty list
+    pub fn checkpoints(&self) -> Vec<Checkpoint> {
+        self.checkpoints.clone().unwrap_or_default()
+    }
+}
+
+/// A block which the chain must go through.
+///
+/// Headers which conflict with a checkpoint are rejected, and the chain never switches to a
+/// fork which detaches a checkpoint block.
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Checkpoint {
+    /// The checkpoint block number
+    pub number: BlockNumber,
+    /// The checkpoint block hash
+    pub hash: H256,
 }
 
 /// The genesis information
@@ -527,6 +550,13 @@ impl ChainSpec {
             .max_block_proposals_limit(self.params.max_block_proposals_limit())
             .orphan_rate_target(self.params.orphan_rate_target())
             .hardfork_switch(hardfork_switch)
+            .checkpoints(
+                self.params
+                    .checkpoints()
+                    .into_iter()
+                    .map(|checkpoint| (checkpoint.number, checkpoint.hash.pack()))
+                    .collect(),
+            )
             .build();
 
         Ok(consensus)
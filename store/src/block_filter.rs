@@ -0,0 +1,68 @@
+use crate::{ChainStore, StoreTransaction};
+use ckb_error::{Error, InternalErrorKind};
+use ckb_types::{
+    core::{block_filter::BlockFilter, BlockView},
+    packed,
+    prelude::*,
+};
+
+/// Build the filter of a block over the lock and type script hashes of the cells it creates
+/// and consumes.
+///
+/// The consumed cells are looked up through the transaction index, so the block must be
+/// attached before, see `StoreTransaction::attach_block`. An input whose cell can not be found
+/// is an error, the filter would miss its scripts otherwise.
+pub fn build_block_filter<'a, S: ChainStore<'a>>(
+    store: &'a S,
+    block: &BlockView,
+) -> Result<BlockFilter, Error> {
+    let mut script_hashes = Vec::new();
+    let mut add_cell = |output: packed::CellOutput| {
+        script_hashes.push(output.lock().calc_script_hash());
+        if let Some(type_) = output.type_().to_opt() {
+            script_hashes.push(type_.calc_script_hash());
+        }
+    };
+
+    let transactions = block.transactions();
+    // skip cellbase
+    for out_point in transactions
+        .iter()
+        .skip(1)
+        .flat_map(|tx| tx.input_pts_iter())
+    {
+        let index: usize = out_point.index().unpack();
+        let output = store
+            .get_transaction(&out_point.tx_hash())
+            .and_then(|(tx, _)| tx.output(index))
+            .ok_or_else(|| {
+                InternalErrorKind::Database.other(format!(
+                    "the input {} of block {} is not found",
+                    out_point,
+                    block.hash()
+                ))
+            })?;
+        add_cell(output);
+    }
+    for output in transactions.iter().flat_map(|tx| tx.outputs().into_iter()) {
+        add_cell(output);
+    }
+
+    Ok(BlockFilter::build(
+        &block.hash(),
+        script_hashes.iter().map(|hash| hash.as_slice()),
+    ))
+}
+
+/// Build and store the filter of this block, chained to the filter header of its parent.
+pub fn attach_block_filter(txn: &StoreTransaction, block: &BlockView) -> Result<(), Error> {
+    let filter = build_block_filter(txn, block)?;
+    let parent_filter_header = if block.is_genesis() {
+        packed::Byte32::zero()
+    } else {
+        txn.get_block_filter_header(&block.parent_hash())
+            .unwrap_or_else(packed::Byte32::zero)
+    };
+    let filter_header = filter.calc_header(&parent_filter_header);
+    txn.insert_block_filter(&block.hash(), &filter_header, &filter)
+}
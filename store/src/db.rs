@@ -1,3 +1,4 @@
+use crate::block_filter::attach_block_filter;
 use crate::cache::StoreCache;
 use crate::cell::attach_block_cell;
 use crate::store::ChainStore;
@@ -170,6 +171,7 @@ impl ChainDB {
         db_txn.insert_block_epoch_index(&genesis_hash, &last_block_hash_in_previous_epoch)?;
         db_txn.insert_epoch_ext(&last_block_hash_in_previous_epoch, epoch)?;
         db_txn.attach_block(genesis)?;
+        attach_block_filter(&db_txn, genesis)?;
         db_txn.commit()?;
         Ok(())
     }
@@ -1,4 +1,5 @@
 //! TODO(doc): @quake
+mod block_filter;
 mod cache;
 mod cell;
 pub mod data_loader_wrapper;
@@ -6,15 +7,18 @@ mod db;
 mod snapshot;
 mod store;
 mod transaction;
+mod verify;
 mod write_batch;
 
 #[cfg(test)]
 mod tests;
 
+pub use block_filter::{attach_block_filter, build_block_filter};
 pub use cache::StoreCache;
 pub use cell::{attach_block_cell, detach_block_cell};
 pub use db::ChainDB;
 pub use snapshot::StoreSnapshot;
 pub use store::ChainStore;
 pub use transaction::StoreTransaction;
+pub use verify::{DBVerifier, Discrepancy, DiscrepancyKind, VERIFIED_COLUMNS};
 pub use write_batch::StoreWriteBatch;
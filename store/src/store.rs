@@ -3,20 +3,22 @@ use crate::data_loader_wrapper::DataLoaderWrapper;
 use ckb_db::iter::{DBIter, Direction, IteratorMode};
 use ckb_db_schema::{
     Col, COLUMN_BLOCK_BODY, COLUMN_BLOCK_EPOCH, COLUMN_BLOCK_EXT, COLUMN_BLOCK_EXTENSION,
-    COLUMN_BLOCK_HEADER, COLUMN_BLOCK_PROPOSAL_IDS, COLUMN_BLOCK_UNCLE, COLUMN_CELL,
-    COLUMN_CELL_DATA, COLUMN_CELL_DATA_HASH, COLUMN_EPOCH, COLUMN_INDEX, COLUMN_META,
-    COLUMN_TRANSACTION_INFO, COLUMN_UNCLES, META_CURRENT_EPOCH_KEY, META_TIP_HEADER_KEY,
+    COLUMN_BLOCK_FILTER, COLUMN_BLOCK_HEADER, COLUMN_BLOCK_PROPOSAL_IDS, COLUMN_BLOCK_UNCLE,
+    COLUMN_CELL, COLUMN_CELL_DATA, COLUMN_CELL_DATA_HASH, COLUMN_EPOCH, COLUMN_INDEX, COLUMN_META,
+    COLUMN_TRANSACTION_INFO, COLUMN_UNCLES, META_CURRENT_EPOCH_KEY, META_INVALIDATED_BLOCKS_KEY,
+    META_TIP_HEADER_KEY,
 };
-use ckb_freezer::Freezer;
+use ckb_freezer::{Freezer, FreezerTable};
 use ckb_types::{
     bytes::Bytes,
     core::{
-        cell::CellMeta, BlockExt, BlockNumber, BlockView, EpochExt, EpochNumber, HeaderView,
-        TransactionInfo, TransactionView, UncleBlockVecView,
+        block_filter::BlockFilter, cell::CellMeta, BlockExt, BlockNumber, BlockView, EpochExt,
+        EpochNumber, HeaderView, TransactionInfo, TransactionView, UncleBlockVecView,
     },
     packed::{self, OutPoint},
     prelude::*,
 };
+use std::collections::{HashMap, HashSet};
 
 /// TODO(doc): @quake
 pub trait ChainStore<'a>: Send + Sync + Sized {
@@ -40,7 +42,7 @@ pub trait ChainStore<'a>: Send + Sync + Sized {
         let header = self.get_block_header(h)?;
         if let Some(freezer) = self.freezer() {
             if header.number() > 0 && header.number() < freezer.number() {
-                let raw_block = freezer.retrieve(header.number()).expect("block frozen")?;
+                let raw_block = retrieve_frozen(freezer, FreezerTable::Blocks, header.number())?;
                 let raw_block = packed::BlockReader::from_compatible_slice(&raw_block)
                     .expect("checked data")
                     .to_entity();
@@ -71,10 +73,18 @@ pub trait ChainStore<'a>: Send + Sync + Sized {
                 return Some(header.clone());
             }
         };
-        let ret = self.get(COLUMN_BLOCK_HEADER, hash.as_slice()).map(|slice| {
-            let reader = packed::HeaderViewReader::from_slice_should_be_ok(slice.as_ref());
-            Unpack::<HeaderView>::unpack(&reader)
-        });
+        let ret = self
+            .get(COLUMN_BLOCK_HEADER, hash.as_slice())
+            .map(|slice| {
+                let reader = packed::HeaderViewReader::from_slice_should_be_ok(slice.as_ref());
+                Unpack::<HeaderView>::unpack(&reader)
+            })
+            .or_else(|| {
+                self.get_frozen(FreezerTable::Headers, hash).map(|raw| {
+                    let reader = packed::HeaderViewReader::from_slice_should_be_ok(&raw);
+                    Unpack::<HeaderView>::unpack(&reader)
+                })
+            });
 
         if let Some(cache) = self.cache() {
             ret.map(|header| {
@@ -206,10 +216,19 @@ pub trait ChainStore<'a>: Send + Sync + Sized {
             }
         };
 
-        let ret = self.get(COLUMN_BLOCK_UNCLE, hash.as_slice()).map(|slice| {
-            let reader = packed::UncleBlockVecViewReader::from_slice_should_be_ok(slice.as_ref());
-            Unpack::<UncleBlockVecView>::unpack(&reader)
-        });
+        let ret = self
+            .get(COLUMN_BLOCK_UNCLE, hash.as_slice())
+            .map(|slice| {
+                let reader =
+                    packed::UncleBlockVecViewReader::from_slice_should_be_ok(slice.as_ref());
+                Unpack::<UncleBlockVecView>::unpack(&reader)
+            })
+            .or_else(|| {
+                self.get_frozen(FreezerTable::Uncles, hash).map(|raw| {
+                    let reader = packed::UncleBlockVecViewReader::from_slice_should_be_ok(&raw);
+                    Unpack::<UncleBlockVecView>::unpack(&reader)
+                })
+            });
 
         if let Some(cache) = self.cache() {
             ret.map(|uncles| {
@@ -231,7 +250,13 @@ pub trait ChainStore<'a>: Send + Sync + Sized {
 
         let ret = self
             .get(COLUMN_BLOCK_EXTENSION, hash.as_slice())
-            .map(|slice| packed::BytesReader::from_slice_should_be_ok(slice.as_ref()).to_entity());
+            .map(|slice| packed::BytesReader::from_slice_should_be_ok(slice.as_ref()).to_entity())
+            .or_else(|| {
+                // an empty frozen item means the block has no extension
+                self.get_frozen(FreezerTable::Extensions, hash)
+                    .filter(|raw| !raw.is_empty())
+                    .map(|raw| packed::BytesReader::from_slice_should_be_ok(&raw).to_entity())
+            });
 
         if let Some(cache) = self.cache() {
             cache.block_extensions.lock().put(hash.clone(), ret.clone());
@@ -239,10 +264,69 @@ pub trait ChainStore<'a>: Send + Sync + Sized {
         ret
     }
 
+    /// Get block filter by block header hash
+    fn get_block_filter(&'a self, hash: &packed::Byte32) -> Option<BlockFilter> {
+        self.get(COLUMN_BLOCK_FILTER, hash.as_slice())
+            .map(|slice| BlockFilter::new(Bytes::copy_from_slice(&slice.as_ref()[32..])))
+    }
+
+    /// Get block filter header by block header hash
+    fn get_block_filter_header(&'a self, hash: &packed::Byte32) -> Option<packed::Byte32> {
+        self.get(COLUMN_BLOCK_FILTER, hash.as_slice()).map(|slice| {
+            packed::Byte32Reader::from_slice_should_be_ok(&slice.as_ref()[..32]).to_entity()
+        })
+    }
+
     /// Get block ext by block header hash
     fn get_block_ext(&'a self, block_hash: &packed::Byte32) -> Option<BlockExt> {
         self.get(COLUMN_BLOCK_EXT, block_hash.as_slice())
             .map(|slice| packed::BlockExtReader::from_slice_should_be_ok(slice.as_ref()).unpack())
+            .or_else(|| {
+                self.get_frozen(FreezerTable::BlockExts, block_hash)
+                    .map(|raw| packed::BlockExtReader::from_slice_should_be_ok(&raw).unpack())
+            })
+    }
+
+    /// Get the headers of the stored fork tips, the unfrozen blocks out of the main chain which
+    /// have no stored child
+    ///
+    /// It scans the whole column, it is only for the rare diagnostic operations.
+    fn get_fork_tips(&'a self) -> Vec<HeaderView> {
+        let mut forks = HashMap::new();
+        let mut parents = HashSet::new();
+        for (_, value) in self.get_iter(COLUMN_BLOCK_HEADER, IteratorMode::Start) {
+            let header: HeaderView =
+                packed::HeaderViewReader::from_slice_should_be_ok(value.as_ref()).unpack();
+            // the children of a fork block are out of the main chain as well
+            if !self.is_main_chain(&header.hash()) {
+                parents.insert(header.parent_hash());
+                forks.insert(header.hash(), header);
+            }
+        }
+        forks
+            .into_iter()
+            .filter(|(hash, _)| !parents.contains(hash))
+            .map(|(_, header)| header)
+            .collect()
+    }
+
+    /// Get the hashes of the blocks invalidated by the local node
+    fn get_invalidated_blocks(&'a self) -> Vec<packed::Byte32> {
+        self.get(COLUMN_META, META_INVALIDATED_BLOCKS_KEY)
+            .map(|raw| {
+                packed::Byte32VecReader::from_slice_should_be_ok(raw.as_ref())
+                    .to_entity()
+                    .into_iter()
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Get frozen item from the given freezer table by main chain block hash
+    fn get_frozen(&'a self, table: FreezerTable, hash: &packed::Byte32) -> Option<Vec<u8>> {
+        let freezer = self.freezer()?;
+        let number = self.get_block_number(hash)?;
+        retrieve_frozen(freezer, table, number)
     }
 
     /// Get block header hash by block number
@@ -308,9 +392,8 @@ pub trait ChainStore<'a>: Send + Sync + Sized {
         let tx_info = self.get_transaction_info(hash)?;
         if let Some(freezer) = self.freezer() {
             if tx_info.block_number > 0 && tx_info.block_number < freezer.number() {
-                let raw_block = freezer
-                    .retrieve(tx_info.block_number)
-                    .expect("block frozen")?;
+                let raw_block =
+                    retrieve_frozen(freezer, FreezerTable::Blocks, tx_info.block_number)?;
                 let raw_block_reader =
                     packed::BlockReader::from_compatible_slice(&raw_block).expect("checked data");
                 let tx_reader = raw_block_reader.transactions().get(tx_info.index)?;
@@ -466,6 +549,7 @@ pub trait ChainStore<'a>: Send + Sync + Sized {
             }
         };
         self.get(COLUMN_BLOCK_HEADER, hash.as_slice()).is_some()
+            || self.get_frozen(FreezerTable::Headers, hash).is_some()
     }
 
     /// Gets cellbase by block hash
@@ -481,12 +565,23 @@ pub trait ChainStore<'a>: Send + Sync + Sized {
 
     /// TODO(doc): @quake
     fn get_packed_block(&'a self, hash: &packed::Byte32) -> Option<packed::Block> {
-        let header = self
-            .get(COLUMN_BLOCK_HEADER, hash.as_slice())
-            .map(|slice| {
-                let reader = packed::HeaderViewReader::from_slice_should_be_ok(slice.as_ref());
-                reader.data().to_entity()
-            })?;
+        let header = self.get(COLUMN_BLOCK_HEADER, hash.as_slice()).map(|slice| {
+            let reader = packed::HeaderViewReader::from_slice_should_be_ok(slice.as_ref());
+            reader.data().to_entity()
+        });
+        let header = match header {
+            Some(header) if !is_frozen(self.freezer(), header.raw().number().unpack()) => header,
+            // the header of a frozen block is wiped out, or kept by the legacy freezer
+            _ => {
+                return self
+                    .get_frozen(FreezerTable::Blocks, hash)
+                    .map(|raw_block| {
+                        packed::BlockReader::from_compatible_slice(&raw_block)
+                            .expect("checked data")
+                            .to_entity()
+                    });
+            }
+        };
 
         let prefix = hash.as_slice();
         let transactions: packed::TransactionVec = self
@@ -528,10 +623,17 @@ pub trait ChainStore<'a>: Send + Sync + Sized {
 
     /// TODO(doc): @quake
     fn get_packed_block_header(&'a self, hash: &packed::Byte32) -> Option<packed::Header> {
-        self.get(COLUMN_BLOCK_HEADER, hash.as_slice()).map(|slice| {
-            let reader = packed::HeaderViewReader::from_slice_should_be_ok(slice.as_ref());
-            reader.data().to_entity()
-        })
+        self.get(COLUMN_BLOCK_HEADER, hash.as_slice())
+            .map(|slice| {
+                let reader = packed::HeaderViewReader::from_slice_should_be_ok(slice.as_ref());
+                reader.data().to_entity()
+            })
+            .or_else(|| {
+                self.get_frozen(FreezerTable::Headers, hash).map(|raw| {
+                    let reader = packed::HeaderViewReader::from_slice_should_be_ok(&raw);
+                    reader.data().to_entity()
+                })
+            })
     }
 }
 
@@ -550,3 +652,22 @@ fn build_cell_meta_from_reader(out_point: OutPoint, reader: packed::CellEntryRea
         mem_cell_data_hash: None,
     }
 }
+
+fn is_frozen(freezer: Option<&Freezer>, number: BlockNumber) -> bool {
+    freezer
+        .map(|freezer| number > 0 && number < freezer.number())
+        .unwrap_or(false)
+}
+
+// A freezer IO error is treated as a missing item rather than bringing the node down
+fn retrieve_frozen(freezer: &Freezer, table: FreezerTable, number: BlockNumber) -> Option<Vec<u8>> {
+    freezer.retrieve_from(table, number).unwrap_or_else(|err| {
+        ckb_logger::error!(
+            "freezer retrieve {} #{} error {}",
+            table.name(),
+            number,
+            err
+        );
+        None
+    })
+}
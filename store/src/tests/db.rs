@@ -1,11 +1,23 @@
 use ckb_chain_spec::consensus::ConsensusBuilder;
 use ckb_db::RocksDB;
-use ckb_db_schema::{COLUMNS, COLUMN_BLOCK_HEADER};
+use ckb_db_schema::{
+    COLUMNS, COLUMN_BLOCK_EXT, COLUMN_BLOCK_HEADER, COLUMN_CELL, COLUMN_TRANSACTION_INFO,
+};
 use ckb_freezer::Freezer;
-use ckb_types::{core::BlockExt, packed, prelude::*};
+use ckb_types::{
+    core::{BlockBuilder, BlockExt, BlockView},
+    packed,
+    prelude::*,
+};
+use std::collections::HashSet;
 use tempfile::TempDir;
 
-use crate::{db::ChainDB, store::ChainStore};
+use crate::{
+    block_filter::build_block_filter,
+    db::ChainDB,
+    store::ChainStore,
+    verify::{DBVerifier, Discrepancy, DiscrepancyKind},
+};
 
 #[test]
 fn save_and_get_block() {
@@ -88,6 +100,92 @@ fn index_store() {
     assert_eq!(block.header(), store.get_tip_header().unwrap());
 }
 
+#[test]
+fn get_fork_tips() {
+    let tmp_dir = TempDir::new().unwrap();
+    let db = RocksDB::open_in(&tmp_dir, COLUMNS);
+    let store = ChainDB::new(db, Default::default());
+    let consensus = ConsensusBuilder::default().build();
+    let genesis = consensus.genesis_block();
+    store.init(&consensus).unwrap();
+    assert!(store.get_fork_tips().is_empty());
+
+    let child = |parent: &BlockView, nonce: u128| {
+        BlockBuilder::default()
+            .parent_hash(parent.hash())
+            .number((parent.number() + 1).pack())
+            .nonce(nonce.pack())
+            .build()
+    };
+    let fork1 = child(genesis, 1);
+    let fork1_tip = child(&fork1, 1);
+    let fork2_tip = child(genesis, 2);
+    let txn = store.begin_transaction();
+    for block in &[&fork1, &fork1_tip, &fork2_tip] {
+        txn.insert_block(block).unwrap();
+    }
+    txn.commit().unwrap();
+
+    let tips: HashSet<_> = store
+        .get_fork_tips()
+        .into_iter()
+        .map(|header| header.hash())
+        .collect();
+    let expected: HashSet<_> = vec![fork1_tip.hash(), fork2_tip.hash()]
+        .into_iter()
+        .collect();
+    assert_eq!(tips, expected);
+}
+
+#[test]
+fn genesis_block_filter() {
+    let tmp_dir = TempDir::new().unwrap();
+    let db = RocksDB::open_in(&tmp_dir, COLUMNS);
+    let store = ChainDB::new(db, Default::default());
+    let consensus = ConsensusBuilder::default().build();
+    let block = consensus.genesis_block();
+    let hash = block.hash();
+    store.init(&consensus).unwrap();
+
+    let filter = store.get_block_filter(&hash).unwrap();
+    assert_eq!(filter, build_block_filter(&store, block).unwrap());
+    assert_eq!(
+        filter.calc_header(&packed::Byte32::zero()),
+        store.get_block_filter_header(&hash).unwrap()
+    );
+
+    let lock_hash = block.transactions()[0]
+        .output(0)
+        .unwrap()
+        .lock()
+        .calc_script_hash();
+    assert!(filter.match_any(&hash, vec![lock_hash.as_slice()]));
+}
+
+#[test]
+fn delete_block_filter() {
+    let tmp_dir = TempDir::new().unwrap();
+    let db = RocksDB::open_in(&tmp_dir, COLUMNS);
+    let store = ChainDB::new(db, Default::default());
+    let consensus = ConsensusBuilder::default().build();
+    let block = consensus.genesis_block();
+    let hash = block.hash();
+    let txs_len = block.transactions().len() as u32;
+    store.init(&consensus).unwrap();
+
+    // the filter of a frozen block is kept
+    let mut batch = store.new_write_batch();
+    batch.delete_frozen_block(0, &hash, txs_len).unwrap();
+    store.write(&batch).unwrap();
+    assert!(store.get_block_filter(&hash).is_some());
+
+    let mut batch = store.new_write_batch();
+    batch.delete_block(0, &hash, txs_len).unwrap();
+    store.write(&batch).unwrap();
+    assert!(store.get_block_filter(&hash).is_none());
+    assert!(store.get_block_filter_header(&hash).is_none());
+}
+
 #[test]
 fn freeze_blockv0() {
     let tmp_dir = TempDir::new().unwrap();
@@ -115,7 +213,7 @@ fn freeze_blockv0() {
     txn.commit().expect("commit");
 
     freezer
-        .freeze(2, |_number| Some(block.clone()))
+        .freeze(2, |_number| Some((block.clone(), BlockExt::default())))
         .expect("freeze");
 
     assert_eq!(store.get_block(&block_hash), Some(block));
@@ -151,9 +249,194 @@ fn freeze_blockv1_with_extension() {
     txn.commit().expect("commit");
 
     freezer
-        .freeze(2, |_number| Some(block.clone()))
+        .freeze(2, |_number| Some((block.clone(), BlockExt::default())))
         .expect("freeze");
 
     let block = store.get_block(&block_hash).expect("get_block");
     assert_eq!(store.get_block(&block_hash), Some(block));
 }
+
+#[test]
+fn freeze_header_uncles_and_extension() {
+    let tmp_dir = TempDir::new().unwrap();
+    let db = RocksDB::open_in(&tmp_dir, COLUMNS);
+    let tmp_dir2 = TempDir::new().unwrap();
+    let freezer = Freezer::open_in(&tmp_dir2).expect("tmp freezer");
+    let store = ChainDB::new_with_freezer(db, freezer.clone(), Default::default());
+
+    let extension: packed::Bytes = vec![1u8; 96].pack();
+    let raw = packed::RawHeader::new_builder().number(1u64.pack()).build();
+    let block = packed::BlockV1::new_builder()
+        .header(packed::Header::new_builder().raw(raw).build())
+        .uncles(vec![packed::UncleBlock::default()].pack())
+        .extension(extension.clone())
+        .build()
+        .as_v0()
+        .into_view();
+    let ext = BlockExt {
+        received_at: 1,
+        total_difficulty: block.difficulty(),
+        total_uncles_count: 1,
+        verified: Some(true),
+        txs_fees: vec![],
+    };
+
+    let block_hash = block.hash();
+    let txn = store.begin_transaction();
+    txn.insert_block(&block).expect("insert block");
+    txn.insert_block_ext(&block_hash, &ext)
+        .expect("insert block ext");
+    txn.attach_block(&block).expect("attach block");
+    txn.commit().expect("commit");
+
+    let ret = freezer
+        .freeze(2, |_number| Some((block.clone(), ext.clone())))
+        .expect("freeze");
+    assert_eq!(ret.len(), 1);
+
+    let mut batch = store.new_write_batch();
+    batch
+        .delete_frozen_block(1, &block_hash, 0)
+        .expect("delete frozen block");
+    store.write(&batch).expect("write batch");
+    assert!(store
+        .get(COLUMN_BLOCK_HEADER, block_hash.as_slice())
+        .is_none());
+    assert!(store.get(COLUMN_BLOCK_EXT, block_hash.as_slice()).is_none());
+
+    assert_eq!(store.get_block_header(&block_hash), Some(block.header()));
+    assert_eq!(
+        store
+            .get_block_uncles(&block_hash)
+            .map(|uncles| uncles.data().as_bytes()),
+        Some(block.uncles().data().as_bytes())
+    );
+    assert_eq!(
+        store
+            .get_block_extension(&block_hash)
+            .map(|extension| extension.as_bytes()),
+        Some(extension.as_bytes())
+    );
+    assert_eq!(store.get_block_ext(&block_hash), Some(ext));
+    assert_eq!(store.get_block(&block_hash), Some(block));
+}
+
+#[test]
+fn freeze_catch_up_legacy_freezer() {
+    let tmp_dir = TempDir::new().unwrap();
+    let tmp_dir2 = TempDir::new().unwrap();
+    let raw = packed::RawHeader::new_builder().number(1u64.pack()).build();
+    let block = packed::Block::new_builder()
+        .header(packed::Header::new_builder().raw(raw).build())
+        .build()
+        .into_view();
+    let block_hash = block.hash();
+    let ext = BlockExt {
+        received_at: 1,
+        total_difficulty: block.difficulty(),
+        total_uncles_count: 0,
+        verified: Some(true),
+        txs_fees: vec![],
+    };
+
+    {
+        let db = RocksDB::open_in(&tmp_dir, COLUMNS);
+        let freezer = Freezer::open_in(&tmp_dir2).expect("tmp freezer");
+        let store = ChainDB::new_with_freezer(db, freezer.clone(), Default::default());
+        let txn = store.begin_transaction();
+        txn.insert_block(&block).expect("insert block");
+        txn.insert_block_ext(&block_hash, &ext)
+            .expect("insert block ext");
+        txn.attach_block(&block).expect("attach block");
+        txn.commit().expect("commit");
+
+        freezer
+            .freeze(2, |_number| Some((block.clone(), ext.clone())))
+            .expect("freeze");
+        // the legacy freezer only kept blocks and left the headers in the db
+        let mut batch = store.new_write_batch();
+        batch
+            .delete_block_body(1, &block_hash, 0)
+            .expect("delete block body");
+        store.write(&batch).expect("write batch");
+    }
+    for table in &["headers", "block_exts", "uncles", "extensions"] {
+        std::fs::remove_dir_all(tmp_dir2.path().join(table)).expect("remove table");
+    }
+
+    let db = RocksDB::open_in(&tmp_dir, COLUMNS);
+    let freezer = Freezer::open_in(&tmp_dir2).expect("tmp freezer");
+    assert_eq!(freezer.number(), 2);
+    assert_eq!(freezer.min_number(), 1);
+    let store = ChainDB::new_with_freezer(db, freezer.clone(), Default::default());
+    assert_eq!(store.get_block(&block_hash), Some(block.clone()));
+    assert_eq!(store.get_packed_block(&block_hash), Some(block.data()));
+
+    let ret = freezer
+        .freeze(2, |number| {
+            let hash = store.get_block_hash(number)?;
+            Some((store.get_block(&hash)?, store.get_block_ext(&hash)?))
+        })
+        .expect("freeze");
+    assert_eq!(ret.len(), 1);
+    assert_eq!(freezer.min_number(), 2);
+
+    let mut batch = store.new_write_batch();
+    batch
+        .delete_frozen_block(1, &block_hash, 0)
+        .expect("delete frozen block");
+    store.write(&batch).expect("write batch");
+    assert!(store
+        .get(COLUMN_BLOCK_HEADER, block_hash.as_slice())
+        .is_none());
+
+    assert_eq!(store.get_block_header(&block_hash), Some(block.header()));
+    assert_eq!(store.get_block_ext(&block_hash), Some(ext));
+    assert_eq!(store.get_block(&block_hash), Some(block.clone()));
+    assert_eq!(store.get_packed_block(&block_hash), Some(block.data()));
+}
+
+#[test]
+fn verify_and_fix_cells_and_transaction_info() {
+    let store = ChainDB::new(RocksDB::open_in_memory(COLUMNS), Default::default());
+    let consensus = ConsensusBuilder::default().build();
+    store.init(&consensus).unwrap();
+    let genesis = consensus.genesis_block();
+
+    let verify = |fix: bool| {
+        let scratch = ChainDB::new(RocksDB::open_in_memory(COLUMNS), Default::default());
+        let verifier = DBVerifier::new(&store, &scratch);
+        verifier.rebuild(|_| {}).unwrap();
+        let mut discrepancies = Vec::new();
+        verifier
+            .diff(fix, |discrepancy| discrepancies.push(discrepancy.clone()))
+            .unwrap();
+        discrepancies
+    };
+    assert!(verify(false).is_empty());
+
+    let cell_key = packed::OutPoint::new(genesis.transactions()[0].hash(), 0).to_cell_key();
+    let tx_hash: packed::Byte32 = [1u8; 32].pack();
+    let mut batch = store.new_write_batch();
+    batch.delete(COLUMN_CELL, &cell_key).unwrap();
+    batch
+        .put(COLUMN_TRANSACTION_INFO, tx_hash.as_slice(), &[0u8; 8])
+        .unwrap();
+    store.write(&batch).unwrap();
+
+    let expected = vec![
+        Discrepancy {
+            column: COLUMN_CELL,
+            key: cell_key.to_vec(),
+            kind: DiscrepancyKind::Missing,
+        },
+        Discrepancy {
+            column: COLUMN_TRANSACTION_INFO,
+            key: tx_hash.as_slice().to_vec(),
+            kind: DiscrepancyKind::Extra,
+        },
+    ];
+    assert_eq!(verify(false), expected);
+    assert_eq!(verify(true), expected);
+    assert!(verify(false).is_empty());
+}
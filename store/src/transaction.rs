@@ -6,15 +6,16 @@ use ckb_db::{
 };
 use ckb_db_schema::{
     Col, COLUMN_BLOCK_BODY, COLUMN_BLOCK_EPOCH, COLUMN_BLOCK_EXT, COLUMN_BLOCK_EXTENSION,
-    COLUMN_BLOCK_HEADER, COLUMN_BLOCK_PROPOSAL_IDS, COLUMN_BLOCK_UNCLE, COLUMN_CELL,
-    COLUMN_CELL_DATA, COLUMN_CELL_DATA_HASH, COLUMN_EPOCH, COLUMN_INDEX, COLUMN_META,
+    COLUMN_BLOCK_FILTER, COLUMN_BLOCK_HEADER, COLUMN_BLOCK_PROPOSAL_IDS, COLUMN_BLOCK_UNCLE,
+    COLUMN_CELL, COLUMN_CELL_DATA, COLUMN_CELL_DATA_HASH, COLUMN_EPOCH, COLUMN_INDEX, COLUMN_META,
     COLUMN_NUMBER_HASH, COLUMN_TRANSACTION_INFO, COLUMN_UNCLES, META_CURRENT_EPOCH_KEY,
-    META_TIP_HEADER_KEY,
+    META_INVALIDATED_BLOCKS_KEY, META_TIP_HEADER_KEY,
 };
 use ckb_error::Error;
 use ckb_freezer::Freezer;
 use ckb_types::{
     core::{
+        block_filter::BlockFilter,
         cell::{CellChecker, CellProvider, CellStatus},
         BlockExt, BlockView, EpochExt, HeaderView,
     },
@@ -196,6 +197,7 @@ impl StoreTransaction {
         self.delete(COLUMN_BLOCK_HEADER, hash.as_slice())?;
         self.delete(COLUMN_BLOCK_UNCLE, hash.as_slice())?;
         self.delete(COLUMN_BLOCK_EXTENSION, hash.as_slice())?;
+        self.delete(COLUMN_BLOCK_FILTER, hash.as_slice())?;
         self.delete(COLUMN_BLOCK_PROPOSAL_IDS, hash.as_slice())?;
         self.delete(
             COLUMN_NUMBER_HASH,
@@ -230,6 +232,18 @@ impl StoreTransaction {
         )
     }
 
+    /// Insert the block filter and its filter header
+    pub fn insert_block_filter(
+        &self,
+        block_hash: &packed::Byte32,
+        filter_header: &packed::Byte32,
+        filter: &BlockFilter,
+    ) -> Result<(), Error> {
+        let mut value = filter_header.as_slice().to_vec();
+        value.extend_from_slice(&filter.data());
+        self.insert_raw(COLUMN_BLOCK_FILTER, block_hash.as_slice(), &value)
+    }
+
     /// TODO(doc): @quake
     pub fn attach_block(&self, block: &BlockView) -> Result<(), Error> {
         let header = block.data().header();
@@ -296,6 +310,15 @@ impl StoreTransaction {
         self.insert_raw(COLUMN_META, META_CURRENT_EPOCH_KEY, epoch.pack().as_slice())
     }
 
+    /// Replaces the hashes of the blocks invalidated by the local node
+    pub fn insert_invalidated_blocks(&self, hashes: Vec<packed::Byte32>) -> Result<(), Error> {
+        self.insert_raw(
+            COLUMN_META,
+            META_INVALIDATED_BLOCKS_KEY,
+            hashes.pack().as_slice(),
+        )
+    }
+
     /// TODO(doc): @quake
     pub fn insert_cells(
         &self,
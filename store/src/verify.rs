@@ -0,0 +1,202 @@
+//! Rebuilds the columns derived from the main chain blocks and diffs them against the stored ones.
+use crate::cell::attach_block_cell;
+use crate::db::ChainDB;
+use crate::store::ChainStore;
+use ckb_db::iter::IteratorMode;
+use ckb_db_schema::{
+    Col, COLUMN_CELL, COLUMN_CELL_DATA, COLUMN_CELL_DATA_HASH, COLUMN_NUMBER_HASH,
+    COLUMN_TRANSACTION_INFO,
+};
+use ckb_error::{Error, InternalErrorKind};
+use ckb_types::{core::BlockNumber, packed, prelude::*};
+use std::cmp::Ordering;
+
+/// Columns rebuilt and diffed by `DBVerifier`
+pub const VERIFIED_COLUMNS: [Col; 5] = [
+    COLUMN_CELL,
+    COLUMN_CELL_DATA,
+    COLUMN_CELL_DATA_HASH,
+    COLUMN_NUMBER_HASH,
+    COLUMN_TRANSACTION_INFO,
+];
+
+const COMMIT_INTERVAL: BlockNumber = 1_000;
+const FIX_BATCH_SIZE: usize = 1_000;
+
+/// How a stored entry differs from the rebuilt one
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum DiscrepancyKind {
+    /// The rebuilt entry is not stored
+    Missing,
+    /// The stored value differs from the rebuilt one
+    Mismatched,
+    /// The stored entry is not derived from any main chain block
+    Extra,
+}
+
+/// An entry of a verified column which differs between the stored and the rebuilt one
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Discrepancy {
+    /// The column
+    pub column: Col,
+    /// The key of the entry
+    pub key: Vec<u8>,
+    /// How the stored entry differs
+    pub kind: DiscrepancyKind,
+}
+
+/// Verifies the live cell set, cell data and data hashes, `COLUMN_NUMBER_HASH` and
+/// `COLUMN_TRANSACTION_INFO` of a store.
+///
+/// The main chain blocks are replayed from genesis into an empty scratch store first,
+/// then every verified column of the store is diffed against the rebuilt one.
+pub struct DBVerifier<'a> {
+    store: &'a ChainDB,
+    scratch: &'a ChainDB,
+}
+
+impl<'a> DBVerifier<'a> {
+    /// Creates a verifier which rebuilds the columns of `store` into `scratch`,
+    /// `scratch` must be empty.
+    pub fn new(store: &'a ChainDB, scratch: &'a ChainDB) -> Self {
+        DBVerifier { store, scratch }
+    }
+
+    /// Replays attaching every main chain block into the scratch store,
+    /// `progress` is called with the number of the last replayed block periodically.
+    pub fn rebuild<F: FnMut(BlockNumber)>(&self, mut progress: F) -> Result<(), Error> {
+        let tip_number = match self.store.get_tip_header() {
+            Some(tip) => tip.number(),
+            None => return Ok(()),
+        };
+        // the block bodies of frozen blocks are wiped out along with their number hash entries
+        let frozen_number = self.store.freezer().map(|freezer| freezer.number());
+
+        let mut txn = self.scratch.begin_transaction();
+        for number in 0..=tip_number {
+            let block = self
+                .store
+                .get_block_hash(number)
+                .and_then(|hash| self.store.get_block(&hash))
+                .ok_or_else(|| {
+                    InternalErrorKind::Database
+                        .other(format!("main chain block {} is missing", number))
+                })?;
+            attach_block_cell(&txn, &block)?;
+            txn.attach_block(&block)?;
+            if !matches!(frozen_number, Some(frozen) if number > 0 && number < frozen) {
+                let txs_len: packed::Uint32 = (block.transactions().len() as u32).pack();
+                txn.insert_raw(
+                    COLUMN_NUMBER_HASH,
+                    packed::NumberHash::new_builder()
+                        .number(number.pack())
+                        .block_hash(block.hash())
+                        .build()
+                        .as_slice(),
+                    txs_len.as_slice(),
+                )?;
+            }
+
+            if (number + 1) % COMMIT_INTERVAL == 0 {
+                txn.commit()?;
+                txn = self.scratch.begin_transaction();
+                progress(number);
+            }
+        }
+        txn.commit()?;
+        progress(tip_number);
+        Ok(())
+    }
+
+    /// Diffs the verified columns of the store against the rebuilt ones, `report` is called
+    /// with every discrepancy found. Returns the number of discrepancies.
+    ///
+    /// If `fix` is set, the stored entries are overwritten with the rebuilt ones and the extra
+    /// entries are deleted.
+    pub fn diff<F: FnMut(&Discrepancy)>(&self, fix: bool, mut report: F) -> Result<usize, Error> {
+        let mut count = 0;
+        for column in VERIFIED_COLUMNS {
+            count += self.diff_column(column, fix, &mut report)?;
+        }
+        Ok(count)
+    }
+
+    fn diff_column<F: FnMut(&Discrepancy)>(
+        &self,
+        column: Col,
+        fix: bool,
+        report: &mut F,
+    ) -> Result<usize, Error> {
+        let mut count = 0;
+        let mut wb = self.store.new_write_batch();
+        let mut stored = self.store.get_iter(column, IteratorMode::Start).peekable();
+        let mut rebuilt = self
+            .scratch
+            .get_iter(column, IteratorMode::Start)
+            .peekable();
+
+        loop {
+            // both iterators are sorted by key
+            let order = match (stored.peek(), rebuilt.peek()) {
+                (None, None) => break,
+                (Some(_), None) => Ordering::Less,
+                (None, Some(_)) => Ordering::Greater,
+                (Some((stored_key, _)), Some((rebuilt_key, _))) => stored_key.cmp(rebuilt_key),
+            };
+            let (key, kind, value) = match order {
+                Ordering::Less => {
+                    let (key, _) = stored.next().expect("peeked");
+                    // number hash entries of stored fork blocks are kept
+                    if column == COLUMN_NUMBER_HASH && self.is_stored_block(&key) {
+                        continue;
+                    }
+                    (key, DiscrepancyKind::Extra, None)
+                }
+                Ordering::Greater => {
+                    let (key, value) = rebuilt.next().expect("peeked");
+                    (key, DiscrepancyKind::Missing, Some(value))
+                }
+                Ordering::Equal => {
+                    let (key, stored_value) = stored.next().expect("peeked");
+                    let (_, value) = rebuilt.next().expect("peeked");
+                    if stored_value == value {
+                        continue;
+                    }
+                    (key, DiscrepancyKind::Mismatched, Some(value))
+                }
+            };
+
+            count += 1;
+            report(&Discrepancy {
+                column,
+                key: key.to_vec(),
+                kind,
+            });
+            if fix {
+                match value {
+                    Some(value) => wb.put(column, &key, &value)?,
+                    None => wb.delete(column, &key)?,
+                }
+                if wb.len() >= FIX_BATCH_SIZE {
+                    self.store.write(&wb)?;
+                    wb.clear()?;
+                }
+            }
+        }
+
+        if !wb.is_empty() {
+            self.store.write(&wb)?;
+        }
+        Ok(count)
+    }
+
+    fn is_stored_block(&self, number_hash: &[u8]) -> bool {
+        packed::NumberHashReader::from_slice(number_hash)
+            .map(|reader| {
+                self.store
+                    .get_block_header(&reader.block_hash().to_entity())
+                    .is_some()
+            })
+            .unwrap_or(false)
+    }
+}
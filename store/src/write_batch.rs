@@ -1,10 +1,15 @@
 use ckb_db::RocksDBWriteBatch;
 use ckb_db_schema::{
-    Col, COLUMN_BLOCK_BODY, COLUMN_BLOCK_EXTENSION, COLUMN_BLOCK_HEADER, COLUMN_BLOCK_PROPOSAL_IDS,
-    COLUMN_BLOCK_UNCLE, COLUMN_CELL, COLUMN_CELL_DATA, COLUMN_CELL_DATA_HASH, COLUMN_NUMBER_HASH,
+    Col, COLUMN_BLOCK_BODY, COLUMN_BLOCK_EXT, COLUMN_BLOCK_EXTENSION, COLUMN_BLOCK_FILTER,
+    COLUMN_BLOCK_HEADER, COLUMN_BLOCK_PROPOSAL_IDS, COLUMN_BLOCK_UNCLE, COLUMN_CELL,
+    COLUMN_CELL_DATA, COLUMN_CELL_DATA_HASH, COLUMN_NUMBER_HASH,
 };
 use ckb_error::Error;
-use ckb_types::{core::BlockNumber, packed, prelude::*};
+use ckb_types::{
+    core::{block_filter::BlockFilter, BlockNumber},
+    packed,
+    prelude::*,
+};
 
 /// TODO(doc): @quake
 pub struct StoreWriteBatch {
@@ -22,6 +27,18 @@ impl StoreWriteBatch {
         self.inner.delete(col, key)
     }
 
+    /// Put the block filter and its filter header
+    pub fn insert_block_filter(
+        &mut self,
+        block_hash: &packed::Byte32,
+        filter_header: &packed::Byte32,
+        filter: &BlockFilter,
+    ) -> Result<(), Error> {
+        let mut value = filter_header.as_slice().to_vec();
+        value.extend_from_slice(&filter.data());
+        self.put(COLUMN_BLOCK_FILTER, block_hash.as_slice(), &value)
+    }
+
     /// Return WriteBatch serialized size (in bytes).
     pub fn size_in_bytes(&self) -> usize {
         self.inner.size_in_bytes()
@@ -124,6 +141,21 @@ impl StoreWriteBatch {
         hash: &packed::Byte32,
         txs_len: u32,
     ) -> Result<(), Error> {
+        self.inner.delete(COLUMN_BLOCK_HEADER, hash.as_slice())?;
+        self.inner.delete(COLUMN_BLOCK_FILTER, hash.as_slice())?;
+        self.delete_block_body(number, hash, txs_len)
+    }
+
+    /// Removes the entire block and its block ext from database once they have been moved into the freezer
+    ///
+    /// The block filter is not frozen, so it is kept to serve the light clients.
+    pub fn delete_frozen_block(
+        &mut self,
+        number: BlockNumber,
+        hash: &packed::Byte32,
+        txs_len: u32,
+    ) -> Result<(), Error> {
+        self.inner.delete(COLUMN_BLOCK_EXT, hash.as_slice())?;
         self.inner.delete(COLUMN_BLOCK_HEADER, hash.as_slice())?;
         self.delete_block_body(number, hash, txs_len)
     }
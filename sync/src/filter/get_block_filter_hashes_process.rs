@@ -0,0 +1,67 @@
+use crate::filter::{BlockFilter, BATCH_SIZE};
+use crate::utils::send_message_to;
+use crate::Status;
+use ckb_network::{CKBProtocolContext, PeerIndex};
+use ckb_store::ChainStore;
+use ckb_types::{core::BlockNumber, packed, prelude::*};
+
+// Filter hashes are small, so more of them fit in a response
+const HASHES_BATCH_SIZE: u64 = BATCH_SIZE * 2;
+
+pub struct GetBlockFilterHashesProcess<'a> {
+    message: packed::GetBlockFilterHashesReader<'a>,
+    filter: &'a BlockFilter,
+    nc: &'a dyn CKBProtocolContext,
+    peer: PeerIndex,
+}
+
+impl<'a> GetBlockFilterHashesProcess<'a> {
+    pub fn new(
+        message: packed::GetBlockFilterHashesReader<'a>,
+        filter: &'a BlockFilter,
+        nc: &'a dyn CKBProtocolContext,
+        peer: PeerIndex,
+    ) -> Self {
+        Self {
+            message,
+            filter,
+            nc,
+            peer,
+        }
+    }
+
+    pub fn execute(self) -> Status {
+        let snapshot = self.filter.shared.shared().snapshot();
+        let start_number: BlockNumber = self.message.start_number().unpack();
+        let end_number = snapshot
+            .tip_number()
+            .min(start_number.saturating_add(HASHES_BATCH_SIZE - 1));
+
+        // the client verifies the hashes against the filter header chain from the parent
+        let parent_block_filter_hash = start_number
+            .checked_sub(1)
+            .and_then(|parent| snapshot.get_block_hash(parent))
+            .and_then(|hash| snapshot.get_block_filter_header(&hash))
+            .unwrap_or_else(packed::Byte32::zero);
+        let block_filter_hashes: Vec<packed::Byte32> = (start_number..=end_number)
+            .map(|number| {
+                snapshot
+                    .get_block_hash(number)
+                    .and_then(|hash| snapshot.get_block_filter(&hash))
+                    .map(|filter| filter.calc_hash())
+            })
+            .take_while(Option::is_some)
+            .flatten()
+            .collect();
+
+        let content = packed::BlockFilterHashes::new_builder()
+            .start_number(start_number.pack())
+            .parent_block_filter_hash(parent_block_filter_hash)
+            .block_filter_hashes(block_filter_hashes.pack())
+            .build();
+        let message = packed::BlockFilterMessage::new_builder()
+            .set(content)
+            .build();
+        send_message_to(self.nc, self.peer, &message)
+    }
+}
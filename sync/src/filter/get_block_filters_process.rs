@@ -0,0 +1,67 @@
+use crate::filter::{BlockFilter, BATCH_SIZE, MAX_FILTERS_BYTES};
+use crate::utils::send_message_to;
+use crate::Status;
+use ckb_network::{CKBProtocolContext, PeerIndex};
+use ckb_store::ChainStore;
+use ckb_types::{core::BlockNumber, packed, prelude::*};
+
+pub struct GetBlockFiltersProcess<'a> {
+    message: packed::GetBlockFiltersReader<'a>,
+    filter: &'a BlockFilter,
+    nc: &'a dyn CKBProtocolContext,
+    peer: PeerIndex,
+}
+
+impl<'a> GetBlockFiltersProcess<'a> {
+    pub fn new(
+        message: packed::GetBlockFiltersReader<'a>,
+        filter: &'a BlockFilter,
+        nc: &'a dyn CKBProtocolContext,
+        peer: PeerIndex,
+    ) -> Self {
+        Self {
+            message,
+            filter,
+            nc,
+            peer,
+        }
+    }
+
+    pub fn execute(self) -> Status {
+        let snapshot = self.filter.shared.shared().snapshot();
+        let start_number: BlockNumber = self.message.start_number().unpack();
+        let end_number = snapshot
+            .tip_number()
+            .min(start_number.saturating_add(BATCH_SIZE - 1));
+
+        let mut block_hashes = Vec::new();
+        let mut filters = Vec::new();
+        let mut filters_bytes = 0;
+        for number in start_number..=end_number {
+            let block_hash = match snapshot.get_block_hash(number) {
+                Some(block_hash) => block_hash,
+                None => break,
+            };
+            let filter = match snapshot.get_block_filter(&block_hash) {
+                Some(filter) => filter.data(),
+                None => break,
+            };
+            filters_bytes += filter.len();
+            if filters_bytes > MAX_FILTERS_BYTES && !filters.is_empty() {
+                break;
+            }
+            block_hashes.push(block_hash);
+            filters.push(filter.pack());
+        }
+
+        let content = packed::BlockFilters::new_builder()
+            .start_number(start_number.pack())
+            .block_hashes(block_hashes.pack())
+            .filters(filters.pack())
+            .build();
+        let message = packed::BlockFilterMessage::new_builder()
+            .set(content)
+            .build();
+        send_message_to(self.nc, self.peer, &message)
+    }
+}
@@ -0,0 +1,138 @@
+mod get_block_filter_hashes_process;
+mod get_block_filters_process;
+
+use crate::filter::get_block_filter_hashes_process::GetBlockFilterHashesProcess;
+use crate::filter::get_block_filters_process::GetBlockFiltersProcess;
+use crate::types::SyncShared;
+use crate::{Status, StatusCode};
+use ckb_constant::sync::BAD_MESSAGE_BAN_TIME;
+use ckb_logger::{debug, error, info};
+use ckb_metrics::metrics;
+use ckb_network::{
+    bytes::Bytes, CKBProtocolContext, CKBProtocolHandler, PeerIndex, SupportProtocols,
+};
+use ckb_types::{packed, prelude::*};
+use std::sync::Arc;
+use std::time::Instant;
+
+// The max number of filters or filter hashes in a response
+const BATCH_SIZE: u64 = 1000;
+// Keep a response under the frame limit of the protocol
+const MAX_FILTERS_BYTES: usize = 1024 * 1024;
+
+/// Block filter protocol handler, which serves the compact block filters and filter hashes
+/// of the main chain to light clients.
+#[derive(Clone)]
+pub struct BlockFilter {
+    /// Sync shared state
+    shared: Arc<SyncShared>,
+}
+
+impl BlockFilter {
+    /// Create a new block filter protocol handler
+    pub fn new(shared: Arc<SyncShared>) -> Self {
+        Self { shared }
+    }
+
+    fn try_process(
+        &self,
+        nc: &dyn CKBProtocolContext,
+        peer: PeerIndex,
+        message: packed::BlockFilterMessageUnionReader<'_>,
+    ) -> Status {
+        match message {
+            packed::BlockFilterMessageUnionReader::GetBlockFilters(reader) => {
+                GetBlockFiltersProcess::new(reader, self, nc, peer).execute()
+            }
+            packed::BlockFilterMessageUnionReader::GetBlockFilterHashes(reader) => {
+                GetBlockFilterHashesProcess::new(reader, self, nc, peer).execute()
+            }
+            // a full node never requests filters, the responses are ignored
+            _ => StatusCode::Ignored.into(),
+        }
+    }
+
+    fn process(
+        &self,
+        nc: &dyn CKBProtocolContext,
+        peer: PeerIndex,
+        message: packed::BlockFilterMessageUnionReader<'_>,
+    ) {
+        let item_name = message.item_name();
+        let item_bytes = message.as_slice().len() as u64;
+        let status = self.try_process(nc, peer, message);
+
+        metrics!(
+            counter,
+            "ckb.messages_bytes",
+            item_bytes,
+            "direction" => "in",
+            "protocol_id" => SupportProtocols::BlockFilter.protocol_id().value().to_string(),
+            "item_id" => message.item_id().to_string(),
+            "status" => (status.code() as u16).to_string(),
+        );
+
+        if let Some(behaviour) = status.behaviour() {
+            info!(
+                "receive {} from {}, report {:?} for {}",
+                item_name, peer, behaviour, status
+            );
+            nc.report_peer(peer, behaviour);
+        } else if let Some(ban_time) = status.should_ban() {
+            error!(
+                "receive {} from {}, ban {:?} for {}",
+                item_name, peer, ban_time, status
+            );
+            nc.ban_peer(peer, ban_time, status.to_string());
+        } else if !status.is_ok() {
+            debug!("receive {} from {}, {}", item_name, peer, status);
+        }
+    }
+}
+
+impl CKBProtocolHandler for BlockFilter {
+    fn init(&mut self, _nc: Arc<dyn CKBProtocolContext + Sync>) {}
+
+    fn received(
+        &mut self,
+        nc: Arc<dyn CKBProtocolContext + Sync>,
+        peer_index: PeerIndex,
+        data: Bytes,
+    ) {
+        let msg = match packed::BlockFilterMessageReader::from_slice(&data) {
+            Ok(msg) => msg.to_enum(),
+            _ => {
+                info!("Peer {} sends us a malformed message", peer_index);
+                nc.ban_peer(
+                    peer_index,
+                    BAD_MESSAGE_BAN_TIME,
+                    String::from("send us a malformed message"),
+                );
+                return;
+            }
+        };
+
+        debug!("received msg {} from {}", msg.item_name(), peer_index);
+        let start_time = Instant::now();
+        self.process(nc.as_ref(), peer_index, msg);
+        debug!(
+            "process message={}, peer={}, cost={:?}",
+            msg.item_name(),
+            peer_index,
+            start_time.elapsed(),
+        );
+    }
+
+    fn connected(
+        &mut self,
+        _nc: Arc<dyn CKBProtocolContext + Sync>,
+        peer_index: PeerIndex,
+        _version: &str,
+    ) {
+        info!("BlockFilter.connected peer={}", peer_index);
+    }
+
+    fn disconnected(&mut self, _nc: Arc<dyn CKBProtocolContext + Sync>, peer_index: PeerIndex) {
+        info!("BlockFilter.disconnected peer={}", peer_index);
+    }
+}
@@ -4,6 +4,8 @@
 //! https://github.com/nervosnetwork/rfcs/blob/master/rfcs/0004-ckb-block-sync/0004-ckb-block-sync.md
 
 mod block_status;
+mod filter;
+mod light_client;
 pub(crate) mod net_time_checker;
 pub(crate) mod orphan_block_pool;
 mod relayer;
@@ -15,11 +17,14 @@ mod utils;
 #[cfg(test)]
 mod tests;
 
+pub use crate::block_status::BlockStatus;
+pub use crate::filter::BlockFilter;
+pub use crate::light_client::LightClient;
 pub use crate::net_time_checker::NetTimeProtocol;
-pub use crate::relayer::Relayer;
+pub use crate::relayer::{Relayer, RECONCILIATION_RELAY_VERSION};
 pub use crate::status::{Status, StatusCode};
 pub use crate::synchronizer::Synchronizer;
-pub use crate::types::SyncShared;
+pub use crate::types::{HeadersSyncState, SyncShared};
 use ckb_constant::sync::MAX_BLOCKS_IN_TRANSIT_PER_PEER;
 
 // Time recording window size, ibd period scheduler dynamically adjusts frequency
@@ -0,0 +1,63 @@
+use crate::light_client::LightClient;
+use crate::utils::send_message_to;
+use crate::{Status, StatusCode};
+use ckb_constant::sync::MAX_HEADERS_LEN;
+use ckb_network::{CKBProtocolContext, PeerIndex};
+use ckb_store::ChainStore;
+use ckb_types::{core::BlockNumber, packed, prelude::*};
+
+pub struct GetBlockHeadersProcess<'a> {
+    message: packed::GetBlockHeadersReader<'a>,
+    light_client: &'a LightClient,
+    nc: &'a dyn CKBProtocolContext,
+    peer: PeerIndex,
+}
+
+impl<'a> GetBlockHeadersProcess<'a> {
+    pub fn new(
+        message: packed::GetBlockHeadersReader<'a>,
+        light_client: &'a LightClient,
+        nc: &'a dyn CKBProtocolContext,
+        peer: PeerIndex,
+    ) -> Self {
+        Self {
+            message,
+            light_client,
+            nc,
+            peer,
+        }
+    }
+
+    pub fn execute(self) -> Status {
+        let start_number: BlockNumber = self.message.start_number().unpack();
+        let count: u32 = self.message.count().unpack();
+        if count as usize > MAX_HEADERS_LEN {
+            return StatusCode::ProtocolMessageIsMalformed.with_context(format!(
+                "GetBlockHeaders asks for {} headers, the limit is {}",
+                count, MAX_HEADERS_LEN
+            ));
+        }
+
+        let snapshot = self.light_client.shared.shared().snapshot();
+        let headers: Vec<packed::Header> = (start_number..)
+            .take(count as usize)
+            .take_while(|number| *number <= snapshot.tip_number())
+            .map(|number| {
+                snapshot
+                    .get_block_hash(number)
+                    .and_then(|hash| snapshot.get_block_header(&hash))
+            })
+            .take_while(Option::is_some)
+            .flatten()
+            .map(|header| header.data())
+            .collect();
+
+        let content = packed::SendBlockHeaders::new_builder()
+            .headers(headers.pack())
+            .build();
+        let message = packed::LightClientMessage::new_builder()
+            .set(content)
+            .build();
+        send_message_to(self.nc, self.peer, &message)
+    }
+}
@@ -0,0 +1,37 @@
+use crate::light_client::LightClient;
+use crate::utils::send_message_to;
+use crate::Status;
+use ckb_network::{CKBProtocolContext, PeerIndex};
+use ckb_types::{packed, prelude::*};
+
+pub struct GetLastStateProcess<'a> {
+    light_client: &'a LightClient,
+    nc: &'a dyn CKBProtocolContext,
+    peer: PeerIndex,
+}
+
+impl<'a> GetLastStateProcess<'a> {
+    pub fn new(
+        light_client: &'a LightClient,
+        nc: &'a dyn CKBProtocolContext,
+        peer: PeerIndex,
+    ) -> Self {
+        Self {
+            light_client,
+            nc,
+            peer,
+        }
+    }
+
+    pub fn execute(self) -> Status {
+        let snapshot = self.light_client.shared.shared().snapshot();
+        let content = packed::SendLastState::new_builder()
+            .tip_header(snapshot.tip_header().data())
+            .total_difficulty(snapshot.total_difficulty().pack())
+            .build();
+        let message = packed::LightClientMessage::new_builder()
+            .set(content)
+            .build();
+        send_message_to(self.nc, self.peer, &message)
+    }
+}
@@ -0,0 +1,114 @@
+use crate::light_client::{LightClient, MAX_PROOFS_BYTES, MAX_TX_HASHES};
+use crate::utils::send_message_to;
+use crate::{Status, StatusCode};
+use ckb_network::{CKBProtocolContext, PeerIndex};
+use ckb_store::ChainStore;
+use ckb_types::{packed, prelude::*};
+use std::collections::HashMap;
+
+pub struct GetTransactionsProofProcess<'a> {
+    message: packed::GetTransactionsProofReader<'a>,
+    light_client: &'a LightClient,
+    nc: &'a dyn CKBProtocolContext,
+    peer: PeerIndex,
+}
+
+impl<'a> GetTransactionsProofProcess<'a> {
+    pub fn new(
+        message: packed::GetTransactionsProofReader<'a>,
+        light_client: &'a LightClient,
+        nc: &'a dyn CKBProtocolContext,
+        peer: PeerIndex,
+    ) -> Self {
+        Self {
+            message,
+            light_client,
+            nc,
+            peer,
+        }
+    }
+
+    pub fn execute(self) -> Status {
+        if self.message.tx_hashes().len() > MAX_TX_HASHES {
+            return StatusCode::ProtocolMessageIsMalformed.with_context(format!(
+                "GetTransactionsProof asks for {} transactions, the limit is {}",
+                self.message.tx_hashes().len(),
+                MAX_TX_HASHES
+            ));
+        }
+
+        let snapshot = self.light_client.shared.shared().snapshot();
+
+        // group the committed transactions by block, keeping the request order
+        let mut missing_tx_hashes = Vec::new();
+        let mut blocks: Vec<(packed::Byte32, Vec<(u32, packed::Byte32)>)> = Vec::new();
+        let mut block_positions: HashMap<packed::Byte32, usize> = HashMap::new();
+        for tx_hash in self.message.tx_hashes().iter() {
+            let tx_hash = tx_hash.to_entity();
+            match snapshot.get_transaction_info(&tx_hash) {
+                Some(tx_info) => {
+                    let position = *block_positions
+                        .entry(tx_info.block_hash.clone())
+                        .or_insert_with(|| {
+                            blocks.push((tx_info.block_hash.clone(), Vec::new()));
+                            blocks.len() - 1
+                        });
+                    let txs = &mut blocks[position].1;
+                    if txs.iter().all(|(index, _)| *index != tx_info.index as u32) {
+                        txs.push((tx_info.index as u32, tx_hash));
+                    }
+                }
+                None => missing_tx_hashes.push(tx_hash),
+            }
+        }
+
+        // transactions of the blocks which don't fit into the response are reported as
+        // missing too, the client can ask for them again
+        let mut proofs = Vec::with_capacity(blocks.len());
+        let mut proofs_bytes = 0;
+        for (block_hash, txs) in blocks {
+            let block = match snapshot.get_block(&block_hash) {
+                Some(block) if proofs_bytes < MAX_PROOFS_BYTES => block,
+                _ => {
+                    missing_tx_hashes.extend(txs.into_iter().map(|(_, tx_hash)| tx_hash));
+                    continue;
+                }
+            };
+            let indices: Vec<u32> = txs.iter().map(|(index, _)| *index).collect();
+            let proof = block
+                .transactions_proof(&indices)
+                .expect("build proof with verified inputs should be OK");
+            // the transactions are the leaves in the order of the proof indices
+            let leaves_offset = block.transactions().len() as u32 - 1;
+            let transactions: Vec<packed::Transaction> = proof
+                .indices()
+                .iter()
+                .filter_map(|index| block.transaction((index - leaves_offset) as usize))
+                .map(|tx| tx.data())
+                .collect();
+
+            let content = packed::TransactionsProof::new_builder()
+                .header(block.header().data())
+                .witnesses_root(block.calc_witnesses_root())
+                .transactions(transactions.pack())
+                .proof(
+                    packed::MerkleProof::new_builder()
+                        .indices(proof.indices().pack())
+                        .lemmas(proof.lemmas().to_vec().pack())
+                        .build(),
+                )
+                .build();
+            proofs_bytes += content.total_size();
+            proofs.push(content);
+        }
+
+        let content = packed::SendTransactionsProof::new_builder()
+            .proofs(proofs.pack())
+            .missing_tx_hashes(missing_tx_hashes.pack())
+            .build();
+        let message = packed::LightClientMessage::new_builder()
+            .set(content)
+            .build();
+        send_message_to(self.nc, self.peer, &message)
+    }
+}
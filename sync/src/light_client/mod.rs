@@ -0,0 +1,170 @@
+mod get_block_headers_process;
+mod get_last_state_process;
+mod get_transactions_proof_process;
+#[cfg(test)]
+mod tests;
+
+use crate::light_client::get_block_headers_process::GetBlockHeadersProcess;
+use crate::light_client::get_last_state_process::GetLastStateProcess;
+use crate::light_client::get_transactions_proof_process::GetTransactionsProofProcess;
+use crate::types::SyncShared;
+use crate::{Status, StatusCode};
+use ckb_constant::sync::BAD_MESSAGE_BAN_TIME;
+use ckb_logger::{debug, error, info};
+use ckb_metrics::metrics;
+use ckb_network::{
+    bytes::Bytes, CKBProtocolContext, CKBProtocolHandler, PeerIndex, SupportProtocols,
+};
+use ckb_types::{packed, prelude::*};
+use ckb_util::Mutex;
+use std::sync::Arc;
+use std::time::Instant;
+
+// The max number of transaction hashes in a proof request
+const MAX_TX_HASHES: usize = 1000;
+// Keep a proof response under the frame limit of the protocol
+const MAX_PROOFS_BYTES: usize = 1024 * 1024;
+
+type RateLimiter<T> = governor::RateLimiter<
+    T,
+    governor::state::keyed::DefaultKeyedStateStore<T>,
+    governor::clock::DefaultClock,
+>;
+
+/// Light client protocol handler, which serves the headers of the main chain, the chain tip
+/// with its total difficulty and the merkle proofs of transactions to light clients.
+#[derive(Clone)]
+pub struct LightClient {
+    /// Sync shared state
+    shared: Arc<SyncShared>,
+    rate_limiter: Arc<Mutex<RateLimiter<(PeerIndex, u32)>>>,
+}
+
+impl LightClient {
+    /// Create a new light client protocol handler
+    pub fn new(shared: Arc<SyncShared>) -> Self {
+        // setup a rate limiter keyed by peer and message type that lets through 10 requests per second
+        let quota = governor::Quota::per_second(std::num::NonZeroU32::new(10).unwrap());
+        let rate_limiter = Arc::new(Mutex::new(RateLimiter::keyed(quota)));
+        Self {
+            shared,
+            rate_limiter,
+        }
+    }
+
+    fn try_process(
+        &self,
+        nc: &dyn CKBProtocolContext,
+        peer: PeerIndex,
+        message: packed::LightClientMessageUnionReader<'_>,
+    ) -> Status {
+        if self
+            .rate_limiter
+            .lock()
+            .check_key(&(peer, message.item_id()))
+            .is_err()
+        {
+            return StatusCode::TooManyRequests.with_context(message.item_name());
+        }
+
+        match message {
+            packed::LightClientMessageUnionReader::GetLastState(_) => {
+                GetLastStateProcess::new(self, nc, peer).execute()
+            }
+            packed::LightClientMessageUnionReader::GetBlockHeaders(reader) => {
+                GetBlockHeadersProcess::new(reader, self, nc, peer).execute()
+            }
+            packed::LightClientMessageUnionReader::GetTransactionsProof(reader) => {
+                GetTransactionsProofProcess::new(reader, self, nc, peer).execute()
+            }
+            // a full node never requests light client data, the responses are ignored
+            _ => StatusCode::Ignored.into(),
+        }
+    }
+
+    fn process(
+        &self,
+        nc: &dyn CKBProtocolContext,
+        peer: PeerIndex,
+        message: packed::LightClientMessageUnionReader<'_>,
+    ) {
+        let item_name = message.item_name();
+        let item_bytes = message.as_slice().len() as u64;
+        let status = self.try_process(nc, peer, message);
+
+        metrics!(
+            counter,
+            "ckb.messages_bytes",
+            item_bytes,
+            "direction" => "in",
+            "protocol_id" => SupportProtocols::LightClient.protocol_id().value().to_string(),
+            "item_id" => message.item_id().to_string(),
+            "status" => (status.code() as u16).to_string(),
+        );
+
+        if let Some(behaviour) = status.behaviour() {
+            info!(
+                "receive {} from {}, report {:?} for {}",
+                item_name, peer, behaviour, status
+            );
+            nc.report_peer(peer, behaviour);
+        } else if let Some(ban_time) = status.should_ban() {
+            error!(
+                "receive {} from {}, ban {:?} for {}",
+                item_name, peer, ban_time, status
+            );
+            nc.ban_peer(peer, ban_time, status.to_string());
+        } else if !status.is_ok() {
+            debug!("receive {} from {}, {}", item_name, peer, status);
+        }
+    }
+}
+
+impl CKBProtocolHandler for LightClient {
+    fn init(&mut self, _nc: Arc<dyn CKBProtocolContext + Sync>) {}
+
+    fn received(
+        &mut self,
+        nc: Arc<dyn CKBProtocolContext + Sync>,
+        peer_index: PeerIndex,
+        data: Bytes,
+    ) {
+        let msg = match packed::LightClientMessageReader::from_slice(&data) {
+            Ok(msg) => msg.to_enum(),
+            _ => {
+                info!("Peer {} sends us a malformed message", peer_index);
+                nc.ban_peer(
+                    peer_index,
+                    BAD_MESSAGE_BAN_TIME,
+                    String::from("send us a malformed message"),
+                );
+                return;
+            }
+        };
+
+        debug!("received msg {} from {}", msg.item_name(), peer_index);
+        let start_time = Instant::now();
+        self.process(nc.as_ref(), peer_index, msg);
+        debug!(
+            "process message={}, peer={}, cost={:?}",
+            msg.item_name(),
+            peer_index,
+            start_time.elapsed(),
+        );
+    }
+
+    fn connected(
+        &mut self,
+        _nc: Arc<dyn CKBProtocolContext + Sync>,
+        peer_index: PeerIndex,
+        _version: &str,
+    ) {
+        info!("LightClient.connected peer={}", peer_index);
+    }
+
+    fn disconnected(&mut self, _nc: Arc<dyn CKBProtocolContext + Sync>, peer_index: PeerIndex) {
+        info!("LightClient.disconnected peer={}", peer_index);
+        // Retains all keys in the rate limiter that were used recently enough.
+        self.rate_limiter.lock().retain_recent();
+    }
+}
@@ -0,0 +1,150 @@
+use crate::light_client::get_transactions_proof_process::GetTransactionsProofProcess;
+use crate::light_client::LightClient;
+use crate::relayer::tests::helper::{append_block, build_chain, MockProtocolContext};
+use crate::Status;
+use ckb_network::{PeerIndex, SupportProtocols};
+use ckb_store::ChainStore;
+use ckb_types::{
+    core::{Capacity, TransactionBuilder},
+    packed,
+    prelude::*,
+    utilities::{merkle_root, MerkleProof},
+};
+use std::collections::HashSet;
+use std::sync::Arc;
+
+// Verify the proofs against the transactions roots of their headers, returns the proven
+// transaction hashes of every block
+fn verify_proofs(response: &packed::SendTransactionsProof) -> Vec<(u64, Vec<packed::Byte32>)> {
+    response
+        .proofs()
+        .into_iter()
+        .map(|tx_proof| {
+            let header = tx_proof.header().into_view();
+            let leaves: Vec<packed::Byte32> = tx_proof
+                .transactions()
+                .into_iter()
+                .map(|tx| tx.calc_tx_hash())
+                .collect();
+            let proof = MerkleProof::new(
+                tx_proof
+                    .proof()
+                    .indices()
+                    .into_iter()
+                    .map(|i| i.unpack())
+                    .collect(),
+                tx_proof.proof().lemmas().into_iter().collect(),
+            );
+            let raw_transactions_root = proof.root(&leaves).unwrap();
+            assert_eq!(
+                merkle_root(&[raw_transactions_root, tx_proof.witnesses_root()]),
+                header.transactions_root()
+            );
+            (header.number(), leaves)
+        })
+        .collect()
+}
+
+fn send_transactions_proof(
+    light_client: &LightClient,
+    tx_hashes: Vec<packed::Byte32>,
+) -> packed::SendTransactionsProof {
+    let content = packed::GetTransactionsProof::new_builder()
+        .tx_hashes(tx_hashes.pack())
+        .build();
+    let nc = MockProtocolContext::new(SupportProtocols::LightClient);
+    let peer_index: PeerIndex = 1.into();
+    let process =
+        GetTransactionsProofProcess::new(content.as_reader(), light_client, &nc, peer_index);
+    assert_eq!(process.execute(), Status::ok());
+
+    let sent_messages = nc.sent_messages();
+    assert_eq!(sent_messages.len(), 1);
+    let message = packed::LightClientMessage::from_slice(&sent_messages[0].2).unwrap();
+    match message.to_enum() {
+        packed::LightClientMessageUnion::SendTransactionsProof(response) => response,
+        _ => panic!("unexpected message"),
+    }
+}
+
+#[test]
+fn test_transactions_proof() {
+    let (relayer, _) = build_chain(5);
+    let light_client = LightClient::new(Arc::clone(relayer.shared()));
+    let snapshot = relayer.shared().shared().snapshot();
+    let cellbase_hash = |number| {
+        let block_hash = snapshot.get_block_hash(number).unwrap();
+        snapshot.get_block(&block_hash).unwrap().transactions()[0].hash()
+    };
+    let unknown_hash = packed::Byte32::new([1u8; 32]);
+    let tx_hashes = vec![cellbase_hash(2), unknown_hash.clone(), cellbase_hash(3)];
+
+    let response = send_transactions_proof(&light_client, tx_hashes);
+    assert_eq!(response.missing_tx_hashes().len(), 1);
+    assert_eq!(response.missing_tx_hashes().get(0), Some(unknown_hash));
+    assert_eq!(
+        verify_proofs(&response),
+        vec![(2, vec![cellbase_hash(2)]), (3, vec![cellbase_hash(3)])]
+    );
+}
+
+#[test]
+fn test_transactions_proof_multiple_transactions_and_blocks() {
+    let (relayer, _) = build_chain(5);
+    let light_client = LightClient::new(Arc::clone(relayer.shared()));
+    let snapshot = relayer.shared().shared().snapshot();
+    let cellbase_out_point = |number| {
+        let block_hash = snapshot.get_block_hash(number).unwrap();
+        snapshot.get_block(&block_hash).unwrap().transactions()[0].output_pts()[0].clone()
+    };
+    // spend the cellbases of blocks 1 ~ 5, 3 transactions in block 6 and 2 in block 7
+    let transactions: Vec<_> = (1..=5)
+        .map(|number| {
+            TransactionBuilder::default()
+                .input(packed::CellInput::new(cellbase_out_point(number), 0))
+                .output(
+                    packed::CellOutputBuilder::default()
+                        .capacity(Capacity::bytes(500 + number as usize).unwrap().pack())
+                        .build(),
+                )
+                .output_data(Default::default())
+                .build()
+        })
+        .collect();
+    let block6 = append_block(&relayer, transactions[..3].to_vec());
+    let block7 = append_block(&relayer, transactions[3..].to_vec());
+
+    // transactions of both blocks interleaved, with a duplicate
+    let tx_hashes = vec![
+        block6.tx_hashes()[3].clone(),
+        block7.tx_hashes()[0].clone(),
+        block6.tx_hashes()[1].clone(),
+        block7.tx_hashes()[2].clone(),
+        block6.tx_hashes()[3].clone(),
+        block7.tx_hashes()[1].clone(),
+    ];
+    let response = send_transactions_proof(&light_client, tx_hashes);
+    assert!(response.missing_tx_hashes().is_empty());
+
+    let proven = verify_proofs(&response);
+    assert_eq!(proven.len(), 2);
+    let expected = vec![
+        (
+            6,
+            vec![block6.tx_hashes()[1].clone(), block6.tx_hashes()[3].clone()],
+        ),
+        (
+            7,
+            vec![
+                block7.tx_hashes()[0].clone(),
+                block7.tx_hashes()[1].clone(),
+                block7.tx_hashes()[2].clone(),
+            ],
+        ),
+    ];
+    for ((number, leaves), (expected_number, expected_leaves)) in proven.into_iter().zip(expected) {
+        assert_eq!(number, expected_number);
+        let leaves: HashSet<_> = leaves.into_iter().collect();
+        assert_eq!(leaves, expected_leaves.into_iter().collect());
+    }
+}
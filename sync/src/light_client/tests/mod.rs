@@ -0,0 +1 @@
+mod get_transactions_proof_process;
@@ -0,0 +1,175 @@
+//! Dandelion++ transaction relay.
+//!
+//! A transaction in the stem phase is forwarded to the stem peer only, which is a single
+//! outbound peer chosen every epoch. In every epoch a node either forwards the stem
+//! transactions it receives or broadcasts them, the fluff phase. The local transactions are
+//! always stemmed. Every stem transaction has an embargo, it is broadcast by this node when
+//! it is not announced by other peers before the embargo ends, so a transaction never gets
+//! lost in the stem.
+use ckb_app_config::DandelionConfig;
+use ckb_network::PeerIndex;
+use ckb_types::packed::Byte32;
+use rand::{seq::SliceRandom, thread_rng, Rng};
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+/// The version of the relay protocol which supports the stem transactions.
+pub(crate) const STEM_RELAY_VERSION: u32 = 3;
+
+/// The max number of the received stem transactions which are waiting to be routed, the
+/// transactions received beyond it are fluffed.
+pub(crate) const MAX_RECEIVED_STEM_TXS: usize = 16384;
+
+/// How a transaction is relayed.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub(crate) enum Route {
+    /// Forward the transaction to the stem peer only.
+    Stem(PeerIndex),
+    /// Broadcast the transaction.
+    Fluff,
+}
+
+struct Epoch {
+    started_at: Instant,
+    stem_peer: Option<PeerIndex>,
+    fluff: bool,
+}
+
+pub(crate) struct Dandelion {
+    config: DandelionConfig,
+    epoch: Option<Epoch>,
+    // stem transactions received from peers which are not routed yet, and when they are
+    // forgotten if the tx-pool never reports them
+    received: HashMap<Byte32, Instant>,
+    // stem transactions and when their embargo ends
+    embargoes: HashMap<Byte32, Instant>,
+}
+
+impl Dandelion {
+    pub(crate) fn new(config: DandelionConfig) -> Self {
+        Dandelion {
+            config,
+            epoch: None,
+            received: HashMap::new(),
+            embargoes: HashMap::new(),
+        }
+    }
+
+    pub(crate) fn is_enabled(&self) -> bool {
+        self.config.enable
+    }
+
+    /// Records the stem transactions received from a peer.
+    pub(crate) fn stem_received<I: IntoIterator<Item = Byte32>>(
+        &mut self,
+        tx_hashes: I,
+        now: Instant,
+    ) {
+        if !self.is_enabled() {
+            return;
+        }
+        let forget_at = now + self.max_embargo();
+        for tx_hash in tx_hashes {
+            if self.received.len() >= MAX_RECEIVED_STEM_TXS {
+                break;
+            }
+            self.received.insert(tx_hash, forget_at);
+        }
+    }
+
+    /// Decides how to relay a verified transaction.
+    ///
+    /// The `original_peer` is none when the transaction is submitted through the local RPC,
+    /// the `stem_peers` are the outbound peers which support the stem transactions.
+    pub(crate) fn route(
+        &mut self,
+        tx_hash: &Byte32,
+        original_peer: Option<PeerIndex>,
+        stem_peers: &[PeerIndex],
+        now: Instant,
+    ) -> Route {
+        let is_local = original_peer.is_none();
+        let is_stem = self.received.remove(tx_hash).is_some();
+        if !self.is_enabled() || !(is_local || is_stem) {
+            return Route::Fluff;
+        }
+
+        let epoch = self.renew_epoch(stem_peers, now);
+        let route = match epoch.stem_peer {
+            Some(peer) if (is_local || !epoch.fluff) && Some(peer) != original_peer => {
+                Route::Stem(peer)
+            }
+            _ => Route::Fluff,
+        };
+        if let Route::Stem(_) = route {
+            let embargo_ms = self.config.embargo_secs * 1000;
+            let delay = embargo_ms + thread_rng().gen_range(0, embargo_ms + 1);
+            self.embargoes
+                .insert(tx_hash.clone(), now + Duration::from_millis(delay));
+        }
+        route
+    }
+
+    /// Lifts the embargoes of the transactions announced by other peers.
+    pub(crate) fn fluffed<I: IntoIterator<Item = Byte32>>(&mut self, tx_hashes: I) {
+        if self.embargoes.is_empty() {
+            return;
+        }
+        for tx_hash in tx_hashes {
+            self.embargoes.remove(&tx_hash);
+        }
+    }
+
+    /// Forgets a transaction which is rejected by the tx-pool.
+    pub(crate) fn forget(&mut self, tx_hash: &Byte32) {
+        self.received.remove(tx_hash);
+        self.embargoes.remove(tx_hash);
+    }
+
+    /// Takes the stem transactions whose embargo has ended.
+    ///
+    /// The received stem transactions which are not routed within the longest embargo are
+    /// forgotten as well.
+    pub(crate) fn take_expired(&mut self, now: Instant) -> Vec<Byte32> {
+        self.received.retain(|_, forget_at| *forget_at > now);
+        let expired: Vec<Byte32> = self
+            .embargoes
+            .iter()
+            .filter(|(_, ends_at)| **ends_at <= now)
+            .map(|(tx_hash, _)| tx_hash.clone())
+            .collect();
+        for tx_hash in &expired {
+            self.embargoes.remove(tx_hash);
+        }
+        expired
+    }
+
+    // The embargo of a stem transaction is at most twice the configured one
+    fn max_embargo(&self) -> Duration {
+        Duration::from_secs(self.config.embargo_secs * 2)
+    }
+
+    // Starts a new epoch when the current one ends or its stem peer is gone
+    fn renew_epoch(&mut self, stem_peers: &[PeerIndex], now: Instant) -> &Epoch {
+        let epoch_length = Duration::from_secs(self.config.epoch_secs);
+        let expired = match self.epoch {
+            Some(ref epoch) => {
+                now.saturating_duration_since(epoch.started_at) >= epoch_length
+                    || epoch
+                        .stem_peer
+                        .map(|peer| !stem_peers.contains(&peer))
+                        .unwrap_or(!stem_peers.is_empty())
+            }
+            None => true,
+        };
+        if expired {
+            let mut rng = thread_rng();
+            self.epoch = Some(Epoch {
+                started_at: now,
+                stem_peer: stem_peers.choose(&mut rng).cloned(),
+                fluff: rng.gen_range(0, 100) < self.config.fluff_probability,
+            });
+        }
+        self.epoch.as_ref().expect("renewed above")
+    }
+}
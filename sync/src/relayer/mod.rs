@@ -4,32 +4,47 @@ mod block_transactions_verifier;
 mod block_uncles_verifier;
 mod compact_block_process;
 mod compact_block_verifier;
+mod dandelion;
 mod get_block_proposal_process;
 mod get_block_transactions_process;
 mod get_transactions_process;
+mod reconciliation;
+mod reconciliation_difference_process;
+mod reconciliation_request_process;
+mod reconciliation_sketch_process;
+mod sketch;
+mod stem_transactions_process;
 #[cfg(test)]
-mod tests;
+pub(crate) mod tests;
 mod transaction_hashes_process;
 mod transactions_process;
 
 use self::block_proposal_process::BlockProposalProcess;
 use self::block_transactions_process::BlockTransactionsProcess;
 use self::compact_block_process::CompactBlockProcess;
+use self::dandelion::{Dandelion, Route, STEM_RELAY_VERSION};
 use self::get_block_proposal_process::GetBlockProposalProcess;
 use self::get_block_transactions_process::GetBlockTransactionsProcess;
 use self::get_transactions_process::GetTransactionsProcess;
+use self::reconciliation::Reconciliation;
+pub use self::reconciliation::RECONCILIATION_RELAY_VERSION;
+use self::reconciliation_difference_process::ReconciliationDifferenceProcess;
+use self::reconciliation_request_process::ReconciliationRequestProcess;
+use self::reconciliation_sketch_process::ReconciliationSketchProcess;
+use self::stem_transactions_process::StemTransactionsProcess;
 use self::transaction_hashes_process::TransactionHashesProcess;
 use self::transactions_process::TransactionsProcess;
 use crate::block_status::BlockStatus;
 use crate::types::{ActiveChain, BlockNumberAndHash, SyncShared};
 use crate::utils::send_message_to;
 use crate::{Status, StatusCode};
+use ckb_app_config::DandelionConfig;
 use ckb_chain::chain::ChainController;
 use ckb_constant::sync::BAD_MESSAGE_BAN_TIME;
 use ckb_logger::{debug_target, error_target, info_target, trace_target, warn_target};
 use ckb_metrics::metrics;
 use ckb_network::{
-    bytes::Bytes, tokio, CKBProtocolContext, CKBProtocolHandler, PeerIndex, SupportProtocols,
+    bytes::Bytes, tokio, CKBProtocolContext, CKBProtocolHandler, Peer, PeerIndex, SupportProtocols,
     TargetSession,
 };
 use ckb_tx_pool::service::TxVerificationResult;
@@ -48,11 +63,15 @@ pub const TX_PROPOSAL_TOKEN: u64 = 0;
 pub const ASK_FOR_TXS_TOKEN: u64 = 1;
 pub const TX_HASHES_TOKEN: u64 = 2;
 pub const SEARCH_ORPHAN_POOL_TOKEN: u64 = 3;
+pub const RECONCILIATION_TOKEN: u64 = 4;
 
 pub const MAX_RELAY_PEERS: usize = 128;
 pub const MAX_RELAY_TXS_NUM_PER_BATCH: usize = 32767;
 pub const MAX_RELAY_TXS_BYTES_PER_BATCH: usize = 1024 * 1024;
 
+// ban time for the peers which relay invalid transactions
+pub(crate) const DEFAULT_BAN_TIME: Duration = Duration::from_secs(3600 * 24 * 3);
+
 type RateLimiter<T> = governor::RateLimiter<
     T,
     governor::state::keyed::DefaultKeyedStateStore<T>,
@@ -73,6 +92,8 @@ pub struct Relayer {
     chain: ChainController,
     pub(crate) shared: Arc<SyncShared>,
     rate_limiter: Arc<Mutex<RateLimiter<(PeerIndex, u32)>>>,
+    dandelion: Arc<Mutex<Dandelion>>,
+    reconciliation: Arc<Mutex<Reconciliation>>,
     v2: bool,
 }
 
@@ -89,6 +110,8 @@ impl Relayer {
             chain,
             shared,
             rate_limiter,
+            dandelion: Arc::new(Mutex::new(Dandelion::new(DandelionConfig::default()))),
+            reconciliation: Arc::new(Mutex::new(Reconciliation::default())),
             v2: false,
         }
     }
@@ -99,6 +122,12 @@ impl Relayer {
         self
     }
 
+    /// Relay the transactions in Dandelion++ style
+    pub fn dandelion(mut self, config: DandelionConfig) -> Self {
+        self.dandelion = Arc::new(Mutex::new(Dandelion::new(config)));
+        self
+    }
+
     /// Get shared state
     pub fn shared(&self) -> &Arc<SyncShared> {
         &self.shared
@@ -124,19 +153,20 @@ impl Relayer {
             return StatusCode::TooManyRequests.with_context(message.item_name());
         }
 
+        // block-relay-only peers take no part in transaction relay, and the transactions are
+        // relayed by only one of the relay protocols
+        if is_transaction_relay(&message)
+            && (is_block_relay_only(nc.as_ref(), peer)
+                || !RelaySwitch::new(&nc, self.v2).relays_transactions())
+        {
+            return Status::ignored();
+        }
+
         match message {
             packed::RelayMessageUnionReader::CompactBlock(reader) => {
                 CompactBlockProcess::new(reader, self, nc, peer).execute()
             }
             packed::RelayMessageUnionReader::RelayTransactions(reader) => {
-                // after ckb2021, v1 doesn't work with relay tx
-                // before ckb2021, v2 doesn't work with relay tx
-                match RelaySwitch::new(&nc, self.v2) {
-                    RelaySwitch::Ckb2021RelayV1 | RelaySwitch::Ckb2019RelayV2 => {
-                        return Status::ignored()
-                    }
-                    RelaySwitch::Ckb2021RelayV2 | RelaySwitch::Ckb2019RelayV1 => (),
-                }
                 if reader.check_data() {
                     TransactionsProcess::new(reader, self, nc, peer).execute()
                 } else {
@@ -145,27 +175,28 @@ impl Relayer {
                 }
             }
             packed::RelayMessageUnionReader::RelayTransactionHashes(reader) => {
-                // after ckb2021, v1 doesn't work with relay tx
-                // before ckb2021, v2 doesn't work with relay tx
-                match RelaySwitch::new(&nc, self.v2) {
-                    RelaySwitch::Ckb2021RelayV1 | RelaySwitch::Ckb2019RelayV2 => {
-                        return Status::ignored()
-                    }
-                    RelaySwitch::Ckb2021RelayV2 | RelaySwitch::Ckb2019RelayV1 => (),
-                }
                 TransactionHashesProcess::new(reader, self, peer).execute()
             }
             packed::RelayMessageUnionReader::GetRelayTransactions(reader) => {
-                // after ckb2021, v1 doesn't work with relay tx
-                // before ckb2021, v2 doesn't work with relay tx
-                match RelaySwitch::new(&nc, self.v2) {
-                    RelaySwitch::Ckb2021RelayV1 | RelaySwitch::Ckb2019RelayV2 => {
-                        return Status::ignored()
-                    }
-                    RelaySwitch::Ckb2021RelayV2 | RelaySwitch::Ckb2019RelayV1 => (),
-                }
                 GetTransactionsProcess::new(reader, self, nc, peer).execute()
             }
+            packed::RelayMessageUnionReader::StemTransactions(reader) => {
+                if reader.check_data() {
+                    StemTransactionsProcess::new(reader, self, nc, peer).execute()
+                } else {
+                    StatusCode::ProtocolMessageIsMalformed
+                        .with_context("StemTransactions is invalid")
+                }
+            }
+            packed::RelayMessageUnionReader::ReconciliationRequest(reader) => {
+                ReconciliationRequestProcess::new(reader, self, nc, peer).execute()
+            }
+            packed::RelayMessageUnionReader::ReconciliationSketch(reader) => {
+                ReconciliationSketchProcess::new(reader, self, nc, peer).execute()
+            }
+            packed::RelayMessageUnionReader::ReconciliationDifference(reader) => {
+                ReconciliationDifferenceProcess::new(reader, self, nc, peer).execute()
+            }
             packed::RelayMessageUnionReader::GetBlockTransactions(reader) => {
                 GetBlockTransactionsProcess::new(reader, self, nc, peer).execute()
             }
@@ -206,7 +237,17 @@ impl Relayer {
             "status" => (status.code() as u16).to_string(),
         );
 
-        if let Some(ban_time) = status.should_ban() {
+        if let Some(behaviour) = status.behaviour() {
+            info_target!(
+                crate::LOG_TARGET_RELAY,
+                "receive {} from {}, report {:?} for {}",
+                item_name,
+                peer,
+                behaviour,
+                status
+            );
+            nc.report_peer(peer, behaviour);
+        } else if let Some(ban_time) = status.should_ban() {
             error_target!(
                 crate::LOG_TARGET_RELAY,
                 "receive {} from {}, ban {:?} for {}",
@@ -306,6 +347,7 @@ impl Relayer {
                 boxed.header().hash(),
                 unix_time_as_millis()
             );
+            nc.block_delivered(peer);
             let block_hash = boxed.hash();
             self.shared().state().remove_header_view(&block_hash);
             let cb = packed::CompactBlock::build_from_block(&boxed, &HashSet::new());
@@ -624,7 +666,11 @@ impl Relayer {
     pub fn send_bulk_of_tx_hashes(&self, nc: &dyn CKBProtocolContext) {
         const BUFFER_SIZE: usize = 42;
 
-        let connected_peers = nc.connected_peers();
+        let connected_peers: Vec<PeerIndex> = nc
+            .connected_peers()
+            .into_iter()
+            .filter(|peer| !is_block_relay_only(nc, *peer))
+            .collect();
         if connected_peers.is_empty() {
             return;
         }
@@ -635,7 +681,15 @@ impl Relayer {
             .state()
             .take_relay_tx_verify_results(MAX_RELAY_TXS_NUM_PER_BATCH);
         let mut selected: HashMap<PeerIndex, Vec<Byte32>> = HashMap::default();
+        let mut stem: HashMap<PeerIndex, Vec<Byte32>> = HashMap::default();
         {
+            let now = Instant::now();
+            let mut dandelion = self.dandelion.lock();
+            let stem_peers = if dandelion.is_enabled() {
+                stem_peers(nc)
+            } else {
+                Vec::new()
+            };
             for tx_verify_result in tx_verify_results {
                 match tx_verify_result {
                     TxVerificationResult::Ok {
@@ -643,11 +697,24 @@ impl Relayer {
                         with_vm_2021,
                         tx_hash,
                     } => {
+                        if let Some(peer) = original_peer {
+                            nc.transaction_delivered(peer);
+                        }
                         // must all fork or all no-fork
                         if ckb2021 != with_vm_2021 {
                             continue;
                         }
 
+                        if let Route::Stem(stem_peer) =
+                            dandelion.route(&tx_hash, original_peer, &stem_peers, now)
+                        {
+                            if original_peer.is_none() {
+                                self.shared.state().mark_as_known_tx(tx_hash.clone());
+                            }
+                            stem.entry(stem_peer).or_default().push(tx_hash);
+                            continue;
+                        }
+
                         for target in &connected_peers {
                             match original_peer {
                                 Some(peer) => {
@@ -671,14 +738,49 @@ impl Relayer {
                         }
                     }
                     TxVerificationResult::Reject { tx_hash } => {
+                        dandelion.forget(&tx_hash);
                         self.shared.state().remove_from_known_txs(&tx_hash);
                     }
                 }
             }
+
+            // broadcast the stem transactions which are not announced by others in time
+            for tx_hash in dandelion.take_expired(now) {
+                for target in &connected_peers {
+                    let hashes = selected
+                        .entry(*target)
+                        .or_insert_with(|| Vec::with_capacity(BUFFER_SIZE));
+                    hashes.push(tx_hash.clone());
+                }
+            }
+        }
+        for (peer, tx_hashes) in stem {
+            self.send_stem_transactions(nc, peer, tx_hashes);
+        }
+        // the announcements to the reconciling peers wait for the next round
+        let flooded: Vec<(PeerIndex, Vec<Byte32>)> = {
+            let mut reconciliation = self.reconciliation.lock();
+            selected
+                .into_iter()
+                .map(|(peer, hashes)| (peer, reconciliation.announce(peer, hashes)))
+                .filter(|(_, hashes)| !hashes.is_empty())
+                .collect()
+        };
+        for (peer, hashes) in flooded {
+            self.send_tx_hashes(nc, peer, hashes);
         }
-        for (peer, hashes) in selected {
+    }
+
+    /// Announce the tx hashes to a peer
+    pub(crate) fn send_tx_hashes(
+        &self,
+        nc: &dyn CKBProtocolContext,
+        peer: PeerIndex,
+        hashes: Vec<Byte32>,
+    ) {
+        for hashes in hashes.chunks(MAX_RELAY_TXS_NUM_PER_BATCH) {
             let content = packed::RelayTransactionHashes::new_builder()
-                .tx_hashes(hashes.pack())
+                .tx_hashes(hashes.to_vec().pack())
                 .build();
             let message = packed::RelayMessage::new_builder().set(content).build();
 
@@ -691,6 +793,135 @@ impl Relayer {
             }
         }
     }
+
+    /// Start the set reconciliation rounds with the peers
+    pub fn request_reconciliation(&self, nc: &dyn CKBProtocolContext) {
+        let now = Instant::now();
+        let (stale, rounds) = {
+            let mut reconciliation = self.reconciliation.lock();
+            (
+                reconciliation.take_stale_queues(now),
+                reconciliation.start_rounds(now),
+            )
+        };
+        // the peers which don't start the rounds get the announcements flooded
+        for (peer, hashes) in stale {
+            if !hashes.is_empty() {
+                self.send_tx_hashes(nc, peer, hashes);
+            }
+        }
+        for (peer, salt, set_size) in rounds {
+            let content = packed::ReconciliationRequest::new_builder()
+                .salt(salt.pack())
+                .set_size(set_size.pack())
+                .build();
+            let message = packed::RelayMessage::new_builder().set(content).build();
+            let status = send_message_to(nc, peer, &message);
+            if !status.is_ok() {
+                debug_target!(
+                    crate::LOG_TARGET_RELAY,
+                    "relayer send ReconciliationRequest error: {:?}",
+                    status,
+                );
+            }
+        }
+    }
+
+    // Forwards the stem transactions to the stem peer, they are broadcast when their embargo
+    // ends if the forwarding fails
+    fn send_stem_transactions(
+        &self,
+        nc: &dyn CKBProtocolContext,
+        peer: PeerIndex,
+        tx_hashes: Vec<Byte32>,
+    ) {
+        let tx_pool = self.shared.shared().tx_pool_controller();
+        let fetch_txs_with_cycles = match tx_pool.fetch_txs_with_cycles(
+            tx_hashes
+                .iter()
+                .map(ProposalShortId::from_tx_hash)
+                .collect(),
+        ) {
+            Ok(fetch_txs_with_cycles) => fetch_txs_with_cycles,
+            Err(err) => {
+                debug_target!(
+                    crate::LOG_TARGET_RELAY,
+                    "relayer tx_pool_controller send fetch_txs_with_cycles error: {:?}",
+                    err,
+                );
+                return;
+            }
+        };
+
+        let mut relay_bytes = 0;
+        let mut relay_txs = Vec::new();
+        let mut batches = Vec::new();
+        for (_, (tx, cycles)) in fetch_txs_with_cycles {
+            let relay_tx = packed::RelayTransaction::new_builder()
+                .cycles(cycles.pack())
+                .transaction(tx.data())
+                .build();
+            if relay_bytes + relay_tx.total_size() > MAX_RELAY_TXS_BYTES_PER_BATCH
+                && !relay_txs.is_empty()
+            {
+                batches.push(std::mem::take(&mut relay_txs));
+                relay_bytes = 0;
+            }
+            relay_bytes += relay_tx.total_size();
+            relay_txs.push(relay_tx);
+        }
+        if !relay_txs.is_empty() {
+            batches.push(relay_txs);
+        }
+
+        for relay_txs in batches {
+            let content = packed::StemTransactions::new_builder()
+                .transactions(
+                    packed::RelayTransactionVec::new_builder()
+                        .set(relay_txs)
+                        .build(),
+                )
+                .build();
+            let message = packed::RelayMessage::new_builder().set(content).build();
+            let status = send_message_to(nc, peer, &message);
+            if !status.is_ok() {
+                debug_target!(
+                    crate::LOG_TARGET_RELAY,
+                    "relayer send StemTransactions error: {:?}",
+                    status,
+                );
+            }
+        }
+    }
+}
+
+fn stem_peers(nc: &dyn CKBProtocolContext) -> Vec<PeerIndex> {
+    nc.connected_peers()
+        .into_iter()
+        .filter(|peer| {
+            nc.get_peer(*peer)
+                .map(|peer| {
+                    peer.is_outbound()
+                        && !peer.is_block_relay_only
+                        && relay_version(nc, &peer) >= STEM_RELAY_VERSION
+                })
+                .unwrap_or(false)
+        })
+        .collect()
+}
+
+// The negotiated version of the relay protocol with a peer
+fn relay_version(nc: &dyn CKBProtocolContext, peer: &Peer) -> u32 {
+    peer.protocols
+        .get(&nc.protocol_id())
+        .and_then(|version| version.parse().ok())
+        .unwrap_or(0)
+}
+
+fn is_block_relay_only(nc: &dyn CKBProtocolContext, peer: PeerIndex) -> bool {
+    nc.get_peer(peer)
+        .map(|peer| peer.is_block_relay_only)
+        .unwrap_or(false)
 }
 
 impl CKBProtocolHandler for Relayer {
@@ -704,6 +935,8 @@ impl CKBProtocolHandler for Relayer {
         // todo: remove when the asynchronous verification is completed
         nc.set_notify(Duration::from_secs(5), SEARCH_ORPHAN_POOL_TOKEN)
             .expect("set_notify at init is ok");
+        nc.set_notify(Duration::from_secs(2), RECONCILIATION_TOKEN)
+            .expect("set_notify at init is ok");
     }
 
     fn received(
@@ -807,11 +1040,20 @@ impl CKBProtocolHandler for Relayer {
 
     fn connected(
         &mut self,
-        _nc: Arc<dyn CKBProtocolContext + Sync>,
+        nc: Arc<dyn CKBProtocolContext + Sync>,
         peer_index: PeerIndex,
         version: &str,
     ) {
         self.shared().state().peers().relay_connected(peer_index);
+        if let Some(peer) = nc.get_peer(peer_index) {
+            if !peer.is_block_relay_only
+                && relay_version(nc.as_ref(), &peer) >= RECONCILIATION_RELAY_VERSION
+            {
+                self.reconciliation
+                    .lock()
+                    .register(peer_index, peer.is_outbound(), Instant::now());
+            }
+        }
         info_target!(
             crate::LOG_TARGET_RELAY,
             "RelayProtocol({}).connected peer={}",
@@ -826,6 +1068,7 @@ impl CKBProtocolHandler for Relayer {
             "RelayProtocol.disconnected peer={}",
             peer_index
         );
+        self.reconciliation.lock().remove(peer_index);
         // Retains all keys in the rate limiter that were used recently enough.
         self.rate_limiter.lock().retain_recent();
     }
@@ -851,6 +1094,9 @@ impl CKBProtocolHandler for Relayer {
                 if nc.remove_notify(SEARCH_ORPHAN_POOL_TOKEN).is_err() {
                     trace_target!(crate::LOG_TARGET_RELAY, "remove v1 relay notify fail");
                 }
+                if nc.remove_notify(RECONCILIATION_TOKEN).is_err() {
+                    trace_target!(crate::LOG_TARGET_RELAY, "remove v1 relay notify fail");
+                }
                 for kv_pair in self.shared().state().peers().state.iter() {
                     let (peer, state) = kv_pair.pair();
                     if !state.peer_flags.is_2021edition {
@@ -870,6 +1116,7 @@ impl CKBProtocolHandler for Relayer {
             }
             ASK_FOR_TXS_TOKEN => self.ask_for_txs(nc.as_ref()),
             TX_HASHES_TOKEN => self.send_bulk_of_tx_hashes(nc.as_ref()),
+            RECONCILIATION_TOKEN => self.request_reconciliation(nc.as_ref()),
             SEARCH_ORPHAN_POOL_TOKEN => {
                 if !self.shared.state().orphan_pool().is_empty() {
                     tokio::task::block_in_place(|| {
@@ -906,4 +1153,27 @@ impl RelaySwitch {
             (false, false) => Self::Ckb2019RelayV1,
         }
     }
+
+    // after ckb2021, v1 doesn't work with relay tx
+    // before ckb2021, v2 doesn't work with relay tx
+    fn relays_transactions(self) -> bool {
+        match self {
+            Self::Ckb2021RelayV2 | Self::Ckb2019RelayV1 => true,
+            Self::Ckb2021RelayV1 | Self::Ckb2019RelayV2 => false,
+        }
+    }
+}
+
+// The messages which relay transactions
+fn is_transaction_relay(message: &packed::RelayMessageUnionReader<'_>) -> bool {
+    matches!(
+        message,
+        packed::RelayMessageUnionReader::RelayTransactions(_)
+            | packed::RelayMessageUnionReader::RelayTransactionHashes(_)
+            | packed::RelayMessageUnionReader::GetRelayTransactions(_)
+            | packed::RelayMessageUnionReader::StemTransactions(_)
+            | packed::RelayMessageUnionReader::ReconciliationRequest(_)
+            | packed::RelayMessageUnionReader::ReconciliationSketch(_)
+            | packed::RelayMessageUnionReader::ReconciliationDifference(_)
+    )
 }
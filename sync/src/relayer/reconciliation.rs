@@ -0,0 +1,295 @@
+//! Set reconciliation of the transaction announcements.
+//!
+//! Instead of flooding every transaction hash to every peer, the hashes for a reconciling peer
+//! are queued, and every round the outbound side of the connection asks the peer for a sketch
+//! of its queue. The sketches of both queues are merged to recover the short ids of the
+//! transactions which only one side knows, and each side announces just those. When the
+//! difference is too large to be recovered, both sides flood their queues. A few outbound
+//! peers keep the flooding, so the transactions still spread fast through the network.
+//!
+//! The inbound side can't tell whether the peer keeps flooding to it, so when the peer doesn't
+//! start a round in time, the queue is flooded and so are the later announcements, until the
+//! peer starts a round again.
+use crate::relayer::sketch::Sketch;
+use ckb_hash::new_blake2b;
+use ckb_network::PeerIndex;
+use ckb_types::packed::Byte32;
+use rand::{thread_rng, Rng};
+use std::collections::{HashMap, HashSet};
+use std::time::{Duration, Instant};
+
+/// The version of the relay protocol which supports the set reconciliation.
+pub const RECONCILIATION_RELAY_VERSION: u32 = 4;
+/// The capacity limit of a sketch, the decoding cost grows quadratically with it.
+pub(crate) const MAX_SKETCH_CAPACITY: usize = 128;
+// The outbound reconciling peers which still get the flooded announcements
+const FLOOD_OUTBOUND_PEERS: usize = 2;
+// The queued announcements of a peer, the ones beyond it are flooded
+const MAX_QUEUE_SIZE: usize = 4096;
+// A round which is not finished in time is abandoned, its transactions are queued again
+pub(crate) const ROUND_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// The short id of a transaction in a reconciliation round.
+pub(crate) fn short_id(salt: u64, tx_hash: &Byte32) -> u32 {
+    let mut hasher = new_blake2b();
+    hasher.update(&salt.to_le_bytes());
+    hasher.update(tx_hash.as_slice());
+    let mut hash = [0u8; 32];
+    hasher.finalize(&mut hash);
+    let mut bytes = [0u8; 4];
+    bytes.copy_from_slice(&hash[..4]);
+    // zero can't be added into a sketch
+    u32::from_le_bytes(bytes).max(1)
+}
+
+/// The sketch capacity to reconcile two sets of the given sizes.
+pub(crate) fn sketch_capacity(local_size: usize, remote_size: usize) -> usize {
+    let difference = local_size.max(remote_size) - local_size.min(remote_size);
+    // the sets mostly overlap, one extra power sum is the checksum of the decoding
+    (difference + local_size.min(remote_size) / 4 + 2).min(MAX_SKETCH_CAPACITY)
+}
+
+/// The result of a reconciliation round on the initiator side.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub(crate) struct Difference {
+    /// Whether the difference is recovered.
+    pub(crate) success: bool,
+    /// The transactions to announce to the peer.
+    pub(crate) announce: Vec<Byte32>,
+    /// The short ids of the transactions which only the peer knows.
+    pub(crate) missing: Vec<u32>,
+    /// The transactions which the peer knows too.
+    pub(crate) known: Vec<Byte32>,
+}
+
+struct Round {
+    salt: u64,
+    started_at: Instant,
+    txs: HashMap<u32, Byte32>,
+}
+
+struct PeerState {
+    // the outbound side of the connection initiates the rounds
+    is_initiator: bool,
+    queue: HashSet<Byte32>,
+    round: Option<Round>,
+    // when the last round started, or the peer was registered
+    last_round_at: Instant,
+    // the inbound side floods the announcements since the peer doesn't start the rounds
+    flooding: bool,
+}
+
+impl PeerState {
+    // Moves the queued transactions into a new round, the ones whose short id collides stay
+    // in the queue for the next round
+    fn start_round(&mut self, salt: u64, now: Instant) -> &Round {
+        self.abandon_round();
+        self.last_round_at = now;
+        let mut txs = HashMap::with_capacity(self.queue.len());
+        let mut collided = HashSet::new();
+        for tx_hash in self.queue.drain() {
+            let id = short_id(salt, &tx_hash);
+            if txs.contains_key(&id) {
+                collided.insert(tx_hash);
+            } else {
+                txs.insert(id, tx_hash);
+            }
+        }
+        self.queue = collided;
+        self.round.get_or_insert(Round {
+            salt,
+            started_at: now,
+            txs,
+        })
+    }
+
+    fn abandon_round(&mut self) {
+        if let Some(round) = self.round.take() {
+            self.queue
+                .extend(round.txs.into_iter().map(|(_, tx_hash)| tx_hash));
+        }
+    }
+}
+
+#[derive(Default)]
+pub(crate) struct Reconciliation {
+    peers: HashMap<PeerIndex, PeerState>,
+    flood_peers: HashSet<PeerIndex>,
+}
+
+impl Reconciliation {
+    /// Registers a peer which supports the set reconciliation.
+    pub(crate) fn register(&mut self, peer: PeerIndex, is_outbound: bool, now: Instant) {
+        if is_outbound && self.flood_peers.len() < FLOOD_OUTBOUND_PEERS {
+            self.flood_peers.insert(peer);
+            return;
+        }
+        self.peers.insert(
+            peer,
+            PeerState {
+                is_initiator: is_outbound,
+                queue: HashSet::new(),
+                round: None,
+                last_round_at: now,
+                flooding: false,
+            },
+        );
+    }
+
+    pub(crate) fn remove(&mut self, peer: PeerIndex) {
+        self.peers.remove(&peer);
+        self.flood_peers.remove(&peer);
+    }
+
+    pub(crate) fn is_reconciling(&self, peer: PeerIndex) -> bool {
+        self.peers.contains_key(&peer)
+    }
+
+    /// Queues the announcements to a peer, returns the ones which should be flooded.
+    pub(crate) fn announce(&mut self, peer: PeerIndex, tx_hashes: Vec<Byte32>) -> Vec<Byte32> {
+        let state = match self.peers.get_mut(&peer) {
+            Some(state) if !state.flooding => state,
+            _ => return tx_hashes,
+        };
+        let mut flood = Vec::new();
+        for tx_hash in tx_hashes {
+            if state.queue.len() < MAX_QUEUE_SIZE {
+                state.queue.insert(tx_hash);
+            } else {
+                flood.push(tx_hash);
+            }
+        }
+        flood
+    }
+
+    /// Starts the rounds with the peers which this node initiates the reconciliation with,
+    /// returns the peers with the salt and the size of the local set of each round.
+    pub(crate) fn start_rounds(&mut self, now: Instant) -> Vec<(PeerIndex, u64, u32)> {
+        let mut rng = thread_rng();
+        self.peers
+            .iter_mut()
+            .filter(|(_, state)| state.is_initiator)
+            .filter(|(_, state)| {
+                state
+                    .round
+                    .as_ref()
+                    .map(|round| now.saturating_duration_since(round.started_at) >= ROUND_TIMEOUT)
+                    .unwrap_or(true)
+            })
+            .map(|(peer, state)| {
+                let round = state.start_round(rng.gen(), now);
+                (*peer, round.salt, round.txs.len() as u32)
+            })
+            .collect()
+    }
+
+    /// Takes the queues of the peers which don't start a round in time, the peers get the
+    /// flooded announcements from now on, until they start a round again.
+    pub(crate) fn take_stale_queues(&mut self, now: Instant) -> Vec<(PeerIndex, Vec<Byte32>)> {
+        self.peers
+            .iter_mut()
+            .filter(|(_, state)| !state.is_initiator && !state.flooding)
+            .filter(|(_, state)| {
+                now.saturating_duration_since(state.last_round_at) >= ROUND_TIMEOUT
+            })
+            .map(|(peer, state)| {
+                state.abandon_round();
+                state.flooding = true;
+                (*peer, state.queue.drain().collect())
+            })
+            .collect()
+    }
+
+    /// Answers a reconciliation request with the sketch of the local set, returns none if the
+    /// peer is not supposed to initiate the reconciliation.
+    pub(crate) fn sketch(
+        &mut self,
+        peer: PeerIndex,
+        salt: u64,
+        remote_size: usize,
+        now: Instant,
+    ) -> Option<Sketch> {
+        let state = self
+            .peers
+            .get_mut(&peer)
+            .filter(|state| !state.is_initiator)?;
+        state.flooding = false;
+        let round = state.start_round(salt, now);
+        let mut sketch = Sketch::new(sketch_capacity(round.txs.len(), remote_size));
+        for id in round.txs.keys() {
+            sketch.add(*id);
+        }
+        Some(sketch)
+    }
+
+    /// Reconciles the local set with the sketch of the peer, returns none if there is no
+    /// round waiting for the sketch.
+    pub(crate) fn reconcile(&mut self, peer: PeerIndex, remote: &Sketch) -> Option<Difference> {
+        let round = self
+            .peers
+            .get_mut(&peer)
+            .filter(|state| state.is_initiator)?
+            .round
+            .take()?;
+        let mut sketch = Sketch::new(remote.capacity());
+        for id in round.txs.keys() {
+            sketch.add(*id);
+        }
+        sketch.merge(remote);
+
+        let mut txs = round.txs;
+        match sketch.decode() {
+            Some(ids) => {
+                let mut difference = Difference {
+                    success: true,
+                    ..Default::default()
+                };
+                for id in ids {
+                    match txs.remove(&id) {
+                        Some(tx_hash) => difference.announce.push(tx_hash),
+                        None => difference.missing.push(id),
+                    }
+                }
+                difference.known = txs.into_iter().map(|(_, tx_hash)| tx_hash).collect();
+                Some(difference)
+            }
+            None => Some(Difference {
+                success: false,
+                announce: txs.into_iter().map(|(_, tx_hash)| tx_hash).collect(),
+                ..Default::default()
+            }),
+        }
+    }
+
+    /// Finishes the round answered by the local sketch with the difference recovered by the
+    /// peer, or returns none if there is no such round.
+    pub(crate) fn finish(
+        &mut self,
+        peer: PeerIndex,
+        success: bool,
+        missing: &[u32],
+    ) -> Option<Difference> {
+        let mut txs = self
+            .peers
+            .get_mut(&peer)
+            .filter(|state| !state.is_initiator)?
+            .round
+            .take()?
+            .txs;
+        if success {
+            let announce = missing.iter().filter_map(|id| txs.remove(id)).collect();
+            Some(Difference {
+                success,
+                announce,
+                missing: Vec::new(),
+                known: txs.into_iter().map(|(_, tx_hash)| tx_hash).collect(),
+            })
+        } else {
+            Some(Difference {
+                success,
+                announce: txs.into_iter().map(|(_, tx_hash)| tx_hash).collect(),
+                ..Default::default()
+            })
+        }
+    }
+}
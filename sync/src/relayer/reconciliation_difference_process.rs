@@ -0,0 +1,60 @@
+use crate::relayer::reconciliation::MAX_SKETCH_CAPACITY;
+use crate::relayer::Relayer;
+use crate::{Status, StatusCode};
+use ckb_network::{CKBProtocolContext, PeerIndex};
+use ckb_types::{packed, prelude::*};
+use std::sync::Arc;
+
+pub struct ReconciliationDifferenceProcess<'a> {
+    message: packed::ReconciliationDifferenceReader<'a>,
+    relayer: &'a Relayer,
+    nc: Arc<dyn CKBProtocolContext>,
+    peer: PeerIndex,
+}
+
+impl<'a> ReconciliationDifferenceProcess<'a> {
+    pub fn new(
+        message: packed::ReconciliationDifferenceReader<'a>,
+        relayer: &'a Relayer,
+        nc: Arc<dyn CKBProtocolContext>,
+        peer: PeerIndex,
+    ) -> Self {
+        ReconciliationDifferenceProcess {
+            message,
+            relayer,
+            nc,
+            peer,
+        }
+    }
+
+    pub fn execute(self) -> Status {
+        // a recovered difference never has more elements than the sketch capacity
+        if self.message.short_ids().len() > MAX_SKETCH_CAPACITY {
+            return StatusCode::ProtocolMessageIsMalformed.with_context(format!(
+                "ReconciliationDifference count({}) > MAX_SKETCH_CAPACITY({})",
+                self.message.short_ids().len(),
+                MAX_SKETCH_CAPACITY,
+            ));
+        }
+
+        let success: bool = self.message.success().unpack();
+        let missing: Vec<u32> = self.message.short_ids().unpack();
+        let difference = match self
+            .relayer
+            .reconciliation
+            .lock()
+            .finish(self.peer, success, &missing)
+        {
+            Some(difference) => difference,
+            None => return Status::ignored(),
+        };
+
+        // the stem transactions which the peer knows too are in the fluff phase
+        self.relayer.dandelion.lock().fluffed(difference.known);
+        if !difference.announce.is_empty() {
+            self.relayer
+                .send_tx_hashes(self.nc.as_ref(), self.peer, difference.announce);
+        }
+        Status::ok()
+    }
+}
@@ -0,0 +1,50 @@
+use crate::relayer::Relayer;
+use crate::utils::send_message_to;
+use crate::Status;
+use ckb_network::{CKBProtocolContext, PeerIndex};
+use ckb_types::{packed, prelude::*};
+use std::sync::Arc;
+use std::time::Instant;
+
+pub struct ReconciliationRequestProcess<'a> {
+    message: packed::ReconciliationRequestReader<'a>,
+    relayer: &'a Relayer,
+    nc: Arc<dyn CKBProtocolContext>,
+    peer: PeerIndex,
+}
+
+impl<'a> ReconciliationRequestProcess<'a> {
+    pub fn new(
+        message: packed::ReconciliationRequestReader<'a>,
+        relayer: &'a Relayer,
+        nc: Arc<dyn CKBProtocolContext>,
+        peer: PeerIndex,
+    ) -> Self {
+        ReconciliationRequestProcess {
+            message,
+            relayer,
+            nc,
+            peer,
+        }
+    }
+
+    pub fn execute(self) -> Status {
+        let salt: u64 = self.message.salt().unpack();
+        let set_size: u32 = self.message.set_size().unpack();
+        let sketch = match self.relayer.reconciliation.lock().sketch(
+            self.peer,
+            salt,
+            set_size as usize,
+            Instant::now(),
+        ) {
+            Some(sketch) => sketch,
+            None => return Status::ignored(),
+        };
+
+        let content = packed::ReconciliationSketch::new_builder()
+            .sketch(sketch.to_vec().pack())
+            .build();
+        let message = packed::RelayMessage::new_builder().set(content).build();
+        send_message_to(self.nc.as_ref(), self.peer, &message)
+    }
+}
@@ -0,0 +1,69 @@
+use crate::relayer::reconciliation::MAX_SKETCH_CAPACITY;
+use crate::relayer::sketch::Sketch;
+use crate::relayer::Relayer;
+use crate::utils::send_message_to;
+use crate::{Status, StatusCode};
+use ckb_network::{CKBProtocolContext, PeerIndex};
+use ckb_types::{packed, prelude::*};
+use std::sync::Arc;
+
+pub struct ReconciliationSketchProcess<'a> {
+    message: packed::ReconciliationSketchReader<'a>,
+    relayer: &'a Relayer,
+    nc: Arc<dyn CKBProtocolContext>,
+    peer: PeerIndex,
+}
+
+impl<'a> ReconciliationSketchProcess<'a> {
+    pub fn new(
+        message: packed::ReconciliationSketchReader<'a>,
+        relayer: &'a Relayer,
+        nc: Arc<dyn CKBProtocolContext>,
+        peer: PeerIndex,
+    ) -> Self {
+        ReconciliationSketchProcess {
+            message,
+            relayer,
+            nc,
+            peer,
+        }
+    }
+
+    pub fn execute(self) -> Status {
+        let sketch = match Sketch::from_slice(self.message.sketch().raw_data()) {
+            Some(sketch) if sketch.capacity() > 0 && sketch.capacity() <= MAX_SKETCH_CAPACITY => {
+                sketch
+            }
+            _ => {
+                return StatusCode::ProtocolMessageIsMalformed.with_context(format!(
+                    "ReconciliationSketch of {} bytes is invalid, the capacity limit is {}",
+                    self.message.sketch().raw_data().len(),
+                    MAX_SKETCH_CAPACITY,
+                ))
+            }
+        };
+
+        let difference = match self
+            .relayer
+            .reconciliation
+            .lock()
+            .reconcile(self.peer, &sketch)
+        {
+            Some(difference) => difference,
+            None => return Status::ignored(),
+        };
+
+        // the stem transactions which the peer knows too are in the fluff phase
+        self.relayer.dandelion.lock().fluffed(difference.known);
+        if !difference.announce.is_empty() {
+            self.relayer
+                .send_tx_hashes(self.nc.as_ref(), self.peer, difference.announce);
+        }
+        let content = packed::ReconciliationDifference::new_builder()
+            .success(difference.success.pack())
+            .short_ids(difference.missing.pack())
+            .build();
+        let message = packed::RelayMessage::new_builder().set(content).build();
+        send_message_to(self.nc.as_ref(), self.peer, &message)
+    }
+}
@@ -0,0 +1,294 @@
+//! PinSketch set sketches of 32-bit elements.
+//!
+//! A sketch of capacity `c` stores the odd power sums `x, x^3, .., x^(2c-1)` of its elements
+//! over GF(2^32), it is `4 * c` bytes long. Sketches of two sets are merged by xor, which
+//! gives the sketch of their symmetric difference, and the difference is recovered from the
+//! merged sketch when it has less than `c` elements, the extra power sum works as a checksum
+//! which makes a wrong recovery unlikely. See https://github.com/sipa/minisketch for the
+//! details.
+
+// x^32 + x^7 + x^3 + x^2 + 1, an irreducible polynomial of degree 32
+const MODULUS: u64 = 0x1_0000_008d;
+// Give up splitting a polynomial after these many attempts, it rarely takes more than 2
+const MAX_SPLIT_ATTEMPTS: u32 = 64;
+
+fn gf_mul(a: u32, b: u32) -> u32 {
+    let mut a = u64::from(a);
+    let mut b = b;
+    let mut r = 0u64;
+    while b != 0 {
+        if b & 1 == 1 {
+            r ^= a;
+        }
+        b >>= 1;
+        a <<= 1;
+        if a >> 32 != 0 {
+            a ^= MODULUS;
+        }
+    }
+    r as u32
+}
+
+// a^(2^32 - 2) is the inverse of a non-zero a
+fn gf_inv(a: u32) -> u32 {
+    let mut r = 1;
+    let mut base = a;
+    let mut exp = u32::max_value() - 1;
+    while exp != 0 {
+        if exp & 1 == 1 {
+            r = gf_mul(r, base);
+        }
+        base = gf_mul(base, base);
+        exp >>= 1;
+    }
+    r
+}
+
+/// A PinSketch of 32-bit non-zero elements.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Sketch {
+    syndromes: Vec<u32>,
+}
+
+impl Sketch {
+    /// Creates an empty sketch which can recover up to `capacity - 1` elements.
+    pub(crate) fn new(capacity: usize) -> Self {
+        Sketch {
+            syndromes: vec![0; capacity],
+        }
+    }
+
+    /// Parses a serialized sketch.
+    pub(crate) fn from_slice(data: &[u8]) -> Option<Self> {
+        if data.len() % 4 != 0 {
+            return None;
+        }
+        let syndromes = data
+            .chunks(4)
+            .map(|chunk| {
+                let mut bytes = [0u8; 4];
+                bytes.copy_from_slice(chunk);
+                u32::from_le_bytes(bytes)
+            })
+            .collect();
+        Some(Sketch { syndromes })
+    }
+
+    /// Serializes the sketch.
+    pub(crate) fn to_vec(&self) -> Vec<u8> {
+        self.syndromes
+            .iter()
+            .flat_map(|syndrome| syndrome.to_le_bytes().to_vec())
+            .collect()
+    }
+
+    /// The number of power sums in the sketch.
+    pub(crate) fn capacity(&self) -> usize {
+        self.syndromes.len()
+    }
+
+    /// Adds an element, adding it again removes it. Zero is ignored.
+    pub(crate) fn add(&mut self, element: u32) {
+        if element == 0 {
+            return;
+        }
+        let square = gf_mul(element, element);
+        let mut power = element;
+        for syndrome in self.syndromes.iter_mut() {
+            *syndrome ^= power;
+            power = gf_mul(power, square);
+        }
+    }
+
+    /// Merges another sketch of the same capacity, the result is the sketch of the symmetric
+    /// difference of the two sets.
+    pub(crate) fn merge(&mut self, other: &Sketch) {
+        for (syndrome, other) in self.syndromes.iter_mut().zip(other.syndromes.iter()) {
+            *syndrome ^= other;
+        }
+    }
+
+    /// Recovers the elements, fails when there are `capacity` or more elements.
+    pub(crate) fn decode(&self) -> Option<Vec<u32>> {
+        let capacity = self.capacity();
+        // all the power sums s_1 .. s_2c, the even ones are s_2i = s_i ^ 2
+        let mut sums = vec![0u32; 2 * capacity];
+        for (i, syndrome) in self.syndromes.iter().enumerate() {
+            sums[2 * i] = *syndrome;
+        }
+        for i in 1..=capacity {
+            sums[2 * i - 1] = gf_mul(sums[i - 1], sums[i - 1]);
+        }
+        if sums.iter().all(|sum| *sum == 0) {
+            return Some(Vec::new());
+        }
+
+        // the locator polynomial, whose roots are the inverses of the elements
+        let locator = berlekamp_massey(&sums);
+        let degree = locator.len() - 1;
+        if degree == 0 || degree >= capacity || locator[degree] == 0 {
+            return None;
+        }
+        // reverse it to get the polynomial whose roots are the elements
+        let poly: Vec<u32> = locator.into_iter().rev().collect();
+        let roots = find_roots(poly)?;
+        if roots.len() != degree || roots.contains(&0) {
+            return None;
+        }
+
+        // the recovered elements must make up the same sketch
+        let mut sketch = Sketch::new(capacity);
+        for root in &roots {
+            sketch.add(*root);
+        }
+        if sketch != *self {
+            return None;
+        }
+        Some(roots)
+    }
+}
+
+fn berlekamp_massey(sums: &[u32]) -> Vec<u32> {
+    let mut current = vec![1u32];
+    let mut previous = vec![1u32];
+    let mut length = 0;
+    let mut shift = 1;
+    let mut previous_discrepancy = 1u32;
+    for (n, sum) in sums.iter().enumerate() {
+        let mut discrepancy = *sum;
+        for i in 1..=length.min(current.len() - 1) {
+            discrepancy ^= gf_mul(current[i], sums[n - i]);
+        }
+        if discrepancy == 0 {
+            shift += 1;
+            continue;
+        }
+        let coef = gf_mul(discrepancy, gf_inv(previous_discrepancy));
+        let mut next = current.clone();
+        if next.len() < previous.len() + shift {
+            next.resize(previous.len() + shift, 0);
+        }
+        for (i, value) in previous.iter().enumerate() {
+            next[i + shift] ^= gf_mul(coef, *value);
+        }
+        if 2 * length <= n {
+            length = n + 1 - length;
+            previous = current;
+            previous_discrepancy = discrepancy;
+            shift = 1;
+        } else {
+            shift += 1;
+        }
+        current = next;
+    }
+    current.resize(length + 1, 0);
+    current
+}
+
+// Polynomials are coefficient vectors starting from the constant term, with the trailing
+// zeros trimmed
+
+fn trim(mut poly: Vec<u32>) -> Vec<u32> {
+    while poly.last() == Some(&0) {
+        poly.pop();
+    }
+    poly
+}
+
+fn make_monic(poly: Vec<u32>) -> Vec<u32> {
+    let inv = gf_inv(*poly.last().expect("non-zero polynomial"));
+    poly.into_iter().map(|coef| gf_mul(coef, inv)).collect()
+}
+
+// Divides by a monic polynomial, returns the quotient and the remainder
+fn div_rem(mut poly: Vec<u32>, divisor: &[u32]) -> (Vec<u32>, Vec<u32>) {
+    let divisor_degree = divisor.len() - 1;
+    if poly.len() < divisor.len() {
+        return (Vec::new(), poly);
+    }
+    let mut quotient = vec![0u32; poly.len() - divisor_degree];
+    for i in (divisor_degree..poly.len()).rev() {
+        let coef = poly[i];
+        if coef == 0 {
+            continue;
+        }
+        quotient[i - divisor_degree] = coef;
+        for (j, value) in divisor.iter().enumerate() {
+            poly[i - divisor_degree + j] ^= gf_mul(coef, *value);
+        }
+    }
+    poly.truncate(divisor_degree);
+    (trim(quotient), trim(poly))
+}
+
+// Squares modulo a monic polynomial, in characteristic 2 (sum a_i z^i)^2 = sum a_i^2 z^2i
+fn square_mod(poly: &[u32], modulus: &[u32]) -> Vec<u32> {
+    let mut square = vec![0u32; poly.len() * 2];
+    for (i, coef) in poly.iter().enumerate() {
+        square[2 * i] = gf_mul(*coef, *coef);
+    }
+    div_rem(trim(square), modulus).1
+}
+
+fn gcd(mut a: Vec<u32>, mut b: Vec<u32>) -> Vec<u32> {
+    while !b.is_empty() {
+        let monic = make_monic(b);
+        let rem = div_rem(a, &monic).1;
+        a = monic;
+        b = rem;
+    }
+    a
+}
+
+// Finds the roots of a polynomial which splits into distinct linear factors
+fn find_roots(poly: Vec<u32>) -> Option<Vec<u32>> {
+    let poly = make_monic(trim(poly));
+    if poly.len() == 2 {
+        return Some(vec![poly[0]]);
+    }
+
+    // z^(2^32) = z modulo the polynomial iff it splits into distinct linear factors
+    let z = div_rem(vec![0, 1], &poly).1;
+    let mut power = z.clone();
+    for _ in 0..32 {
+        power = square_mod(&power, &poly);
+    }
+    if power != z {
+        return None;
+    }
+
+    let mut roots = Vec::with_capacity(poly.len() - 1);
+    let mut pending = vec![poly];
+    let mut beta = 1u32;
+    while let Some(poly) = pending.pop() {
+        if poly.len() == 2 {
+            roots.push(poly[0]);
+            continue;
+        }
+        let mut split = None;
+        for _ in 0..MAX_SPLIT_ATTEMPTS {
+            // Tr(beta * z) splits the roots by the trace of beta * root
+            let mut term = div_rem(vec![0, beta], &poly).1;
+            let mut trace = term.clone();
+            for _ in 1..32 {
+                term = square_mod(&term, &poly);
+                trace.resize(trace.len().max(term.len()), 0);
+                for (i, coef) in term.iter().enumerate() {
+                    trace[i] ^= coef;
+                }
+            }
+            beta = gf_mul(beta, 3);
+            let factor = gcd(poly.clone(), trim(trace));
+            if factor.len() > 1 && factor.len() < poly.len() {
+                let factor = make_monic(factor);
+                let quotient = div_rem(poly.clone(), &factor).0;
+                split = Some((factor, make_monic(quotient)));
+                break;
+            }
+        }
+        let (factor, quotient) = split?;
+        pending.push(factor);
+        pending.push(quotient);
+    }
+    Some(roots)
+}
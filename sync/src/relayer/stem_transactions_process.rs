@@ -0,0 +1,103 @@
+use crate::relayer::{Relayer, DEFAULT_BAN_TIME, MAX_RELAY_TXS_NUM_PER_BATCH};
+use crate::{Status, StatusCode};
+use ckb_logger::error;
+use ckb_network::{CKBProtocolContext, PeerIndex};
+use ckb_types::{
+    core::{Cycle, TransactionView},
+    packed,
+    prelude::*,
+};
+use std::sync::Arc;
+use std::time::Instant;
+
+pub struct StemTransactionsProcess<'a> {
+    message: packed::StemTransactionsReader<'a>,
+    relayer: &'a Relayer,
+    nc: Arc<dyn CKBProtocolContext + Sync>,
+    peer: PeerIndex,
+}
+
+impl<'a> StemTransactionsProcess<'a> {
+    pub fn new(
+        message: packed::StemTransactionsReader<'a>,
+        relayer: &'a Relayer,
+        nc: Arc<dyn CKBProtocolContext + Sync>,
+        peer: PeerIndex,
+    ) -> Self {
+        StemTransactionsProcess {
+            message,
+            relayer,
+            nc,
+            peer,
+        }
+    }
+
+    pub fn execute(self) -> Status {
+        if self.message.transactions().len() > MAX_RELAY_TXS_NUM_PER_BATCH {
+            return StatusCode::ProtocolMessageIsMalformed.with_context(format!(
+                "StemTransactions count({}) > MAX_RELAY_TXS_NUM_PER_BATCH({})",
+                self.message.transactions().len(),
+                MAX_RELAY_TXS_NUM_PER_BATCH,
+            ));
+        }
+
+        let shared_state = self.relayer.shared().state();
+        // stem transactions are pushed without being requested, ignore the known ones
+        let txs: Vec<(TransactionView, Cycle)> = {
+            let tx_filter = shared_state.tx_filter();
+            self.message
+                .transactions()
+                .iter()
+                .map(|tx| {
+                    (
+                        tx.transaction().to_entity().into_view(),
+                        tx.cycles().unpack(),
+                    )
+                })
+                .filter(|(tx, _)| !tx_filter.contains(&tx.hash()))
+                .collect()
+        };
+
+        if txs.is_empty() {
+            return Status::ok();
+        }
+
+        let max_block_cycles = self.relayer.shared().consensus().max_block_cycles();
+        if txs
+            .iter()
+            .any(|(_, declared_cycles)| declared_cycles > &max_block_cycles)
+        {
+            self.nc.ban_peer(
+                self.peer,
+                DEFAULT_BAN_TIME,
+                String::from("relay declared cycles greater than max_block_cycles"),
+            );
+            return Status::ok();
+        }
+
+        shared_state.mark_as_known_txs(txs.iter().map(|(tx, _)| tx.hash()));
+        self.relayer
+            .dandelion
+            .lock()
+            .stem_received(txs.iter().map(|(tx, _)| tx.hash()), Instant::now());
+
+        let tx_pool = self.relayer.shared.shared().tx_pool_controller().clone();
+        let peer = self.peer;
+        self.relayer
+            .shared
+            .shared()
+            .async_handle()
+            .spawn(async move {
+                for (tx, declared_cycles) in txs {
+                    if let Err(e) = tx_pool
+                        .submit_remote_tx(tx.clone(), declared_cycles, peer)
+                        .await
+                    {
+                        error!("submit_tx error {}", e);
+                    }
+                }
+            });
+
+        Status::ok()
+    }
+}
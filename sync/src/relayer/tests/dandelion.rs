@@ -0,0 +1,112 @@
+use crate::relayer::dandelion::{Dandelion, Route, MAX_RECEIVED_STEM_TXS};
+use ckb_app_config::DandelionConfig;
+use ckb_network::PeerIndex;
+use ckb_types::packed::Byte32;
+use std::time::{Duration, Instant};
+
+#[test]
+fn test_stem_and_embargo() {
+    let mut dandelion = Dandelion::new(DandelionConfig {
+        enable: true,
+        fluff_probability: 0,
+        epoch_secs: 600,
+        embargo_secs: 30,
+    });
+    let stem_peer: PeerIndex = 1.into();
+    let other_peer: PeerIndex = 2.into();
+    let now = Instant::now();
+
+    // the local transactions are stemmed
+    let local_tx = Byte32::new([1u8; 32]);
+    assert_eq!(
+        dandelion.route(&local_tx, None, &[stem_peer], now),
+        Route::Stem(stem_peer)
+    );
+
+    // the transactions which are not received as stem transactions are broadcast
+    let fluff_tx = Byte32::new([2u8; 32]);
+    assert_eq!(
+        dandelion.route(&fluff_tx, Some(other_peer), &[stem_peer], now),
+        Route::Fluff
+    );
+
+    // the stem transactions are forwarded, but never back to the peer they come from
+    let stem_tx = Byte32::new([3u8; 32]);
+    let returned_tx = Byte32::new([4u8; 32]);
+    dandelion.stem_received(vec![stem_tx.clone(), returned_tx.clone()], now);
+    assert_eq!(
+        dandelion.route(&stem_tx, Some(other_peer), &[stem_peer], now),
+        Route::Stem(stem_peer)
+    );
+    assert_eq!(
+        dandelion.route(&returned_tx, Some(stem_peer), &[stem_peer], now),
+        Route::Fluff
+    );
+
+    // the embargo is lifted once the transaction is announced by others
+    dandelion.fluffed(vec![stem_tx]);
+    assert!(dandelion.take_expired(now).is_empty());
+    assert_eq!(
+        dandelion.take_expired(now + Duration::from_secs(60)),
+        vec![local_tx]
+    );
+}
+
+#[test]
+fn test_disabled() {
+    let mut dandelion = Dandelion::new(DandelionConfig::default());
+    let stem_peer: PeerIndex = 1.into();
+    let tx_hash = Byte32::new([1u8; 32]);
+    let now = Instant::now();
+    dandelion.stem_received(vec![tx_hash.clone()], now);
+    assert_eq!(
+        dandelion.route(&tx_hash, None, &[stem_peer], now),
+        Route::Fluff
+    );
+}
+
+#[test]
+fn test_received_stem_transactions_are_bounded() {
+    let mut dandelion = Dandelion::new(DandelionConfig {
+        enable: true,
+        fluff_probability: 0,
+        epoch_secs: 600,
+        embargo_secs: 30,
+    });
+    let stem_peer: PeerIndex = 1.into();
+    let other_peer: PeerIndex = 2.into();
+    let now = Instant::now();
+
+    // the stem transactions beyond the limit are fluffed
+    let tx_hashes: Vec<Byte32> = (0..=MAX_RECEIVED_STEM_TXS as u64)
+        .map(|i| {
+            let mut hash = [0u8; 32];
+            hash[..8].copy_from_slice(&i.to_le_bytes());
+            Byte32::new(hash)
+        })
+        .collect();
+    dandelion.stem_received(tx_hashes.clone(), now);
+    assert_eq!(
+        dandelion.route(
+            &tx_hashes[MAX_RECEIVED_STEM_TXS],
+            Some(other_peer),
+            &[stem_peer],
+            now
+        ),
+        Route::Fluff
+    );
+
+    // the stem transactions which are never routed are forgotten after the longest embargo
+    assert!(dandelion
+        .take_expired(now + Duration::from_secs(30))
+        .is_empty());
+    assert_eq!(
+        dandelion.route(&tx_hashes[0], Some(other_peer), &[stem_peer], now),
+        Route::Stem(stem_peer)
+    );
+    dandelion.take_expired(now + Duration::from_secs(60));
+    assert_eq!(
+        dandelion.route(&tx_hashes[1], Some(other_peer), &[stem_peer], now),
+        Route::Fluff
+    );
+}
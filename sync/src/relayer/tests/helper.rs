@@ -15,7 +15,7 @@ use ckb_types::prelude::*;
 use ckb_types::{
     bytes::Bytes,
     core::{
-        capacity_bytes, BlockBuilder, BlockNumber, Capacity, EpochNumberWithFraction,
+        capacity_bytes, BlockBuilder, BlockNumber, BlockView, Capacity, EpochNumberWithFraction,
         HeaderBuilder, HeaderView, TransactionBuilder, TransactionView,
     },
     packed::{
@@ -206,6 +206,35 @@ pub(crate) fn build_chain(tip: BlockNumber) -> (Relayer, OutPoint) {
     )
 }
 
+// Append a block with the transactions after its cellbase to the tip, skipping verification
+pub(crate) fn append_block(relayer: &Relayer, transactions: Vec<TransactionView>) -> BlockView {
+    let shared = relayer.shared.shared();
+    let parent = shared.snapshot().tip_header().clone();
+    let (_, _, always_success_script) = always_success_cell();
+    let cellbase = TransactionBuilder::default()
+        .input(CellInput::new_cellbase_input(parent.number() + 1))
+        .output(
+            CellOutputBuilder::default()
+                .capacity(capacity_bytes!(50000).pack())
+                .lock(always_success_script.to_owned())
+                .build(),
+        )
+        .output_data(Bytes::new().pack())
+        .witness(Script::default().into_witness())
+        .build();
+    let header = new_header_builder(shared, &parent).build();
+    let block = BlockBuilder::default()
+        .header(header)
+        .transaction(cellbase)
+        .transactions(transactions)
+        .build();
+    relayer
+        .chain
+        .internal_process_block(Arc::new(block.clone()), Switch::DISABLE_ALL)
+        .expect("processing block should be ok");
+    block
+}
+
 pub(crate) struct MockProtocolContext {
     protocol: SupportProtocols,
     sent_messages: RefCell<Vec<(ProtocolId, PeerIndex, P2pBytes)>>,
@@ -229,6 +258,10 @@ impl MockProtocolContext {
             .borrow()
             .contains(&(protocol_id, peer_index, data))
     }
+
+    pub(crate) fn sent_messages(&self) -> Vec<(ProtocolId, PeerIndex, P2pBytes)> {
+        self.sent_messages.borrow().clone()
+    }
 }
 
 impl CKBProtocolContext for MockProtocolContext {
@@ -4,5 +4,7 @@ mod block_transactions_verifier;
 mod compact_block;
 mod compact_block_process;
 mod compact_block_verifier;
-mod helper;
+mod dandelion;
+pub(crate) mod helper;
+mod reconciliation;
 mod reconstruct_block;
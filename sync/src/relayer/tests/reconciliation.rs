@@ -0,0 +1,119 @@
+use crate::relayer::reconciliation::{Reconciliation, ROUND_TIMEOUT};
+use ckb_network::PeerIndex;
+use ckb_types::packed::Byte32;
+use std::collections::HashSet;
+use std::time::Instant;
+
+fn tx_hashes(range: std::ops::RangeInclusive<u8>) -> Vec<Byte32> {
+    range.map(|i| Byte32::new([i; 32])).collect()
+}
+
+// The initiator fills its flood peers first, so that the peer under test reconciles
+fn initiator(peer: PeerIndex, now: Instant) -> Reconciliation {
+    let mut reconciliation = Reconciliation::default();
+    reconciliation.register(100.into(), true, now);
+    reconciliation.register(101.into(), true, now);
+    reconciliation.register(peer, true, now);
+    assert!(!reconciliation.is_reconciling(100.into()));
+    assert!(reconciliation.is_reconciling(peer));
+    reconciliation
+}
+
+#[test]
+fn test_reconciliation_round() {
+    let peer_a: PeerIndex = 1.into();
+    let peer_b: PeerIndex = 2.into();
+    let now = Instant::now();
+    let mut a = initiator(peer_b, now);
+    let mut b = Reconciliation::default();
+    b.register(peer_a, false, now);
+
+    // the announcements to the reconciling peers are queued
+    let mut a_txs = tx_hashes(1..=50);
+    a_txs.extend(tx_hashes(51..=53));
+    let mut b_txs = tx_hashes(1..=50);
+    b_txs.extend(tx_hashes(54..=55));
+    assert!(a.announce(peer_b, a_txs).is_empty());
+    assert!(b.announce(peer_a, b_txs).is_empty());
+    assert_eq!(a.announce(100.into(), tx_hashes(1..=1)).len(), 1);
+
+    // only the initiator starts the rounds
+    assert!(b.start_rounds(now).is_empty());
+    let rounds = a.start_rounds(now);
+    assert_eq!(rounds.len(), 1);
+    let (peer, salt, set_size) = rounds[0];
+    assert_eq!((peer, set_size), (peer_b, 53));
+    assert!(a.start_rounds(now).is_empty());
+
+    let sketch = b.sketch(peer_a, salt, set_size as usize, now).unwrap();
+    let difference = a.reconcile(peer_b, &sketch).unwrap();
+    assert!(difference.success);
+    assert_eq!(
+        difference.announce.into_iter().collect::<HashSet<_>>(),
+        tx_hashes(51..=53).into_iter().collect()
+    );
+    assert_eq!(difference.missing.len(), 2);
+    assert_eq!(
+        difference.known.into_iter().collect::<HashSet<_>>(),
+        tx_hashes(1..=50).into_iter().collect()
+    );
+    let finished = b.finish(peer_a, true, &difference.missing).unwrap();
+    assert_eq!(
+        finished.announce.into_iter().collect::<HashSet<_>>(),
+        tx_hashes(54..=55).into_iter().collect()
+    );
+    assert_eq!(finished.known.len(), 50);
+
+    // both sides flood their sets when the difference can't be recovered
+    assert!(a.announce(peer_b, tx_hashes(61..=70)).is_empty());
+    assert!(b.announce(peer_a, tx_hashes(71..=80)).is_empty());
+    let (_, salt, set_size) = a.start_rounds(now)[0];
+    let sketch = b.sketch(peer_a, salt, set_size as usize, now).unwrap();
+    let difference = a.reconcile(peer_b, &sketch).unwrap();
+    assert!(!difference.success);
+    assert_eq!(difference.announce.len(), 10);
+    assert!(difference.known.is_empty());
+    let finished = b.finish(peer_a, false, &[]).unwrap();
+    assert_eq!(finished.announce.len(), 10);
+    assert!(finished.known.is_empty());
+
+    // the rounds are gone
+    assert!(a.reconcile(peer_b, &sketch).is_none());
+    assert!(b.finish(peer_a, true, &[]).is_none());
+}
+
+#[test]
+fn test_flood_peer_gets_queued_announcements() {
+    let peer_a: PeerIndex = 1.into();
+    let peer_b: PeerIndex = 2.into();
+    let now = Instant::now();
+    // a connects to b, and b is one of the flood peers of a
+    let mut a = Reconciliation::default();
+    a.register(peer_b, true, now);
+    let mut b = Reconciliation::default();
+    b.register(peer_a, false, now);
+    assert!(!a.is_reconciling(peer_b));
+    assert!(b.is_reconciling(peer_a));
+
+    // a floods to b, and never starts a round with it
+    assert_eq!(a.announce(peer_b, tx_hashes(1..=10)).len(), 10);
+    assert!(a.start_rounds(now).is_empty());
+    assert!(b.announce(peer_a, tx_hashes(11..=20)).is_empty());
+    assert!(b.take_stale_queues(now).is_empty());
+
+    // b floods the queue once no round is started in time, and the later announcements too
+    let stale = b.take_stale_queues(now + ROUND_TIMEOUT);
+    assert_eq!(stale.len(), 1);
+    assert_eq!(stale[0].0, peer_a);
+    assert_eq!(
+        stale[0].1.iter().cloned().collect::<HashSet<_>>(),
+        tx_hashes(11..=20).into_iter().collect()
+    );
+    assert_eq!(b.announce(peer_a, tx_hashes(21..=30)).len(), 10);
+    assert!(b.take_stale_queues(now + ROUND_TIMEOUT * 2).is_empty());
+
+    // the announcements are queued again once the peer starts a round
+    let later = now + ROUND_TIMEOUT * 2;
+    assert!(b.sketch(peer_a, 1, 0, later).is_some());
+    assert!(b.announce(peer_a, tx_hashes(31..=40)).is_empty());
+}
@@ -35,6 +35,12 @@ impl<'a> TransactionHashesProcess<'a> {
             }
         }
 
+        // the stem transactions announced by others are in the fluff phase
+        self.relayer
+            .dandelion
+            .lock()
+            .fluffed(self.message.tx_hashes().iter().map(|x| x.to_entity()));
+
         let tx_hashes: Vec<_> = {
             let tx_filter = state.tx_filter();
             self.message
@@ -1,16 +1,13 @@
-use crate::relayer::Relayer;
+use crate::relayer::{Relayer, DEFAULT_BAN_TIME};
 use crate::Status;
 use ckb_logger::error;
-use ckb_network::{CKBProtocolContext, PeerIndex};
+use ckb_network::{Behaviour, CKBProtocolContext, PeerIndex};
 use ckb_types::{
     core::{Cycle, TransactionView},
     packed,
     prelude::*,
 };
 use std::sync::Arc;
-use std::time::Duration;
-
-const DEFAULT_BAN_TIME: Duration = Duration::from_secs(3600 * 24 * 3);
 
 pub struct TransactionsProcess<'a> {
     message: packed::RelayTransactionsReader<'a>,
@@ -36,6 +33,7 @@ impl<'a> TransactionsProcess<'a> {
 
     pub fn execute(self) -> Status {
         let shared_state = self.relayer.shared().state();
+        let mut unrequested = false;
         let txs: Vec<(TransactionView, Cycle)> = {
             // ignore the tx if it's already known or it has never been requested before
             let tx_filter = shared_state.tx_filter();
@@ -51,15 +49,27 @@ impl<'a> TransactionsProcess<'a> {
                     )
                 })
                 .filter(|(tx, _)| {
-                    !tx_filter.contains(&tx.hash())
-                        && unknown_tx_hashes
-                            .get_priority(&tx.hash())
-                            .map(|priority| priority.requesting_peer() == Some(self.peer))
-                            .unwrap_or_default()
+                    if tx_filter.contains(&tx.hash()) {
+                        return false;
+                    }
+                    match unknown_tx_hashes.get_priority(&tx.hash()) {
+                        Some(priority) if priority.requesting_peer() == Some(self.peer) => true,
+                        _ => {
+                            // the response may arrive after the request timed out
+                            if !shared_state.is_tx_requested(&tx.hash(), self.peer) {
+                                unrequested = true;
+                            }
+                            false
+                        }
+                    }
                 })
                 .collect()
         };
 
+        if unrequested {
+            self.nc.report_peer(self.peer, Behaviour::UnrequestedData);
+        }
+
         if txs.is_empty() {
             return Status::ok();
         }
@@ -1,4 +1,5 @@
 use ckb_constant::sync::{BAD_MESSAGE_BAN_TIME, SYNC_USELESS_BAN_TIME};
+use ckb_network::Behaviour;
 use std::fmt::{self, Display, Formatter};
 use std::time::Duration;
 
@@ -152,8 +153,10 @@ impl Status {
     }
 
     /// Whether should ban session
+    ///
+    /// The statuses scored through `behaviour` are not banned directly.
     pub fn should_ban(&self) -> Option<Duration> {
-        if !(400..500).contains(&(self.code as u16)) {
+        if !(400..500).contains(&(self.code as u16)) || self.behaviour().is_some() {
             return None;
         }
         match self.code {
@@ -162,6 +165,18 @@ impl Status {
         }
     }
 
+    /// The misbehaviour to report to the peer store
+    pub fn behaviour(&self) -> Option<Behaviour> {
+        match self.code {
+            StatusCode::BlockIsInvalid
+            | StatusCode::CompactBlockHasInvalidHeader
+            | StatusCode::CompactBlockHasInvalidUncle
+            | StatusCode::CompactBlockHasUnmatchedTransactionRootWithReconstructedBlock
+            | StatusCode::HeadersIsInvalid => Some(Behaviour::InvalidBlock),
+            _ => None,
+        }
+    }
+
     /// Whether should output a warning log
     pub fn should_warn(&self) -> bool {
         self.code as u16 >= 500
@@ -135,7 +135,14 @@ impl<'a> BlockFetcher<'a> {
                 let parent_hash = header.parent_hash();
                 let hash = header.hash();
 
-                if status.contains(BlockStatus::BLOCK_STORED) {
+                if status.contains(BlockStatus::BLOCK_INVALID) {
+                    // The best known chain of the peer goes through an invalid block, e.g. a
+                    // block invalidated by the local node
+                    for header in fetch {
+                        inflight.remove_by_block((header.number(), header.hash()).into());
+                    }
+                    return None;
+                } else if status.contains(BlockStatus::BLOCK_STORED) {
                     // If the block is stored, its ancestor must on store
                     // So we can skip the search of this space directly
                     self.synchronizer
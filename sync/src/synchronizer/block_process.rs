@@ -1,12 +1,13 @@
 use crate::{synchronizer::Synchronizer, utils::is_internal_db_error, Status, StatusCode};
 use ckb_logger::debug;
-use ckb_network::PeerIndex;
+use ckb_network::{CKBProtocolContext, PeerIndex};
 use ckb_types::{packed, prelude::*};
 
 pub struct BlockProcess<'a> {
     message: packed::SendBlockReader<'a>,
     synchronizer: &'a Synchronizer,
-    _peer: PeerIndex,
+    peer: PeerIndex,
+    nc: &'a dyn CKBProtocolContext,
 }
 
 impl<'a> BlockProcess<'a> {
@@ -14,11 +15,13 @@ impl<'a> BlockProcess<'a> {
         message: packed::SendBlockReader<'a>,
         synchronizer: &'a Synchronizer,
         peer: PeerIndex,
+        nc: &'a dyn CKBProtocolContext,
     ) -> Self {
         BlockProcess {
             message,
             synchronizer,
-            _peer: peer,
+            peer,
+            nc,
         }
     }
 
@@ -33,13 +36,17 @@ impl<'a> BlockProcess<'a> {
         let state = shared.state();
 
         if state.new_block_received(&block) {
-            if let Err(err) = self.synchronizer.process_new_block(block.clone()) {
-                if !is_internal_db_error(&err) {
-                    return StatusCode::BlockIsInvalid.with_context(format!(
-                        "{}, error: {}",
-                        block.hash(),
-                        err,
-                    ));
+            match self.synchronizer.process_new_block(block.clone()) {
+                Ok(true) => self.nc.block_delivered(self.peer),
+                Ok(false) => {}
+                Err(err) => {
+                    if !is_internal_db_error(&err) {
+                        return StatusCode::BlockIsInvalid.with_context(format!(
+                            "{}, error: {}",
+                            block.hash(),
+                            err,
+                        ));
+                    }
                 }
             }
         }
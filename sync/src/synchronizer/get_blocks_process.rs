@@ -67,6 +67,14 @@ impl<'a> GetBlocksProcess<'a> {
                 continue;
             }
 
+            // block serving is throttled first when the upload limit is reached
+            if self.nc.upload_limit_reached(self.peer) {
+                return StatusCode::TooManyRequests.with_context(format!(
+                    "upload limit reached, stop serving blocks from {}",
+                    block_hash
+                ));
+            }
+
             if let Some(block) = active_chain.get_block(&block_hash) {
                 debug!(
                     "respond_block {} {} to peer {:?}",
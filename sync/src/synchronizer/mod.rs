@@ -27,7 +27,7 @@ use ckb_error::Error as CKBError;
 use ckb_logger::{debug, error, info, trace, warn};
 use ckb_metrics::metrics;
 use ckb_network::{
-    bytes::Bytes, CKBProtocolContext, CKBProtocolHandler, PeerIndex, ServiceControl,
+    bytes::Bytes, Behaviour, CKBProtocolContext, CKBProtocolHandler, PeerIndex, ServiceControl,
     SupportProtocols,
 };
 use ckb_types::{
@@ -37,6 +37,7 @@ use ckb_types::{
 };
 use faketime::unix_time_as_millis;
 use std::{
+    cmp,
     collections::HashSet,
     sync::{atomic::Ordering, Arc},
     time::{Duration, Instant},
@@ -46,11 +47,18 @@ pub const SEND_GET_HEADERS_TOKEN: u64 = 0;
 pub const IBD_BLOCK_FETCH_TOKEN: u64 = 1;
 pub const NOT_IBD_BLOCK_FETCH_TOKEN: u64 = 2;
 pub const TIMEOUT_EVICTION_TOKEN: u64 = 3;
+pub const STALE_TIP_CHECK_TOKEN: u64 = 4;
 pub const NO_PEER_CHECK_TOKEN: u64 = 255;
 
 const SYNC_NOTIFY_INTERVAL: Duration = Duration::from_secs(1);
 const IBD_BLOCK_FETCH_INTERVAL: Duration = Duration::from_millis(40);
 const NOT_IBD_BLOCK_FETCH_INTERVAL: Duration = Duration::from_millis(200);
+const STALE_TIP_CHECK_INTERVAL: Duration = Duration::from_secs(45);
+// The tip is stale if it doesn't change for this multiple of the target block interval
+const STALE_TIP_INTERVAL_MULTIPLE: u32 = 30;
+// The freshly connected outbound peers, including the extra one, are not evicted until they have
+// been connected for this long
+const MIN_EXTRA_OUTBOUND_CONNECTED_TIME: Duration = Duration::from_secs(30);
 
 #[derive(Copy, Clone)]
 enum CanStart {
@@ -245,7 +253,7 @@ impl Synchronizer {
             }
             packed::SyncMessageUnionReader::SendBlock(reader) => {
                 if reader.check_data() {
-                    BlockProcess::new(reader, self, peer).execute()
+                    BlockProcess::new(reader, self, peer, nc).execute()
                 } else {
                     StatusCode::ProtocolMessageIsMalformed.with_context("SendBlock is invalid")
                 }
@@ -275,7 +283,13 @@ impl Synchronizer {
             "status" => (status.code() as u16).to_string(),
         );
 
-        if let Some(ban_time) = status.should_ban() {
+        if let Some(behaviour) = status.behaviour() {
+            info!(
+                "receive {} from {}, report {:?} for {}",
+                item_name, peer, behaviour, status
+            );
+            nc.report_peer(peer, behaviour);
+        } else if let Some(ban_time) = status.should_ban() {
             error!(
                 "receive {} from {}, ban {:?} for {}",
                 item_name, peer, ban_time, status
@@ -467,12 +481,82 @@ impl Synchronizer {
         }
         for peer in eviction {
             info!("timeout eviction peer={}", peer);
+            nc.report_peer(peer, Behaviour::Timeout);
             if let Err(err) = nc.disconnect(peer, "sync timeout eviction") {
                 debug!("synchronizer disconnect error: {:?}", err);
             }
         }
     }
 
+    /// Tries to find a new outbound peer when the tip doesn't change for a long time, since the
+    /// current peers may not know a better chain. While there is an extra outbound peer, evicts
+    /// the settled outbound peer which gives us the fewest new headers.
+    pub fn check_stale_tip(&self, nc: &dyn CKBProtocolContext) {
+        let active_chain = self.shared.active_chain();
+        let tip_header = active_chain.tip_header();
+        let target_interval = Duration::from_secs(
+            self.shared.consensus().epoch_duration_target()
+                / active_chain.epoch_ext().length().max(1),
+        );
+        let unchanged = self
+            .shared
+            .state()
+            .tip_unchanged_duration(&tip_header.hash());
+        let stale = unchanged > target_interval * STALE_TIP_INTERVAL_MULTIPLE;
+        if stale && !self.shared.state().set_stale_tip(true) {
+            warn!(
+                "the tip {} has not changed for {:?}, try to connect a new outbound peer",
+                tip_header.number(),
+                unchanged
+            );
+            metrics!(counter, "ckb.sync.stale_tip", 1);
+            self.shared
+                .shared()
+                .notify_controller()
+                .notify_stale_tip(tip_header);
+        } else if !stale {
+            self.shared.state().set_stale_tip(false);
+        }
+        nc.set_try_new_outbound_peer(stale);
+
+        if nc.extra_outbound_count() > 0 {
+            self.evict_extra_outbound_peer(nc);
+        }
+    }
+
+    fn evict_extra_outbound_peer(&self, nc: &dyn CKBProtocolContext) {
+        let candidate = self
+            .peers()
+            .state
+            .iter()
+            .filter(|kv_pair| {
+                let flags = kv_pair.value().peer_flags;
+                flags.is_outbound && !flags.is_protect && !flags.is_whitelist
+            })
+            .filter_map(|kv_pair| {
+                let peer = nc.get_peer(*kv_pair.key()).filter(|peer| {
+                    !peer.is_block_relay_only
+                        && peer.connected_time.elapsed() >= MIN_EXTRA_OUTBOUND_CONNECTED_TIME
+                })?;
+                Some((
+                    *kv_pair.key(),
+                    kv_pair.value().new_headers_count,
+                    peer.connected_time,
+                ))
+            })
+            // the newer connection is evicted first if the counts are equal
+            .min_by_key(|(_, count, connected_time)| (*count, cmp::Reverse(*connected_time)));
+        if let Some((peer, count, _)) = candidate {
+            info!(
+                "evict the extra outbound peer={} which gives {} new headers",
+                peer, count
+            );
+            if let Err(err) = nc.disconnect(peer, "evict extra outbound peer") {
+                debug!("synchronizer disconnect error: {:?}", err);
+            }
+        }
+    }
+
     fn start_sync_headers(&self, nc: &dyn CKBProtocolContext) {
         let now = unix_time_as_millis();
         let active_chain = self.shared.active_chain();
@@ -669,6 +753,8 @@ impl CKBProtocolHandler for Synchronizer {
             .expect("set_notify at init is ok");
         nc.set_notify(NOT_IBD_BLOCK_FETCH_INTERVAL, NOT_IBD_BLOCK_FETCH_TOKEN)
             .expect("set_notify at init is ok");
+        nc.set_notify(STALE_TIP_CHECK_INTERVAL, STALE_TIP_CHECK_TOKEN)
+            .expect("set_notify at init is ok");
         nc.set_notify(Duration::from_secs(2), NO_PEER_CHECK_TOKEN)
             .expect("set_notify at init is ok");
     }
@@ -823,6 +909,9 @@ impl CKBProtocolHandler for Synchronizer {
                 TIMEOUT_EVICTION_TOKEN => {
                     self.eviction(nc.as_ref());
                 }
+                STALE_TIP_CHECK_TOKEN => {
+                    self.check_stale_tip(nc.as_ref());
+                }
                 // Here is just for NO_PEER_CHECK_TOKEN token, only handle it when there is no peer.
                 _ => {}
             }
@@ -3,9 +3,11 @@ use crate::tests::util::{build_chain, inherit_block};
 use crate::SyncShared;
 use ckb_chain::chain::ChainService;
 use ckb_launcher::SharedBuilder;
+use ckb_network::PeerIndex;
 use ckb_store::{self, ChainStore};
 use ckb_test_chain_utils::always_success_cellbase;
 use ckb_types::core::{BlockBuilder, BlockView, Capacity};
+use ckb_types::packed::Byte32;
 use ckb_types::prelude::*;
 use std::sync::Arc;
 
@@ -122,6 +124,69 @@ fn test_insert_parent_unknown_block() {
     );
 }
 
+#[test]
+fn test_insert_orphan_chain() {
+    let (shared1, _) = build_chain(4);
+    let (shared, chain) = {
+        let (shared, mut pack) = SharedBuilder::with_temp_db()
+            .consensus(shared1.consensus().clone())
+            .build()
+            .unwrap();
+        let chain_controller = {
+            let chain_service = ChainService::new(shared.clone(), pack.take_proposal_table());
+            chain_service.start::<&str>(None)
+        };
+        (
+            SyncShared::new(shared, Default::default(), pack.take_relay_tx_receiver()),
+            chain_controller,
+        )
+    };
+    let blocks: Vec<_> = (1..=4)
+        .map(|number| {
+            let hash = shared1.store().get_block_hash(number).unwrap();
+            Arc::new(shared1.store().get_block(&hash).unwrap())
+        })
+        .collect();
+    // a fork whose first block is invalid
+    let invalid = blocks[1]
+        .as_advanced_builder()
+        .header(blocks[1].header())
+        .number(1000.pack())
+        .build();
+    let invalid_child = blocks[2]
+        .as_advanced_builder()
+        .header(blocks[2].header())
+        .parent_hash(invalid.hash())
+        .build();
+
+    for block in blocks[1..].iter().cloned().chain(vec![
+        Arc::new(invalid.clone()),
+        Arc::new(invalid_child.clone()),
+    ]) {
+        assert!(!shared
+            .insert_new_block(&chain, block)
+            .expect("insert orphan block"));
+    }
+
+    // the orphan descendants are submitted together after their parent
+    assert!(shared
+        .insert_new_block(&chain, Arc::clone(&blocks[0]))
+        .expect("insert parent of orphan blocks"));
+    assert_eq!(shared.active_chain().tip_hash(), blocks[3].hash());
+    assert_eq!(
+        shared.active_chain().get_block_status(&invalid.hash()),
+        BlockStatus::BLOCK_INVALID
+    );
+    // the child of the invalid block is dropped, but not marked as invalid by itself
+    assert_eq!(
+        shared
+            .active_chain()
+            .get_block_status(&invalid_child.hash()),
+        BlockStatus::UNKNOWN
+    );
+    assert!(shared.store().get_block(&invalid_child.hash()).is_none());
+}
+
 #[test]
 fn test_switch_valid_fork() {
     let (shared, chain) = build_chain(4);
@@ -183,3 +248,93 @@ fn test_switch_valid_fork() {
         );
     }
 }
+
+#[test]
+fn test_track_chain_tips() {
+    let (shared, _chain) = build_chain(3);
+    let active_chain = shared.active_chain();
+    let fork_parent = active_chain.get_block_hash(1).unwrap();
+
+    let fork_header = inherit_block(shared.shared(), &fork_parent)
+        .nonce(1u128.pack())
+        .build()
+        .header();
+    let fork_tip = fork_header
+        .as_advanced_builder()
+        .parent_hash(fork_header.hash())
+        .number((fork_header.number() + 1).pack())
+        .build();
+    let peer_index: PeerIndex = 1.into();
+    shared.insert_valid_header(peer_index, &fork_header);
+    shared.insert_valid_header(peer_index, &fork_tip);
+
+    // the fork header is extended by the fork tip
+    assert_eq!(shared.state().chain_tips(), vec![fork_tip.hash()]);
+}
+
+#[test]
+fn test_invalidate_and_reconsider_block() {
+    let (shared, chain) = build_chain(4);
+    let active_chain = shared.active_chain();
+    let tip = active_chain.tip_header();
+    let invalid = active_chain.get_block_hash(3).unwrap();
+    let valid = active_chain.get_block_hash(2).unwrap();
+
+    let descendant = inherit_block(shared.shared(), &tip.hash()).build();
+    shared.insert_valid_header(1.into(), &descendant.header());
+    assert_eq!(
+        shared.state().shared_best_header().hash(),
+        descendant.hash()
+    );
+
+    // the best header descends from the invalidated block
+    shared
+        .invalidate_block(&chain, invalid.clone())
+        .expect("invalidate block");
+    assert_eq!(
+        shared.active_chain().get_block_status(&invalid),
+        BlockStatus::BLOCK_INVALID
+    );
+    assert_eq!(shared.active_chain().tip_hash(), valid);
+    assert_eq!(shared.state().shared_best_header().hash(), valid);
+
+    // the descendants are rejected, but not marked as invalid
+    assert!(shared
+        .insert_new_block(&chain, Arc::new(descendant.clone()))
+        .is_err());
+    assert!(!shared
+        .active_chain()
+        .get_block_status(&descendant.hash())
+        .contains(BlockStatus::BLOCK_INVALID));
+
+    shared
+        .reconsider_block(&chain, invalid.clone())
+        .expect("reconsider block");
+    assert!(!shared
+        .active_chain()
+        .get_block_status(&invalid)
+        .contains(BlockStatus::BLOCK_INVALID));
+    assert_eq!(shared.active_chain().tip_hash(), tip.hash());
+    assert_eq!(shared.state().shared_best_header().hash(), tip.hash());
+    assert!(shared
+        .insert_new_block(&chain, Arc::new(descendant))
+        .expect("insert descendant"));
+}
+
+#[test]
+fn test_requested_txs() {
+    let (shared, _chain) = build_chain(2);
+    let state = shared.state();
+    let tx_hash = Byte32::zero();
+    let (announcer, other): (PeerIndex, PeerIndex) = (1.into(), 2.into());
+
+    // no peer is connected, the hash is queued though ignored as too many
+    state.add_ask_for_txs(announcer, vec![tx_hash.clone()]);
+    assert!(!state.is_tx_requested(&tx_hash, announcer));
+    let asked = state.pop_ask_for_txs();
+    assert_eq!(asked.get(&announcer), Some(&vec![tx_hash.clone()]));
+    // the request is remembered even after it is answered
+    state.mark_as_known_txs(vec![tx_hash.clone()].into_iter());
+    assert!(state.is_tx_requested(&tx_hash, announcer));
+    assert!(!state.is_tx_requested(&tx_hash, other));
+}
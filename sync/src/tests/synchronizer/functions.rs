@@ -29,8 +29,11 @@ use std::{
     collections::{HashMap, HashSet},
     ops::Deref,
     pin::Pin,
-    sync::{atomic::Ordering, Arc},
-    time::Duration,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::{Duration, Instant},
 };
 
 use crate::{
@@ -399,6 +402,8 @@ fn test_get_locator_response() {
 struct DummyNetworkContext {
     pub peers: HashMap<PeerIndex, Peer>,
     pub disconnected: Arc<Mutex<HashSet<PeerIndex>>>,
+    pub try_new_outbound_peer: Arc<AtomicBool>,
+    pub extra_outbound_count: u32,
 }
 
 fn mock_peer_info() -> Peer {
@@ -503,6 +508,12 @@ impl CKBProtocolContext for DummyNetworkContext {
     fn protocol_id(&self) -> ProtocolId {
         unimplemented!();
     }
+    fn set_try_new_outbound_peer(&self, enable: bool) {
+        self.try_new_outbound_peer.store(enable, Ordering::SeqCst);
+    }
+    fn extra_outbound_count(&self) -> u32 {
+        self.extra_outbound_count
+    }
 }
 
 fn mock_network_context(peer_num: usize) -> DummyNetworkContext {
@@ -513,6 +524,8 @@ fn mock_network_context(peer_num: usize) -> DummyNetworkContext {
     DummyNetworkContext {
         peers,
         disconnected: Arc::new(Mutex::new(HashSet::default())),
+        try_new_outbound_peer: Arc::new(AtomicBool::new(false)),
+        extra_outbound_count: 0,
     }
 }
 
@@ -596,7 +609,7 @@ fn test_sync_process() {
     for block in &fetched_blocks {
         let block = SendBlockBuilder::default().block(block.data()).build();
         assert_eq!(
-            BlockProcess::new(block.as_reader(), &synchronizer1, peer1).execute(),
+            BlockProcess::new(block.as_reader(), &synchronizer1, peer1, &mock_nc).execute(),
             Status::ok(),
         );
     }
@@ -974,6 +987,98 @@ fn test_n_sync_started() {
     synchronizer.shared().state().tip_synced(&mut state);
 }
 
+#[cfg(not(disable_faketime))]
+#[test]
+fn test_stale_tip() {
+    let faketime_file = faketime::millis_tempfile(0).expect("create faketime file");
+    faketime::enable(&faketime_file);
+
+    let (chain_controller, shared, synchronizer) = start_chain(None);
+    let network_context = mock_network_context(0);
+    let stale_time = shared.consensus().epoch_duration_target()
+        / shared.snapshot().epoch_ext().length()
+        * 30
+        * 1000;
+
+    let stale_tips = shared.notify_controller().subscribe_stale_tip("test");
+    let notify_timeout = Duration::from_millis(500);
+
+    synchronizer.check_stale_tip(&network_context);
+    assert!(!network_context.try_new_outbound_peer.load(Ordering::SeqCst));
+
+    faketime::write_millis(&faketime_file, stale_time).expect("write millis");
+    synchronizer.check_stale_tip(&network_context);
+    assert!(!network_context.try_new_outbound_peer.load(Ordering::SeqCst));
+
+    faketime::write_millis(&faketime_file, stale_time + 1).expect("write millis");
+    synchronizer.check_stale_tip(&network_context);
+    assert!(network_context.try_new_outbound_peer.load(Ordering::SeqCst));
+    let tip_hash = shared.snapshot().tip_hash();
+    let notified = stale_tips
+        .recv_timeout(notify_timeout)
+        .map(|tip| tip.hash());
+    assert_eq!(notified, Ok(tip_hash));
+
+    // the stale tip is notified once
+    synchronizer.check_stale_tip(&network_context);
+    assert!(network_context.try_new_outbound_peer.load(Ordering::SeqCst));
+    assert!(stale_tips.recv_timeout(notify_timeout).is_err());
+
+    // the new tip is not stale
+    insert_block(&chain_controller, &shared, 1, 1);
+    synchronizer.check_stale_tip(&network_context);
+    assert!(!network_context.try_new_outbound_peer.load(Ordering::SeqCst));
+    assert!(stale_tips.recv_timeout(notify_timeout).is_err());
+
+    // until it has not changed for a long time as well
+    faketime::write_millis(&faketime_file, stale_time * 2 + 2).expect("write millis");
+    synchronizer.check_stale_tip(&network_context);
+    assert!(network_context.try_new_outbound_peer.load(Ordering::SeqCst));
+    let tip = shared.snapshot().tip_header().clone();
+    assert_eq!(tip.number(), 1);
+    let notified = stale_tips
+        .recv_timeout(notify_timeout)
+        .map(|tip| tip.hash());
+    assert_eq!(notified, Ok(tip.hash()));
+}
+
+#[test]
+fn test_evict_extra_outbound_peer() {
+    let (_, _, synchronizer) = start_chain(None);
+    let mut network_context = mock_network_context(4);
+    let settled = Instant::now()
+        .checked_sub(Duration::from_secs(60))
+        .expect("settled connected time");
+    for (peer, new_headers_count) in [(0, 5), (1, 3), (2, 0), (3, 1)] {
+        let mut state = PeerState::default();
+        state.peer_flags.is_outbound = true;
+        state.new_headers_count = new_headers_count;
+        // the protected peer is never evicted
+        state.peer_flags.is_protect = peer == 2;
+        synchronizer.peers().state.insert(peer.into(), state);
+        // the extra peer is just connected
+        if peer != 3 {
+            network_context
+                .peers
+                .get_mut(&peer.into())
+                .expect("mock peer")
+                .connected_time = settled;
+        }
+    }
+
+    // no extra outbound peer
+    synchronizer.check_stale_tip(&network_context);
+    assert!(network_context.disconnected.lock().is_empty());
+
+    // the settled peer with the fewest new headers is evicted, not the fresh extra one
+    network_context.extra_outbound_count = 1;
+    synchronizer.check_stale_tip(&network_context);
+    assert_eq!(
+        network_context.disconnected.lock().deref(),
+        &vec![1].into_iter().map(Into::into).collect()
+    );
+}
+
 #[test]
 // `peer.last_common_header` represents what's the fork point between the local main-chain
 // and the peer's mani-chain. It may be unmatched with the current state. So we expect that
@@ -7,7 +7,7 @@ use ckb_types::{
 use rand::{thread_rng, Rng};
 use std::collections::{BTreeMap, HashMap};
 
-use crate::types::HeaderView;
+use crate::types::{HeaderMap, HeaderView};
 
 const SKIPLIST_LENGTH: u64 = 10_000;
 
@@ -87,3 +87,39 @@ fn test_get_ancestor_use_skip_list() {
         assert_eq!(found_0_header.hash(), view_0.hash());
     }
 }
+
+#[test]
+fn test_reload_persistent_header_map() {
+    let tmpdir = tempfile::tempdir().unwrap();
+    let path = tmpdir.path().join("header_map");
+
+    let mut hashes = Vec::new();
+    {
+        let header_map = HeaderMap::persistent(&path, 2);
+        let mut parent_hash = Byte32::zero();
+        for number in 0..10u64 {
+            let header = HeaderBuilder::default()
+                .number(number.pack())
+                .parent_hash(parent_hash)
+                .build();
+            parent_hash = header.hash();
+            hashes.push(header.hash());
+            header_map.insert(HeaderView::new(header, U256::from(number)));
+        }
+        // a header detached from the stored chain
+        let orphan = HeaderBuilder::default()
+            .number(8u64.pack())
+            .parent_hash(Byte32::zero())
+            .build();
+        hashes.push(orphan.hash());
+        header_map.insert(HeaderView::new(orphan, U256::from(100u64)));
+    }
+
+    let header_map = HeaderMap::persistent(&path, 2);
+    let tip_hash = hashes[3].clone();
+    let best = header_map.prune(3, |hash| hash == &tip_hash).unwrap();
+    assert_eq!(best.hash(), hashes[9]);
+    for (number, hash) in hashes.iter().enumerate() {
+        assert_eq!(header_map.contains_key(hash), number > 3 && number < 10);
+    }
+}
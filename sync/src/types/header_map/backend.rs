@@ -9,6 +9,13 @@ pub(crate) trait KeyValueBackend {
     where
         P: AsRef<path::Path>;
 
+    /// Creates a backend which keeps the data in the directory across restarts.
+    fn persistent<P>(path: P) -> Self
+    where
+        P: AsRef<path::Path>;
+
+    fn is_persistent(&self) -> bool;
+
     fn len(&self) -> usize;
     fn is_empty(&self) -> bool {
         self.len() == 0
@@ -22,4 +29,7 @@ pub(crate) trait KeyValueBackend {
     fn get(&self, key: &Byte32) -> Option<HeaderView>;
     fn insert(&mut self, value: &HeaderView) -> Option<HeaderView>;
     fn remove(&mut self, key: &Byte32) -> Option<HeaderView>;
+    fn for_each<F>(&self, f: F)
+    where
+        F: FnMut(HeaderView);
 }
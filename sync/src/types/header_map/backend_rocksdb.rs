@@ -1,8 +1,8 @@
 use std::path;
 
 use ckb_db::internal::{
-    ops::{Delete as _, GetPinned as _, Open as _, Put as _},
-    BlockBasedOptions, Options, DB,
+    ops::{Delete as _, GetPinned as _, Iterate as _, Open as _, Put as _},
+    BlockBasedOptions, IteratorMode, Options, DB,
 };
 use ckb_logger::{debug, warn};
 use ckb_types::{packed::Byte32, prelude::*};
@@ -13,10 +13,43 @@ use crate::types::HeaderView;
 
 pub(crate) struct RocksDBBackend {
     tmpdir: Option<path::PathBuf>,
-    resource: Option<(TempDir, DB)>,
+    // The directory of a persistent backend, the data in it is kept after closing.
+    path: Option<path::PathBuf>,
+    resource: Option<(Option<TempDir>, DB)>,
     count: usize,
 }
 
+impl RocksDBBackend {
+    fn open_db(path: &path::Path) -> DB {
+        // We minimize memory usage at all costs here.
+        // If we want to use more memory, we should increase the limit of KeyValueMemory.
+        let opts = {
+            let mut block_opts = BlockBasedOptions::default();
+            block_opts.disable_cache();
+            let mut opts = Options::default();
+            opts.create_if_missing(true);
+            opts.set_block_based_table_factory(&block_opts);
+            opts.set_write_buffer_size(4 * 1024 * 1024);
+            opts.set_max_write_buffer_number(2);
+            opts.set_min_write_buffer_number_to_merge(1);
+            opts
+        };
+        match DB::open(&opts, path) {
+            Ok(db) => {
+                debug!(
+                    "open a key-value database({}) to save header map into disk",
+                    path.to_str().unwrap_or("")
+                );
+                db
+            }
+            Err(e) => panic!(
+                "failed to open a key-value database to save header map into disk: {}",
+                e
+            ),
+        }
+    }
+}
+
 impl KeyValueBackend for RocksDBBackend {
     fn new<P>(tmpdir: Option<P>) -> Self
     where
@@ -24,11 +57,30 @@ impl KeyValueBackend for RocksDBBackend {
     {
         Self {
             tmpdir: tmpdir.map(|p| p.as_ref().to_path_buf()),
+            path: None,
             resource: None,
             count: 0,
         }
     }
 
+    fn persistent<P>(path: P) -> Self
+    where
+        P: AsRef<path::Path>,
+    {
+        let mut backend = Self {
+            tmpdir: None,
+            path: Some(path.as_ref().to_path_buf()),
+            resource: None,
+            count: 0,
+        };
+        backend.open();
+        backend
+    }
+
+    fn is_persistent(&self) -> bool {
+        self.path.is_some()
+    }
+
     fn len(&self) -> usize {
         self.count
     }
@@ -38,7 +90,14 @@ impl KeyValueBackend for RocksDBBackend {
     }
 
     fn open(&mut self) {
-        if !self.is_opened() {
+        if self.is_opened() {
+            return;
+        }
+        if let Some(ref path) = self.path {
+            let db = Self::open_db(path);
+            self.count = db.iterator(IteratorMode::Start).count();
+            self.resource.replace((None, db));
+        } else {
             let mut builder = tempfile::Builder::new();
             builder.prefix("ckb-tmp-");
             let cache_dir_res = if let Some(ref tmpdir) = self.tmpdir {
@@ -48,32 +107,8 @@ impl KeyValueBackend for RocksDBBackend {
             };
             match cache_dir_res {
                 Ok(cache_dir) => {
-                    // We minimize memory usage at all costs here.
-                    // If we want to use more memory, we should increase the limit of KeyValueMemory.
-                    let opts = {
-                        let mut block_opts = BlockBasedOptions::default();
-                        block_opts.disable_cache();
-                        let mut opts = Options::default();
-                        opts.create_if_missing(true);
-                        opts.set_block_based_table_factory(&block_opts);
-                        opts.set_write_buffer_size(4 * 1024 * 1024);
-                        opts.set_max_write_buffer_number(2);
-                        opts.set_min_write_buffer_number_to_merge(1);
-                        opts
-                    };
-                    match DB::open(&opts, cache_dir.path()) {
-                        Ok(db) => {
-                            debug!(
-                                "open a key-value database({}) to save header map into disk",
-                                cache_dir.path().to_str().unwrap_or("")
-                            );
-                            self.resource.replace((cache_dir, db));
-                        }
-                        Err(e) => panic!(
-                            "failed to open a key-value database to save header map into disk: {}",
-                            e
-                        ),
-                    }
+                    let db = Self::open_db(cache_dir.path());
+                    self.resource.replace((Some(cache_dir), db));
                 }
                 Err(e) => panic!(
                     "failed to create a tempdir to save header map into disk: {}",
@@ -84,9 +119,12 @@ impl KeyValueBackend for RocksDBBackend {
     }
 
     fn try_close(&mut self) -> bool {
-        if self.is_opened() {
+        if self.is_persistent() {
+            // the persistent data is only released when the header map is dropped
+            false
+        } else if self.is_opened() {
             if self.is_empty() {
-                if let Some((cache_dir, db)) = self.resource.take() {
+                if let Some((Some(cache_dir), db)) = self.resource.take() {
                     drop(db);
                     let _ignore = cache_dir.close();
                 }
@@ -165,4 +203,15 @@ impl KeyValueBackend for RocksDBBackend {
         }
         value_opt
     }
+
+    fn for_each<F>(&self, mut f: F)
+    where
+        F: FnMut(HeaderView),
+    {
+        if let Some((_, ref db)) = self.resource {
+            for (_, value) in db.iterator(IteratorMode::Start) {
+                f(HeaderView::from_slice_should_be_ok(&value));
+            }
+        }
+    }
 }
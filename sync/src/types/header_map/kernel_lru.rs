@@ -1,8 +1,8 @@
-use std::path;
+use std::{collections::HashSet, path};
 
 #[cfg(feature = "stats")]
 use ckb_logger::trace;
-use ckb_types::packed::Byte32;
+use ckb_types::{core::BlockNumber, packed::Byte32, U256};
 
 use super::{KeyValueBackend, KeyValueMemory};
 use crate::types::HeaderView;
@@ -50,8 +50,24 @@ where
     where
         P: AsRef<path::Path>,
     {
+        Self::with_backend(Backend::new(tmpdir), primary_limit, backend_close_threshold)
+    }
+
+    /// Creates a kernel whose headers are all written through into the persistent backend, the
+    /// primary only caches the recently used ones.
+    pub(crate) fn persistent<P>(path: P, primary_limit: usize) -> Self
+    where
+        P: AsRef<path::Path>,
+    {
+        Self::with_backend(Backend::persistent(path), primary_limit, 0)
+    }
+
+    fn with_backend(
+        backend: Backend,
+        primary_limit: usize,
+        backend_close_threshold: usize,
+    ) -> Self {
         let primary = Default::default();
-        let backend = Backend::new(tmpdir);
 
         #[cfg(not(feature = "stats"))]
         {
@@ -104,6 +120,11 @@ where
         if !self.backend.is_opened() {
             return None;
         }
+        if self.backend.is_persistent() {
+            let view = self.backend.get(hash)?;
+            self.cache(view.clone());
+            return Some(view);
+        }
         #[cfg(feature = "stats")]
         {
             self.mut_stats().tick_backend_delete();
@@ -138,6 +159,15 @@ where
             self.trace();
             self.mut_stats().tick_primary_insert();
         }
+        if self.backend.is_persistent() {
+            #[cfg(feature = "stats")]
+            {
+                self.mut_stats().tick_backend_insert();
+            }
+            let view_opt = self.backend.insert(&view);
+            self.cache(view);
+            return view_opt;
+        }
         if let Some(view) = self.primary.insert(view.hash(), view.clone()) {
             return Some(view);
         }
@@ -162,6 +192,14 @@ where
             self.trace();
             self.mut_stats().tick_primary_delete();
         }
+        if self.backend.is_persistent() {
+            #[cfg(feature = "stats")]
+            {
+                self.mut_stats().tick_backend_delete();
+            }
+            self.primary.remove(hash);
+            return self.backend.remove(hash);
+        }
         if let Some(view) = self.primary.remove(hash) {
             return Some(view);
         }
@@ -179,6 +217,67 @@ where
         view_opt
     }
 
+    /// Removes the persisted headers which don't extend the stored chain beyond the tip,
+    /// returns the remaining header with the most total difficulty.
+    ///
+    /// The headers are checked from the lowest one, each header is kept only if its parent is a
+    /// stored block or a kept header.
+    pub(crate) fn prune<F>(&mut self, tip_number: BlockNumber, is_stored: F) -> Option<HeaderView>
+    where
+        F: Fn(&Byte32) -> bool,
+    {
+        let mut headers = Vec::new();
+        let mut pruned = Vec::new();
+        self.backend.for_each(|view| {
+            if view.number() > tip_number {
+                let total_difficulty = view.total_difficulty().to_owned();
+                headers.push((
+                    view.number(),
+                    view.hash(),
+                    view.parent_hash(),
+                    total_difficulty,
+                ));
+            } else {
+                pruned.push(view.hash());
+            }
+        });
+        headers.sort_unstable_by_key(|(number, _, _, _)| *number);
+
+        let mut connected = HashSet::with_capacity(headers.len());
+        let mut best: Option<(U256, Byte32)> = None;
+        for (_, hash, parent_hash, total_difficulty) in headers {
+            if connected.contains(&parent_hash) || is_stored(&parent_hash) {
+                if best
+                    .as_ref()
+                    .map(|(best_difficulty, _)| &total_difficulty > best_difficulty)
+                    .unwrap_or(true)
+                {
+                    best = Some((total_difficulty, hash.clone()));
+                }
+                connected.insert(hash);
+            } else {
+                pruned.push(hash);
+            }
+        }
+        for hash in pruned {
+            self.primary.remove(&hash);
+            self.backend.remove(&hash);
+        }
+        best.and_then(|(_, hash)| self.backend.get(&hash))
+    }
+
+    // Caches a header which is already in the persistent backend
+    fn cache(&mut self, view: HeaderView) {
+        self.primary.insert(view.hash(), view);
+        if self.primary.len() > self.primary_limit {
+            #[cfg(feature = "stats")]
+            {
+                self.mut_stats().tick_primary_delete();
+            }
+            self.primary.pop_front();
+        }
+    }
+
     #[cfg(feature = "stats")]
     fn trace(&mut self) {
         let progress = self.stats().trace_progress();
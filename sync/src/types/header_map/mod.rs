@@ -1,6 +1,6 @@
 use std::path;
 
-use ckb_types::packed::Byte32;
+use ckb_types::{core::BlockNumber, packed::Byte32};
 use ckb_util::Mutex;
 
 use crate::types::HeaderView;
@@ -30,6 +30,25 @@ impl HeaderMapLru {
         Self(Mutex::new(inner))
     }
 
+    /// Opens the header map kept in the directory, all the headers are written into the disk
+    /// so they survive the restarts.
+    pub(crate) fn persistent<P>(path: P, primary_limit: usize) -> Self
+    where
+        P: AsRef<path::Path>,
+    {
+        let inner = HeaderMapLruKernel::persistent(path, primary_limit);
+        Self(Mutex::new(inner))
+    }
+
+    /// Removes the headers which don't extend the stored chain beyond the tip, returns the best
+    /// remaining one.
+    pub(crate) fn prune<F>(&self, tip_number: BlockNumber, is_stored: F) -> Option<HeaderView>
+    where
+        F: Fn(&Byte32) -> bool,
+    {
+        self.0.lock().prune(tip_number, is_stored)
+    }
+
     pub(crate) fn contains_key(&self, hash: &Byte32) -> bool {
         self.0.lock().contains_key(hash)
     }
@@ -13,8 +13,8 @@ use ckb_constant::sync::{
     MAX_UNKNOWN_TX_HASHES_SIZE, MAX_UNKNOWN_TX_HASHES_SIZE_PER_PEER, POW_INTERVAL,
     RETRY_ASK_TX_TIMEOUT_INCREASE, SUSPEND_SYNC_TIME,
 };
-use ckb_error::Error as CKBError;
-use ckb_logger::{debug, error, trace};
+use ckb_error::{Error as CKBError, InternalErrorKind};
+use ckb_logger::{debug, error, info, trace};
 use ckb_metrics::metrics;
 use ckb_network::{CKBProtocolContext, PeerIndex, SupportProtocols};
 use ckb_shared::{shared::Shared, Snapshot};
@@ -36,7 +36,7 @@ use lru::LruCache;
 use std::collections::{btree_map::Entry, BTreeMap, HashMap, HashSet};
 use std::hash::Hash;
 use std::path::{Path, PathBuf};
-use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 use std::{cmp, fmt, iter};
@@ -53,6 +53,8 @@ const GET_HEADERS_CACHE_SIZE: usize = 10000;
 const GET_HEADERS_TIMEOUT: Duration = Duration::from_secs(15);
 const TX_FILTER_SIZE: usize = 50000;
 const ORPHAN_BLOCK_SIZE: usize = 1024;
+// The tracked chain tips, the lowest ones are evicted beyond it
+const MAX_CHAIN_TIPS: usize = 1024;
 // 2 ** 13 < 6 * 1800 < 2 ** 14
 const ONE_DAY_BLOCK_NUMBER: u64 = 8192;
 const SHRINK_THRESHOLD: usize = 300;
@@ -112,6 +114,10 @@ impl ChainSyncState {
         matches!(self.headers_sync_state, HeadersSyncState::Started)
     }
 
+    pub fn headers_sync_state(&self) -> HeadersSyncState {
+        self.headers_sync_state
+    }
+
     fn started_or_tip_synced(&self) -> bool {
         matches!(
             self.headers_sync_state,
@@ -120,8 +126,8 @@ impl ChainSyncState {
     }
 }
 
-#[derive(Clone, Debug)]
-enum HeadersSyncState {
+#[derive(Clone, Copy, Debug)]
+pub enum HeadersSyncState {
     Initialized,
     SyncProtocolConnected,
     Started,
@@ -146,16 +152,16 @@ pub struct PeerFlags {
 #[derive(Clone, Default, Debug, Copy)]
 pub struct HeadersSyncController {
     // The timestamp when sync started
-    pub(crate) started_ts: u64,
+    pub started_ts: u64,
     // The timestamp of better tip header when sync started
-    pub(crate) started_tip_ts: u64,
+    pub started_tip_ts: u64,
 
     // The timestamp when the process last updated
-    pub(crate) last_updated_ts: u64,
+    pub last_updated_ts: u64,
     // The timestamp of better tip header when the process last updated
-    pub(crate) last_updated_tip_ts: u64,
+    pub last_updated_tip_ts: u64,
 
-    pub(crate) is_close_to_the_end: bool,
+    pub is_close_to_the_end: bool,
 }
 
 impl HeadersSyncController {
@@ -278,6 +284,8 @@ pub struct PeerState {
     // use on ibd concurrent block download
     // save `get_headers` locator hashes here
     pub unknown_header_list: Vec<Byte32>,
+    // The count of the headers this peer gave us first
+    pub new_headers_count: u64,
 }
 
 impl PeerState {
@@ -289,6 +297,7 @@ impl PeerState {
             best_known_header: None,
             last_common_header: None,
             unknown_header_list: Vec::new(),
+            new_headers_count: 0,
         }
     }
 
@@ -375,6 +384,11 @@ impl InflightState {
             timestamp: unix_time_as_millis(),
         }
     }
+
+    /// The timestamp when the block is requested
+    pub fn timestamp(&self) -> u64 {
+        self.timestamp
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -610,6 +624,13 @@ impl InflightBlocks {
         )
     }
 
+    pub fn peer_task_count(&self, peer: PeerIndex) -> usize {
+        self.download_schedulers.get(&peer).map_or(
+            INIT_BLOCKS_IN_TRANSIT_PER_PEER,
+            DownloadScheduler::task_count,
+        )
+    }
+
     pub fn inflight_block_by_peer(&self, peer: PeerIndex) -> Option<&HashSet<BlockNumberAndHash>> {
         self.download_schedulers.get(&peer).map(|d| &d.hashes)
     }
@@ -836,6 +857,12 @@ impl Peers {
         }
     }
 
+    pub fn new_header_received(&self, peer: PeerIndex) {
+        if let Some(mut peer_state) = self.state.get_mut(&peer) {
+            peer_state.new_headers_count += 1;
+        }
+    }
+
     pub fn get_last_common_header(&self, pi: PeerIndex) -> Option<core::HeaderView> {
         self.state
             .get(&pi)
@@ -1149,12 +1176,37 @@ impl SyncShared {
                 snapshot.tip_header().to_owned(),
             )
         };
-        let shared_best_header = RwLock::new(HeaderView::new(header, total_difficulty));
-        let header_map = HeaderMap::new(
-            tmpdir,
-            sync_config.header_map.primary_limit,
-            sync_config.header_map.backend_close_threshold,
-        );
+        let tip_hash = header.hash();
+        let mut best_header = HeaderView::new(header, total_difficulty);
+        let header_map = if sync_config.header_map.persistent {
+            let header_map = HeaderMap::persistent(
+                &sync_config.header_map.path,
+                sync_config.header_map.primary_limit,
+            );
+            // the headers below the tip are either stored or stale, and the headers which are
+            // detached from the stored chain can't be trusted after the restart
+            let snapshot = shared.snapshot();
+            let reloaded = header_map.prune(best_header.number(), |hash| {
+                snapshot.get_block_header(hash).is_some()
+            });
+            if let Some(header) = reloaded {
+                if header.is_better_than(best_header.total_difficulty()) {
+                    info!(
+                        "reload the headers up to {} from the persistent header map",
+                        header.number()
+                    );
+                    best_header = header;
+                }
+            }
+            header_map
+        } else {
+            HeaderMap::new(
+                tmpdir,
+                sync_config.header_map.primary_limit,
+                sync_config.header_map.backend_close_threshold,
+            )
+        };
+        let shared_best_header = RwLock::new(best_header);
 
         let state = SyncState {
             n_sync_started: AtomicUsize::new(0),
@@ -1164,6 +1216,7 @@ impl SyncShared {
             block_status_map: DashMap::new(),
             tx_filter: Mutex::new(Filter::new(TX_FILTER_SIZE)),
             unknown_tx_hashes: Mutex::new(KeyedPriorityQueue::new()),
+            requested_txs: Mutex::new(Filter::new(MAX_UNKNOWN_TX_HASHES_SIZE)),
             peers: Peers::default(),
             pending_get_block_proposals: DashMap::new(),
             pending_compact_blocks: Mutex::new(HashMap::default()),
@@ -1174,6 +1227,9 @@ impl SyncShared {
             tx_relay_receiver,
             assume_valid_target: Mutex::new(sync_config.assume_valid_target),
             min_chain_work: sync_config.min_chain_work,
+            chain_tips: Mutex::new(HashMap::default()),
+            last_tip_update: Mutex::new((tip_hash, unix_time_as_millis())),
+            stale_tip: AtomicBool::new(false),
         };
 
         SyncShared {
@@ -1211,6 +1267,59 @@ impl SyncShared {
         self.shared.consensus()
     }
 
+    /// Invalidates the block in the chain, and the sync state, the blocks of its descendants
+    /// are no longer downloaded
+    pub fn invalidate_block(
+        &self,
+        chain: &ChainController,
+        block_hash: Byte32,
+    ) -> Result<(), CKBError> {
+        let number = self
+            .store()
+            .get_block_header(&block_hash)
+            .map(|header| header.number());
+        chain.invalidate_block(block_hash.clone())?;
+        self.state
+            .insert_block_status(block_hash.clone(), BlockStatus::BLOCK_INVALID);
+
+        // the best known header may descend from the invalidated block
+        let active_chain = self.active_chain();
+        let best_header = self.state.shared_best_header();
+        let on_invalid_chain = number
+            .and_then(|number| active_chain.get_ancestor(&best_header.hash(), number))
+            .map(|ancestor| ancestor.hash() == block_hash)
+            .unwrap_or(false);
+        if on_invalid_chain {
+            self.state.reset_shared_best_header(HeaderView::new(
+                active_chain.tip_header(),
+                active_chain.total_difficulty().to_owned(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Reconsiders the block in the chain, and the sync state, the blocks of its descendants
+    /// are downloaded again
+    pub fn reconsider_block(
+        &self,
+        chain: &ChainController,
+        block_hash: Byte32,
+    ) -> Result<(), CKBError> {
+        chain.reconsider_block(block_hash.clone())?;
+        self.state.remove_block_status(&block_hash);
+
+        // the chain may switch to a heavier fork
+        let active_chain = self.active_chain();
+        let tip = HeaderView::new(
+            active_chain.tip_header(),
+            active_chain.total_difficulty().to_owned(),
+        );
+        if tip.is_better_than(self.state.shared_best_header().total_difficulty()) {
+            self.state.reset_shared_best_header(tip);
+        }
+        Ok(())
+    }
+
     /// Insert new block to chain store
     pub fn insert_new_block(
         &self,
@@ -1256,26 +1365,45 @@ impl SyncShared {
                     "try accepting {} descendant orphan blocks by exist parents hash",
                     descendants.len()
                 );
-                for block in descendants {
-                    // If we can not find the block's parent in database, that means it was failed to accept
-                    // its parent, so we treat it as an invalid block as well.
-                    if !self.is_stored(&block.parent_hash()) {
+                // The descendants are submitted together, so the chain verifies them ahead in
+                // a pipeline during the initial block download
+                let submitted: Vec<_> = descendants
+                    .into_iter()
+                    .map(|block| {
+                        let block = Arc::new(block);
+                        let switch = self.assume_valid_switch(&block).unwrap_or(Switch::NONE);
+                        let response = chain.submit_block(Arc::clone(&block), switch);
+                        (block, response)
+                    })
+                    .collect();
+                let mut rejected = HashSet::new();
+                for (block, response) in submitted {
+                    // If the block's parent was failed to accept, we treat it as an invalid
+                    // block as well, the chain rejects it without verification.
+                    if rejected.contains(&block.parent_hash()) {
                         debug!(
                             "parent-unknown orphan block, block: {}, {}, parent: {}",
                             block.header().number(),
                             block.header().hash(),
                             block.header().parent_hash(),
                         );
+                        let _ = response.recv();
+                        rejected.insert(block.hash());
                         continue;
                     }
 
-                    let block = Arc::new(block);
-                    if let Err(err) = self.accept_block(chain, Arc::clone(&block)) {
+                    let ret = response.recv().unwrap_or_else(|_| {
+                        Err(InternalErrorKind::System
+                            .other("Chain service has gone")
+                            .into())
+                    });
+                    if let Err(err) = self.block_accepted(&block, ret) {
                         debug!(
                             "accept descendant orphan block {} error {:?}",
                             block.header().hash(),
                             err
                         );
+                        rejected.insert(block.hash());
                     }
                 }
             }
@@ -1297,22 +1425,31 @@ impl SyncShared {
         chain: &ChainController,
         block: Arc<core::BlockView>,
     ) -> Result<bool, CKBError> {
-        let ret = {
-            let mut assume_valid_target = self.state.assume_valid_target();
-            if let Some(ref target) = *assume_valid_target {
-                // if the target has been reached, delete it
-                let switch = if target == &Unpack::<H256>::unpack(&core::BlockView::hash(&block)) {
-                    assume_valid_target.take();
-                    Switch::NONE
-                } else {
-                    Switch::DISABLE_SCRIPT
-                };
-
-                chain.internal_process_block(Arc::clone(&block), switch)
-            } else {
-                chain.process_block(Arc::clone(&block))
-            }
+        let ret = match self.assume_valid_switch(&block) {
+            Some(switch) => chain.internal_process_block(Arc::clone(&block), switch),
+            None => chain.process_block(Arc::clone(&block)),
         };
+        self.block_accepted(&block, ret)
+    }
+
+    // The verification switch while the assume valid target is not reached
+    fn assume_valid_switch(&self, block: &core::BlockView) -> Option<Switch> {
+        let mut assume_valid_target = self.state.assume_valid_target();
+        let target = assume_valid_target.as_ref()?;
+        // if the target has been reached, delete it
+        if target == &Unpack::<H256>::unpack(&block.hash()) {
+            assume_valid_target.take();
+            Some(Switch::NONE)
+        } else {
+            Some(Switch::DISABLE_SCRIPT)
+        }
+    }
+
+    fn block_accepted(
+        &self,
+        block: &core::BlockView,
+        ret: Result<bool, CKBError>,
+    ) -> Result<bool, CKBError> {
         if let Err(ref error) = ret {
             if !is_internal_db_error(error) {
                 error!("accept block {:?} {}", block, error);
@@ -1326,8 +1463,8 @@ impl SyncShared {
             // So we just simply remove the corresponding in-memory block status,
             // and the next time `get_block_status` would acquire the real-time
             // status via fetching block_ext from the database.
-            self.state.remove_block_status(&block.as_ref().hash());
-            self.state.remove_header_view(&block.as_ref().hash());
+            self.state.remove_block_status(&block.hash());
+            self.state.remove_header_view(&block.hash());
         }
 
         ret
@@ -1366,7 +1503,10 @@ impl SyncShared {
                 }
             },
         );
-        self.state.header_map.insert(header_view.clone());
+        if self.state.header_map.insert(header_view.clone()).is_none() {
+            self.state.peers().new_header_received(peer);
+        }
+        self.state.update_chain_tips(header);
         self.state
             .peers()
             .may_set_best_known_header(peer, header_view.clone());
@@ -1496,6 +1636,9 @@ pub struct SyncState {
 
     // The priority is ordering by timestamp (reversed), means do not ask the tx before this timestamp (timeout).
     unknown_tx_hashes: Mutex<KeyedPriorityQueue<Byte32, UnknownTxHashPriority>>,
+    // The transactions asked from the peers, so the responses after the request timed out are
+    // not taken as unrequested
+    requested_txs: Mutex<Filter<(Byte32, PeerIndex)>>,
 
     /* Status relevant to peers */
     peers: Peers,
@@ -1514,6 +1657,12 @@ pub struct SyncState {
     tx_relay_receiver: Receiver<TxVerificationResult>,
     assume_valid_target: Mutex<Option<H256>>,
     min_chain_work: U256,
+
+    /* The tips of the known forks, by the block numbers */
+    chain_tips: Mutex<HashMap<Byte32, BlockNumber>>,
+    /* The last tip and when it is seen first, to detect the stale tip */
+    last_tip_update: Mutex<(Byte32, u64)>,
+    stale_tip: AtomicBool,
 }
 
 impl SyncState {
@@ -1594,6 +1743,51 @@ impl SyncState {
         *self.shared_best_header.write() = header;
     }
 
+    // Replaces the shared best header, even with a worse one, e.g. when the best one is on a
+    // chain invalidated by the local node
+    fn reset_shared_best_header(&self, header: HeaderView) {
+        metrics!(gauge, "ckb.shared_best_number", header.number() as i64);
+        *self.shared_best_header.write() = header;
+    }
+
+    // Tracks the header as a chain tip, its parent is not a tip any more
+    fn update_chain_tips(&self, header: &core::HeaderView) {
+        let mut chain_tips = self.chain_tips.lock();
+        chain_tips.remove(&header.parent_hash());
+        chain_tips.insert(header.hash(), header.number());
+        if chain_tips.len() > MAX_CHAIN_TIPS {
+            let lowest = chain_tips
+                .iter()
+                .min_by_key(|(_, number)| **number)
+                .map(|(hash, _)| hash.clone());
+            if let Some(hash) = lowest {
+                chain_tips.remove(&hash);
+            }
+        }
+    }
+
+    /// Records the current tip, returns how long the tip has not changed.
+    pub fn tip_unchanged_duration(&self, tip_hash: &Byte32) -> Duration {
+        let mut last_tip_update = self.last_tip_update.lock();
+        if &last_tip_update.0 != tip_hash {
+            *last_tip_update = (tip_hash.clone(), unix_time_as_millis());
+        }
+        Duration::from_millis(unix_time_as_millis().saturating_sub(last_tip_update.1))
+    }
+
+    /// Sets whether the tip is stale, returns the previous state.
+    pub fn set_stale_tip(&self, stale: bool) -> bool {
+        self.stale_tip.swap(stale, Ordering::AcqRel)
+    }
+
+    /// The hashes of the chain tips tracked from the received headers.
+    ///
+    /// A tracked tip may be stored and extended by the blocks later, or be in the main chain.
+    /// The tips of the stored blocks are in the store.
+    pub fn chain_tips(&self) -> Vec<Byte32> {
+        self.chain_tips.lock().keys().cloned().collect()
+    }
+
     pub fn remove_header_view(&self, hash: &Byte32) {
         self.header_map.remove(hash);
     }
@@ -1654,9 +1848,11 @@ impl SyncState {
             return result;
         }
 
+        let mut requested_txs = self.requested_txs.lock();
         while let Some((tx_hash, mut priority)) = unknown_tx_hashes.pop() {
             if priority.should_request(now) {
                 if let Some(peer_index) = priority.next_request_peer() {
+                    requested_txs.insert((tx_hash.clone(), peer_index));
                     result
                         .entry(peer_index)
                         .and_modify(|hashes| hashes.push(tx_hash.clone()))
@@ -1722,6 +1918,11 @@ impl SyncState {
         Status::ok()
     }
 
+    // Whether the transaction has been asked from the peer
+    pub fn is_tx_requested(&self, hash: &Byte32, peer: PeerIndex) -> bool {
+        self.requested_txs.lock().contains(&(hash.clone(), peer))
+    }
+
     pub fn already_known_tx(&self, hash: &Byte32) -> bool {
         self.tx_filter.lock().contains(hash)
     }
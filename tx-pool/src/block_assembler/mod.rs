@@ -27,7 +27,7 @@ use ckb_types::{
 use lru::LruCache;
 use std::collections::HashSet;
 use std::sync::{atomic::AtomicU64, Arc};
-use tokio::sync::Mutex;
+use tokio::sync::{Mutex, Notify};
 use tokio::task::block_in_place;
 
 const BLOCK_TEMPLATE_TIMEOUT: u64 = 3000;
@@ -65,6 +65,8 @@ pub struct BlockAssembler {
     pub(crate) last_uncles_updated_at: Arc<AtomicU64>,
     pub(crate) template_caches: Arc<Mutex<LruCache<BlockTemplateCacheKey, TemplateCache>>>,
     pub(crate) candidate_uncles: Arc<Mutex<CandidateUncles>>,
+    // notified when the txs, the uncles or the tip which the block template is built on change
+    pub(crate) template_changed: Arc<Notify>,
 }
 
 impl BlockAssembler {
@@ -76,6 +78,7 @@ impl BlockAssembler {
             last_uncles_updated_at: Arc::new(AtomicU64::new(0)),
             template_caches: Arc::new(Mutex::new(LruCache::new(TEMPLATE_CACHE_SIZE))),
             candidate_uncles: Arc::new(Mutex::new(CandidateUncles::new())),
+            template_changed: Arc::new(Notify::new()),
         }
     }
 
@@ -1,6 +1,7 @@
 use super::component::TxEntry;
 use crate::error::Reject;
 use crate::pool::TxPool;
+use ckb_jsonrpc_types::BlockTemplate;
 
 /// Callback boxed fn pointer wrapper
 pub type Callback = Box<dyn Fn(&mut TxPool, &TxEntry) + Sync + Send>;
@@ -8,6 +9,8 @@ pub type Callback = Box<dyn Fn(&mut TxPool, &TxEntry) + Sync + Send>;
 pub type ProposedCallback = Box<dyn Fn(&mut TxPool, &TxEntry, bool) + Sync + Send>;
 /// Reject Callback boxed fn pointer wrapper
 pub type RejectCallback = Box<dyn Fn(&mut TxPool, &TxEntry, Reject) + Sync + Send>;
+/// Block template Callback boxed fn pointer wrapper
+pub type BlockTemplateCallback = Box<dyn Fn(&BlockTemplate) + Sync + Send>;
 
 /// Struct hold callbacks
 pub struct Callbacks {
@@ -15,6 +18,7 @@ pub struct Callbacks {
     pub(crate) proposed: Option<ProposedCallback>,
     pub(crate) committed: Option<Callback>,
     pub(crate) reject: Option<RejectCallback>,
+    pub(crate) block_template: Option<BlockTemplateCallback>,
 }
 
 impl Default for Callbacks {
@@ -31,6 +35,7 @@ impl Callbacks {
             proposed: None,
             committed: None,
             reject: None,
+            block_template: None,
         }
     }
 
@@ -54,6 +59,11 @@ impl Callbacks {
         self.reject = Some(callback);
     }
 
+    /// Register a new block template callback
+    pub fn register_block_template(&mut self, callback: BlockTemplateCallback) {
+        self.block_template = Some(callback);
+    }
+
     /// Call on after pending
     pub fn call_pending(&self, tx_pool: &mut TxPool, entry: &TxEntry) {
         if let Some(call) = &self.pending {
@@ -81,4 +91,11 @@ impl Callbacks {
             call(tx_pool, entry, reject)
         }
     }
+
+    /// Call on after block template changed
+    pub fn call_block_template(&self, template: &BlockTemplate) {
+        if let Some(call) = &self.block_template {
+            call(template)
+        }
+    }
 }
@@ -19,7 +19,7 @@ use ckb_dao::DaoCalculator;
 use ckb_error::{AnyError, InternalErrorKind};
 use ckb_jsonrpc_types::BlockTemplate;
 use ckb_logger::{debug, error, info};
-use ckb_network::PeerIndex;
+use ckb_network::{Behaviour, PeerIndex};
 use ckb_snapshot::Snapshot;
 use ckb_store::ChainStore;
 use ckb_types::{
@@ -491,6 +491,42 @@ impl TxPoolService {
         }
     }
 
+    /// Wakes up the block template notification, the template is rebuilt and notified if it
+    /// has changed.
+    pub(crate) fn notify_template_changed(&self) {
+        if let Some(ref block_assembler) = self.block_assembler {
+            block_assembler.template_changed.notify_one();
+        }
+    }
+
+    /// Notifies the block template via the callback if it has changed since `last_template`.
+    pub(crate) async fn notify_block_template(&self, last_template: &mut Option<BlockTemplate>) {
+        let snapshot = self.tx_pool.read().await.cloned_snapshot();
+        match self
+            .get_block_template(None, None, None, snapshot, None)
+            .await
+        {
+            Ok(template) => {
+                // a rebuilt template of the same content only differs in these fields
+                let changed = last_template
+                    .as_ref()
+                    .map(|last| {
+                        BlockTemplate {
+                            work_id: last.work_id,
+                            current_time: last.current_time,
+                            ..template.clone()
+                        } != *last
+                    })
+                    .unwrap_or(true);
+                if changed {
+                    self.callbacks.call_block_template(&template);
+                    *last_template = Some(template);
+                }
+            }
+            Err(err) => debug!("notify_block_template error {}", err),
+        }
+    }
+
     pub(crate) async fn fetch_tx_verify_cache(&self, hash: &Byte32) -> Option<CacheEntry> {
         let guard = self.txs_verify_cache.read().await;
         guard.peek(hash).cloned()
@@ -543,6 +579,9 @@ impl TxPoolService {
                 Ok(())
             })
             .await;
+        if ret.is_ok() {
+            self.notify_template_changed();
+        }
 
         (ret, snapshot)
     }
@@ -746,6 +785,9 @@ impl TxPoolService {
                     } else {
                         if reject.is_malformed_tx() {
                             self.ban_malformed(peer, format!("reject {}", reject));
+                        } else if matches!(reject, Reject::Verification(..)) {
+                            self.network
+                                .report_peer(peer, Behaviour::InvalidTransaction);
                         }
                         if matches!(reject, Reject::Resolve(..) | Reject::Verification(..)) {
                             self.put_recent_reject(&tx_hash, reject).await;
@@ -869,6 +911,9 @@ impl TxPoolService {
 
                                 if reject.is_malformed_tx() {
                                     self.ban_malformed(orphan.peer, format!("reject {}", reject));
+                                } else if matches!(reject, Reject::Verification(..)) {
+                                    self.network
+                                        .report_peer(orphan.peer, Behaviour::InvalidTransaction);
                                 }
                                 if matches!(reject, Reject::Resolve(..) | Reject::Verification(..))
                                 {
@@ -1,7 +1,9 @@
 //! Tx-pool background service
 
 use crate::block_assembler::BlockAssembler;
-use crate::callback::{Callback, Callbacks, ProposedCallback, RejectCallback};
+use crate::callback::{
+    BlockTemplateCallback, Callback, Callbacks, ProposedCallback, RejectCallback,
+};
 use crate::chunk_process::Command;
 use crate::component::{chunk::ChunkQueue, entry::TxEntry, orphan::OrphanPool};
 use crate::error::{handle_recv_error, handle_send_cmd_error, handle_try_send_error};
@@ -34,11 +36,14 @@ use std::sync::{
     atomic::{AtomicBool, AtomicU64, Ordering},
     Arc,
 };
+use std::time::Duration;
 use tokio::sync::watch;
 use tokio::sync::{mpsc, RwLock};
 use tokio::task::block_in_place;
 
 pub(crate) const DEFAULT_CHANNEL_SIZE: usize = 512;
+// The block template is rebuilt for the notification at most once per this interval
+const TEMPLATE_NOTIFY_INTERVAL: Duration = Duration::from_millis(100);
 
 pub(crate) struct Request<A, R> {
     pub responder: oneshot::Sender<R>,
@@ -622,6 +627,11 @@ impl TxPoolServiceBuilder {
         self.callbacks.register_reject(callback);
     }
 
+    /// Register new block template callback
+    pub fn register_block_template(&mut self, callback: BlockTemplateCallback) {
+        self.callbacks.register_block_template(callback);
+    }
+
     /// Start a background thread tx-pool service by taking ownership of the Builder, and returns a TxPoolController.
     pub fn start(self, network: NetworkController) {
         let last_txs_updated_at = Arc::new(AtomicU64::new(0));
@@ -671,6 +681,30 @@ impl TxPoolServiceBuilder {
         let mut reorg_receiver = self.reorg_receiver;
         let handle_clone = self.handle.clone();
 
+        // the block template is rebuilt and notified when it may be changed, the changes
+        // during a rebuild and the interval after it are coalesced into the next one
+        if let Some(ref block_assembler) = service.block_assembler {
+            let template_service = service.clone();
+            let template_changed = Arc::clone(&block_assembler.template_changed);
+            let mut signal_receiver = self.signal_receiver.clone();
+            self.handle.spawn(async move {
+                let mut last_template = None;
+                loop {
+                    tokio::select! {
+                        _ = template_changed.notified() => {},
+                        _ = signal_receiver.changed() => break,
+                    }
+                    template_service
+                        .notify_block_template(&mut last_template)
+                        .await;
+                    tokio::select! {
+                        _ = tokio::time::sleep(TEMPLATE_NOTIFY_INTERVAL) => {},
+                        _ = signal_receiver.changed() => break,
+                    }
+                }
+            });
+        }
+
         let process_service = service.clone();
         let mut signal_receiver = self.signal_receiver.clone();
         self.handle.spawn(async move {
@@ -702,7 +736,8 @@ impl TxPoolServiceBuilder {
                             detached_proposal_id,
                             snapshot,
                         )
-                        .await
+                        .await;
+                        service.notify_template_changed();
                     },
                     _ = signal_receiver.changed() => break,
                     else => break,
@@ -949,6 +984,7 @@ async fn process(mut service: TxPoolService, message: Message) {
                 block_assembler
                     .last_uncles_updated_at
                     .store(unix_time_as_millis(), Ordering::SeqCst);
+                block_assembler.template_changed.notify_one();
             }
         }
         Message::PlugEntry(Request {
@@ -970,6 +1006,7 @@ async fn process(mut service: TxPoolService, message: Message) {
                     }
                 }
             };
+            service.notify_template_changed();
             if let Err(e) = responder.send(()) {
                 error!("responder send plug_entry failed {:?}", e);
             };
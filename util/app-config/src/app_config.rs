@@ -71,6 +71,9 @@ pub struct CKBAppConfig {
 
     /// Block assembler options.
     pub block_assembler: Option<BlockAssemblerConfig>,
+    /// Stratum server options.
+    #[serde(default)]
+    pub stratum: Option<StratumConfig>,
     /// Database config options.
     #[serde(default)]
     pub db: DBConfig,
@@ -277,6 +280,11 @@ impl CKBAppConfig {
         }))?;
 
         self.network.path = self.data_dir.join("network");
+        self.network.sync.header_map.path = self.data_dir.join("header_map");
+        self.network.asmap = self
+            .network
+            .asmap
+            .map(|asmap| canonicalize_data_dir(asmap, root_dir));
         if self.tmp_dir.is_none() {
             self.tmp_dir = Some(self.data_dir.join("tmp"));
         }
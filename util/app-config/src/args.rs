@@ -182,6 +182,10 @@ pub struct MigrateArgs {
     pub check: bool,
     /// Do migration without interactive prompt.
     pub force: bool,
+    /// Print the pending migrations instead of really perform the migration.
+    pub dry_run: bool,
+    /// Report the migration progress as JSON lines.
+    pub json_progress: bool,
 }
 
 /// Parsed command line arguments for `ckb db-repair`.
@@ -190,6 +194,28 @@ pub struct RepairArgs {
     pub config: Box<CKBAppConfig>,
 }
 
+/// Parsed command line arguments for `ckb db-stats`.
+pub struct DBStatsArgs {
+    /// Parsed `ckb.toml`.
+    pub config: Box<CKBAppConfig>,
+    /// Loaded consensus.
+    pub consensus: Consensus,
+    /// Column families to compact before printing the statistics.
+    pub compact: Vec<String>,
+}
+
+/// Parsed command line arguments for `ckb db-verify`.
+pub struct DBVerifyArgs {
+    /// Parsed `ckb.toml`.
+    pub config: Box<CKBAppConfig>,
+    /// Loaded consensus.
+    pub consensus: Consensus,
+    /// The directory to make the temporary scratch database in.
+    pub tmp_target: PathBuf,
+    /// Overwrites the stored entries with the rebuilt ones.
+    pub fix: bool,
+}
+
 impl CustomizeSpec {
     /// No specified parameters for chain spec.
     pub fn is_unset(&self) -> bool {
@@ -33,6 +33,10 @@ pub const CMD_FROM_SECRET: &str = "from-secret";
 pub const CMD_MIGRATE: &str = "migrate";
 /// Subcommand `db-repair`.
 pub const CMD_DB_REPAIR: &str = "db-repair";
+/// Subcommand `db-stats`.
+pub const CMD_DB_STATS: &str = "db-stats";
+/// Subcommand `db-verify`.
+pub const CMD_DB_VERIFY: &str = "db-verify";
 
 /// Command line argument `--config-dir`.
 pub const ARG_CONFIG_DIR: &str = "config-dir";
@@ -110,6 +114,14 @@ pub const ARG_OVERWRITE_CHAIN_SPEC: &str = "overwrite-spec";
 pub const ARG_ASSUME_VALID_TARGET: &str = "assume-valid-target";
 /// Command line argument `--check`.
 pub const ARG_MIGRATE_CHECK: &str = "check";
+/// Command line argument `--compact`.
+pub const ARG_COMPACT: &str = "compact";
+/// Command line argument `--dry-run`.
+pub const ARG_DRY_RUN: &str = "dry-run";
+/// Command line argument `--json-progress`.
+pub const ARG_JSON_PROGRESS: &str = "json-progress";
+/// Command line argument `--fix`.
+pub const ARG_FIX: &str = "fix";
 
 /// Command line arguments group `ba` for block assembler.
 const GROUP_BA: &str = "ba";
@@ -141,6 +153,8 @@ pub(crate) fn basic_app<'b>() -> App<'static, 'b> {
         .subcommand(peer_id())
         .subcommand(migrate())
         .subcommand(db_repair())
+        .subcommand(db_stats())
+        .subcommand(db_verify())
 }
 
 /// Parse the command line arguments by supplying the version information.
@@ -351,12 +365,68 @@ fn migrate() -> App<'static, 'static> {
                 .conflicts_with(ARG_MIGRATE_CHECK)
                 .help("Do migration without interactive prompt"),
         )
+        .arg(
+            Arg::with_name(ARG_DRY_RUN)
+                .long(ARG_DRY_RUN)
+                .conflicts_with_all(&[ARG_MIGRATE_CHECK, ARG_FORCE])
+                .help("Print the pending migrations with their estimated work without migrating"),
+        )
+        .arg(
+            Arg::with_name(ARG_JSON_PROGRESS)
+                .long(ARG_JSON_PROGRESS)
+                .conflicts_with_all(&[ARG_MIGRATE_CHECK, ARG_DRY_RUN])
+                .help(
+                    "Report the migration progress as one JSON object per line \
+                    instead of progress bars",
+                ),
+        )
 }
 
 fn db_repair() -> App<'static, 'static> {
     SubCommand::with_name(CMD_DB_REPAIR).about("Try repair ckb database")
 }
 
+fn db_verify() -> App<'static, 'static> {
+    SubCommand::with_name(CMD_DB_VERIFY)
+        .about(
+            "Rebuilds the live cell set, cell data, number hash and transaction info \
+             from the main chain blocks and reports the differences to the stored ones",
+        )
+        .arg(
+            Arg::with_name(ARG_TMP_TARGET)
+                .long(ARG_TMP_TARGET)
+                .takes_value(true)
+                .help(
+                    "Specifies a target path, the rebuilt columns are kept in a temporary \
+                     directory inside of target which is deleted when finished. \
+                     The default is the tmp directory of the data directory",
+                ),
+        )
+        .arg(
+            Arg::with_name(ARG_FIX)
+                .long(ARG_FIX)
+                .help("Overwrites the stored entries with the rebuilt ones"),
+        )
+}
+
+fn db_stats() -> App<'static, 'static> {
+    SubCommand::with_name(CMD_DB_STATS)
+        .about(
+            "Prints database statistics of each column family and freezer table\n\
+             Example:\n\
+             ckb -C <dir> db-stats --compact 2",
+        )
+        .arg(
+            Arg::with_name(ARG_COMPACT)
+                .long(ARG_COMPACT)
+                .value_name("column")
+                .takes_value(true)
+                .multiple(true)
+                .number_of_values(1)
+                .help("Compacts the whole key range of the column family before printing"),
+        )
+}
+
 fn list_hashes() -> App<'static, 'static> {
     SubCommand::with_name(CMD_LIST_HASHES)
         .about("Lists well known hashes")
@@ -18,12 +18,49 @@ pub struct Config {
 pub struct ClientConfig {
     /// CKB node RPC endpoint.
     pub rpc_url: String,
+    /// Backup CKB node RPC endpoints.
+    ///
+    /// Miner gets work from the first healthy endpoint in the order of `rpc_url` followed by
+    /// these ones, and submits the found blocks to all the healthy endpoints.
+    #[serde(default)]
+    pub backup_rpc_urls: Vec<String>,
     /// The pool interval in seconds to get work from the CKB node.
     pub poll_interval: u64,
     /// By default, miner submits a block and continues to get the next work.
     ///
     /// When this is enabled, miner will block until the submission RPC returns.
     pub block_on_submit: bool,
+    /// The TCP RPC address of the CKB node to subscribe the block templates.
+    ///
+    /// When it is set, miner gets the new work pushed via the `block_template` subscription, and
+    /// falls back to polling while the subscription is unavailable.
+    #[serde(default)]
+    pub subscription_address: Option<String>,
+    /// Backup TCP RPC addresses to subscribe the block templates.
+    ///
+    /// Miner subscribes via the first available one in the order of `subscription_address`
+    /// followed by these ones.
+    #[serde(default)]
+    pub backup_subscription_addresses: Vec<String>,
+    /// The timeout in milliseconds of the RPC requests (default 10000).
+    ///
+    /// An endpoint is considered unhealthy when its request times out.
+    #[serde(default = "default_rpc_timeout")]
+    pub rpc_timeout: u64,
+    /// The timeout in seconds to receive a block template from the subscription (default 60).
+    ///
+    /// The subscription is considered broken when nothing is pushed for this long, so it should
+    /// be longer than the expected block interval.
+    #[serde(default = "default_subscription_read_timeout")]
+    pub subscription_read_timeout: u64,
+}
+
+const fn default_rpc_timeout() -> u64 {
+    10_000
+}
+
+const fn default_subscription_read_timeout() -> u64 {
+    60
 }
 
 /// Miner worker config options.
@@ -15,13 +15,13 @@ pub use miner::{
     ExtraHashFunction, WorkerConfig as MinerWorkerConfig,
 };
 pub use network::{
-    default_support_all_protocols, Config as NetworkConfig, HeaderMapConfig, SupportProtocol,
-    SyncConfig,
+    default_support_all_protocols, BandwidthConfig, Config as NetworkConfig, DandelionConfig,
+    HeaderMapConfig, ProxyConfig, SupportProtocol, SyncConfig,
 };
 pub use network_alert::Config as NetworkAlertConfig;
 pub use notify::Config as NotifyConfig;
 pub use rpc::{Config as RpcConfig, Module as RpcModule};
 pub use store::Config as StoreConfig;
-pub use tx_pool::{BlockAssemblerConfig, TxPoolConfig};
+pub use tx_pool::{BlockAssemblerConfig, StratumConfig, TxPoolConfig};
 
 pub(crate) use network::{generate_random_key, read_secret_key, write_secret_to_file};
@@ -1,9 +1,11 @@
+use ckb_chain_spec::Checkpoint;
 use ckb_types::{H256, U256};
 use multiaddr::Multiaddr;
 use rand::Rng;
 use serde::{Deserialize, Serialize};
 use std::fs;
 use std::io::{Error, ErrorKind, Read, Write};
+use std::net::SocketAddr;
 use std::path::PathBuf;
 
 // Max data size in send buffer: 24MB (a little larger than max frame length)
@@ -24,6 +26,13 @@ pub struct Config {
     ///
     /// When node A connects to B, B is the outbound peer of A.
     pub max_outbound_peers: u32,
+    /// Number of extra outbound peers which only relay blocks.
+    ///
+    /// They take part in neither transaction relay nor address gossip, which makes them
+    /// hard to discover and helps to resist eclipse attacks. They are not counted in
+    /// `max_outbound_peers`.
+    #[serde(default)]
+    pub max_outbound_block_relay_peers: u32,
     /// Network data storage directory path.
     #[serde(default)]
     pub path: PathBuf,
@@ -72,6 +81,8 @@ pub struct Config {
     #[serde(default)]
     pub bootnode_mode: bool,
     /// Supported protocols list
+    ///
+    /// The optional `LightClient` protocol is not in the default list.
     #[serde(default = "default_support_all_protocols")]
     pub support_protocols: Vec<SupportProtocol>,
     /// Max send buffer size in bytes.
@@ -79,9 +90,122 @@ pub struct Config {
     /// Network use reuse port or not
     #[serde(default = "default_reuse")]
     pub reuse_port_on_linux: bool,
+    /// Peers are banned once their misbehaviour score drops below this threshold.
+    ///
+    /// Every peer starts with the score 100.
+    #[serde(default)]
+    pub ban_score: Option<i32>,
+    /// How long a misbehaving peer is banned, in seconds.
+    #[serde(default)]
+    pub ban_timeout_secs: Option<u64>,
+    /// Path to the AS map file, which maps IP networks to autonomous system numbers.
+    ///
+    /// When it is set, peers are grouped by ASN instead of IP prefix to pick the inbound
+    /// peers to evict, and the outbound peers are dialed in distinct groups only. Every line
+    /// of the file is an IP network and an ASN separated by whitespace, e.g.
+    /// `1.0.0.0/24 13335`, lines starting with `#` are ignored.
+    #[serde(default)]
+    pub asmap: Option<PathBuf>,
     /// Chain synchronization config options.
     #[serde(default)]
     pub sync: SyncConfig,
+    /// Upload limits.
+    #[serde(default)]
+    pub bandwidth: BandwidthConfig,
+    /// Dandelion++ transaction relay.
+    #[serde(default)]
+    pub dandelion: DandelionConfig,
+    /// Relay the transaction announcements by set reconciliation with the peers which
+    /// support it, the relay protocol version 4 is only advertised when it is enabled.
+    #[serde(default)]
+    pub tx_reconciliation: bool,
+    /// SOCKS5 proxy which all outbound connections go through.
+    ///
+    /// When it is set, the listen addresses and the addresses observed by remote peers are
+    /// never announced, only the onion addresses in `public_addresses` are. Without it, onion
+    /// addresses are neither stored nor dialed.
+    ///
+    /// Onion services are addressed as `/dns4/<service id>.onion/tcp/<port>`, since the
+    /// multiaddr implementation in use does not support `/onion3`. This is a compatibility
+    /// workaround: these addresses are only understood by nodes treating `.onion` host names
+    /// the same way.
+    #[serde(default)]
+    pub proxy: Option<ProxyConfig>,
+}
+
+/// Upload limits config options.
+///
+/// The messages of all protocols, including identify, ping and discovery, are counted
+/// towards the limits, except the disconnect messages sent when closing a session.
+/// When a limit is reached, the node stops serving blocks to the sync protocol until the
+/// window ends, other protocols are not throttled.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct BandwidthConfig {
+    /// The length of the window in seconds.
+    pub window_secs: u64,
+    /// Maximum bytes uploaded to all peers in a window, no limit if not set.
+    #[serde(default)]
+    pub max_upload_bytes: Option<u64>,
+    /// Maximum bytes uploaded to a single peer in a window, no limit if not set.
+    #[serde(default)]
+    pub max_peer_upload_bytes: Option<u64>,
+}
+
+impl Default for BandwidthConfig {
+    fn default() -> Self {
+        Self {
+            window_secs: 60,
+            max_upload_bytes: None,
+            max_peer_upload_bytes: None,
+        }
+    }
+}
+
+/// Dandelion++ transaction relay config options.
+///
+/// In the stem phase a transaction is forwarded to a single outbound peer, which forwards it
+/// again or broadcasts it, the fluff phase. A transaction in the stem phase is broadcast by
+/// this node when it is not seen broadcast by others before its embargo ends.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct DandelionConfig {
+    /// Relay the local transactions and the stem transactions through the stem phase.
+    pub enable: bool,
+    /// The probability in percent that this node broadcasts the stem transactions it
+    /// receives in an epoch, instead of forwarding them.
+    pub fluff_probability: u8,
+    /// The length of an epoch in seconds, the stem peer and the fluff decision are renewed
+    /// every epoch.
+    pub epoch_secs: u64,
+    /// The minimum embargo of a stem transaction in seconds, a random delay up to the same
+    /// length is added.
+    pub embargo_secs: u64,
+}
+
+impl Default for DandelionConfig {
+    fn default() -> Self {
+        Self {
+            enable: false,
+            fluff_probability: 10,
+            epoch_secs: 600,
+            embargo_secs: 30,
+        }
+    }
+}
+
+/// SOCKS5 proxy config options.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ProxyConfig {
+    /// The proxy address, e.g. "127.0.0.1:9050" for a local Tor daemon.
+    pub address: SocketAddr,
+    /// Username for the username/password authentication.
+    #[serde(default)]
+    pub username: Option<String>,
+    /// Password for the username/password authentication.
+    #[serde(default)]
+    pub password: Option<String>,
 }
 
 /// Chain synchronization config options.
@@ -97,6 +221,9 @@ pub struct SyncConfig {
     /// Proof of minimum work during synchronization
     #[serde(skip, default)]
     pub min_chain_work: U256,
+    /// Checkpoints which the chain must go through, besides the ones in the chain spec
+    #[serde(default)]
+    pub checkpoints: Vec<Checkpoint>,
 }
 
 /// Header map config options.
@@ -109,6 +236,12 @@ pub struct HeaderMapConfig {
     pub primary_limit: usize,
     /// Disable cache if the size of data in memory less than this threshold
     pub backend_close_threshold: usize,
+    /// Keep the header map in the data directory, so the headers survive the restarts
+    #[serde(default)]
+    pub persistent: bool,
+    /// The directory to keep the header map when it is persistent
+    #[serde(default)]
+    pub path: PathBuf,
 }
 
 impl Default for HeaderMapConfig {
@@ -116,6 +249,8 @@ impl Default for HeaderMapConfig {
         Self {
             primary_limit: 300_000,
             backend_close_threshold: 20_000,
+            persistent: false,
+            path: PathBuf::new(),
         }
     }
 }
@@ -132,6 +267,8 @@ pub enum SupportProtocol {
     Relay,
     Time,
     Alert,
+    BlockFilter,
+    LightClient,
 }
 
 #[allow(missing_docs)]
@@ -146,6 +283,7 @@ pub fn default_support_all_protocols() -> Vec<SupportProtocol> {
         SupportProtocol::Relay,
         SupportProtocol::Time,
         SupportProtocol::Alert,
+        SupportProtocol::BlockFilter,
     ]
 }
 
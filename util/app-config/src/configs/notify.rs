@@ -11,4 +11,9 @@ pub struct Config {
     ///
     /// The script is called with the alert message as the argument.
     pub network_alert_notify_script: Option<String>,
+    /// An executable script to be called once the tip becomes stale, i.e. it has not changed
+    /// for a long time.
+    ///
+    /// The script is called with the tip block hash as the argument.
+    pub stale_tip_notify_script: Option<String>,
 }
@@ -63,6 +63,42 @@ const fn default_use_binary_version_as_message_prefix() -> bool {
     true
 }
 
+/// Stratum server config options.
+///
+/// The stratum server pushes the jobs built from the block template to the connected miners, it
+/// requires the block assembler.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct StratumConfig {
+    /// The address the stratum server listens on.
+    pub listen_address: String,
+    /// The password the workers authorize with.
+    ///
+    /// It is required unless the server listens on a loopback address.
+    #[serde(default)]
+    pub password: Option<String>,
+    /// The target the shares must meet.
+    ///
+    /// The block target is used when it is not set or the block target is easier.
+    #[serde(default)]
+    pub share_target: Option<H256>,
+    /// The bytes of the nonce allocated to each connection as its extranonce (default 4).
+    #[serde(default = "default_extranonce_size")]
+    pub extranonce_size: usize,
+    /// The maximum number of the concurrent sessions, the connections beyond it are closed
+    /// (default 256).
+    #[serde(default = "default_max_sessions")]
+    pub max_sessions: usize,
+}
+
+const fn default_extranonce_size() -> usize {
+    4
+}
+
+const fn default_max_sessions() -> usize {
+    256
+}
+
 impl TxPoolConfig {
     /// Canonicalizes paths in the config options.
     ///
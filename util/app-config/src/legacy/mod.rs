@@ -41,6 +41,8 @@ pub(crate) struct CKBAppConfig {
     chain: crate::ChainConfig,
     block_assembler: Option<crate::BlockAssemblerConfig>,
     #[serde(default)]
+    stratum: Option<crate::StratumConfig>,
+    #[serde(default)]
     db: crate::DBConfig,
 
     #[serde(default)]
@@ -95,6 +97,7 @@ impl From<CKBAppConfig> for crate::CKBAppConfig {
             memory_tracker,
             chain,
             block_assembler,
+            stratum,
             db,
             indexer: _,
             network,
@@ -119,6 +122,7 @@ impl From<CKBAppConfig> for crate::CKBAppConfig {
             memory_tracker,
             chain,
             block_assembler,
+            stratum,
             db,
             network,
             rpc,
@@ -15,8 +15,8 @@ pub use app_config::{
     AppConfig, CKBAppConfig, ChainConfig, LogConfig, MetricsConfig, MinerAppConfig,
 };
 pub use args::{
-    ExportArgs, ImportArgs, InitArgs, MigrateArgs, MinerArgs, PeerIDArgs, RepairArgs, ReplayArgs,
-    ResetDataArgs, RunArgs, StatsArgs,
+    DBStatsArgs, DBVerifyArgs, ExportArgs, ImportArgs, InitArgs, MigrateArgs, MinerArgs,
+    PeerIDArgs, RepairArgs, ReplayArgs, ResetDataArgs, RunArgs, StatsArgs,
 };
 pub use configs::*;
 pub use exit_code::ExitCode;
@@ -25,7 +25,7 @@ pub use sentry_config::SentryConfig;
 
 use ckb_chain_spec::{consensus::Consensus, ChainSpec};
 use ckb_jsonrpc_types::ScriptHashType;
-use ckb_types::{u256, H256, U256};
+use ckb_types::{prelude::*, u256, H256, U256};
 use clap::{value_t, ArgMatches, ErrorKind};
 use std::{path::PathBuf, str::FromStr};
 
@@ -72,7 +72,7 @@ impl Setup {
 
     /// Executes `ckb run`.
     pub fn run(self, matches: &ArgMatches<'_>) -> Result<RunArgs, ExitCode> {
-        let consensus = self.consensus()?;
+        let mut consensus = self.consensus()?;
         let chain_spec_hash = self.chain_spec()?.hash;
         let mut config = self.config.into_ckb()?;
 
@@ -93,6 +93,13 @@ impl Setup {
             .value_of(cli::ARG_ASSUME_VALID_TARGET)
             .and_then(|s| H256::from_str(&s[2..]).ok());
 
+        // the configured checkpoints override the ones in the chain spec
+        for checkpoint in &config.network.sync.checkpoints {
+            consensus
+                .checkpoints
+                .insert(checkpoint.number, checkpoint.hash.pack());
+        }
+
         Ok(RunArgs {
             config,
             consensus,
@@ -109,12 +116,16 @@ impl Setup {
         let config = self.config.into_ckb()?;
         let check = matches.is_present(cli::ARG_MIGRATE_CHECK);
         let force = matches.is_present(cli::ARG_FORCE);
+        let dry_run = matches.is_present(cli::ARG_DRY_RUN);
+        let json_progress = matches.is_present(cli::ARG_JSON_PROGRESS);
 
         Ok(MigrateArgs {
             config,
             consensus,
             check,
             force,
+            dry_run,
+            json_progress,
         })
     }
 
@@ -125,6 +136,43 @@ impl Setup {
         Ok(RepairArgs { config })
     }
 
+    /// `db-stats` subcommand
+    pub fn db_stats(self, matches: &ArgMatches<'_>) -> Result<DBStatsArgs, ExitCode> {
+        let consensus = self.consensus()?;
+        let config = self.config.into_ckb()?;
+        let compact = matches
+            .values_of(cli::ARG_COMPACT)
+            .map(|values| values.map(ToOwned::to_owned).collect())
+            .unwrap_or_default();
+
+        Ok(DBStatsArgs {
+            config,
+            consensus,
+            compact,
+        })
+    }
+
+    /// `db-verify` subcommand
+    pub fn db_verify(self, matches: &ArgMatches<'_>) -> Result<DBVerifyArgs, ExitCode> {
+        let consensus = self.consensus()?;
+        let config = self.config.into_ckb()?;
+        let tmp_target = match option_value_t!(matches, cli::ARG_TMP_TARGET, PathBuf)? {
+            Some(tmp_target) => tmp_target,
+            None => config
+                .tmp_dir
+                .clone()
+                .unwrap_or_else(|| config.data_dir.join("tmp")),
+        };
+        let fix = matches.is_present(cli::ARG_FIX);
+
+        Ok(DBVerifyArgs {
+            config,
+            consensus,
+            tmp_target,
+            fix,
+        })
+    }
+
     /// Executes `ckb miner`.
     pub fn miner(self, matches: &ArgMatches<'_>) -> Result<MinerArgs, ExitCode> {
         let spec = self.chain_spec()?;
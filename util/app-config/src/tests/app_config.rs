@@ -70,6 +70,13 @@ fn test_export_dev_config_files() {
             Resource::file_system(dir.path().join("specs").join("dev.toml"))
         );
         assert_eq!(miner_config.miner.client.rpc_url, "http://127.0.0.1:7000/");
+        assert!(miner_config.miner.client.backup_rpc_urls.is_empty());
+        assert!(miner_config
+            .miner
+            .client
+            .backup_subscription_addresses
+            .is_empty());
+        assert_eq!(miner_config.miner.client.rpc_timeout, 10_000);
     }
 }
 
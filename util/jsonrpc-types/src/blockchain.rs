@@ -3,7 +3,7 @@ use crate::{
     BlockNumber, Byte32, Capacity, Cycle, EpochNumber, EpochNumberWithFraction, ProposalShortId,
     Timestamp, Uint128, Uint32, Uint64, Version,
 };
-use ckb_types::{core, packed, prelude::*, H256};
+use ckb_types::{core, packed, prelude::*, H256, U256};
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
@@ -1222,6 +1222,55 @@ pub struct TransactionProof {
     pub proof: MerkleProof,
 }
 
+/// The compact block filter of a block.
+///
+/// The filter is a Golomb-coded set of the lock and type script hashes of the cells created and
+/// consumed by the block. A light client tests whether the block touches its scripts by matching
+/// the script hashes against the filter.
+#[derive(Clone, Default, Serialize, Deserialize, PartialEq, Eq, Hash, Debug)]
+pub struct BlockFilter {
+    /// The filter data.
+    ///
+    /// It is the number of elements as a little endian 64-bit integer, followed by the
+    /// Golomb-Rice coded deltas of the sorted element hashes.
+    pub data: JsonBytes,
+    /// The hash of the filter data.
+    pub hash: H256,
+    /// The filter header, which is the hash of the filter hash and the parent filter header.
+    pub header: H256,
+}
+
+/// The tip of a known chain, which may be a fork of the canonical chain.
+#[derive(Clone, Serialize, Deserialize, PartialEq, Eq, Hash, Debug)]
+pub struct ChainTip {
+    /// The tip block hash.
+    pub hash: H256,
+    /// The tip block number.
+    pub number: BlockNumber,
+    /// The number of the blocks from the tip to the canonical chain, it is 0 for the
+    /// canonical chain tip.
+    pub branch_len: BlockNumber,
+    /// The accumulated difficulty of the chain ending at the tip.
+    pub total_difficulty: U256,
+    /// The validation status of the tip.
+    pub status: ChainTipStatus,
+}
+
+/// The validation status of a chain tip.
+#[derive(Clone, Serialize, Deserialize, PartialEq, Eq, Hash, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum ChainTipStatus {
+    /// Status "active". The tip of the canonical chain.
+    Active,
+    /// Status "valid". The blocks of the fork are fully verified.
+    Valid,
+    /// Status "headers_only". The headers are verified, but some blocks are not received or
+    /// not fully verified yet.
+    HeadersOnly,
+    /// Status "invalid". The tip block or one of its ancestors is invalid.
+    Invalid,
+}
+
 /// Proof of CKB Merkle Tree.
 ///
 /// CKB Merkle Tree is a [CBMT](https://github.com/nervosnetwork/rfcs/blob/master/rfcs/0006-merkle-tree/0006-merkle-tree.md) using CKB blake2b hash as the merge function.
@@ -0,0 +1,43 @@
+use crate::Uint64;
+use serde::{Deserialize, Serialize};
+
+/// Database statistics.
+#[derive(Clone, Default, Serialize, Deserialize, Debug)]
+pub struct DBStats {
+    /// Statistics of each RocksDB column family.
+    pub columns: Vec<ColumnStats>,
+    /// Size of each freezer table.
+    ///
+    /// It is empty when the freezer is not enabled.
+    pub freezer: Vec<FreezerTableStats>,
+}
+
+/// Statistics of a RocksDB column family.
+///
+/// The properties are null when they are not available.
+#[derive(Clone, Default, Serialize, Deserialize, Debug)]
+pub struct ColumnStats {
+    /// The column family name, see `ckb-db-schema` for the data stored in each column.
+    pub column: String,
+    /// Estimated number of keys.
+    pub estimate_num_keys: Option<Uint64>,
+    /// Total size in bytes of the SST files belonging to the latest version.
+    pub live_sst_files_size: Option<Uint64>,
+    /// Total size in bytes of all SST files, including the obsolete ones not deleted yet.
+    pub total_sst_files_size: Option<Uint64>,
+    /// Estimated bytes compaction needs to rewrite to get all levels down to under target size.
+    pub estimate_pending_compaction_bytes: Option<Uint64>,
+    /// Memory size in bytes of the column family entries residing in block cache.
+    pub block_cache_usage: Option<Uint64>,
+}
+
+/// Size of a freezer table.
+#[derive(Clone, Default, Serialize, Deserialize, Debug)]
+pub struct FreezerTableStats {
+    /// The table name.
+    ///
+    /// Examples: "blocks", "headers", "block_exts", "uncles", "extensions".
+    pub table: String,
+    /// Total size in bytes of the table files.
+    pub size: Uint64,
+}
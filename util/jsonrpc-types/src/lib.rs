@@ -5,6 +5,7 @@ mod blockchain;
 mod bytes;
 mod cell;
 mod chain_info;
+mod db_stats;
 mod debug;
 mod experiment;
 mod fee_rate;
@@ -24,21 +25,23 @@ pub use self::block_template::{
     BlockTemplate, CellbaseTemplate, TransactionTemplate, UncleTemplate,
 };
 pub use self::blockchain::{
-    Block, BlockEconomicState, BlockIssuance, BlockView, CellDep, CellInput, CellOutput, Consensus,
-    DepType, EpochView, HardForkFeature, Header, HeaderView, MerkleProof, MinerReward, OutPoint,
-    ProposalWindow, Script, ScriptHashType, Status, Transaction, TransactionProof, TransactionView,
-    TransactionWithStatus, TxStatus, UncleBlock, UncleBlockView,
+    Block, BlockEconomicState, BlockFilter, BlockIssuance, BlockView, CellDep, CellInput,
+    CellOutput, ChainTip, ChainTipStatus, Consensus, DepType, EpochView, HardForkFeature, Header,
+    HeaderView, MerkleProof, MinerReward, OutPoint, ProposalWindow, Script, ScriptHashType, Status,
+    Transaction, TransactionProof, TransactionView, TransactionWithStatus, TxStatus, UncleBlock,
+    UncleBlockView,
 };
 pub use self::bytes::JsonBytes;
 pub use self::cell::{CellData, CellInfo, CellWithStatus};
 pub use self::chain_info::ChainInfo;
+pub use self::db_stats::{ColumnStats, DBStats, FreezerTableStats};
 pub use self::debug::{ExtraLoggerConfig, MainLoggerConfig};
 pub use self::experiment::{DaoWithdrawingCalculationKind, DryRunResult};
 pub use self::fee_rate::FeeRateDef;
 pub use self::fixed_bytes::Byte32;
 pub use self::net::{
-    BannedAddr, LocalNode, LocalNodeProtocol, NodeAddress, PeerSyncState, RemoteNode,
-    RemoteNodeProtocol, SyncState,
+    BannedAddr, HeadersSyncProgress, HeadersSyncState, InflightBlock, LocalNode, LocalNodeProtocol,
+    NodeAddress, PeerSyncState, RemoteNode, RemoteNodeProtocol, SyncPeer, SyncState,
 };
 pub use self::pool::{
     OutputsValidator, PoolTransactionEntry, PoolTransactionReject, RawTxPool, TxPoolEntries,
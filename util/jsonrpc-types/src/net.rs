@@ -100,37 +100,54 @@ pub struct LocalNodeProtocol {
 ///   "connected_duration": "0x2f",
 ///   "is_outbound": true,
 ///   "last_ping_duration": "0x1a",
+///   "network_group": "AS16509",
 ///   "node_id": "QmXwUgF48ULy6hkgfqrEwEfuHW7WyWyWauueRDAYQHNDfN",
 ///   "protocols": [
 ///     {
+///       "bytes_received": "0x5e",
+///       "bytes_sent": "0x60",
 ///       "id": "0x4",
 ///       "version": "0.0.1"
 ///     },
 ///     {
+///       "bytes_received": "0x1f",
+///       "bytes_sent": "0x1f",
 ///       "id": "0x2",
 ///       "version": "0.0.1"
 ///     },
 ///     {
+///       "bytes_received": "0x2c0",
+///       "bytes_sent": "0x3a1",
 ///       "id": "0x1",
 ///       "version": "0.0.1"
 ///     },
 ///     {
+///       "bytes_received": "0x4b3e1",
+///       "bytes_sent": "0x19a2c7",
 ///       "id": "0x64",
 ///       "version": "1"
 ///     },
 ///     {
+///       "bytes_received": "0x40",
+///       "bytes_sent": "0x40",
 ///       "id": "0x6e",
 ///       "version": "1"
 ///     },
 ///     {
+///       "bytes_received": "0x1f4",
+///       "bytes_sent": "0x1f4",
 ///       "id": "0x66",
 ///       "version": "1"
 ///     },
 ///     {
+///       "bytes_received": "0x2d14",
+///       "bytes_sent": "0x3a0b",
 ///       "id": "0x65",
 ///       "version": "1"
 ///     },
 ///     {
+///       "bytes_received": "0x6a",
+///       "bytes_sent": "0x6a",
 ///       "id": "0x0",
 ///       "version": "0.0.1"
 ///     }
@@ -166,6 +183,11 @@ pub struct RemoteNode {
     ///
     /// Null means no ping responses have been received yet.
     pub last_ping_duration: Option<Uint64>,
+    /// The network group of the connected address.
+    ///
+    /// It is the autonomous system number, e.g. "AS13335", if the node loads an AS map which
+    /// covers the address, or the IP prefix, e.g. "174.80.0.0/16", otherwise.
+    pub network_group: String,
     /// Chain synchronization state.
     ///
     /// Null means chain sync has not started with this remote node yet.
@@ -184,6 +206,10 @@ pub struct RemoteNodeProtocol {
     pub id: Uint64,
     /// Active protocol version.
     pub version: String,
+    /// Bytes of the messages sent to the remote node through this protocol.
+    pub bytes_sent: Uint64,
+    /// Bytes of the messages received from the remote node through this protocol.
+    pub bytes_received: Uint64,
 }
 
 /// The chain synchronization state between the local node and a remote node.
@@ -227,7 +253,9 @@ pub struct NodeAddress {
     pub address: String,
     /// Address score.
     ///
-    /// A higher score means a higher probability of a successful connection.
+    /// A higher score means a higher probability of a successful connection. The score of the
+    /// connected address drops when the remote node misbehaves, and the node is banned once
+    /// the score drops below `ban_score` in the network config.
     pub score: Uint64,
 }
 
@@ -284,3 +312,133 @@ pub struct SyncState {
     /// The download scheduler's time analysis data, the low is the 9/10 of the cut-off point, unit ms
     pub low_time: Uint64,
 }
+
+/// The chain synchronization details of a connected remote node.
+///
+/// ## Examples
+///
+/// ```
+/// # serde_json::from_str::<ckb_jsonrpc_types::SyncPeer>(r#"
+/// {
+///   "peer_index": "0x1",
+///   "node_id": "QmaaaLB4uPyDpZwTQGhV63zuYrKm4reyN2tF1j2ain4oE7",
+///   "address": "/ip4/192.168.0.3/tcp/8115",
+///   "is_outbound": true,
+///   "is_protect": true,
+///   "is_whitelist": false,
+///   "is_2021edition": true,
+///   "best_known_header_hash": "0xa5f5c85987a15de25661e5a214f2c1449cd803f071acc7999820f25246471f40",
+///   "best_known_header_number": "0x400",
+///   "last_common_header_hash": "0xa5f5c85987a15de25661e5a214f2c1449cd803f071acc7999820f25246471f40",
+///   "last_common_header_number": "0x3f0",
+///   "inflight_blocks": [
+///     {
+///       "number": "0x3f1",
+///       "hash": "0xb5f5c85987a15de25661e5a214f2c1449cd803f071acc7999820f25246471f40",
+///       "requested_at": "0x17c7b1a9c10"
+///     }
+///   ],
+///   "download_task_count": "0x20",
+///   "headers_sync_state": { "state": "started" },
+///   "headers_sync_progress": {
+///     "started_at": "0x17c7b1a0000",
+///     "started_tip_timestamp": "0x17c7b190000",
+///     "last_updated_at": "0x17c7b1a9000",
+///     "last_updated_tip_timestamp": "0x17c7b1a8000",
+///     "is_close_to_the_end": false
+///   },
+///   "chain_sync_timeout": null,
+///   "sent_getheaders": false
+/// }
+/// # "#).unwrap();
+/// ```
+#[derive(Clone, Serialize, Deserialize, PartialEq, Eq, Hash, Debug)]
+pub struct SyncPeer {
+    /// The index of the connection session with the remote node.
+    pub peer_index: Uint64,
+    /// The remote node ID, which can be passed to the RPC `remove_node`.
+    pub node_id: String,
+    /// The connected address, whose IP can be passed to the RPC `set_ban`.
+    pub address: String,
+    /// Whether this is an outbound remote node.
+    pub is_outbound: bool,
+    /// Whether the remote node is protected from the eviction.
+    pub is_protect: bool,
+    /// Whether the remote node is in the whitelist.
+    pub is_whitelist: bool,
+    /// Whether the remote node supports the ckb2021 edition.
+    pub is_2021edition: bool,
+    /// Best known header hash of the remote node.
+    pub best_known_header_hash: Option<Byte32>,
+    /// Best known header number of the remote node.
+    pub best_known_header_number: Option<BlockNumber>,
+    /// Last common header hash of the local node and the remote node.
+    pub last_common_header_hash: Option<Byte32>,
+    /// Last common header number of the local node and the remote node.
+    pub last_common_header_number: Option<BlockNumber>,
+    /// The blocks being downloaded from the remote node, sorted by the block number.
+    pub inflight_blocks: Vec<InflightBlock>,
+    /// How many blocks the download scheduler requests from the remote node concurrently.
+    ///
+    /// The scheduler raises it when the remote node responds in time and lowers it when the
+    /// downloads time out.
+    pub download_task_count: Uint64,
+    /// The headers synchronization state.
+    pub headers_sync_state: HeadersSyncState,
+    /// The progress of the headers synchronization, which tells whether the headers download
+    /// from the remote node times out.
+    ///
+    /// Null means the headers synchronization with the remote node is not in progress.
+    pub headers_sync_progress: Option<HeadersSyncProgress>,
+    /// When the remote node is disconnected unless its best known header catches up the local
+    /// chain tip.
+    ///
+    /// Null means there is no such deadline.
+    pub chain_sync_timeout: Option<Timestamp>,
+    /// Whether the last chance `GetHeaders` message is sent before the `chain_sync_timeout`.
+    pub sent_getheaders: bool,
+}
+
+/// A block being downloaded from a remote node.
+#[derive(Clone, Serialize, Deserialize, PartialEq, Eq, Hash, Debug)]
+pub struct InflightBlock {
+    /// The block number.
+    pub number: BlockNumber,
+    /// The block hash.
+    pub hash: Byte32,
+    /// When the block is requested.
+    pub requested_at: Timestamp,
+}
+
+/// The headers synchronization state with a remote node.
+#[derive(Clone, Serialize, Deserialize, PartialEq, Eq, Hash, Debug)]
+#[serde(tag = "state", content = "until", rename_all = "snake_case")]
+pub enum HeadersSyncState {
+    /// The sync protocol is not connected yet.
+    Initialized,
+    /// The sync protocol is connected, the headers synchronization can start.
+    SyncProtocolConnected,
+    /// The headers are being downloaded from the remote node.
+    Started,
+    /// The headers synchronization is suspended until the timestamp.
+    Suspended(Timestamp),
+    /// The headers are synchronized to the tip, the remote node is not chosen to synchronize
+    /// again until the timestamp.
+    TipSynced(Timestamp),
+}
+
+/// The progress of the headers synchronization with a remote node.
+#[derive(Clone, Serialize, Deserialize, PartialEq, Eq, Hash, Debug)]
+pub struct HeadersSyncProgress {
+    /// When the synchronization started.
+    pub started_at: Timestamp,
+    /// The timestamp of the best known header when the synchronization started.
+    pub started_tip_timestamp: Timestamp,
+    /// When the progress is last updated.
+    pub last_updated_at: Timestamp,
+    /// The timestamp of the best known header when the progress is last updated.
+    pub last_updated_tip_timestamp: Timestamp,
+    /// Whether the best known header is close to the current time, the timeout is not checked
+    /// then.
+    pub is_close_to_the_end: bool,
+}
@@ -14,4 +14,6 @@ pub enum Topic {
     ProposedTransaction,
     /// Subscribe transactions which are abandoned by tx-pool.
     RejectedTransaction,
+    /// Subscribe block templates which are changed by tx-pool.
+    BlockTemplate,
 }
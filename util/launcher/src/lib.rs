@@ -25,10 +25,13 @@ use ckb_network::{
 use ckb_network_alert::alert_relayer::AlertRelayer;
 use ckb_proposal_table::ProposalTable;
 use ckb_resource::Resource;
-use ckb_rpc::{RpcServer, ServiceBuilder};
+use ckb_rpc::{RpcServer, ServiceBuilder, StratumServer};
 use ckb_shared::Shared;
 use ckb_store::{ChainDB, ChainStore};
-use ckb_sync::{NetTimeProtocol, Relayer, SyncShared, Synchronizer};
+use ckb_sync::{
+    BlockFilter, LightClient, NetTimeProtocol, Relayer, SyncShared, Synchronizer,
+    RECONCILIATION_RELAY_VERSION,
+};
 use ckb_tx_pool::service::TxVerificationResult;
 use ckb_types::prelude::*;
 use ckb_verification::GenesisVerifier;
@@ -276,13 +279,19 @@ impl Launcher {
         let support_protocols = &self.args.config.network.support_protocols;
 
         if support_protocols.contains(&SupportProtocol::Relay) {
-            let relayer = Relayer::new(chain_controller.clone(), Arc::clone(&sync_shared));
+            let relayer = Relayer::new(chain_controller.clone(), Arc::clone(&sync_shared))
+                .dandelion(self.args.config.network.dandelion.clone());
 
-            protocols.push(CKBProtocol::new_with_support_protocol(
+            let relay_v2 = CKBProtocol::new_with_support_protocol(
                 SupportProtocols::RelayV2,
                 Box::new(relayer.clone().v2()),
                 Arc::clone(&network_state),
-            ));
+            );
+            if self.args.config.network.tx_reconciliation {
+                protocols.push(relay_v2);
+            } else {
+                protocols.push(relay_v2.max_version(RECONCILIATION_RELAY_VERSION - 1));
+            }
 
             if !fork_enable {
                 protocols.push(CKBProtocol::new_with_support_protocol(
@@ -302,6 +311,24 @@ impl Launcher {
             ));
         }
 
+        if support_protocols.contains(&SupportProtocol::BlockFilter) {
+            let filter = BlockFilter::new(Arc::clone(&sync_shared));
+            protocols.push(CKBProtocol::new_with_support_protocol(
+                SupportProtocols::BlockFilter,
+                Box::new(filter),
+                Arc::clone(&network_state),
+            ));
+        }
+
+        if support_protocols.contains(&SupportProtocol::LightClient) {
+            let light_client = LightClient::new(Arc::clone(&sync_shared));
+            protocols.push(CKBProtocol::new_with_support_protocol(
+                SupportProtocols::LightClient,
+                Box::new(light_client),
+                Arc::clone(&network_state),
+            ));
+        }
+
         let alert_signature_config = self.args.config.alert_signature.clone().unwrap_or_default();
         let alert_relayer = AlertRelayer::new(
             self.version.to_string(),
@@ -359,12 +386,16 @@ impl Launcher {
                 chain_controller.clone(),
                 miner_enable,
             )
-            .enable_net(network_controller.clone(), sync_shared)
+            .enable_net(network_controller.clone(), Arc::clone(&sync_shared))
             .enable_stats(shared.clone(), Arc::clone(&alert_notifier))
             .enable_experiment(shared.clone())
-            .enable_integration_test(shared.clone(), network_controller.clone(), chain_controller)
+            .enable_integration_test(
+                shared.clone(),
+                network_controller.clone(),
+                chain_controller.clone(),
+            )
             .enable_alert(alert_verifier, alert_notifier, network_controller.clone())
-            .enable_debug();
+            .enable_debug(shared.clone(), chain_controller, sync_shared);
         let io_handler = builder.build();
 
         let rpc_server = RpcServer::new(
@@ -375,4 +406,28 @@ impl Launcher {
 
         (network_controller, rpc_server)
     }
+
+    /// Start stratum server if it is configured and the miner is enabled
+    pub fn start_stratum(
+        &self,
+        shared: &Shared,
+        chain_controller: ChainController,
+        network_controller: NetworkController,
+        miner_enable: bool,
+    ) -> Result<Option<StratumServer>, ExitCode> {
+        let config = match self.args.config.stratum.clone() {
+            Some(config) => config,
+            None => return Ok(None),
+        };
+        if !miner_enable {
+            info!("Stratum server is disabled because the miner is disabled");
+            return Ok(None);
+        }
+        StratumServer::start(config, shared.clone(), chain_controller, network_controller)
+            .map(Some)
+            .map_err(|err| {
+                eprintln!("Start stratum server error: {}", err);
+                ExitCode::Config
+            })
+    }
 }
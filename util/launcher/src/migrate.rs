@@ -2,8 +2,8 @@
 
 use crate::migrations;
 use ckb_db::{ReadOnlyDB, RocksDB};
-use ckb_db_migration::{DefaultMigration, Migrations};
-use ckb_db_schema::{COLUMNS, COLUMN_META};
+use ckb_db_migration::{DefaultMigration, Migrations, PendingMigration, ProgressFormat};
+use ckb_db_schema::{COLUMNS, COLUMN_BLOCK_HEADER, COLUMN_META};
 use ckb_error::Error;
 use std::cmp::Ordering;
 use std::path::PathBuf;
@@ -18,7 +18,9 @@ pub struct Migrate {
 
 impl Migrate {
     /// Construct new migrate
-    pub fn new<P: Into<PathBuf>>(path: P) -> Self {
+    ///
+    /// The migrations reading the frozen blocks open the freezer at `ancient` if it exists.
+    pub fn new<P: Into<PathBuf>>(path: P, ancient: Option<PathBuf>) -> Self {
         let mut migrations = Migrations::default();
         migrations.add_migration(Box::new(DefaultMigration::new(INIT_DB_VERSION)));
         migrations.add_migration(Box::new(migrations::ChangeMoleculeTableToStruct)); // since v0.35.0
@@ -26,6 +28,7 @@ impl Migrate {
         migrations.add_migration(Box::new(migrations::AddNumberHashMapping)); // since v0.40.0
         migrations.add_migration(Box::new(migrations::AddExtraDataHash)); // since v0.43.0
         migrations.add_migration(Box::new(migrations::AddBlockExtensionColumnFamily)); // since v0.100.0
+        migrations.add_migration(Box::new(migrations::AddBlockFilter { ancient })); // since v0.102.0
 
         Migrate {
             migrations,
@@ -33,10 +36,15 @@ impl Migrate {
         }
     }
 
+    /// Set how the progress of running migrations is reported
+    pub fn set_progress_format(&mut self, format: ProgressFormat) {
+        self.migrations.set_progress_format(format);
+    }
+
     /// Open read only db
     pub fn open_read_only_db(&self) -> Result<Option<ReadOnlyDB>, Error> {
-        // open cf meta column for empty check
-        ReadOnlyDB::open_cf(&self.path, vec![COLUMN_META])
+        // open cf meta column for empty check, and block header column for estimating the work
+        ReadOnlyDB::open_cf(&self.path, vec![COLUMN_META, COLUMN_BLOCK_HEADER])
     }
 
     /// Check if database's version is matched with the executable binary version.
@@ -56,6 +64,11 @@ impl Migrate {
         self.migrations.expensive(db)
     }
 
+    /// Returns the migrations which would be performed, with their estimated work.
+    pub fn pending(&self, db: &ReadOnlyDB) -> Vec<PendingMigration> {
+        self.migrations.pending(db)
+    }
+
     /// Open bulk load db.
     pub fn open_bulk_load_db(&self) -> Result<Option<RocksDB>, Error> {
         RocksDB::prepare_for_bulk_load_open(&self.path, COLUMNS)
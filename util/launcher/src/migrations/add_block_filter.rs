@@ -0,0 +1,97 @@
+use ckb_app_config::StoreConfig;
+use ckb_db::{ReadOnlyDB, Result, RocksDB};
+use ckb_db_migration::{Checkpoint, Migration, ProgressBar, ProgressStyle};
+use ckb_error::InternalErrorKind;
+use ckb_freezer::Freezer;
+use ckb_store::{build_block_filter, ChainDB, ChainStore};
+use ckb_types::{core::BlockNumber, packed, prelude::*};
+use std::path::PathBuf;
+use std::sync::Arc;
+
+/// Builds the filters of the main chain blocks.
+///
+/// The blocks moved into the freezer are read through it, so the freezer directory is required
+/// once some blocks have been frozen.
+pub struct AddBlockFilter {
+    pub(crate) ancient: Option<PathBuf>,
+}
+
+const VERSION: &str = "20211210120000";
+const CHECKPOINT: Checkpoint<'static> = Checkpoint::new(VERSION);
+const BATCH: usize = 1_000;
+
+impl Migration for AddBlockFilter {
+    fn migrate(
+        &self,
+        db: RocksDB,
+        pb: Arc<dyn Fn(u64) -> ProgressBar + Send + Sync>,
+    ) -> Result<RocksDB> {
+        let chain_db = match self.ancient.as_ref().filter(|path| path.exists()) {
+            Some(path) => {
+                let freezer = Freezer::open(path.clone())?;
+                ChainDB::new_with_freezer(db, freezer, StoreConfig::default())
+            }
+            None => ChainDB::new(db, StoreConfig::default()),
+        };
+        let tip_number = chain_db
+            .get_tip_header()
+            .map(|header| header.number())
+            .unwrap_or_default();
+        // filter headers are chained, so the blocks are processed in order, resuming from
+        // the number saved by the interrupted run
+        let mut number: BlockNumber = CHECKPOINT
+            .get(chain_db.db())?
+            .map(|raw| packed::Uint64Reader::from_slice_should_be_ok(&raw).unpack())
+            .unwrap_or_default();
+        let mut parent_filter_header = number
+            .checked_sub(1)
+            .and_then(|parent| chain_db.get_block_hash(parent))
+            .and_then(|hash| chain_db.get_block_filter_header(&hash))
+            .unwrap_or_else(packed::Byte32::zero);
+
+        let pb = pb(tip_number + 1);
+        pb.set_style(
+            ProgressStyle::default_bar()
+                .template(
+                    "{prefix:.bold.dim} {spinner:.green} [{elapsed_precise}] [{bar:40.cyan/blue}] {pos}/{len} ({eta}) {msg}",
+                )
+                .progress_chars("#>-"),
+        );
+        pb.set_position(number);
+        pb.enable_steady_tick(5000);
+
+        let mut wb = chain_db.new_write_batch();
+        while number <= tip_number {
+            let block = chain_db
+                .get_block_hash(number)
+                .and_then(|hash| chain_db.get_block(&hash))
+                .ok_or_else(|| {
+                    InternalErrorKind::Database
+                        .other(format!("main chain block {} is missing", number))
+                })?;
+            let filter = build_block_filter(&chain_db, &block)?;
+            let filter_header = filter.calc_header(&parent_filter_header);
+            wb.insert_block_filter(&block.hash(), &filter_header, &filter)?;
+            parent_filter_header = filter_header;
+            number += 1;
+
+            if wb.len() >= BATCH || number > tip_number {
+                chain_db.write(&wb)?;
+                wb.clear()?;
+                let next_number: packed::Uint64 = number.pack();
+                CHECKPOINT.set(chain_db.db(), next_number.as_slice())?;
+            }
+            pb.inc(1);
+        }
+        pb.finish_with_message("done!");
+        Ok(chain_db.into_inner())
+    }
+
+    fn version(&self) -> &str {
+        VERSION
+    }
+
+    fn estimate(&self, db: &ReadOnlyDB) -> Option<u64> {
+        super::tip_number(db).map(|number| number + 1)
+    }
+}
@@ -1,5 +1,5 @@
 use ckb_db::{Direction, IteratorMode, Result, RocksDB};
-use ckb_db_migration::{Migration, ProgressBar, ProgressStyle};
+use ckb_db_migration::{Checkpoint, Migration, ProgressBar, ProgressStyle};
 use ckb_db_schema::{COLUMN_CELL_DATA, COLUMN_CELL_DATA_HASH};
 use ckb_types::{packed, prelude::*};
 use std::sync::Arc;
@@ -31,7 +31,9 @@ impl Migration for AddExtraDataHash {
             .tick_chars("⠁⠂⠄⡀⢀⠠⠐⠈ ")
             .template("{prefix:.bold.dim} {spinner} {wide_msg}");
         pb.set_style(spinner_style);
-        let mut next_key = vec![0];
+        // resume from the key saved by the interrupted run
+        let checkpoint = Checkpoint::new(VERSION);
+        let mut next_key = checkpoint.get(&db)?.unwrap_or_else(|| vec![0]);
         while !next_key.is_empty() {
             let mut wb = db.new_write_batch();
             let mut cell_data_migration = |key: &[u8], value: &[u8]| -> Result<()> {
@@ -50,6 +52,7 @@ impl Migration for AddExtraDataHash {
             let (_count, nk) =
                 db.traverse(COLUMN_CELL_DATA, &mut cell_data_migration, mode, LIMIT)?;
             next_key = nk;
+            checkpoint.put(&mut wb, &next_key)?;
 
             if !wb.is_empty() {
                 db.write(&wb)?;
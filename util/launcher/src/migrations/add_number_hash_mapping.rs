@@ -1,5 +1,5 @@
 use ckb_app_config::StoreConfig;
-use ckb_db::{Direction, IteratorMode, Result, RocksDB};
+use ckb_db::{Direction, IteratorMode, ReadOnlyDB, Result, RocksDB};
 use ckb_db_migration::{Migration, ProgressBar, ProgressStyle};
 use ckb_db_schema::{COLUMN_BLOCK_BODY, COLUMN_INDEX, COLUMN_NUMBER_HASH};
 use ckb_migration_template::multi_thread_migration;
@@ -56,4 +56,8 @@ impl Migration for AddNumberHashMapping {
     fn version(&self) -> &str {
         VERSION
     }
+
+    fn estimate(&self, db: &ReadOnlyDB) -> Option<u64> {
+        super::tip_number(db).map(|number| number + 1)
+    }
 }
@@ -1,6 +1,6 @@
 use ckb_app_config::StoreConfig;
-use ckb_db::RocksDB;
-use ckb_db_migration::{Migration, ProgressBar, ProgressStyle};
+use ckb_db::{ReadOnlyDB, RocksDB};
+use ckb_db_migration::{Checkpoint, Migration, ProgressBar, ProgressStyle};
 use ckb_db_schema::COLUMN_CELL;
 use ckb_error::Error;
 use ckb_migration_template::multi_thread_migration;
@@ -14,6 +14,10 @@ use std::sync::Arc;
 
 const RESTORE_CELL_VERSION: &str = "20200707214700";
 const MAX_DELETE_BATCH_SIZE: usize = 32 * 1024;
+const CHECKPOINT: Checkpoint<'static> = Checkpoint::new(RESTORE_CELL_VERSION);
+// saved once the cells of all blocks are inserted, the consumed cells deletion is
+// idempotent and always starts over
+const CELLS_INSERTED: &[u8] = b"cells-inserted";
 
 pub struct CellMigration;
 
@@ -23,7 +27,10 @@ impl Migration for CellMigration {
         mut db: RocksDB,
         pb: Arc<dyn Fn(u64) -> ProgressBar + Send + Sync>,
     ) -> Result<RocksDB, Error> {
-        clean_cell_column(&mut db)?;
+        let cells_inserted = CHECKPOINT.get(&db)?.as_deref() == Some(CELLS_INSERTED);
+        if !cells_inserted {
+            clean_cell_column(&mut db)?;
+        }
 
         multi_thread_migration! {
             {
@@ -36,7 +43,9 @@ impl Migration for CellMigration {
                     if block.transactions().len() > 1 {
                         hashes.push(block.hash());
                     }
-                    insert_block_cell(&mut wb, &block);
+                    if !cells_inserted {
+                        insert_block_cell(&mut wb, &block);
+                    }
 
                     if wb.len() > BATCH {
                         chain_db.write(&wb).unwrap();
@@ -53,6 +62,10 @@ impl Migration for CellMigration {
                 // wait all cell insert
                 barrier.wait();
 
+                if i == 0 && !cells_inserted {
+                    CHECKPOINT.set(chain_db.db(), CELLS_INSERTED).unwrap();
+                }
+
                 pbi.set_length(size + hashes.len() as u64);
 
                 for hash in hashes {
@@ -72,6 +85,10 @@ impl Migration for CellMigration {
     fn version(&self) -> &str {
         RESTORE_CELL_VERSION
     }
+
+    fn estimate(&self, db: &ReadOnlyDB) -> Option<u64> {
+        super::tip_number(db).map(|number| number + 1)
+    }
 }
 
 // https://github.com/facebook/rocksdb/issues/1295
@@ -1,11 +1,29 @@
 mod add_block_extension_cf;
+mod add_block_filter;
 mod add_extra_data_hash;
 mod add_number_hash_mapping;
 mod cell;
 mod table_to_struct;
 
 pub use add_block_extension_cf::AddBlockExtensionColumnFamily;
+pub use add_block_filter::AddBlockFilter;
 pub use add_extra_data_hash::AddExtraDataHash;
 pub use add_number_hash_mapping::AddNumberHashMapping;
 pub use cell::CellMigration;
 pub use table_to_struct::ChangeMoleculeTableToStruct;
+
+use ckb_db::ReadOnlyDB;
+use ckb_db_schema::{COLUMN_BLOCK_HEADER, COLUMN_META, META_TIP_HEADER_KEY};
+use ckb_types::{core::BlockNumber, packed, prelude::*};
+
+// Used to estimate the work of block based migrations, requires the read only db
+// to open `COLUMN_BLOCK_HEADER`.
+fn tip_number(db: &ReadOnlyDB) -> Option<BlockNumber> {
+    let tip_hash = db.get_pinned(COLUMN_META, META_TIP_HEADER_KEY).ok()??;
+    let raw = db.get_pinned(COLUMN_BLOCK_HEADER, &tip_hash).ok()??;
+    packed::HeaderViewReader::from_slice(&raw)
+        .ok()
+        // headers are stored as molecule table before ChangeMoleculeTableToStruct
+        .or_else(|| packed::HeaderViewReader::from_slice(raw.get(12..)?).ok())
+        .map(|reader| reader.data().raw().number().unpack())
+}
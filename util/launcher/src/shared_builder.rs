@@ -23,7 +23,7 @@ use ckb_stop_handler::StopHandler;
 use ckb_store::ChainDB;
 use ckb_store::ChainStore;
 use ckb_tx_pool::{
-    error::Reject, service::TxVerificationResult, TokioRwLock, TxEntry, TxPool,
+    error::Reject, service::TxVerificationResult, BlockTemplate, TokioRwLock, TxEntry, TxPool,
     TxPoolServiceBuilder,
 };
 use ckb_types::core::EpochExt;
@@ -52,8 +52,9 @@ pub fn open_or_create_db(
     bin_name: &str,
     root_dir: &Path,
     config: &DBConfig,
+    ancient: Option<PathBuf>,
 ) -> Result<RocksDB, ExitCode> {
-    let migrate = Migrate::new(&config.path);
+    let migrate = Migrate::new(&config.path, ancient);
 
     let read_only_db = migrate.open_read_only_db().map_err(|e| {
         eprintln!("migrate error {}", e);
@@ -122,7 +123,7 @@ impl SharedBuilder {
         ancient: Option<PathBuf>,
         async_handle: Handle,
     ) -> Result<SharedBuilder, ExitCode> {
-        let db = open_or_create_db(bin_name, root_dir, db_config)?;
+        let db = open_or_create_db(bin_name, root_dir, db_config, ancient.clone())?;
 
         Ok(SharedBuilder {
             db,
@@ -138,19 +139,14 @@ impl SharedBuilder {
 
     /// Generates the SharedBuilder with temp db
     pub fn with_temp_db() -> Self {
-        use once_cell::{sync, unsync};
+        use once_cell::sync;
         use std::{
             borrow::Borrow,
             sync::atomic::{AtomicUsize, Ordering},
         };
 
-        // once #[thread_local] is stable
-        // #[thread_local]
-        // static RUNTIME_HANDLE: unsync::OnceCell<...
-
         thread_local! {
             static TMP_DIR: sync::OnceCell<TempDir> = sync::OnceCell::new();
-            static RUNTIME_HANDLE: unsync::OnceCell<(Handle, StopHandler<()>)> = unsync::OnceCell::new();
         }
 
         static DB_COUNT: AtomicUsize = AtomicUsize::new(0);
@@ -169,6 +165,26 @@ impl SharedBuilder {
             RocksDB::open_in(db_dir, COLUMNS)
         };
 
+        Self::with_db(db)
+    }
+
+    /// Generates the SharedBuilder with in-memory db, which never touches the disk
+    pub fn with_memory_db() -> Self {
+        Self::with_db(RocksDB::open_in_memory(COLUMNS))
+    }
+
+    fn with_db(db: RocksDB) -> Self {
+        use once_cell::unsync;
+        use std::borrow::Borrow;
+
+        // once #[thread_local] is stable
+        // #[thread_local]
+        // static RUNTIME_HANDLE: unsync::OnceCell<...
+
+        thread_local! {
+            static RUNTIME_HANDLE: unsync::OnceCell<(Handle, StopHandler<()>)> = unsync::OnceCell::new();
+        }
+
         RUNTIME_HANDLE.with(|runtime| SharedBuilder {
             db,
             ancient_path: None,
@@ -459,7 +475,7 @@ fn register_tx_pool_callback(tx_pool_builder: &mut TxPoolServiceBuilder, notify:
         tx_pool.update_statics_for_remove_tx(entry.size, entry.cycles);
     }));
 
-    let notify_reject = notify;
+    let notify_reject = notify.clone();
     tx_pool_builder.register_reject(Box::new(
         move |tx_pool: &mut TxPool, entry: &TxEntry, reject: Reject| {
             // update statics
@@ -486,4 +502,9 @@ fn register_tx_pool_callback(tx_pool_builder: &mut TxPoolServiceBuilder, notify:
             notify_reject.notify_reject_transaction(notify_tx_entry, reject);
         },
     ));
+
+    let notify_block_template = notify;
+    tx_pool_builder.register_block_template(Box::new(move |template: &BlockTemplate| {
+        notify_block_template.notify_block_template(template.clone());
+    }));
 }
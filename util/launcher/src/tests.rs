@@ -143,13 +143,13 @@ fn test_mock_migration() {
     drop(db_txn);
     drop(db);
 
-    let mg = Migrate::new(tmp_dir.as_ref().to_path_buf());
+    let mg = Migrate::new(tmp_dir.as_ref().to_path_buf(), None);
 
     let db = mg.open_bulk_load_db().unwrap().unwrap();
 
     mg.migrate(db).unwrap();
 
-    let mg2 = Migrate::new(tmp_dir.as_ref().to_path_buf());
+    let mg2 = Migrate::new(tmp_dir.as_ref().to_path_buf(), None);
 
     let rdb = mg2.open_read_only_db().unwrap().unwrap();
 
@@ -41,7 +41,7 @@ mod rocksdb;
 
 pub use jemalloc::jemalloc_profiling_dump;
 pub use process::track_current_process;
-pub use rocksdb::TrackRocksDBMemory;
+pub use rocksdb::{ColumnFamilyStats, GatherRocksDBStats, TrackRocksDBMemory};
 
 /// Track the memory usage of the CKB process and Jemalloc.
 pub fn track_current_process_simple(interval: u64) {
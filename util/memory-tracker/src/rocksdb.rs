@@ -18,6 +18,15 @@ impl PropertyValue<u64> {
     }
 }
 
+impl<T> PropertyValue<T> {
+    pub(crate) fn into_option(self) -> Option<T> {
+        match self {
+            Self::Value(v) => Some(v),
+            Self::Null | Self::Error(_) => None,
+        }
+    }
+}
+
 impl<T> From<Result<Option<T>, String>> for PropertyValue<T> {
     fn from(res: Result<Option<T>, String>) -> Self {
         match res {
@@ -46,6 +55,65 @@ pub trait TrackRocksDBMemory {
     fn gather_int_values(&self, _: &str) {}
 }
 
+/// Statistics of a RocksDB column family.
+///
+/// A field is `None` when the property is not supported or failed to be read.
+#[derive(Debug, Clone, Default)]
+pub struct ColumnFamilyStats {
+    /// The column family name.
+    pub name: String,
+    /// Estimated number of keys, `rocksdb.estimate-num-keys`.
+    pub estimate_num_keys: Option<u64>,
+    /// Total size of the SST files belonging to the latest version, `rocksdb.live-sst-files-size`.
+    pub live_sst_files_size: Option<u64>,
+    /// Total size of all SST files, `rocksdb.total-sst-files-size`.
+    pub total_sst_files_size: Option<u64>,
+    /// Estimated bytes compaction needs to rewrite, `rocksdb.estimate-pending-compaction-bytes`.
+    pub estimate_pending_compaction_bytes: Option<u64>,
+    /// Memory size of the entries residing in block cache, `rocksdb.block-cache-usage`.
+    pub block_cache_usage: Option<u64>,
+}
+
+/// A trait which used to collect the statistics of every RocksDB column family.
+pub trait GatherRocksDBStats {
+    /// Collects the statistics of every column family, sorted by the column family name.
+    fn gather_column_family_stats(&self) -> Vec<ColumnFamilyStats>;
+}
+
+impl<RocksDB> GatherRocksDBStats for RocksDB
+where
+    RocksDB: GetColumnFamilys + GetPropertyCF,
+{
+    fn gather_column_family_stats(&self) -> Vec<ColumnFamilyStats> {
+        let mut stats: Vec<_> = self
+            .get_cfs()
+            .iter()
+            .map(|(cf_name, cf)| {
+                let int_value = |key: &str| {
+                    let value: PropertyValue<u64> = self
+                        .property_int_value_cf(cf, &format!("rocksdb.{}", key))
+                        .map_err(|err| format!("{}", err))
+                        .into();
+                    value.into_option()
+                };
+                ColumnFamilyStats {
+                    name: cf_name.to_owned(),
+                    estimate_num_keys: int_value("estimate-num-keys"),
+                    live_sst_files_size: int_value("live-sst-files-size"),
+                    total_sst_files_size: int_value("total-sst-files-size"),
+                    estimate_pending_compaction_bytes: int_value(
+                        "estimate-pending-compaction-bytes",
+                    ),
+                    block_cache_usage: int_value("block-cache-usage"),
+                }
+            })
+            .collect();
+        // column families are named by numbers
+        stats.sort_by_key(|cf| (cf.name.len(), cf.name.clone()));
+        stats
+    }
+}
+
 pub(crate) struct DummyRocksDB;
 
 impl TrackRocksDBMemory for DummyRocksDB {}
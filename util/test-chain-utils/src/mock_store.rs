@@ -12,27 +12,19 @@ use ckb_types::{
 };
 use faketime::unix_time_as_millis;
 use std::sync::Arc;
-use tempfile::TempDir;
 
-/// A temporary RocksDB for mocking chain storage.
+/// An in-memory RocksDB for mocking chain storage.
 #[doc(hidden)]
 #[derive(Clone)]
 pub struct MockStore {
-    // The fields of a struct are dropped in declaration order.
-    // So, put `ChainDB` (`RocksDB`) before `TempDir`.
-    //
-    // Ref: https://doc.rust-lang.org/reference/destructors.html
     inner: Arc<ChainDB>,
-    tmp_dir: Arc<TempDir>,
 }
 
 impl Default for MockStore {
     fn default() -> Self {
-        let tmp_dir = TempDir::new().unwrap();
-        let db = RocksDB::open_in(&tmp_dir, COLUMNS);
+        let db = RocksDB::open_in_memory(COLUMNS);
         MockStore {
             inner: Arc::new(ChainDB::new(db, Default::default())),
-            tmp_dir: Arc::new(tmp_dir),
         }
     }
 }
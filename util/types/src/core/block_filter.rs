@@ -0,0 +1,240 @@
+//! Compact block filters.
+//!
+//! A block filter is a Golomb-coded set of the lock and type script hashes of the cells
+//! created and consumed by a block, so a light wallet can tell whether a block touches its
+//! scripts without downloading the block.
+//!
+//! The filter data is the number of elements as a little endian `u64`, followed by the
+//! Golomb-Rice coded deltas of the sorted element hashes.
+use crate::{bytes::Bytes, packed, prelude::*};
+use ckb_hash::{blake2b_256, new_blake2b};
+
+/// The Golomb-Rice coding parameter, the number of low bits written verbatim.
+pub const BLOCK_FILTER_P: u8 = 19;
+/// The inverse of the false positive rate.
+pub const BLOCK_FILTER_M: u64 = 784_931;
+
+const COUNT_SIZE: usize = 8;
+
+/// A compact block filter.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BlockFilter {
+    data: Bytes,
+}
+
+impl Default for BlockFilter {
+    fn default() -> Self {
+        BlockFilter::build(&packed::Byte32::zero(), ::std::iter::empty::<&[u8]>())
+    }
+}
+
+impl BlockFilter {
+    /// Build the filter of a block from the elements it touches.
+    ///
+    /// The elements are hashed with the block hash as the key, duplicates are removed.
+    pub fn build<'a, I: IntoIterator<Item = &'a [u8]>>(
+        block_hash: &packed::Byte32,
+        elements: I,
+    ) -> Self {
+        // the range depends on the count, so duplicates are removed before hashing, the
+        // values of distinct elements may still collide and are all kept
+        let mut elements: Vec<&[u8]> = elements.into_iter().collect();
+        elements.sort_unstable();
+        elements.dedup();
+        let range = (elements.len() as u64).saturating_mul(BLOCK_FILTER_M);
+        let mut values: Vec<u64> = elements
+            .into_iter()
+            .map(|element| hash_to_range(block_hash, element, range))
+            .collect();
+        values.sort_unstable();
+
+        let mut writer = BitWriter::default();
+        let mut last = 0;
+        for value in &values {
+            let delta = value - last;
+            writer.write_unary(delta >> BLOCK_FILTER_P);
+            writer.write_bits(delta, BLOCK_FILTER_P);
+            last = *value;
+        }
+
+        let mut data = Vec::with_capacity(COUNT_SIZE + writer.bytes.len());
+        data.extend_from_slice(&(values.len() as u64).to_le_bytes());
+        data.extend_from_slice(&writer.bytes);
+        BlockFilter { data: data.into() }
+    }
+
+    /// Wrap the filter data, e.g. received from the network.
+    pub fn new(data: Bytes) -> Self {
+        BlockFilter { data }
+    }
+
+    /// The filter data.
+    pub fn data(&self) -> Bytes {
+        self.data.clone()
+    }
+
+    /// The number of elements in the filter.
+    pub fn len(&self) -> u64 {
+        if self.data.len() < COUNT_SIZE {
+            return 0;
+        }
+        let mut count = [0u8; COUNT_SIZE];
+        count.copy_from_slice(&self.data[..COUNT_SIZE]);
+        u64::from_le_bytes(count)
+    }
+
+    /// Whether the filter has no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Calculate the hash of the filter data.
+    pub fn calc_hash(&self) -> packed::Byte32 {
+        blake2b_256(&self.data).pack()
+    }
+
+    /// Calculate the filter header, which commits to this filter and all the filters before it.
+    ///
+    /// The parent header of the genesis block filter is zero.
+    pub fn calc_header(&self, parent_header: &packed::Byte32) -> packed::Byte32 {
+        calc_filter_header(&self.calc_hash(), parent_header)
+    }
+
+    /// Whether any of the queries may be in the filter.
+    ///
+    /// False positives happen at a rate of `1 / BLOCK_FILTER_M`, there are no false
+    /// negatives. Malformed filter data matches nothing.
+    pub fn match_any<'a, I: IntoIterator<Item = &'a [u8]>>(
+        &self,
+        block_hash: &packed::Byte32,
+        queries: I,
+    ) -> bool {
+        let count = self.len();
+        let range = count.saturating_mul(BLOCK_FILTER_M);
+        let mut queries: Vec<u64> = queries
+            .into_iter()
+            .map(|query| hash_to_range(block_hash, query, range))
+            .collect();
+        if count == 0 || queries.is_empty() {
+            return false;
+        }
+        queries.sort_unstable();
+
+        let mut reader = BitReader::new(&self.data[COUNT_SIZE..]);
+        let mut queries = queries.into_iter().peekable();
+        let mut value = 0u64;
+        for _ in 0..count {
+            let delta = match reader
+                .read_unary()
+                .and_then(|quotient| Some((quotient, reader.read_bits(BLOCK_FILTER_P)?)))
+            {
+                Some((quotient, remainder)) => (quotient << BLOCK_FILTER_P) | remainder,
+                None => return false,
+            };
+            value = value.saturating_add(delta);
+            while let Some(query) = queries.peek() {
+                if *query == value {
+                    return true;
+                } else if *query < value {
+                    queries.next();
+                } else {
+                    break;
+                }
+            }
+            if queries.peek().is_none() {
+                return false;
+            }
+        }
+        false
+    }
+}
+
+/// Calculate a filter header from the filter hash and the parent filter header.
+pub fn calc_filter_header(
+    filter_hash: &packed::Byte32,
+    parent_header: &packed::Byte32,
+) -> packed::Byte32 {
+    let mut hasher = new_blake2b();
+    hasher.update(filter_hash.as_slice());
+    hasher.update(parent_header.as_slice());
+    let mut header = [0u8; 32];
+    hasher.finalize(&mut header);
+    header.pack()
+}
+
+// Map the element hash uniformly into [0, range)
+fn hash_to_range(block_hash: &packed::Byte32, element: &[u8], range: u64) -> u64 {
+    let mut hasher = new_blake2b();
+    hasher.update(block_hash.as_slice());
+    hasher.update(element);
+    let mut hash = [0u8; 32];
+    hasher.finalize(&mut hash);
+    let mut value = [0u8; 8];
+    value.copy_from_slice(&hash[..8]);
+    ((u128::from(u64::from_le_bytes(value)) * u128::from(range)) >> 64) as u64
+}
+
+#[derive(Default)]
+struct BitWriter {
+    bytes: Vec<u8>,
+    used: u8,
+}
+
+impl BitWriter {
+    fn write_bit(&mut self, bit: bool) {
+        if self.used == 0 {
+            self.bytes.push(0);
+        }
+        if bit {
+            *self.bytes.last_mut().expect("pushed above") |= 0x80 >> self.used;
+        }
+        self.used = (self.used + 1) % 8;
+    }
+
+    fn write_unary(&mut self, n: u64) {
+        for _ in 0..n {
+            self.write_bit(true);
+        }
+        self.write_bit(false);
+    }
+
+    fn write_bits(&mut self, value: u64, bits: u8) {
+        for i in (0..bits).rev() {
+            self.write_bit((value >> i) & 1 == 1);
+        }
+    }
+}
+
+struct BitReader<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl<'a> BitReader<'a> {
+    fn new(bytes: &'a [u8]) -> Self {
+        BitReader { bytes, position: 0 }
+    }
+
+    fn read_bit(&mut self) -> Option<bool> {
+        let byte = self.bytes.get(self.position / 8)?;
+        let bit = byte & (0x80 >> (self.position % 8)) != 0;
+        self.position += 1;
+        Some(bit)
+    }
+
+    fn read_unary(&mut self) -> Option<u64> {
+        let mut n = 0;
+        while self.read_bit()? {
+            n += 1;
+        }
+        Some(n)
+    }
+
+    fn read_bits(&mut self, bits: u8) -> Option<u64> {
+        let mut value = 0;
+        for _ in 0..bits {
+            value = (value << 1) | u64::from(self.read_bit()?);
+        }
+        Some(value)
+    }
+}
@@ -11,6 +11,7 @@
 //! [those bytes]: ../packed/index.html
 #![allow(clippy::from_over_into)]
 
+pub mod block_filter;
 pub mod cell;
 pub mod error;
 pub mod hardfork;
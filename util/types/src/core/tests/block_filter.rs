@@ -0,0 +1,61 @@
+use crate::{
+    core::block_filter::{calc_filter_header, BlockFilter},
+    h256,
+    packed::Byte32,
+    prelude::*,
+};
+
+#[test]
+fn test_block_filter_match() {
+    let block_hash: Byte32 = h256!("0x1").pack();
+    let elements: Vec<Vec<u8>> = (0u8..100).map(|i| vec![i; 32]).collect();
+    let filter = BlockFilter::build(&block_hash, elements.iter().map(|e| &e[..]));
+    assert_eq!(filter.len(), 100);
+
+    for element in &elements {
+        assert!(filter.match_any(&block_hash, vec![&element[..]]));
+    }
+    let missing = vec![0xffu8; 32];
+    assert!(!filter.match_any(&block_hash, vec![&missing[..]]));
+    assert!(filter.match_any(&block_hash, vec![&missing[..], &elements[42][..]]));
+
+    // the decoded filter is the same as the built one
+    let decoded = BlockFilter::new(filter.data());
+    assert!(decoded.match_any(&block_hash, vec![&elements[0][..]]));
+
+    // truncated data matches nothing
+    let truncated = BlockFilter::new(filter.data().slice(..10));
+    assert!(!truncated.match_any(&block_hash, vec![&elements[99][..]]));
+
+    let empty = BlockFilter::build(&block_hash, ::std::iter::empty::<&[u8]>());
+    assert!(empty.is_empty());
+    assert!(!empty.match_any(&block_hash, vec![&elements[0][..]]));
+
+    let parent = Byte32::zero();
+    assert_eq!(
+        filter.calc_header(&parent),
+        calc_filter_header(&filter.calc_hash(), &parent)
+    );
+    assert_ne!(filter.calc_header(&parent), empty.calc_header(&parent));
+}
+
+#[test]
+fn test_block_filter_duplicate_elements() {
+    let block_hash: Byte32 = h256!("0x2").pack();
+    let elements: Vec<Vec<u8>> = (0u8..20).map(|i| vec![i; 32]).collect();
+    // every element is touched several times, e.g. by several cells with the same lock
+    let duplicated = elements
+        .iter()
+        .chain(elements.iter())
+        .chain(elements.iter().take(5));
+    let filter = BlockFilter::build(&block_hash, duplicated.map(|e| &e[..]));
+    assert_eq!(filter.len(), 20);
+    assert_eq!(
+        filter,
+        BlockFilter::build(&block_hash, elements.iter().map(|e| &e[..]))
+    );
+
+    for element in &elements {
+        assert!(filter.match_any(&block_hash, vec![&element[..]]));
+    }
+}
@@ -1,3 +1,4 @@
+mod block_filter;
 mod blockchain;
 mod cell;
 mod fee_rate;
@@ -1,4 +1,9 @@
-use crate::{core::BlockView, packed, prelude::*};
+use crate::{
+    core::{BlockBuilder, BlockView, TransactionBuilder},
+    packed,
+    prelude::*,
+    utilities::merkle_root,
+};
 
 #[test]
 fn test_block_view_convert_from_packed_block() {
@@ -83,3 +88,31 @@ fn test_extension_field_in_block_view() {
     assert_ne!(block.extra_hash(), block2_v1.extra_hash());
     assert_eq!(block.extra_hash(), block2_v1_un.extra_hash());
 }
+
+#[test]
+fn test_transactions_proof() {
+    let block = BlockBuilder::default()
+        .transactions(
+            (0u32..5)
+                .map(|i| TransactionBuilder::default().version(i.pack()).build())
+                .collect::<Vec<_>>(),
+        )
+        .build();
+
+    let proof = block.transactions_proof(&[4, 1, 3]).unwrap();
+    // the leaves are in the order of the proof indices
+    let leaves_offset = block.transactions().len() as u32 - 1;
+    let leaves: Vec<packed::Byte32> = proof
+        .indices()
+        .iter()
+        .map(|index| block.tx_hashes()[(index - leaves_offset) as usize].clone())
+        .collect();
+    assert_eq!(leaves.len(), 3);
+    let raw_transactions_root = proof.root(&leaves).unwrap();
+    assert_eq!(
+        merkle_root(&[raw_transactions_root, block.calc_witnesses_root()]),
+        block.transactions_root()
+    );
+
+    assert!(block.transactions_proof(&[5]).is_none());
+}
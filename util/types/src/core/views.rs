@@ -10,7 +10,7 @@ use crate::{
     core::{BlockNumber, Capacity, EpochNumberWithFraction, Version},
     packed,
     prelude::*,
-    utilities::merkle_root,
+    utilities::{merkle_root, MerkleProof, CBMT},
     U256,
 };
 
@@ -765,6 +765,14 @@ impl BlockView {
     pub fn calc_witnesses_root(&self) -> packed::Byte32 {
         merkle_root(&self.tx_witness_hashes[..])
     }
+
+    /// Builds the merkle proof of the transactions at the indices against the merkle root for
+    /// transactions without witnesses.
+    ///
+    /// Returns `None` if any index is out of range.
+    pub fn transactions_proof(&self, indices: &[u32]) -> Option<MerkleProof> {
+        CBMT::build_merkle_proof(&self.tx_hashes[..], indices)
+    }
 }
 
 /*
@@ -98,6 +98,13 @@ impl<'r> packed::RelayTransactionsReader<'r> {
     }
 }
 
+impl<'r> packed::StemTransactionsReader<'r> {
+    /// Recursively checks whether the structure of the binary data is correct.
+    pub fn check_data(&self) -> bool {
+        self.transactions().check_data()
+    }
+}
+
 impl<'r> packed::SendBlockReader<'r> {
     /// Recursively checks whether the structure of the binary data is correct.
     pub fn check_data(&self) -> bool {
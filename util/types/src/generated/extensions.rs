@@ -4743,7 +4743,7 @@ impl ::core::default::Default for RelayMessage {
     }
 }
 impl RelayMessage {
-    pub const ITEMS_COUNT: usize = 8;
+    pub const ITEMS_COUNT: usize = 12;
     pub fn item_id(&self) -> molecule::Number {
         molecule::unpack_number(self.as_slice())
     }
@@ -4758,6 +4758,10 @@ impl RelayMessage {
             5 => BlockTransactions::new_unchecked(inner).into(),
             6 => GetBlockProposal::new_unchecked(inner).into(),
             7 => BlockProposal::new_unchecked(inner).into(),
+            8 => StemTransactions::new_unchecked(inner).into(),
+            9 => ReconciliationRequest::new_unchecked(inner).into(),
+            10 => ReconciliationSketch::new_unchecked(inner).into(),
+            11 => ReconciliationDifference::new_unchecked(inner).into(),
             _ => panic!("{}: invalid data", Self::NAME),
         }
     }
@@ -4814,7 +4818,7 @@ impl<'r> ::core::fmt::Display for RelayMessageReader<'r> {
     }
 }
 impl<'r> RelayMessageReader<'r> {
-    pub const ITEMS_COUNT: usize = 8;
+    pub const ITEMS_COUNT: usize = 12;
     pub fn item_id(&self) -> molecule::Number {
         molecule::unpack_number(self.as_slice())
     }
@@ -4829,6 +4833,10 @@ impl<'r> RelayMessageReader<'r> {
             5 => BlockTransactionsReader::new_unchecked(inner).into(),
             6 => GetBlockProposalReader::new_unchecked(inner).into(),
             7 => BlockProposalReader::new_unchecked(inner).into(),
+            8 => StemTransactionsReader::new_unchecked(inner).into(),
+            9 => ReconciliationRequestReader::new_unchecked(inner).into(),
+            10 => ReconciliationSketchReader::new_unchecked(inner).into(),
+            11 => ReconciliationDifferenceReader::new_unchecked(inner).into(),
             _ => panic!("{}: invalid data", Self::NAME),
         }
     }
@@ -4862,6 +4870,10 @@ impl<'r> molecule::prelude::Reader<'r> for RelayMessageReader<'r> {
             5 => BlockTransactionsReader::verify(inner_slice, compatible),
             6 => GetBlockProposalReader::verify(inner_slice, compatible),
             7 => BlockProposalReader::verify(inner_slice, compatible),
+            8 => StemTransactionsReader::verify(inner_slice, compatible),
+            9 => ReconciliationRequestReader::verify(inner_slice, compatible),
+            10 => ReconciliationSketchReader::verify(inner_slice, compatible),
+            11 => ReconciliationDifferenceReader::verify(inner_slice, compatible),
             _ => ve!(Self, UnknownItem, Self::ITEMS_COUNT, item_id),
         }?;
         Ok(())
@@ -4870,7 +4882,7 @@ impl<'r> molecule::prelude::Reader<'r> for RelayMessageReader<'r> {
 #[derive(Debug, Default)]
 pub struct RelayMessageBuilder(pub(crate) RelayMessageUnion);
 impl RelayMessageBuilder {
-    pub const ITEMS_COUNT: usize = 8;
+    pub const ITEMS_COUNT: usize = 12;
     pub fn set<I>(mut self, v: I) -> Self
     where
         I: ::core::convert::Into<RelayMessageUnion>,
@@ -4906,6 +4918,10 @@ pub enum RelayMessageUnion {
     BlockTransactions(BlockTransactions),
     GetBlockProposal(GetBlockProposal),
     BlockProposal(BlockProposal),
+    StemTransactions(StemTransactions),
+    ReconciliationRequest(ReconciliationRequest),
+    ReconciliationSketch(ReconciliationSketch),
+    ReconciliationDifference(ReconciliationDifference),
 }
 #[derive(Debug, Clone, Copy)]
 pub enum RelayMessageUnionReader<'r> {
@@ -4917,6 +4933,10 @@ pub enum RelayMessageUnionReader<'r> {
     BlockTransactions(BlockTransactionsReader<'r>),
     GetBlockProposal(GetBlockProposalReader<'r>),
     BlockProposal(BlockProposalReader<'r>),
+    StemTransactions(StemTransactionsReader<'r>),
+    ReconciliationRequest(ReconciliationRequestReader<'r>),
+    ReconciliationSketch(ReconciliationSketchReader<'r>),
+    ReconciliationDifference(ReconciliationDifferenceReader<'r>),
 }
 impl ::core::default::Default for RelayMessageUnion {
     fn default() -> Self {
@@ -4968,6 +4988,36 @@ impl ::core::fmt::Display for RelayMessageUnion {
             RelayMessageUnion::BlockProposal(ref item) => {
                 write!(f, "{}::{}({})", Self::NAME, BlockProposal::NAME, item)
             }
+            RelayMessageUnion::StemTransactions(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, StemTransactions::NAME, item)
+            }
+            RelayMessageUnion::ReconciliationRequest(ref item) => {
+                write!(
+                    f,
+                    "{}::{}({})",
+                    Self::NAME,
+                    ReconciliationRequest::NAME,
+                    item
+                )
+            }
+            RelayMessageUnion::ReconciliationSketch(ref item) => {
+                write!(
+                    f,
+                    "{}::{}({})",
+                    Self::NAME,
+                    ReconciliationSketch::NAME,
+                    item
+                )
+            }
+            RelayMessageUnion::ReconciliationDifference(ref item) => {
+                write!(
+                    f,
+                    "{}::{}({})",
+                    Self::NAME,
+                    ReconciliationDifference::NAME,
+                    item
+                )
+            }
         }
     }
 }
@@ -5016,6 +5066,36 @@ impl<'r> ::core::fmt::Display for RelayMessageUnionReader<'r> {
             RelayMessageUnionReader::BlockProposal(ref item) => {
                 write!(f, "{}::{}({})", Self::NAME, BlockProposal::NAME, item)
             }
+            RelayMessageUnionReader::StemTransactions(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, StemTransactions::NAME, item)
+            }
+            RelayMessageUnionReader::ReconciliationRequest(ref item) => {
+                write!(
+                    f,
+                    "{}::{}({})",
+                    Self::NAME,
+                    ReconciliationRequest::NAME,
+                    item
+                )
+            }
+            RelayMessageUnionReader::ReconciliationSketch(ref item) => {
+                write!(
+                    f,
+                    "{}::{}({})",
+                    Self::NAME,
+                    ReconciliationSketch::NAME,
+                    item
+                )
+            }
+            RelayMessageUnionReader::ReconciliationDifference(ref item) => {
+                write!(
+                    f,
+                    "{}::{}({})",
+                    Self::NAME,
+                    ReconciliationDifference::NAME,
+                    item
+                )
+            }
         }
     }
 }
@@ -5030,6 +5110,10 @@ impl RelayMessageUnion {
             RelayMessageUnion::BlockTransactions(ref item) => write!(f, "{}", item),
             RelayMessageUnion::GetBlockProposal(ref item) => write!(f, "{}", item),
             RelayMessageUnion::BlockProposal(ref item) => write!(f, "{}", item),
+            RelayMessageUnion::StemTransactions(ref item) => write!(f, "{}", item),
+            RelayMessageUnion::ReconciliationRequest(ref item) => write!(f, "{}", item),
+            RelayMessageUnion::ReconciliationSketch(ref item) => write!(f, "{}", item),
+            RelayMessageUnion::ReconciliationDifference(ref item) => write!(f, "{}", item),
         }
     }
 }
@@ -5044,6 +5128,10 @@ impl<'r> RelayMessageUnionReader<'r> {
             RelayMessageUnionReader::BlockTransactions(ref item) => write!(f, "{}", item),
             RelayMessageUnionReader::GetBlockProposal(ref item) => write!(f, "{}", item),
             RelayMessageUnionReader::BlockProposal(ref item) => write!(f, "{}", item),
+            RelayMessageUnionReader::StemTransactions(ref item) => write!(f, "{}", item),
+            RelayMessageUnionReader::ReconciliationRequest(ref item) => write!(f, "{}", item),
+            RelayMessageUnionReader::ReconciliationSketch(ref item) => write!(f, "{}", item),
+            RelayMessageUnionReader::ReconciliationDifference(ref item) => write!(f, "{}", item),
         }
     }
 }
@@ -5087,6 +5175,26 @@ impl ::core::convert::From<BlockProposal> for RelayMessageUnion {
         RelayMessageUnion::BlockProposal(item)
     }
 }
+impl ::core::convert::From<StemTransactions> for RelayMessageUnion {
+    fn from(item: StemTransactions) -> Self {
+        RelayMessageUnion::StemTransactions(item)
+    }
+}
+impl ::core::convert::From<ReconciliationRequest> for RelayMessageUnion {
+    fn from(item: ReconciliationRequest) -> Self {
+        RelayMessageUnion::ReconciliationRequest(item)
+    }
+}
+impl ::core::convert::From<ReconciliationSketch> for RelayMessageUnion {
+    fn from(item: ReconciliationSketch) -> Self {
+        RelayMessageUnion::ReconciliationSketch(item)
+    }
+}
+impl ::core::convert::From<ReconciliationDifference> for RelayMessageUnion {
+    fn from(item: ReconciliationDifference) -> Self {
+        RelayMessageUnion::ReconciliationDifference(item)
+    }
+}
 impl<'r> ::core::convert::From<CompactBlockReader<'r>> for RelayMessageUnionReader<'r> {
     fn from(item: CompactBlockReader<'r>) -> Self {
         RelayMessageUnionReader::CompactBlock(item)
@@ -5127,6 +5235,26 @@ impl<'r> ::core::convert::From<BlockProposalReader<'r>> for RelayMessageUnionRea
         RelayMessageUnionReader::BlockProposal(item)
     }
 }
+impl<'r> ::core::convert::From<StemTransactionsReader<'r>> for RelayMessageUnionReader<'r> {
+    fn from(item: StemTransactionsReader<'r>) -> Self {
+        RelayMessageUnionReader::StemTransactions(item)
+    }
+}
+impl<'r> ::core::convert::From<ReconciliationRequestReader<'r>> for RelayMessageUnionReader<'r> {
+    fn from(item: ReconciliationRequestReader<'r>) -> Self {
+        RelayMessageUnionReader::ReconciliationRequest(item)
+    }
+}
+impl<'r> ::core::convert::From<ReconciliationSketchReader<'r>> for RelayMessageUnionReader<'r> {
+    fn from(item: ReconciliationSketchReader<'r>) -> Self {
+        RelayMessageUnionReader::ReconciliationSketch(item)
+    }
+}
+impl<'r> ::core::convert::From<ReconciliationDifferenceReader<'r>> for RelayMessageUnionReader<'r> {
+    fn from(item: ReconciliationDifferenceReader<'r>) -> Self {
+        RelayMessageUnionReader::ReconciliationDifference(item)
+    }
+}
 impl RelayMessageUnion {
     pub const NAME: &'static str = "RelayMessageUnion";
     pub fn as_bytes(&self) -> molecule::bytes::Bytes {
@@ -5139,6 +5267,10 @@ impl RelayMessageUnion {
             RelayMessageUnion::BlockTransactions(item) => item.as_bytes(),
             RelayMessageUnion::GetBlockProposal(item) => item.as_bytes(),
             RelayMessageUnion::BlockProposal(item) => item.as_bytes(),
+            RelayMessageUnion::StemTransactions(item) => item.as_bytes(),
+            RelayMessageUnion::ReconciliationRequest(item) => item.as_bytes(),
+            RelayMessageUnion::ReconciliationSketch(item) => item.as_bytes(),
+            RelayMessageUnion::ReconciliationDifference(item) => item.as_bytes(),
         }
     }
     pub fn as_slice(&self) -> &[u8] {
@@ -5151,6 +5283,10 @@ impl RelayMessageUnion {
             RelayMessageUnion::BlockTransactions(item) => item.as_slice(),
             RelayMessageUnion::GetBlockProposal(item) => item.as_slice(),
             RelayMessageUnion::BlockProposal(item) => item.as_slice(),
+            RelayMessageUnion::StemTransactions(item) => item.as_slice(),
+            RelayMessageUnion::ReconciliationRequest(item) => item.as_slice(),
+            RelayMessageUnion::ReconciliationSketch(item) => item.as_slice(),
+            RelayMessageUnion::ReconciliationDifference(item) => item.as_slice(),
         }
     }
     pub fn item_id(&self) -> molecule::Number {
@@ -5163,6 +5299,10 @@ impl RelayMessageUnion {
             RelayMessageUnion::BlockTransactions(_) => 5,
             RelayMessageUnion::GetBlockProposal(_) => 6,
             RelayMessageUnion::BlockProposal(_) => 7,
+            RelayMessageUnion::StemTransactions(_) => 8,
+            RelayMessageUnion::ReconciliationRequest(_) => 9,
+            RelayMessageUnion::ReconciliationSketch(_) => 10,
+            RelayMessageUnion::ReconciliationDifference(_) => 11,
         }
     }
     pub fn item_name(&self) -> &str {
@@ -5175,6 +5315,10 @@ impl RelayMessageUnion {
             RelayMessageUnion::BlockTransactions(_) => "BlockTransactions",
             RelayMessageUnion::GetBlockProposal(_) => "GetBlockProposal",
             RelayMessageUnion::BlockProposal(_) => "BlockProposal",
+            RelayMessageUnion::StemTransactions(_) => "StemTransactions",
+            RelayMessageUnion::ReconciliationRequest(_) => "ReconciliationRequest",
+            RelayMessageUnion::ReconciliationSketch(_) => "ReconciliationSketch",
+            RelayMessageUnion::ReconciliationDifference(_) => "ReconciliationDifference",
         }
     }
     pub fn as_reader<'r>(&'r self) -> RelayMessageUnionReader<'r> {
@@ -5187,6 +5331,10 @@ impl RelayMessageUnion {
             RelayMessageUnion::BlockTransactions(item) => item.as_reader().into(),
             RelayMessageUnion::GetBlockProposal(item) => item.as_reader().into(),
             RelayMessageUnion::BlockProposal(item) => item.as_reader().into(),
+            RelayMessageUnion::StemTransactions(item) => item.as_reader().into(),
+            RelayMessageUnion::ReconciliationRequest(item) => item.as_reader().into(),
+            RelayMessageUnion::ReconciliationSketch(item) => item.as_reader().into(),
+            RelayMessageUnion::ReconciliationDifference(item) => item.as_reader().into(),
         }
     }
 }
@@ -5202,6 +5350,10 @@ impl<'r> RelayMessageUnionReader<'r> {
             RelayMessageUnionReader::BlockTransactions(item) => item.as_slice(),
             RelayMessageUnionReader::GetBlockProposal(item) => item.as_slice(),
             RelayMessageUnionReader::BlockProposal(item) => item.as_slice(),
+            RelayMessageUnionReader::StemTransactions(item) => item.as_slice(),
+            RelayMessageUnionReader::ReconciliationRequest(item) => item.as_slice(),
+            RelayMessageUnionReader::ReconciliationSketch(item) => item.as_slice(),
+            RelayMessageUnionReader::ReconciliationDifference(item) => item.as_slice(),
         }
     }
     pub fn item_id(&self) -> molecule::Number {
@@ -5214,6 +5366,10 @@ impl<'r> RelayMessageUnionReader<'r> {
             RelayMessageUnionReader::BlockTransactions(_) => 5,
             RelayMessageUnionReader::GetBlockProposal(_) => 6,
             RelayMessageUnionReader::BlockProposal(_) => 7,
+            RelayMessageUnionReader::StemTransactions(_) => 8,
+            RelayMessageUnionReader::ReconciliationRequest(_) => 9,
+            RelayMessageUnionReader::ReconciliationSketch(_) => 10,
+            RelayMessageUnionReader::ReconciliationDifference(_) => 11,
         }
     }
     pub fn item_name(&self) -> &str {
@@ -5226,6 +5382,10 @@ impl<'r> RelayMessageUnionReader<'r> {
             RelayMessageUnionReader::BlockTransactions(_) => "BlockTransactions",
             RelayMessageUnionReader::GetBlockProposal(_) => "GetBlockProposal",
             RelayMessageUnionReader::BlockProposal(_) => "BlockProposal",
+            RelayMessageUnionReader::StemTransactions(_) => "StemTransactions",
+            RelayMessageUnionReader::ReconciliationRequest(_) => "ReconciliationRequest",
+            RelayMessageUnionReader::ReconciliationSketch(_) => "ReconciliationSketch",
+            RelayMessageUnionReader::ReconciliationDifference(_) => "ReconciliationDifference",
         }
     }
 }
@@ -8367,8 +8527,8 @@ impl molecule::prelude::Builder for BlockProposalBuilder {
     }
 }
 #[derive(Clone)]
-pub struct IndexTransaction(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for IndexTransaction {
+pub struct StemTransactions(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for StemTransactions {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -8377,16 +8537,15 @@ impl ::core::fmt::LowerHex for IndexTransaction {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Debug for IndexTransaction {
+impl ::core::fmt::Debug for StemTransactions {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::fmt::Display for IndexTransaction {
+impl ::core::fmt::Display for StemTransactions {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "index", self.index())?;
-        write!(f, ", {}: {}", "transaction", self.transaction())?;
+        write!(f, "{}: {}", "transactions", self.transactions())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -8394,19 +8553,14 @@ impl ::core::fmt::Display for IndexTransaction {
         write!(f, " }}")
     }
 }
-impl ::core::default::Default for IndexTransaction {
+impl ::core::default::Default for StemTransactions {
     fn default() -> Self {
-        let v: Vec<u8> = vec![
-            84, 0, 0, 0, 12, 0, 0, 0, 16, 0, 0, 0, 0, 0, 0, 0, 68, 0, 0, 0, 12, 0, 0, 0, 64, 0, 0,
-            0, 52, 0, 0, 0, 28, 0, 0, 0, 32, 0, 0, 0, 36, 0, 0, 0, 40, 0, 0, 0, 44, 0, 0, 0, 48, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 4, 0, 0, 0, 4, 0, 0,
-            0,
-        ];
-        IndexTransaction::new_unchecked(v.into())
+        let v: Vec<u8> = vec![12, 0, 0, 0, 8, 0, 0, 0, 4, 0, 0, 0];
+        StemTransactions::new_unchecked(v.into())
     }
 }
-impl IndexTransaction {
-    pub const FIELD_COUNT: usize = 2;
+impl StemTransactions {
+    pub const FIELD_COUNT: usize = 1;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -8423,31 +8577,25 @@ impl IndexTransaction {
     pub fn has_extra_fields(&self) -> bool {
         Self::FIELD_COUNT != self.field_count()
     }
-    pub fn index(&self) -> Uint32 {
+    pub fn transactions(&self) -> RelayTransactionVec {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[4..]) as usize;
-        let end = molecule::unpack_number(&slice[8..]) as usize;
-        Uint32::new_unchecked(self.0.slice(start..end))
-    }
-    pub fn transaction(&self) -> Transaction {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[8..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[12..]) as usize;
-            Transaction::new_unchecked(self.0.slice(start..end))
+            let end = molecule::unpack_number(&slice[8..]) as usize;
+            RelayTransactionVec::new_unchecked(self.0.slice(start..end))
         } else {
-            Transaction::new_unchecked(self.0.slice(start..))
+            RelayTransactionVec::new_unchecked(self.0.slice(start..))
         }
     }
-    pub fn as_reader<'r>(&'r self) -> IndexTransactionReader<'r> {
-        IndexTransactionReader::new_unchecked(self.as_slice())
+    pub fn as_reader<'r>(&'r self) -> StemTransactionsReader<'r> {
+        StemTransactionsReader::new_unchecked(self.as_slice())
     }
 }
-impl molecule::prelude::Entity for IndexTransaction {
-    type Builder = IndexTransactionBuilder;
-    const NAME: &'static str = "IndexTransaction";
+impl molecule::prelude::Entity for StemTransactions {
+    type Builder = StemTransactionsBuilder;
+    const NAME: &'static str = "StemTransactions";
     fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        IndexTransaction(data)
+        StemTransactions(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -8456,23 +8604,21 @@ impl molecule::prelude::Entity for IndexTransaction {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        IndexTransactionReader::from_slice(slice).map(|reader| reader.to_entity())
+        StemTransactionsReader::from_slice(slice).map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        IndexTransactionReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+        StemTransactionsReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
         ::core::default::Default::default()
     }
     fn as_builder(self) -> Self::Builder {
-        Self::new_builder()
-            .index(self.index())
-            .transaction(self.transaction())
+        Self::new_builder().transactions(self.transactions())
     }
 }
 #[derive(Clone, Copy)]
-pub struct IndexTransactionReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for IndexTransactionReader<'r> {
+pub struct StemTransactionsReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for StemTransactionsReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -8481,16 +8627,15 @@ impl<'r> ::core::fmt::LowerHex for IndexTransactionReader<'r> {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl<'r> ::core::fmt::Debug for IndexTransactionReader<'r> {
+impl<'r> ::core::fmt::Debug for StemTransactionsReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::core::fmt::Display for IndexTransactionReader<'r> {
+impl<'r> ::core::fmt::Display for StemTransactionsReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "index", self.index())?;
-        write!(f, ", {}: {}", "transaction", self.transaction())?;
+        write!(f, "{}: {}", "transactions", self.transactions())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -8498,8 +8643,8 @@ impl<'r> ::core::fmt::Display for IndexTransactionReader<'r> {
         write!(f, " }}")
     }
 }
-impl<'r> IndexTransactionReader<'r> {
-    pub const FIELD_COUNT: usize = 2;
+impl<'r> StemTransactionsReader<'r> {
+    pub const FIELD_COUNT: usize = 1;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -8516,31 +8661,25 @@ impl<'r> IndexTransactionReader<'r> {
     pub fn has_extra_fields(&self) -> bool {
         Self::FIELD_COUNT != self.field_count()
     }
-    pub fn index(&self) -> Uint32Reader<'r> {
+    pub fn transactions(&self) -> RelayTransactionVecReader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[4..]) as usize;
-        let end = molecule::unpack_number(&slice[8..]) as usize;
-        Uint32Reader::new_unchecked(&self.as_slice()[start..end])
-    }
-    pub fn transaction(&self) -> TransactionReader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[8..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[12..]) as usize;
-            TransactionReader::new_unchecked(&self.as_slice()[start..end])
+            let end = molecule::unpack_number(&slice[8..]) as usize;
+            RelayTransactionVecReader::new_unchecked(&self.as_slice()[start..end])
         } else {
-            TransactionReader::new_unchecked(&self.as_slice()[start..])
+            RelayTransactionVecReader::new_unchecked(&self.as_slice()[start..])
         }
     }
 }
-impl<'r> molecule::prelude::Reader<'r> for IndexTransactionReader<'r> {
-    type Entity = IndexTransaction;
-    const NAME: &'static str = "IndexTransactionReader";
+impl<'r> molecule::prelude::Reader<'r> for StemTransactionsReader<'r> {
+    type Entity = StemTransactions;
+    const NAME: &'static str = "StemTransactionsReader";
     fn to_entity(&self) -> Self::Entity {
         Self::Entity::new_unchecked(self.as_slice().to_owned().into())
     }
     fn new_unchecked(slice: &'r [u8]) -> Self {
-        IndexTransactionReader(slice)
+        StemTransactionsReader(slice)
     }
     fn as_slice(&self) -> &'r [u8] {
         self.0
@@ -8582,60 +8721,49 @@ impl<'r> molecule::prelude::Reader<'r> for IndexTransactionReader<'r> {
         if offsets.windows(2).any(|i| i[0] > i[1]) {
             return ve!(Self, OffsetsNotMatch);
         }
-        Uint32Reader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
-        TransactionReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        RelayTransactionVecReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
         Ok(())
     }
 }
 #[derive(Debug, Default)]
-pub struct IndexTransactionBuilder {
-    pub(crate) index: Uint32,
-    pub(crate) transaction: Transaction,
+pub struct StemTransactionsBuilder {
+    pub(crate) transactions: RelayTransactionVec,
 }
-impl IndexTransactionBuilder {
-    pub const FIELD_COUNT: usize = 2;
-    pub fn index(mut self, v: Uint32) -> Self {
-        self.index = v;
-        self
-    }
-    pub fn transaction(mut self, v: Transaction) -> Self {
-        self.transaction = v;
+impl StemTransactionsBuilder {
+    pub const FIELD_COUNT: usize = 1;
+    pub fn transactions(mut self, v: RelayTransactionVec) -> Self {
+        self.transactions = v;
         self
     }
 }
-impl molecule::prelude::Builder for IndexTransactionBuilder {
-    type Entity = IndexTransaction;
-    const NAME: &'static str = "IndexTransactionBuilder";
+impl molecule::prelude::Builder for StemTransactionsBuilder {
+    type Entity = StemTransactions;
+    const NAME: &'static str = "StemTransactionsBuilder";
     fn expected_length(&self) -> usize {
-        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
-            + self.index.as_slice().len()
-            + self.transaction.as_slice().len()
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1) + self.transactions.as_slice().len()
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
         let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
         let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
         offsets.push(total_size);
-        total_size += self.index.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.transaction.as_slice().len();
+        total_size += self.transactions.as_slice().len();
         writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
         for offset in offsets.into_iter() {
             writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
         }
-        writer.write_all(self.index.as_slice())?;
-        writer.write_all(self.transaction.as_slice())?;
+        writer.write_all(self.transactions.as_slice())?;
         Ok(())
     }
     fn build(&self) -> Self::Entity {
         let mut inner = Vec::with_capacity(self.expected_length());
         self.write(&mut inner)
             .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        IndexTransaction::new_unchecked(inner.into())
+        StemTransactions::new_unchecked(inner.into())
     }
 }
 #[derive(Clone)]
-pub struct IndexTransactionVec(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for IndexTransactionVec {
+pub struct ReconciliationRequest(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for ReconciliationRequest {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -8644,75 +8772,74 @@ impl ::core::fmt::LowerHex for IndexTransactionVec {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Debug for IndexTransactionVec {
+impl ::core::fmt::Debug for ReconciliationRequest {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::fmt::Display for IndexTransactionVec {
+impl ::core::fmt::Display for ReconciliationRequest {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{} [", Self::NAME)?;
-        for i in 0..self.len() {
-            if i == 0 {
-                write!(f, "{}", self.get_unchecked(i))?;
-            } else {
-                write!(f, ", {}", self.get_unchecked(i))?;
-            }
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "salt", self.salt())?;
+        write!(f, ", {}: {}", "set_size", self.set_size())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
         }
-        write!(f, "]")
+        write!(f, " }}")
     }
 }
-impl ::core::default::Default for IndexTransactionVec {
+impl ::core::default::Default for ReconciliationRequest {
     fn default() -> Self {
-        let v: Vec<u8> = vec![4, 0, 0, 0];
-        IndexTransactionVec::new_unchecked(v.into())
+        let v: Vec<u8> = vec![
+            24, 0, 0, 0, 12, 0, 0, 0, 20, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ];
+        ReconciliationRequest::new_unchecked(v.into())
     }
 }
-impl IndexTransactionVec {
+impl ReconciliationRequest {
+    pub const FIELD_COUNT: usize = 2;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
-    pub fn item_count(&self) -> usize {
+    pub fn field_count(&self) -> usize {
         if self.total_size() == molecule::NUMBER_SIZE {
             0
         } else {
             (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
         }
     }
-    pub fn len(&self) -> usize {
-        self.item_count()
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
     }
-    pub fn is_empty(&self) -> bool {
-        self.len() == 0
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
     }
-    pub fn get(&self, idx: usize) -> Option<IndexTransaction> {
-        if idx >= self.len() {
-            None
-        } else {
-            Some(self.get_unchecked(idx))
-        }
+    pub fn salt(&self) -> Uint64 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        Uint64::new_unchecked(self.0.slice(start..end))
     }
-    pub fn get_unchecked(&self, idx: usize) -> IndexTransaction {
+    pub fn set_size(&self) -> Uint32 {
         let slice = self.as_slice();
-        let start_idx = molecule::NUMBER_SIZE * (1 + idx);
-        let start = molecule::unpack_number(&slice[start_idx..]) as usize;
-        if idx == self.len() - 1 {
-            IndexTransaction::new_unchecked(self.0.slice(start..))
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[12..]) as usize;
+            Uint32::new_unchecked(self.0.slice(start..end))
         } else {
-            let end_idx = start_idx + molecule::NUMBER_SIZE;
-            let end = molecule::unpack_number(&slice[end_idx..]) as usize;
-            IndexTransaction::new_unchecked(self.0.slice(start..end))
+            Uint32::new_unchecked(self.0.slice(start..))
         }
     }
-    pub fn as_reader<'r>(&'r self) -> IndexTransactionVecReader<'r> {
-        IndexTransactionVecReader::new_unchecked(self.as_slice())
+    pub fn as_reader<'r>(&'r self) -> ReconciliationRequestReader<'r> {
+        ReconciliationRequestReader::new_unchecked(self.as_slice())
     }
 }
-impl molecule::prelude::Entity for IndexTransactionVec {
-    type Builder = IndexTransactionVecBuilder;
-    const NAME: &'static str = "IndexTransactionVec";
+impl molecule::prelude::Entity for ReconciliationRequest {
+    type Builder = ReconciliationRequestBuilder;
+    const NAME: &'static str = "ReconciliationRequest";
     fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        IndexTransactionVec(data)
+        ReconciliationRequest(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -8721,21 +8848,23 @@ impl molecule::prelude::Entity for IndexTransactionVec {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        IndexTransactionVecReader::from_slice(slice).map(|reader| reader.to_entity())
+        ReconciliationRequestReader::from_slice(slice).map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        IndexTransactionVecReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+        ReconciliationRequestReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
         ::core::default::Default::default()
     }
     fn as_builder(self) -> Self::Builder {
-        Self::new_builder().extend(self.into_iter())
+        Self::new_builder()
+            .salt(self.salt())
+            .set_size(self.set_size())
     }
 }
 #[derive(Clone, Copy)]
-pub struct IndexTransactionVecReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for IndexTransactionVecReader<'r> {
+pub struct ReconciliationRequestReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for ReconciliationRequestReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -8744,69 +8873,66 @@ impl<'r> ::core::fmt::LowerHex for IndexTransactionVecReader<'r> {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl<'r> ::core::fmt::Debug for IndexTransactionVecReader<'r> {
+impl<'r> ::core::fmt::Debug for ReconciliationRequestReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::core::fmt::Display for IndexTransactionVecReader<'r> {
+impl<'r> ::core::fmt::Display for ReconciliationRequestReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{} [", Self::NAME)?;
-        for i in 0..self.len() {
-            if i == 0 {
-                write!(f, "{}", self.get_unchecked(i))?;
-            } else {
-                write!(f, ", {}", self.get_unchecked(i))?;
-            }
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "salt", self.salt())?;
+        write!(f, ", {}: {}", "set_size", self.set_size())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
         }
-        write!(f, "]")
+        write!(f, " }}")
     }
 }
-impl<'r> IndexTransactionVecReader<'r> {
+impl<'r> ReconciliationRequestReader<'r> {
+    pub const FIELD_COUNT: usize = 2;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
-    pub fn item_count(&self) -> usize {
+    pub fn field_count(&self) -> usize {
         if self.total_size() == molecule::NUMBER_SIZE {
             0
         } else {
             (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
         }
     }
-    pub fn len(&self) -> usize {
-        self.item_count()
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
     }
-    pub fn is_empty(&self) -> bool {
-        self.len() == 0
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
     }
-    pub fn get(&self, idx: usize) -> Option<IndexTransactionReader<'r>> {
-        if idx >= self.len() {
-            None
-        } else {
-            Some(self.get_unchecked(idx))
-        }
+    pub fn salt(&self) -> Uint64Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        Uint64Reader::new_unchecked(&self.as_slice()[start..end])
     }
-    pub fn get_unchecked(&self, idx: usize) -> IndexTransactionReader<'r> {
+    pub fn set_size(&self) -> Uint32Reader<'r> {
         let slice = self.as_slice();
-        let start_idx = molecule::NUMBER_SIZE * (1 + idx);
-        let start = molecule::unpack_number(&slice[start_idx..]) as usize;
-        if idx == self.len() - 1 {
-            IndexTransactionReader::new_unchecked(&self.as_slice()[start..])
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[12..]) as usize;
+            Uint32Reader::new_unchecked(&self.as_slice()[start..end])
         } else {
-            let end_idx = start_idx + molecule::NUMBER_SIZE;
-            let end = molecule::unpack_number(&slice[end_idx..]) as usize;
-            IndexTransactionReader::new_unchecked(&self.as_slice()[start..end])
+            Uint32Reader::new_unchecked(&self.as_slice()[start..])
         }
     }
 }
-impl<'r> molecule::prelude::Reader<'r> for IndexTransactionVecReader<'r> {
-    type Entity = IndexTransactionVec;
-    const NAME: &'static str = "IndexTransactionVecReader";
+impl<'r> molecule::prelude::Reader<'r> for ReconciliationRequestReader<'r> {
+    type Entity = ReconciliationRequest;
+    const NAME: &'static str = "ReconciliationRequestReader";
     fn to_entity(&self) -> Self::Entity {
         Self::Entity::new_unchecked(self.as_slice().to_owned().into())
     }
     fn new_unchecked(slice: &'r [u8]) -> Self {
-        IndexTransactionVecReader(slice)
+        ReconciliationRequestReader(slice)
     }
     fn as_slice(&self) -> &'r [u8] {
         self.0
@@ -8821,16 +8947,11 @@ impl<'r> molecule::prelude::Reader<'r> for IndexTransactionVecReader<'r> {
         if slice_len != total_size {
             return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
         }
-        if slice_len == molecule::NUMBER_SIZE {
+        if slice_len == molecule::NUMBER_SIZE && Self::FIELD_COUNT == 0 {
             return Ok(());
         }
         if slice_len < molecule::NUMBER_SIZE * 2 {
-            return ve!(
-                Self,
-                TotalSizeNotMatch,
-                molecule::NUMBER_SIZE * 2,
-                slice_len
-            );
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
         }
         let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
         if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
@@ -8839,6 +8960,12 @@ impl<'r> molecule::prelude::Reader<'r> for IndexTransactionVecReader<'r> {
         if slice_len < offset_first {
             return ve!(Self, HeaderIsBroken, offset_first, slice_len);
         }
+        let field_count = offset_first / molecule::NUMBER_SIZE - 1;
+        if field_count < Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        } else if !compatible && field_count > Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        };
         let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
             .chunks_exact(molecule::NUMBER_SIZE)
             .map(|x| molecule::unpack_number(x) as usize)
@@ -8847,141 +8974,60 @@ impl<'r> molecule::prelude::Reader<'r> for IndexTransactionVecReader<'r> {
         if offsets.windows(2).any(|i| i[0] > i[1]) {
             return ve!(Self, OffsetsNotMatch);
         }
-        for pair in offsets.windows(2) {
-            let start = pair[0];
-            let end = pair[1];
-            IndexTransactionReader::verify(&slice[start..end], compatible)?;
-        }
+        Uint64Reader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        Uint32Reader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
         Ok(())
     }
 }
 #[derive(Debug, Default)]
-pub struct IndexTransactionVecBuilder(pub(crate) Vec<IndexTransaction>);
-impl IndexTransactionVecBuilder {
-    pub fn set(mut self, v: Vec<IndexTransaction>) -> Self {
-        self.0 = v;
-        self
-    }
-    pub fn push(mut self, v: IndexTransaction) -> Self {
-        self.0.push(v);
+pub struct ReconciliationRequestBuilder {
+    pub(crate) salt: Uint64,
+    pub(crate) set_size: Uint32,
+}
+impl ReconciliationRequestBuilder {
+    pub const FIELD_COUNT: usize = 2;
+    pub fn salt(mut self, v: Uint64) -> Self {
+        self.salt = v;
         self
     }
-    pub fn extend<T: ::core::iter::IntoIterator<Item = IndexTransaction>>(
-        mut self,
-        iter: T,
-    ) -> Self {
-        for elem in iter {
-            self.0.push(elem);
-        }
+    pub fn set_size(mut self, v: Uint32) -> Self {
+        self.set_size = v;
         self
     }
-    pub fn replace(&mut self, index: usize, v: IndexTransaction) -> Option<IndexTransaction> {
-        self.0
-            .get_mut(index)
-            .map(|item| ::core::mem::replace(item, v))
-    }
 }
-impl molecule::prelude::Builder for IndexTransactionVecBuilder {
-    type Entity = IndexTransactionVec;
-    const NAME: &'static str = "IndexTransactionVecBuilder";
+impl molecule::prelude::Builder for ReconciliationRequestBuilder {
+    type Entity = ReconciliationRequest;
+    const NAME: &'static str = "ReconciliationRequestBuilder";
     fn expected_length(&self) -> usize {
-        molecule::NUMBER_SIZE * (self.0.len() + 1)
-            + self
-                .0
-                .iter()
-                .map(|inner| inner.as_slice().len())
-                .sum::<usize>()
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
+            + self.salt.as_slice().len()
+            + self.set_size.as_slice().len()
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
-        let item_count = self.0.len();
-        if item_count == 0 {
-            writer.write_all(&molecule::pack_number(
-                molecule::NUMBER_SIZE as molecule::Number,
-            ))?;
-        } else {
-            let (total_size, offsets) = self.0.iter().fold(
-                (
-                    molecule::NUMBER_SIZE * (item_count + 1),
-                    Vec::with_capacity(item_count),
-                ),
-                |(start, mut offsets), inner| {
-                    offsets.push(start);
-                    (start + inner.as_slice().len(), offsets)
-                },
-            );
-            writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
-            for offset in offsets.into_iter() {
-                writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
-            }
-            for inner in self.0.iter() {
-                writer.write_all(inner.as_slice())?;
-            }
+        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
+        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
+        offsets.push(total_size);
+        total_size += self.salt.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.set_size.as_slice().len();
+        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+        for offset in offsets.into_iter() {
+            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
         }
+        writer.write_all(self.salt.as_slice())?;
+        writer.write_all(self.set_size.as_slice())?;
         Ok(())
     }
     fn build(&self) -> Self::Entity {
         let mut inner = Vec::with_capacity(self.expected_length());
         self.write(&mut inner)
             .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        IndexTransactionVec::new_unchecked(inner.into())
-    }
-}
-pub struct IndexTransactionVecIterator(IndexTransactionVec, usize, usize);
-impl ::core::iter::Iterator for IndexTransactionVecIterator {
-    type Item = IndexTransaction;
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.1 >= self.2 {
-            None
-        } else {
-            let ret = self.0.get_unchecked(self.1);
-            self.1 += 1;
-            Some(ret)
-        }
-    }
-}
-impl ::core::iter::ExactSizeIterator for IndexTransactionVecIterator {
-    fn len(&self) -> usize {
-        self.2 - self.1
-    }
-}
-impl ::core::iter::IntoIterator for IndexTransactionVec {
-    type Item = IndexTransaction;
-    type IntoIter = IndexTransactionVecIterator;
-    fn into_iter(self) -> Self::IntoIter {
-        let len = self.len();
-        IndexTransactionVecIterator(self, 0, len)
-    }
-}
-impl<'r> IndexTransactionVecReader<'r> {
-    pub fn iter<'t>(&'t self) -> IndexTransactionVecReaderIterator<'t, 'r> {
-        IndexTransactionVecReaderIterator(&self, 0, self.len())
-    }
-}
-pub struct IndexTransactionVecReaderIterator<'t, 'r>(
-    &'t IndexTransactionVecReader<'r>,
-    usize,
-    usize,
-);
-impl<'t: 'r, 'r> ::core::iter::Iterator for IndexTransactionVecReaderIterator<'t, 'r> {
-    type Item = IndexTransactionReader<'t>;
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.1 >= self.2 {
-            None
-        } else {
-            let ret = self.0.get_unchecked(self.1);
-            self.1 += 1;
-            Some(ret)
-        }
-    }
-}
-impl<'t: 'r, 'r> ::core::iter::ExactSizeIterator for IndexTransactionVecReaderIterator<'t, 'r> {
-    fn len(&self) -> usize {
-        self.2 - self.1
+        ReconciliationRequest::new_unchecked(inner.into())
     }
 }
 #[derive(Clone)]
-pub struct SyncMessage(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for SyncMessage {
+pub struct ReconciliationSketch(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for ReconciliationSketch {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -8990,56 +9036,65 @@ impl ::core::fmt::LowerHex for SyncMessage {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Debug for SyncMessage {
+impl ::core::fmt::Debug for ReconciliationSketch {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::fmt::Display for SyncMessage {
+impl ::core::fmt::Display for ReconciliationSketch {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{}(", Self::NAME)?;
-        self.to_enum().display_inner(f)?;
-        write!(f, ")")
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "sketch", self.sketch())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
     }
 }
-impl ::core::default::Default for SyncMessage {
+impl ::core::default::Default for ReconciliationSketch {
     fn default() -> Self {
-        let v: Vec<u8> = vec![
-            0, 0, 0, 0, 48, 0, 0, 0, 12, 0, 0, 0, 44, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        ];
-        SyncMessage::new_unchecked(v.into())
+        let v: Vec<u8> = vec![12, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0];
+        ReconciliationSketch::new_unchecked(v.into())
     }
 }
-impl SyncMessage {
-    pub const ITEMS_COUNT: usize = 9;
-    pub fn item_id(&self) -> molecule::Number {
-        molecule::unpack_number(self.as_slice())
+impl ReconciliationSketch {
+    pub const FIELD_COUNT: usize = 1;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
     }
-    pub fn to_enum(&self) -> SyncMessageUnion {
-        let inner = self.0.slice(molecule::NUMBER_SIZE..);
-        match self.item_id() {
-            0 => GetHeaders::new_unchecked(inner).into(),
-            1 => SendHeaders::new_unchecked(inner).into(),
-            2 => GetBlocks::new_unchecked(inner).into(),
-            3 => SendBlock::new_unchecked(inner).into(),
-            4 => SetFilter::new_unchecked(inner).into(),
-            5 => AddFilter::new_unchecked(inner).into(),
-            6 => ClearFilter::new_unchecked(inner).into(),
-            7 => FilteredBlock::new_unchecked(inner).into(),
-            8 => InIBD::new_unchecked(inner).into(),
-            _ => panic!("{}: invalid data", Self::NAME),
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
         }
     }
-    pub fn as_reader<'r>(&'r self) -> SyncMessageReader<'r> {
-        SyncMessageReader::new_unchecked(self.as_slice())
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn sketch(&self) -> Bytes {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[8..]) as usize;
+            Bytes::new_unchecked(self.0.slice(start..end))
+        } else {
+            Bytes::new_unchecked(self.0.slice(start..))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> ReconciliationSketchReader<'r> {
+        ReconciliationSketchReader::new_unchecked(self.as_slice())
     }
 }
-impl molecule::prelude::Entity for SyncMessage {
-    type Builder = SyncMessageBuilder;
-    const NAME: &'static str = "SyncMessage";
+impl molecule::prelude::Entity for ReconciliationSketch {
+    type Builder = ReconciliationSketchBuilder;
+    const NAME: &'static str = "ReconciliationSketch";
     fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        SyncMessage(data)
+        ReconciliationSketch(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -9048,21 +9103,21 @@ impl molecule::prelude::Entity for SyncMessage {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        SyncMessageReader::from_slice(slice).map(|reader| reader.to_entity())
+        ReconciliationSketchReader::from_slice(slice).map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        SyncMessageReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+        ReconciliationSketchReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
         ::core::default::Default::default()
     }
     fn as_builder(self) -> Self::Builder {
-        Self::new_builder().set(self.to_enum())
+        Self::new_builder().sketch(self.sketch())
     }
 }
 #[derive(Clone, Copy)]
-pub struct SyncMessageReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for SyncMessageReader<'r> {
+pub struct ReconciliationSketchReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for ReconciliationSketchReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -9071,47 +9126,59 @@ impl<'r> ::core::fmt::LowerHex for SyncMessageReader<'r> {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl<'r> ::core::fmt::Debug for SyncMessageReader<'r> {
+impl<'r> ::core::fmt::Debug for ReconciliationSketchReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::core::fmt::Display for SyncMessageReader<'r> {
+impl<'r> ::core::fmt::Display for ReconciliationSketchReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{}(", Self::NAME)?;
-        self.to_enum().display_inner(f)?;
-        write!(f, ")")
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "sketch", self.sketch())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
     }
 }
-impl<'r> SyncMessageReader<'r> {
-    pub const ITEMS_COUNT: usize = 9;
-    pub fn item_id(&self) -> molecule::Number {
-        molecule::unpack_number(self.as_slice())
+impl<'r> ReconciliationSketchReader<'r> {
+    pub const FIELD_COUNT: usize = 1;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
     }
-    pub fn to_enum(&self) -> SyncMessageUnionReader<'r> {
-        let inner = &self.as_slice()[molecule::NUMBER_SIZE..];
-        match self.item_id() {
-            0 => GetHeadersReader::new_unchecked(inner).into(),
-            1 => SendHeadersReader::new_unchecked(inner).into(),
-            2 => GetBlocksReader::new_unchecked(inner).into(),
-            3 => SendBlockReader::new_unchecked(inner).into(),
-            4 => SetFilterReader::new_unchecked(inner).into(),
-            5 => AddFilterReader::new_unchecked(inner).into(),
-            6 => ClearFilterReader::new_unchecked(inner).into(),
-            7 => FilteredBlockReader::new_unchecked(inner).into(),
-            8 => InIBDReader::new_unchecked(inner).into(),
-            _ => panic!("{}: invalid data", Self::NAME),
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn sketch(&self) -> BytesReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[8..]) as usize;
+            BytesReader::new_unchecked(&self.as_slice()[start..end])
+        } else {
+            BytesReader::new_unchecked(&self.as_slice()[start..])
         }
     }
 }
-impl<'r> molecule::prelude::Reader<'r> for SyncMessageReader<'r> {
-    type Entity = SyncMessage;
-    const NAME: &'static str = "SyncMessageReader";
+impl<'r> molecule::prelude::Reader<'r> for ReconciliationSketchReader<'r> {
+    type Entity = ReconciliationSketch;
+    const NAME: &'static str = "ReconciliationSketchReader";
     fn to_entity(&self) -> Self::Entity {
         Self::Entity::new_unchecked(self.as_slice().to_owned().into())
     }
     fn new_unchecked(slice: &'r [u8]) -> Self {
-        SyncMessageReader(slice)
+        ReconciliationSketchReader(slice)
     }
     fn as_slice(&self) -> &'r [u8] {
         self.0
@@ -9122,380 +9189,4898 @@ impl<'r> molecule::prelude::Reader<'r> for SyncMessageReader<'r> {
         if slice_len < molecule::NUMBER_SIZE {
             return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
         }
-        let item_id = molecule::unpack_number(slice);
-        let inner_slice = &slice[molecule::NUMBER_SIZE..];
-        match item_id {
-            0 => GetHeadersReader::verify(inner_slice, compatible),
-            1 => SendHeadersReader::verify(inner_slice, compatible),
-            2 => GetBlocksReader::verify(inner_slice, compatible),
-            3 => SendBlockReader::verify(inner_slice, compatible),
-            4 => SetFilterReader::verify(inner_slice, compatible),
-            5 => AddFilterReader::verify(inner_slice, compatible),
-            6 => ClearFilterReader::verify(inner_slice, compatible),
-            7 => FilteredBlockReader::verify(inner_slice, compatible),
-            8 => InIBDReader::verify(inner_slice, compatible),
-            _ => ve!(Self, UnknownItem, Self::ITEMS_COUNT, item_id),
-        }?;
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len == molecule::NUMBER_SIZE && Self::FIELD_COUNT == 0 {
+            return Ok(());
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        if slice_len < offset_first {
+            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
+        }
+        let field_count = offset_first / molecule::NUMBER_SIZE - 1;
+        if field_count < Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        } else if !compatible && field_count > Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        };
+        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
+            .chunks_exact(molecule::NUMBER_SIZE)
+            .map(|x| molecule::unpack_number(x) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        BytesReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
         Ok(())
     }
 }
 #[derive(Debug, Default)]
-pub struct SyncMessageBuilder(pub(crate) SyncMessageUnion);
-impl SyncMessageBuilder {
-    pub const ITEMS_COUNT: usize = 9;
-    pub fn set<I>(mut self, v: I) -> Self
-    where
-        I: ::core::convert::Into<SyncMessageUnion>,
-    {
-        self.0 = v.into();
+pub struct ReconciliationSketchBuilder {
+    pub(crate) sketch: Bytes,
+}
+impl ReconciliationSketchBuilder {
+    pub const FIELD_COUNT: usize = 1;
+    pub fn sketch(mut self, v: Bytes) -> Self {
+        self.sketch = v;
         self
     }
 }
-impl molecule::prelude::Builder for SyncMessageBuilder {
-    type Entity = SyncMessage;
-    const NAME: &'static str = "SyncMessageBuilder";
+impl molecule::prelude::Builder for ReconciliationSketchBuilder {
+    type Entity = ReconciliationSketch;
+    const NAME: &'static str = "ReconciliationSketchBuilder";
     fn expected_length(&self) -> usize {
-        molecule::NUMBER_SIZE + self.0.as_slice().len()
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1) + self.sketch.as_slice().len()
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
-        writer.write_all(&molecule::pack_number(self.0.item_id()))?;
-        writer.write_all(self.0.as_slice())
+        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
+        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
+        offsets.push(total_size);
+        total_size += self.sketch.as_slice().len();
+        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+        for offset in offsets.into_iter() {
+            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+        }
+        writer.write_all(self.sketch.as_slice())?;
+        Ok(())
     }
     fn build(&self) -> Self::Entity {
         let mut inner = Vec::with_capacity(self.expected_length());
         self.write(&mut inner)
             .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        SyncMessage::new_unchecked(inner.into())
+        ReconciliationSketch::new_unchecked(inner.into())
     }
 }
-#[derive(Debug, Clone)]
-pub enum SyncMessageUnion {
-    GetHeaders(GetHeaders),
-    SendHeaders(SendHeaders),
-    GetBlocks(GetBlocks),
-    SendBlock(SendBlock),
-    SetFilter(SetFilter),
-    AddFilter(AddFilter),
-    ClearFilter(ClearFilter),
-    FilteredBlock(FilteredBlock),
-    InIBD(InIBD),
-}
-#[derive(Debug, Clone, Copy)]
-pub enum SyncMessageUnionReader<'r> {
-    GetHeaders(GetHeadersReader<'r>),
-    SendHeaders(SendHeadersReader<'r>),
-    GetBlocks(GetBlocksReader<'r>),
-    SendBlock(SendBlockReader<'r>),
-    SetFilter(SetFilterReader<'r>),
-    AddFilter(AddFilterReader<'r>),
-    ClearFilter(ClearFilterReader<'r>),
-    FilteredBlock(FilteredBlockReader<'r>),
-    InIBD(InIBDReader<'r>),
-}
-impl ::core::default::Default for SyncMessageUnion {
-    fn default() -> Self {
-        SyncMessageUnion::GetHeaders(::core::default::Default::default())
+#[derive(Clone)]
+pub struct ReconciliationDifference(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for ReconciliationDifference {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Display for SyncMessageUnion {
+impl ::core::fmt::Debug for ReconciliationDifference {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        match self {
-            SyncMessageUnion::GetHeaders(ref item) => {
-                write!(f, "{}::{}({})", Self::NAME, GetHeaders::NAME, item)
-            }
-            SyncMessageUnion::SendHeaders(ref item) => {
-                write!(f, "{}::{}({})", Self::NAME, SendHeaders::NAME, item)
-            }
-            SyncMessageUnion::GetBlocks(ref item) => {
-                write!(f, "{}::{}({})", Self::NAME, GetBlocks::NAME, item)
-            }
-            SyncMessageUnion::SendBlock(ref item) => {
-                write!(f, "{}::{}({})", Self::NAME, SendBlock::NAME, item)
-            }
-            SyncMessageUnion::SetFilter(ref item) => {
-                write!(f, "{}::{}({})", Self::NAME, SetFilter::NAME, item)
-            }
-            SyncMessageUnion::AddFilter(ref item) => {
-                write!(f, "{}::{}({})", Self::NAME, AddFilter::NAME, item)
-            }
-            SyncMessageUnion::ClearFilter(ref item) => {
-                write!(f, "{}::{}({})", Self::NAME, ClearFilter::NAME, item)
-            }
-            SyncMessageUnion::FilteredBlock(ref item) => {
-                write!(f, "{}::{}({})", Self::NAME, FilteredBlock::NAME, item)
-            }
-            SyncMessageUnion::InIBD(ref item) => {
-                write!(f, "{}::{}({})", Self::NAME, InIBD::NAME, item)
-            }
-        }
+        write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::core::fmt::Display for SyncMessageUnionReader<'r> {
+impl ::core::fmt::Display for ReconciliationDifference {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        match self {
-            SyncMessageUnionReader::GetHeaders(ref item) => {
-                write!(f, "{}::{}({})", Self::NAME, GetHeaders::NAME, item)
-            }
-            SyncMessageUnionReader::SendHeaders(ref item) => {
-                write!(f, "{}::{}({})", Self::NAME, SendHeaders::NAME, item)
-            }
-            SyncMessageUnionReader::GetBlocks(ref item) => {
-                write!(f, "{}::{}({})", Self::NAME, GetBlocks::NAME, item)
-            }
-            SyncMessageUnionReader::SendBlock(ref item) => {
-                write!(f, "{}::{}({})", Self::NAME, SendBlock::NAME, item)
-            }
-            SyncMessageUnionReader::SetFilter(ref item) => {
-                write!(f, "{}::{}({})", Self::NAME, SetFilter::NAME, item)
-            }
-            SyncMessageUnionReader::AddFilter(ref item) => {
-                write!(f, "{}::{}({})", Self::NAME, AddFilter::NAME, item)
-            }
-            SyncMessageUnionReader::ClearFilter(ref item) => {
-                write!(f, "{}::{}({})", Self::NAME, ClearFilter::NAME, item)
-            }
-            SyncMessageUnionReader::FilteredBlock(ref item) => {
-                write!(f, "{}::{}({})", Self::NAME, FilteredBlock::NAME, item)
-            }
-            SyncMessageUnionReader::InIBD(ref item) => {
-                write!(f, "{}::{}({})", Self::NAME, InIBD::NAME, item)
-            }
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "success", self.success())?;
+        write!(f, ", {}: {}", "short_ids", self.short_ids())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
         }
+        write!(f, " }}")
     }
 }
-impl SyncMessageUnion {
-    pub(crate) fn display_inner(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        match self {
-            SyncMessageUnion::GetHeaders(ref item) => write!(f, "{}", item),
-            SyncMessageUnion::SendHeaders(ref item) => write!(f, "{}", item),
-            SyncMessageUnion::GetBlocks(ref item) => write!(f, "{}", item),
-            SyncMessageUnion::SendBlock(ref item) => write!(f, "{}", item),
-            SyncMessageUnion::SetFilter(ref item) => write!(f, "{}", item),
-            SyncMessageUnion::AddFilter(ref item) => write!(f, "{}", item),
-            SyncMessageUnion::ClearFilter(ref item) => write!(f, "{}", item),
-            SyncMessageUnion::FilteredBlock(ref item) => write!(f, "{}", item),
-            SyncMessageUnion::InIBD(ref item) => write!(f, "{}", item),
-        }
+impl ::core::default::Default for ReconciliationDifference {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![17, 0, 0, 0, 12, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0];
+        ReconciliationDifference::new_unchecked(v.into())
     }
 }
-impl<'r> SyncMessageUnionReader<'r> {
-    pub(crate) fn display_inner(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        match self {
-            SyncMessageUnionReader::GetHeaders(ref item) => write!(f, "{}", item),
-            SyncMessageUnionReader::SendHeaders(ref item) => write!(f, "{}", item),
-            SyncMessageUnionReader::GetBlocks(ref item) => write!(f, "{}", item),
-            SyncMessageUnionReader::SendBlock(ref item) => write!(f, "{}", item),
-            SyncMessageUnionReader::SetFilter(ref item) => write!(f, "{}", item),
-            SyncMessageUnionReader::AddFilter(ref item) => write!(f, "{}", item),
-            SyncMessageUnionReader::ClearFilter(ref item) => write!(f, "{}", item),
-            SyncMessageUnionReader::FilteredBlock(ref item) => write!(f, "{}", item),
-            SyncMessageUnionReader::InIBD(ref item) => write!(f, "{}", item),
+impl ReconciliationDifference {
+    pub const FIELD_COUNT: usize = 2;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
         }
     }
-}
-impl ::core::convert::From<GetHeaders> for SyncMessageUnion {
-    fn from(item: GetHeaders) -> Self {
-        SyncMessageUnion::GetHeaders(item)
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
     }
-}
-impl ::core::convert::From<SendHeaders> for SyncMessageUnion {
-    fn from(item: SendHeaders) -> Self {
-        SyncMessageUnion::SendHeaders(item)
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
     }
-}
+    pub fn success(&self) -> Bool {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        Bool::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn short_ids(&self) -> Uint32Vec {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[12..]) as usize;
+            Uint32Vec::new_unchecked(self.0.slice(start..end))
+        } else {
+            Uint32Vec::new_unchecked(self.0.slice(start..))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> ReconciliationDifferenceReader<'r> {
+        ReconciliationDifferenceReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for ReconciliationDifference {
+    type Builder = ReconciliationDifferenceBuilder;
+    const NAME: &'static str = "ReconciliationDifference";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        ReconciliationDifference(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        ReconciliationDifferenceReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        ReconciliationDifferenceReader::from_compatible_slice(slice)
+            .map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder()
+            .success(self.success())
+            .short_ids(self.short_ids())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct ReconciliationDifferenceReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for ReconciliationDifferenceReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for ReconciliationDifferenceReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for ReconciliationDifferenceReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "success", self.success())?;
+        write!(f, ", {}: {}", "short_ids", self.short_ids())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl<'r> ReconciliationDifferenceReader<'r> {
+    pub const FIELD_COUNT: usize = 2;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn success(&self) -> BoolReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        BoolReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn short_ids(&self) -> Uint32VecReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[12..]) as usize;
+            Uint32VecReader::new_unchecked(&self.as_slice()[start..end])
+        } else {
+            Uint32VecReader::new_unchecked(&self.as_slice()[start..])
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for ReconciliationDifferenceReader<'r> {
+    type Entity = ReconciliationDifference;
+    const NAME: &'static str = "ReconciliationDifferenceReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        ReconciliationDifferenceReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len == molecule::NUMBER_SIZE && Self::FIELD_COUNT == 0 {
+            return Ok(());
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        if slice_len < offset_first {
+            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
+        }
+        let field_count = offset_first / molecule::NUMBER_SIZE - 1;
+        if field_count < Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        } else if !compatible && field_count > Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        };
+        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
+            .chunks_exact(molecule::NUMBER_SIZE)
+            .map(|x| molecule::unpack_number(x) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        BoolReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        Uint32VecReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct ReconciliationDifferenceBuilder {
+    pub(crate) success: Bool,
+    pub(crate) short_ids: Uint32Vec,
+}
+impl ReconciliationDifferenceBuilder {
+    pub const FIELD_COUNT: usize = 2;
+    pub fn success(mut self, v: Bool) -> Self {
+        self.success = v;
+        self
+    }
+    pub fn short_ids(mut self, v: Uint32Vec) -> Self {
+        self.short_ids = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for ReconciliationDifferenceBuilder {
+    type Entity = ReconciliationDifference;
+    const NAME: &'static str = "ReconciliationDifferenceBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
+            + self.success.as_slice().len()
+            + self.short_ids.as_slice().len()
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
+        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
+        offsets.push(total_size);
+        total_size += self.success.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.short_ids.as_slice().len();
+        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+        for offset in offsets.into_iter() {
+            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+        }
+        writer.write_all(self.success.as_slice())?;
+        writer.write_all(self.short_ids.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        ReconciliationDifference::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct IndexTransaction(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for IndexTransaction {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for IndexTransaction {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for IndexTransaction {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "index", self.index())?;
+        write!(f, ", {}: {}", "transaction", self.transaction())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for IndexTransaction {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![
+            84, 0, 0, 0, 12, 0, 0, 0, 16, 0, 0, 0, 0, 0, 0, 0, 68, 0, 0, 0, 12, 0, 0, 0, 64, 0, 0,
+            0, 52, 0, 0, 0, 28, 0, 0, 0, 32, 0, 0, 0, 36, 0, 0, 0, 40, 0, 0, 0, 44, 0, 0, 0, 48, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 4, 0, 0, 0, 4, 0, 0,
+            0,
+        ];
+        IndexTransaction::new_unchecked(v.into())
+    }
+}
+impl IndexTransaction {
+    pub const FIELD_COUNT: usize = 2;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn index(&self) -> Uint32 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        Uint32::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn transaction(&self) -> Transaction {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[12..]) as usize;
+            Transaction::new_unchecked(self.0.slice(start..end))
+        } else {
+            Transaction::new_unchecked(self.0.slice(start..))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> IndexTransactionReader<'r> {
+        IndexTransactionReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for IndexTransaction {
+    type Builder = IndexTransactionBuilder;
+    const NAME: &'static str = "IndexTransaction";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        IndexTransaction(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        IndexTransactionReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        IndexTransactionReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder()
+            .index(self.index())
+            .transaction(self.transaction())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct IndexTransactionReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for IndexTransactionReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for IndexTransactionReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for IndexTransactionReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "index", self.index())?;
+        write!(f, ", {}: {}", "transaction", self.transaction())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl<'r> IndexTransactionReader<'r> {
+    pub const FIELD_COUNT: usize = 2;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn index(&self) -> Uint32Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        Uint32Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn transaction(&self) -> TransactionReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[12..]) as usize;
+            TransactionReader::new_unchecked(&self.as_slice()[start..end])
+        } else {
+            TransactionReader::new_unchecked(&self.as_slice()[start..])
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for IndexTransactionReader<'r> {
+    type Entity = IndexTransaction;
+    const NAME: &'static str = "IndexTransactionReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        IndexTransactionReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len == molecule::NUMBER_SIZE && Self::FIELD_COUNT == 0 {
+            return Ok(());
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        if slice_len < offset_first {
+            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
+        }
+        let field_count = offset_first / molecule::NUMBER_SIZE - 1;
+        if field_count < Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        } else if !compatible && field_count > Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        };
+        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
+            .chunks_exact(molecule::NUMBER_SIZE)
+            .map(|x| molecule::unpack_number(x) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        Uint32Reader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        TransactionReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct IndexTransactionBuilder {
+    pub(crate) index: Uint32,
+    pub(crate) transaction: Transaction,
+}
+impl IndexTransactionBuilder {
+    pub const FIELD_COUNT: usize = 2;
+    pub fn index(mut self, v: Uint32) -> Self {
+        self.index = v;
+        self
+    }
+    pub fn transaction(mut self, v: Transaction) -> Self {
+        self.transaction = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for IndexTransactionBuilder {
+    type Entity = IndexTransaction;
+    const NAME: &'static str = "IndexTransactionBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
+            + self.index.as_slice().len()
+            + self.transaction.as_slice().len()
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
+        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
+        offsets.push(total_size);
+        total_size += self.index.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.transaction.as_slice().len();
+        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+        for offset in offsets.into_iter() {
+            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+        }
+        writer.write_all(self.index.as_slice())?;
+        writer.write_all(self.transaction.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        IndexTransaction::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct IndexTransactionVec(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for IndexTransactionVec {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for IndexTransactionVec {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for IndexTransactionVec {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} [", Self::NAME)?;
+        for i in 0..self.len() {
+            if i == 0 {
+                write!(f, "{}", self.get_unchecked(i))?;
+            } else {
+                write!(f, ", {}", self.get_unchecked(i))?;
+            }
+        }
+        write!(f, "]")
+    }
+}
+impl ::core::default::Default for IndexTransactionVec {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![4, 0, 0, 0];
+        IndexTransactionVec::new_unchecked(v.into())
+    }
+}
+impl IndexTransactionVec {
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn item_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn len(&self) -> usize {
+        self.item_count()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    pub fn get(&self, idx: usize) -> Option<IndexTransaction> {
+        if idx >= self.len() {
+            None
+        } else {
+            Some(self.get_unchecked(idx))
+        }
+    }
+    pub fn get_unchecked(&self, idx: usize) -> IndexTransaction {
+        let slice = self.as_slice();
+        let start_idx = molecule::NUMBER_SIZE * (1 + idx);
+        let start = molecule::unpack_number(&slice[start_idx..]) as usize;
+        if idx == self.len() - 1 {
+            IndexTransaction::new_unchecked(self.0.slice(start..))
+        } else {
+            let end_idx = start_idx + molecule::NUMBER_SIZE;
+            let end = molecule::unpack_number(&slice[end_idx..]) as usize;
+            IndexTransaction::new_unchecked(self.0.slice(start..end))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> IndexTransactionVecReader<'r> {
+        IndexTransactionVecReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for IndexTransactionVec {
+    type Builder = IndexTransactionVecBuilder;
+    const NAME: &'static str = "IndexTransactionVec";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        IndexTransactionVec(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        IndexTransactionVecReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        IndexTransactionVecReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder().extend(self.into_iter())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct IndexTransactionVecReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for IndexTransactionVecReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for IndexTransactionVecReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for IndexTransactionVecReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} [", Self::NAME)?;
+        for i in 0..self.len() {
+            if i == 0 {
+                write!(f, "{}", self.get_unchecked(i))?;
+            } else {
+                write!(f, ", {}", self.get_unchecked(i))?;
+            }
+        }
+        write!(f, "]")
+    }
+}
+impl<'r> IndexTransactionVecReader<'r> {
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn item_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn len(&self) -> usize {
+        self.item_count()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    pub fn get(&self, idx: usize) -> Option<IndexTransactionReader<'r>> {
+        if idx >= self.len() {
+            None
+        } else {
+            Some(self.get_unchecked(idx))
+        }
+    }
+    pub fn get_unchecked(&self, idx: usize) -> IndexTransactionReader<'r> {
+        let slice = self.as_slice();
+        let start_idx = molecule::NUMBER_SIZE * (1 + idx);
+        let start = molecule::unpack_number(&slice[start_idx..]) as usize;
+        if idx == self.len() - 1 {
+            IndexTransactionReader::new_unchecked(&self.as_slice()[start..])
+        } else {
+            let end_idx = start_idx + molecule::NUMBER_SIZE;
+            let end = molecule::unpack_number(&slice[end_idx..]) as usize;
+            IndexTransactionReader::new_unchecked(&self.as_slice()[start..end])
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for IndexTransactionVecReader<'r> {
+    type Entity = IndexTransactionVec;
+    const NAME: &'static str = "IndexTransactionVecReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        IndexTransactionVecReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len == molecule::NUMBER_SIZE {
+            return Ok(());
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(
+                Self,
+                TotalSizeNotMatch,
+                molecule::NUMBER_SIZE * 2,
+                slice_len
+            );
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        if slice_len < offset_first {
+            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
+        }
+        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
+            .chunks_exact(molecule::NUMBER_SIZE)
+            .map(|x| molecule::unpack_number(x) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        for pair in offsets.windows(2) {
+            let start = pair[0];
+            let end = pair[1];
+            IndexTransactionReader::verify(&slice[start..end], compatible)?;
+        }
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct IndexTransactionVecBuilder(pub(crate) Vec<IndexTransaction>);
+impl IndexTransactionVecBuilder {
+    pub fn set(mut self, v: Vec<IndexTransaction>) -> Self {
+        self.0 = v;
+        self
+    }
+    pub fn push(mut self, v: IndexTransaction) -> Self {
+        self.0.push(v);
+        self
+    }
+    pub fn extend<T: ::core::iter::IntoIterator<Item = IndexTransaction>>(
+        mut self,
+        iter: T,
+    ) -> Self {
+        for elem in iter {
+            self.0.push(elem);
+        }
+        self
+    }
+    pub fn replace(&mut self, index: usize, v: IndexTransaction) -> Option<IndexTransaction> {
+        self.0
+            .get_mut(index)
+            .map(|item| ::core::mem::replace(item, v))
+    }
+}
+impl molecule::prelude::Builder for IndexTransactionVecBuilder {
+    type Entity = IndexTransactionVec;
+    const NAME: &'static str = "IndexTransactionVecBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE * (self.0.len() + 1)
+            + self
+                .0
+                .iter()
+                .map(|inner| inner.as_slice().len())
+                .sum::<usize>()
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        let item_count = self.0.len();
+        if item_count == 0 {
+            writer.write_all(&molecule::pack_number(
+                molecule::NUMBER_SIZE as molecule::Number,
+            ))?;
+        } else {
+            let (total_size, offsets) = self.0.iter().fold(
+                (
+                    molecule::NUMBER_SIZE * (item_count + 1),
+                    Vec::with_capacity(item_count),
+                ),
+                |(start, mut offsets), inner| {
+                    offsets.push(start);
+                    (start + inner.as_slice().len(), offsets)
+                },
+            );
+            writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+            for offset in offsets.into_iter() {
+                writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+            }
+            for inner in self.0.iter() {
+                writer.write_all(inner.as_slice())?;
+            }
+        }
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        IndexTransactionVec::new_unchecked(inner.into())
+    }
+}
+pub struct IndexTransactionVecIterator(IndexTransactionVec, usize, usize);
+impl ::core::iter::Iterator for IndexTransactionVecIterator {
+    type Item = IndexTransaction;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.1 >= self.2 {
+            None
+        } else {
+            let ret = self.0.get_unchecked(self.1);
+            self.1 += 1;
+            Some(ret)
+        }
+    }
+}
+impl ::core::iter::ExactSizeIterator for IndexTransactionVecIterator {
+    fn len(&self) -> usize {
+        self.2 - self.1
+    }
+}
+impl ::core::iter::IntoIterator for IndexTransactionVec {
+    type Item = IndexTransaction;
+    type IntoIter = IndexTransactionVecIterator;
+    fn into_iter(self) -> Self::IntoIter {
+        let len = self.len();
+        IndexTransactionVecIterator(self, 0, len)
+    }
+}
+impl<'r> IndexTransactionVecReader<'r> {
+    pub fn iter<'t>(&'t self) -> IndexTransactionVecReaderIterator<'t, 'r> {
+        IndexTransactionVecReaderIterator(&self, 0, self.len())
+    }
+}
+pub struct IndexTransactionVecReaderIterator<'t, 'r>(
+    &'t IndexTransactionVecReader<'r>,
+    usize,
+    usize,
+);
+impl<'t: 'r, 'r> ::core::iter::Iterator for IndexTransactionVecReaderIterator<'t, 'r> {
+    type Item = IndexTransactionReader<'t>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.1 >= self.2 {
+            None
+        } else {
+            let ret = self.0.get_unchecked(self.1);
+            self.1 += 1;
+            Some(ret)
+        }
+    }
+}
+impl<'t: 'r, 'r> ::core::iter::ExactSizeIterator for IndexTransactionVecReaderIterator<'t, 'r> {
+    fn len(&self) -> usize {
+        self.2 - self.1
+    }
+}
+#[derive(Clone)]
+pub struct SyncMessage(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for SyncMessage {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for SyncMessage {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for SyncMessage {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}(", Self::NAME)?;
+        self.to_enum().display_inner(f)?;
+        write!(f, ")")
+    }
+}
+impl ::core::default::Default for SyncMessage {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![
+            0, 0, 0, 0, 48, 0, 0, 0, 12, 0, 0, 0, 44, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ];
+        SyncMessage::new_unchecked(v.into())
+    }
+}
+impl SyncMessage {
+    pub const ITEMS_COUNT: usize = 9;
+    pub fn item_id(&self) -> molecule::Number {
+        molecule::unpack_number(self.as_slice())
+    }
+    pub fn to_enum(&self) -> SyncMessageUnion {
+        let inner = self.0.slice(molecule::NUMBER_SIZE..);
+        match self.item_id() {
+            0 => GetHeaders::new_unchecked(inner).into(),
+            1 => SendHeaders::new_unchecked(inner).into(),
+            2 => GetBlocks::new_unchecked(inner).into(),
+            3 => SendBlock::new_unchecked(inner).into(),
+            4 => SetFilter::new_unchecked(inner).into(),
+            5 => AddFilter::new_unchecked(inner).into(),
+            6 => ClearFilter::new_unchecked(inner).into(),
+            7 => FilteredBlock::new_unchecked(inner).into(),
+            8 => InIBD::new_unchecked(inner).into(),
+            _ => panic!("{}: invalid data", Self::NAME),
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> SyncMessageReader<'r> {
+        SyncMessageReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for SyncMessage {
+    type Builder = SyncMessageBuilder;
+    const NAME: &'static str = "SyncMessage";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        SyncMessage(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        SyncMessageReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        SyncMessageReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder().set(self.to_enum())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct SyncMessageReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for SyncMessageReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for SyncMessageReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for SyncMessageReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}(", Self::NAME)?;
+        self.to_enum().display_inner(f)?;
+        write!(f, ")")
+    }
+}
+impl<'r> SyncMessageReader<'r> {
+    pub const ITEMS_COUNT: usize = 9;
+    pub fn item_id(&self) -> molecule::Number {
+        molecule::unpack_number(self.as_slice())
+    }
+    pub fn to_enum(&self) -> SyncMessageUnionReader<'r> {
+        let inner = &self.as_slice()[molecule::NUMBER_SIZE..];
+        match self.item_id() {
+            0 => GetHeadersReader::new_unchecked(inner).into(),
+            1 => SendHeadersReader::new_unchecked(inner).into(),
+            2 => GetBlocksReader::new_unchecked(inner).into(),
+            3 => SendBlockReader::new_unchecked(inner).into(),
+            4 => SetFilterReader::new_unchecked(inner).into(),
+            5 => AddFilterReader::new_unchecked(inner).into(),
+            6 => ClearFilterReader::new_unchecked(inner).into(),
+            7 => FilteredBlockReader::new_unchecked(inner).into(),
+            8 => InIBDReader::new_unchecked(inner).into(),
+            _ => panic!("{}: invalid data", Self::NAME),
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for SyncMessageReader<'r> {
+    type Entity = SyncMessage;
+    const NAME: &'static str = "SyncMessageReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        SyncMessageReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let item_id = molecule::unpack_number(slice);
+        let inner_slice = &slice[molecule::NUMBER_SIZE..];
+        match item_id {
+            0 => GetHeadersReader::verify(inner_slice, compatible),
+            1 => SendHeadersReader::verify(inner_slice, compatible),
+            2 => GetBlocksReader::verify(inner_slice, compatible),
+            3 => SendBlockReader::verify(inner_slice, compatible),
+            4 => SetFilterReader::verify(inner_slice, compatible),
+            5 => AddFilterReader::verify(inner_slice, compatible),
+            6 => ClearFilterReader::verify(inner_slice, compatible),
+            7 => FilteredBlockReader::verify(inner_slice, compatible),
+            8 => InIBDReader::verify(inner_slice, compatible),
+            _ => ve!(Self, UnknownItem, Self::ITEMS_COUNT, item_id),
+        }?;
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct SyncMessageBuilder(pub(crate) SyncMessageUnion);
+impl SyncMessageBuilder {
+    pub const ITEMS_COUNT: usize = 9;
+    pub fn set<I>(mut self, v: I) -> Self
+    where
+        I: ::core::convert::Into<SyncMessageUnion>,
+    {
+        self.0 = v.into();
+        self
+    }
+}
+impl molecule::prelude::Builder for SyncMessageBuilder {
+    type Entity = SyncMessage;
+    const NAME: &'static str = "SyncMessageBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE + self.0.as_slice().len()
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        writer.write_all(&molecule::pack_number(self.0.item_id()))?;
+        writer.write_all(self.0.as_slice())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        SyncMessage::new_unchecked(inner.into())
+    }
+}
+#[derive(Debug, Clone)]
+pub enum SyncMessageUnion {
+    GetHeaders(GetHeaders),
+    SendHeaders(SendHeaders),
+    GetBlocks(GetBlocks),
+    SendBlock(SendBlock),
+    SetFilter(SetFilter),
+    AddFilter(AddFilter),
+    ClearFilter(ClearFilter),
+    FilteredBlock(FilteredBlock),
+    InIBD(InIBD),
+}
+#[derive(Debug, Clone, Copy)]
+pub enum SyncMessageUnionReader<'r> {
+    GetHeaders(GetHeadersReader<'r>),
+    SendHeaders(SendHeadersReader<'r>),
+    GetBlocks(GetBlocksReader<'r>),
+    SendBlock(SendBlockReader<'r>),
+    SetFilter(SetFilterReader<'r>),
+    AddFilter(AddFilterReader<'r>),
+    ClearFilter(ClearFilterReader<'r>),
+    FilteredBlock(FilteredBlockReader<'r>),
+    InIBD(InIBDReader<'r>),
+}
+impl ::core::default::Default for SyncMessageUnion {
+    fn default() -> Self {
+        SyncMessageUnion::GetHeaders(::core::default::Default::default())
+    }
+}
+impl ::core::fmt::Display for SyncMessageUnion {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        match self {
+            SyncMessageUnion::GetHeaders(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, GetHeaders::NAME, item)
+            }
+            SyncMessageUnion::SendHeaders(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, SendHeaders::NAME, item)
+            }
+            SyncMessageUnion::GetBlocks(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, GetBlocks::NAME, item)
+            }
+            SyncMessageUnion::SendBlock(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, SendBlock::NAME, item)
+            }
+            SyncMessageUnion::SetFilter(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, SetFilter::NAME, item)
+            }
+            SyncMessageUnion::AddFilter(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, AddFilter::NAME, item)
+            }
+            SyncMessageUnion::ClearFilter(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, ClearFilter::NAME, item)
+            }
+            SyncMessageUnion::FilteredBlock(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, FilteredBlock::NAME, item)
+            }
+            SyncMessageUnion::InIBD(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, InIBD::NAME, item)
+            }
+        }
+    }
+}
+impl<'r> ::core::fmt::Display for SyncMessageUnionReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        match self {
+            SyncMessageUnionReader::GetHeaders(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, GetHeaders::NAME, item)
+            }
+            SyncMessageUnionReader::SendHeaders(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, SendHeaders::NAME, item)
+            }
+            SyncMessageUnionReader::GetBlocks(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, GetBlocks::NAME, item)
+            }
+            SyncMessageUnionReader::SendBlock(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, SendBlock::NAME, item)
+            }
+            SyncMessageUnionReader::SetFilter(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, SetFilter::NAME, item)
+            }
+            SyncMessageUnionReader::AddFilter(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, AddFilter::NAME, item)
+            }
+            SyncMessageUnionReader::ClearFilter(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, ClearFilter::NAME, item)
+            }
+            SyncMessageUnionReader::FilteredBlock(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, FilteredBlock::NAME, item)
+            }
+            SyncMessageUnionReader::InIBD(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, InIBD::NAME, item)
+            }
+        }
+    }
+}
+impl SyncMessageUnion {
+    pub(crate) fn display_inner(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        match self {
+            SyncMessageUnion::GetHeaders(ref item) => write!(f, "{}", item),
+            SyncMessageUnion::SendHeaders(ref item) => write!(f, "{}", item),
+            SyncMessageUnion::GetBlocks(ref item) => write!(f, "{}", item),
+            SyncMessageUnion::SendBlock(ref item) => write!(f, "{}", item),
+            SyncMessageUnion::SetFilter(ref item) => write!(f, "{}", item),
+            SyncMessageUnion::AddFilter(ref item) => write!(f, "{}", item),
+            SyncMessageUnion::ClearFilter(ref item) => write!(f, "{}", item),
+            SyncMessageUnion::FilteredBlock(ref item) => write!(f, "{}", item),
+            SyncMessageUnion::InIBD(ref item) => write!(f, "{}", item),
+        }
+    }
+}
+impl<'r> SyncMessageUnionReader<'r> {
+    pub(crate) fn display_inner(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        match self {
+            SyncMessageUnionReader::GetHeaders(ref item) => write!(f, "{}", item),
+            SyncMessageUnionReader::SendHeaders(ref item) => write!(f, "{}", item),
+            SyncMessageUnionReader::GetBlocks(ref item) => write!(f, "{}", item),
+            SyncMessageUnionReader::SendBlock(ref item) => write!(f, "{}", item),
+            SyncMessageUnionReader::SetFilter(ref item) => write!(f, "{}", item),
+            SyncMessageUnionReader::AddFilter(ref item) => write!(f, "{}", item),
+            SyncMessageUnionReader::ClearFilter(ref item) => write!(f, "{}", item),
+            SyncMessageUnionReader::FilteredBlock(ref item) => write!(f, "{}", item),
+            SyncMessageUnionReader::InIBD(ref item) => write!(f, "{}", item),
+        }
+    }
+}
+impl ::core::convert::From<GetHeaders> for SyncMessageUnion {
+    fn from(item: GetHeaders) -> Self {
+        SyncMessageUnion::GetHeaders(item)
+    }
+}
+impl ::core::convert::From<SendHeaders> for SyncMessageUnion {
+    fn from(item: SendHeaders) -> Self {
+        SyncMessageUnion::SendHeaders(item)
+    }
+}
 impl ::core::convert::From<GetBlocks> for SyncMessageUnion {
     fn from(item: GetBlocks) -> Self {
         SyncMessageUnion::GetBlocks(item)
     }
 }
-impl ::core::convert::From<SendBlock> for SyncMessageUnion {
-    fn from(item: SendBlock) -> Self {
-        SyncMessageUnion::SendBlock(item)
+impl ::core::convert::From<SendBlock> for SyncMessageUnion {
+    fn from(item: SendBlock) -> Self {
+        SyncMessageUnion::SendBlock(item)
+    }
+}
+impl ::core::convert::From<SetFilter> for SyncMessageUnion {
+    fn from(item: SetFilter) -> Self {
+        SyncMessageUnion::SetFilter(item)
+    }
+}
+impl ::core::convert::From<AddFilter> for SyncMessageUnion {
+    fn from(item: AddFilter) -> Self {
+        SyncMessageUnion::AddFilter(item)
+    }
+}
+impl ::core::convert::From<ClearFilter> for SyncMessageUnion {
+    fn from(item: ClearFilter) -> Self {
+        SyncMessageUnion::ClearFilter(item)
+    }
+}
+impl ::core::convert::From<FilteredBlock> for SyncMessageUnion {
+    fn from(item: FilteredBlock) -> Self {
+        SyncMessageUnion::FilteredBlock(item)
+    }
+}
+impl ::core::convert::From<InIBD> for SyncMessageUnion {
+    fn from(item: InIBD) -> Self {
+        SyncMessageUnion::InIBD(item)
+    }
+}
+impl<'r> ::core::convert::From<GetHeadersReader<'r>> for SyncMessageUnionReader<'r> {
+    fn from(item: GetHeadersReader<'r>) -> Self {
+        SyncMessageUnionReader::GetHeaders(item)
+    }
+}
+impl<'r> ::core::convert::From<SendHeadersReader<'r>> for SyncMessageUnionReader<'r> {
+    fn from(item: SendHeadersReader<'r>) -> Self {
+        SyncMessageUnionReader::SendHeaders(item)
+    }
+}
+impl<'r> ::core::convert::From<GetBlocksReader<'r>> for SyncMessageUnionReader<'r> {
+    fn from(item: GetBlocksReader<'r>) -> Self {
+        SyncMessageUnionReader::GetBlocks(item)
+    }
+}
+impl<'r> ::core::convert::From<SendBlockReader<'r>> for SyncMessageUnionReader<'r> {
+    fn from(item: SendBlockReader<'r>) -> Self {
+        SyncMessageUnionReader::SendBlock(item)
+    }
+}
+impl<'r> ::core::convert::From<SetFilterReader<'r>> for SyncMessageUnionReader<'r> {
+    fn from(item: SetFilterReader<'r>) -> Self {
+        SyncMessageUnionReader::SetFilter(item)
+    }
+}
+impl<'r> ::core::convert::From<AddFilterReader<'r>> for SyncMessageUnionReader<'r> {
+    fn from(item: AddFilterReader<'r>) -> Self {
+        SyncMessageUnionReader::AddFilter(item)
+    }
+}
+impl<'r> ::core::convert::From<ClearFilterReader<'r>> for SyncMessageUnionReader<'r> {
+    fn from(item: ClearFilterReader<'r>) -> Self {
+        SyncMessageUnionReader::ClearFilter(item)
+    }
+}
+impl<'r> ::core::convert::From<FilteredBlockReader<'r>> for SyncMessageUnionReader<'r> {
+    fn from(item: FilteredBlockReader<'r>) -> Self {
+        SyncMessageUnionReader::FilteredBlock(item)
+    }
+}
+impl<'r> ::core::convert::From<InIBDReader<'r>> for SyncMessageUnionReader<'r> {
+    fn from(item: InIBDReader<'r>) -> Self {
+        SyncMessageUnionReader::InIBD(item)
+    }
+}
+impl SyncMessageUnion {
+    pub const NAME: &'static str = "SyncMessageUnion";
+    pub fn as_bytes(&self) -> molecule::bytes::Bytes {
+        match self {
+            SyncMessageUnion::GetHeaders(item) => item.as_bytes(),
+            SyncMessageUnion::SendHeaders(item) => item.as_bytes(),
+            SyncMessageUnion::GetBlocks(item) => item.as_bytes(),
+            SyncMessageUnion::SendBlock(item) => item.as_bytes(),
+            SyncMessageUnion::SetFilter(item) => item.as_bytes(),
+            SyncMessageUnion::AddFilter(item) => item.as_bytes(),
+            SyncMessageUnion::ClearFilter(item) => item.as_bytes(),
+            SyncMessageUnion::FilteredBlock(item) => item.as_bytes(),
+            SyncMessageUnion::InIBD(item) => item.as_bytes(),
+        }
+    }
+    pub fn as_slice(&self) -> &[u8] {
+        match self {
+            SyncMessageUnion::GetHeaders(item) => item.as_slice(),
+            SyncMessageUnion::SendHeaders(item) => item.as_slice(),
+            SyncMessageUnion::GetBlocks(item) => item.as_slice(),
+            SyncMessageUnion::SendBlock(item) => item.as_slice(),
+            SyncMessageUnion::SetFilter(item) => item.as_slice(),
+            SyncMessageUnion::AddFilter(item) => item.as_slice(),
+            SyncMessageUnion::ClearFilter(item) => item.as_slice(),
+            SyncMessageUnion::FilteredBlock(item) => item.as_slice(),
+            SyncMessageUnion::InIBD(item) => item.as_slice(),
+        }
+    }
+    pub fn item_id(&self) -> molecule::Number {
+        match self {
+            SyncMessageUnion::GetHeaders(_) => 0,
+            SyncMessageUnion::SendHeaders(_) => 1,
+            SyncMessageUnion::GetBlocks(_) => 2,
+            SyncMessageUnion::SendBlock(_) => 3,
+            SyncMessageUnion::SetFilter(_) => 4,
+            SyncMessageUnion::AddFilter(_) => 5,
+            SyncMessageUnion::ClearFilter(_) => 6,
+            SyncMessageUnion::FilteredBlock(_) => 7,
+            SyncMessageUnion::InIBD(_) => 8,
+        }
+    }
+    pub fn item_name(&self) -> &str {
+        match self {
+            SyncMessageUnion::GetHeaders(_) => "GetHeaders",
+            SyncMessageUnion::SendHeaders(_) => "SendHeaders",
+            SyncMessageUnion::GetBlocks(_) => "GetBlocks",
+            SyncMessageUnion::SendBlock(_) => "SendBlock",
+            SyncMessageUnion::SetFilter(_) => "SetFilter",
+            SyncMessageUnion::AddFilter(_) => "AddFilter",
+            SyncMessageUnion::ClearFilter(_) => "ClearFilter",
+            SyncMessageUnion::FilteredBlock(_) => "FilteredBlock",
+            SyncMessageUnion::InIBD(_) => "InIBD",
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> SyncMessageUnionReader<'r> {
+        match self {
+            SyncMessageUnion::GetHeaders(item) => item.as_reader().into(),
+            SyncMessageUnion::SendHeaders(item) => item.as_reader().into(),
+            SyncMessageUnion::GetBlocks(item) => item.as_reader().into(),
+            SyncMessageUnion::SendBlock(item) => item.as_reader().into(),
+            SyncMessageUnion::SetFilter(item) => item.as_reader().into(),
+            SyncMessageUnion::AddFilter(item) => item.as_reader().into(),
+            SyncMessageUnion::ClearFilter(item) => item.as_reader().into(),
+            SyncMessageUnion::FilteredBlock(item) => item.as_reader().into(),
+            SyncMessageUnion::InIBD(item) => item.as_reader().into(),
+        }
+    }
+}
+impl<'r> SyncMessageUnionReader<'r> {
+    pub const NAME: &'r str = "SyncMessageUnionReader";
+    pub fn as_slice(&self) -> &'r [u8] {
+        match self {
+            SyncMessageUnionReader::GetHeaders(item) => item.as_slice(),
+            SyncMessageUnionReader::SendHeaders(item) => item.as_slice(),
+            SyncMessageUnionReader::GetBlocks(item) => item.as_slice(),
+            SyncMessageUnionReader::SendBlock(item) => item.as_slice(),
+            SyncMessageUnionReader::SetFilter(item) => item.as_slice(),
+            SyncMessageUnionReader::AddFilter(item) => item.as_slice(),
+            SyncMessageUnionReader::ClearFilter(item) => item.as_slice(),
+            SyncMessageUnionReader::FilteredBlock(item) => item.as_slice(),
+            SyncMessageUnionReader::InIBD(item) => item.as_slice(),
+        }
+    }
+    pub fn item_id(&self) -> molecule::Number {
+        match self {
+            SyncMessageUnionReader::GetHeaders(_) => 0,
+            SyncMessageUnionReader::SendHeaders(_) => 1,
+            SyncMessageUnionReader::GetBlocks(_) => 2,
+            SyncMessageUnionReader::SendBlock(_) => 3,
+            SyncMessageUnionReader::SetFilter(_) => 4,
+            SyncMessageUnionReader::AddFilter(_) => 5,
+            SyncMessageUnionReader::ClearFilter(_) => 6,
+            SyncMessageUnionReader::FilteredBlock(_) => 7,
+            SyncMessageUnionReader::InIBD(_) => 8,
+        }
+    }
+    pub fn item_name(&self) -> &str {
+        match self {
+            SyncMessageUnionReader::GetHeaders(_) => "GetHeaders",
+            SyncMessageUnionReader::SendHeaders(_) => "SendHeaders",
+            SyncMessageUnionReader::GetBlocks(_) => "GetBlocks",
+            SyncMessageUnionReader::SendBlock(_) => "SendBlock",
+            SyncMessageUnionReader::SetFilter(_) => "SetFilter",
+            SyncMessageUnionReader::AddFilter(_) => "AddFilter",
+            SyncMessageUnionReader::ClearFilter(_) => "ClearFilter",
+            SyncMessageUnionReader::FilteredBlock(_) => "FilteredBlock",
+            SyncMessageUnionReader::InIBD(_) => "InIBD",
+        }
+    }
+}
+#[derive(Clone)]
+pub struct GetHeaders(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for GetHeaders {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for GetHeaders {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for GetHeaders {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "hash_stop", self.hash_stop())?;
+        write!(
+            f,
+            ", {}: {}",
+            "block_locator_hashes",
+            self.block_locator_hashes()
+        )?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for GetHeaders {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![
+            48, 0, 0, 0, 12, 0, 0, 0, 44, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ];
+        GetHeaders::new_unchecked(v.into())
+    }
+}
+impl GetHeaders {
+    pub const FIELD_COUNT: usize = 2;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn hash_stop(&self) -> Byte32 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        Byte32::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn block_locator_hashes(&self) -> Byte32Vec {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[12..]) as usize;
+            Byte32Vec::new_unchecked(self.0.slice(start..end))
+        } else {
+            Byte32Vec::new_unchecked(self.0.slice(start..))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> GetHeadersReader<'r> {
+        GetHeadersReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for GetHeaders {
+    type Builder = GetHeadersBuilder;
+    const NAME: &'static str = "GetHeaders";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        GetHeaders(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        GetHeadersReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        GetHeadersReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder()
+            .hash_stop(self.hash_stop())
+            .block_locator_hashes(self.block_locator_hashes())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct GetHeadersReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for GetHeadersReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for GetHeadersReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for GetHeadersReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "hash_stop", self.hash_stop())?;
+        write!(
+            f,
+            ", {}: {}",
+            "block_locator_hashes",
+            self.block_locator_hashes()
+        )?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl<'r> GetHeadersReader<'r> {
+    pub const FIELD_COUNT: usize = 2;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn hash_stop(&self) -> Byte32Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn block_locator_hashes(&self) -> Byte32VecReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[12..]) as usize;
+            Byte32VecReader::new_unchecked(&self.as_slice()[start..end])
+        } else {
+            Byte32VecReader::new_unchecked(&self.as_slice()[start..])
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for GetHeadersReader<'r> {
+    type Entity = GetHeaders;
+    const NAME: &'static str = "GetHeadersReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        GetHeadersReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len == molecule::NUMBER_SIZE && Self::FIELD_COUNT == 0 {
+            return Ok(());
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        if slice_len < offset_first {
+            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
+        }
+        let field_count = offset_first / molecule::NUMBER_SIZE - 1;
+        if field_count < Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        } else if !compatible && field_count > Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        };
+        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
+            .chunks_exact(molecule::NUMBER_SIZE)
+            .map(|x| molecule::unpack_number(x) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        Byte32Reader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        Byte32VecReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct GetHeadersBuilder {
+    pub(crate) hash_stop: Byte32,
+    pub(crate) block_locator_hashes: Byte32Vec,
+}
+impl GetHeadersBuilder {
+    pub const FIELD_COUNT: usize = 2;
+    pub fn hash_stop(mut self, v: Byte32) -> Self {
+        self.hash_stop = v;
+        self
+    }
+    pub fn block_locator_hashes(mut self, v: Byte32Vec) -> Self {
+        self.block_locator_hashes = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for GetHeadersBuilder {
+    type Entity = GetHeaders;
+    const NAME: &'static str = "GetHeadersBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
+            + self.hash_stop.as_slice().len()
+            + self.block_locator_hashes.as_slice().len()
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
+        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
+        offsets.push(total_size);
+        total_size += self.hash_stop.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.block_locator_hashes.as_slice().len();
+        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+        for offset in offsets.into_iter() {
+            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+        }
+        writer.write_all(self.hash_stop.as_slice())?;
+        writer.write_all(self.block_locator_hashes.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        GetHeaders::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct GetBlocks(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for GetBlocks {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for GetBlocks {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for GetBlocks {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "block_hashes", self.block_hashes())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for GetBlocks {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![12, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0];
+        GetBlocks::new_unchecked(v.into())
+    }
+}
+impl GetBlocks {
+    pub const FIELD_COUNT: usize = 1;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn block_hashes(&self) -> Byte32Vec {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[8..]) as usize;
+            Byte32Vec::new_unchecked(self.0.slice(start..end))
+        } else {
+            Byte32Vec::new_unchecked(self.0.slice(start..))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> GetBlocksReader<'r> {
+        GetBlocksReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for GetBlocks {
+    type Builder = GetBlocksBuilder;
+    const NAME: &'static str = "GetBlocks";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        GetBlocks(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        GetBlocksReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        GetBlocksReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder().block_hashes(self.block_hashes())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct GetBlocksReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for GetBlocksReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for GetBlocksReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for GetBlocksReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "block_hashes", self.block_hashes())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl<'r> GetBlocksReader<'r> {
+    pub const FIELD_COUNT: usize = 1;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn block_hashes(&self) -> Byte32VecReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[8..]) as usize;
+            Byte32VecReader::new_unchecked(&self.as_slice()[start..end])
+        } else {
+            Byte32VecReader::new_unchecked(&self.as_slice()[start..])
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for GetBlocksReader<'r> {
+    type Entity = GetBlocks;
+    const NAME: &'static str = "GetBlocksReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        GetBlocksReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len == molecule::NUMBER_SIZE && Self::FIELD_COUNT == 0 {
+            return Ok(());
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        if slice_len < offset_first {
+            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
+        }
+        let field_count = offset_first / molecule::NUMBER_SIZE - 1;
+        if field_count < Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        } else if !compatible && field_count > Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        };
+        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
+            .chunks_exact(molecule::NUMBER_SIZE)
+            .map(|x| molecule::unpack_number(x) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        Byte32VecReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct GetBlocksBuilder {
+    pub(crate) block_hashes: Byte32Vec,
+}
+impl GetBlocksBuilder {
+    pub const FIELD_COUNT: usize = 1;
+    pub fn block_hashes(mut self, v: Byte32Vec) -> Self {
+        self.block_hashes = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for GetBlocksBuilder {
+    type Entity = GetBlocks;
+    const NAME: &'static str = "GetBlocksBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1) + self.block_hashes.as_slice().len()
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
+        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
+        offsets.push(total_size);
+        total_size += self.block_hashes.as_slice().len();
+        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+        for offset in offsets.into_iter() {
+            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+        }
+        writer.write_all(self.block_hashes.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        GetBlocks::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct SendHeaders(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for SendHeaders {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for SendHeaders {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for SendHeaders {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "headers", self.headers())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for SendHeaders {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![12, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0];
+        SendHeaders::new_unchecked(v.into())
+    }
+}
+impl SendHeaders {
+    pub const FIELD_COUNT: usize = 1;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn headers(&self) -> HeaderVec {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[8..]) as usize;
+            HeaderVec::new_unchecked(self.0.slice(start..end))
+        } else {
+            HeaderVec::new_unchecked(self.0.slice(start..))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> SendHeadersReader<'r> {
+        SendHeadersReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for SendHeaders {
+    type Builder = SendHeadersBuilder;
+    const NAME: &'static str = "SendHeaders";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        SendHeaders(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        SendHeadersReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        SendHeadersReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder().headers(self.headers())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct SendHeadersReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for SendHeadersReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for SendHeadersReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for SendHeadersReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "headers", self.headers())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl<'r> SendHeadersReader<'r> {
+    pub const FIELD_COUNT: usize = 1;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn headers(&self) -> HeaderVecReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[8..]) as usize;
+            HeaderVecReader::new_unchecked(&self.as_slice()[start..end])
+        } else {
+            HeaderVecReader::new_unchecked(&self.as_slice()[start..])
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for SendHeadersReader<'r> {
+    type Entity = SendHeaders;
+    const NAME: &'static str = "SendHeadersReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        SendHeadersReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len == molecule::NUMBER_SIZE && Self::FIELD_COUNT == 0 {
+            return Ok(());
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        if slice_len < offset_first {
+            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
+        }
+        let field_count = offset_first / molecule::NUMBER_SIZE - 1;
+        if field_count < Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        } else if !compatible && field_count > Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        };
+        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
+            .chunks_exact(molecule::NUMBER_SIZE)
+            .map(|x| molecule::unpack_number(x) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        HeaderVecReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct SendHeadersBuilder {
+    pub(crate) headers: HeaderVec,
+}
+impl SendHeadersBuilder {
+    pub const FIELD_COUNT: usize = 1;
+    pub fn headers(mut self, v: HeaderVec) -> Self {
+        self.headers = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for SendHeadersBuilder {
+    type Entity = SendHeaders;
+    const NAME: &'static str = "SendHeadersBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1) + self.headers.as_slice().len()
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
+        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
+        offsets.push(total_size);
+        total_size += self.headers.as_slice().len();
+        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+        for offset in offsets.into_iter() {
+            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+        }
+        writer.write_all(self.headers.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        SendHeaders::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct SendBlock(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for SendBlock {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for SendBlock {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for SendBlock {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "block", self.block())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for SendBlock {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![
+            248, 0, 0, 0, 8, 0, 0, 0, 240, 0, 0, 0, 20, 0, 0, 0, 228, 0, 0, 0, 232, 0, 0, 0, 236,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0,
+        ];
+        SendBlock::new_unchecked(v.into())
+    }
+}
+impl SendBlock {
+    pub const FIELD_COUNT: usize = 1;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn block(&self) -> Block {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[8..]) as usize;
+            Block::new_unchecked(self.0.slice(start..end))
+        } else {
+            Block::new_unchecked(self.0.slice(start..))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> SendBlockReader<'r> {
+        SendBlockReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for SendBlock {
+    type Builder = SendBlockBuilder;
+    const NAME: &'static str = "SendBlock";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        SendBlock(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        SendBlockReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        SendBlockReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder().block(self.block())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct SendBlockReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for SendBlockReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for SendBlockReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for SendBlockReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "block", self.block())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl<'r> SendBlockReader<'r> {
+    pub const FIELD_COUNT: usize = 1;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn block(&self) -> BlockReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[8..]) as usize;
+            BlockReader::new_unchecked(&self.as_slice()[start..end])
+        } else {
+            BlockReader::new_unchecked(&self.as_slice()[start..])
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for SendBlockReader<'r> {
+    type Entity = SendBlock;
+    const NAME: &'static str = "SendBlockReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        SendBlockReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len == molecule::NUMBER_SIZE && Self::FIELD_COUNT == 0 {
+            return Ok(());
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        if slice_len < offset_first {
+            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
+        }
+        let field_count = offset_first / molecule::NUMBER_SIZE - 1;
+        if field_count < Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        } else if !compatible && field_count > Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        };
+        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
+            .chunks_exact(molecule::NUMBER_SIZE)
+            .map(|x| molecule::unpack_number(x) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        BlockReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct SendBlockBuilder {
+    pub(crate) block: Block,
+}
+impl SendBlockBuilder {
+    pub const FIELD_COUNT: usize = 1;
+    pub fn block(mut self, v: Block) -> Self {
+        self.block = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for SendBlockBuilder {
+    type Entity = SendBlock;
+    const NAME: &'static str = "SendBlockBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1) + self.block.as_slice().len()
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
+        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
+        offsets.push(total_size);
+        total_size += self.block.as_slice().len();
+        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+        for offset in offsets.into_iter() {
+            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+        }
+        writer.write_all(self.block.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        SendBlock::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct SetFilter(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for SetFilter {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for SetFilter {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for SetFilter {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "hash_seed", self.hash_seed())?;
+        write!(f, ", {}: {}", "filter", self.filter())?;
+        write!(f, ", {}: {}", "num_hashes", self.num_hashes())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for SetFilter {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![
+            25, 0, 0, 0, 16, 0, 0, 0, 20, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ];
+        SetFilter::new_unchecked(v.into())
+    }
+}
+impl SetFilter {
+    pub const FIELD_COUNT: usize = 3;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn hash_seed(&self) -> Uint32 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        Uint32::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn filter(&self) -> Bytes {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        Bytes::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn num_hashes(&self) -> Byte {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[16..]) as usize;
+            Byte::new_unchecked(self.0.slice(start..end))
+        } else {
+            Byte::new_unchecked(self.0.slice(start..))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> SetFilterReader<'r> {
+        SetFilterReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for SetFilter {
+    type Builder = SetFilterBuilder;
+    const NAME: &'static str = "SetFilter";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        SetFilter(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        SetFilterReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        SetFilterReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder()
+            .hash_seed(self.hash_seed())
+            .filter(self.filter())
+            .num_hashes(self.num_hashes())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct SetFilterReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for SetFilterReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for SetFilterReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for SetFilterReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "hash_seed", self.hash_seed())?;
+        write!(f, ", {}: {}", "filter", self.filter())?;
+        write!(f, ", {}: {}", "num_hashes", self.num_hashes())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl<'r> SetFilterReader<'r> {
+    pub const FIELD_COUNT: usize = 3;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn hash_seed(&self) -> Uint32Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        Uint32Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn filter(&self) -> BytesReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        BytesReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn num_hashes(&self) -> ByteReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[16..]) as usize;
+            ByteReader::new_unchecked(&self.as_slice()[start..end])
+        } else {
+            ByteReader::new_unchecked(&self.as_slice()[start..])
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for SetFilterReader<'r> {
+    type Entity = SetFilter;
+    const NAME: &'static str = "SetFilterReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        SetFilterReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len == molecule::NUMBER_SIZE && Self::FIELD_COUNT == 0 {
+            return Ok(());
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        if slice_len < offset_first {
+            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
+        }
+        let field_count = offset_first / molecule::NUMBER_SIZE - 1;
+        if field_count < Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        } else if !compatible && field_count > Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        };
+        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
+            .chunks_exact(molecule::NUMBER_SIZE)
+            .map(|x| molecule::unpack_number(x) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        Uint32Reader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        BytesReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        ByteReader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct SetFilterBuilder {
+    pub(crate) hash_seed: Uint32,
+    pub(crate) filter: Bytes,
+    pub(crate) num_hashes: Byte,
+}
+impl SetFilterBuilder {
+    pub const FIELD_COUNT: usize = 3;
+    pub fn hash_seed(mut self, v: Uint32) -> Self {
+        self.hash_seed = v;
+        self
+    }
+    pub fn filter(mut self, v: Bytes) -> Self {
+        self.filter = v;
+        self
+    }
+    pub fn num_hashes(mut self, v: Byte) -> Self {
+        self.num_hashes = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for SetFilterBuilder {
+    type Entity = SetFilter;
+    const NAME: &'static str = "SetFilterBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
+            + self.hash_seed.as_slice().len()
+            + self.filter.as_slice().len()
+            + self.num_hashes.as_slice().len()
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
+        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
+        offsets.push(total_size);
+        total_size += self.hash_seed.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.filter.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.num_hashes.as_slice().len();
+        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+        for offset in offsets.into_iter() {
+            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+        }
+        writer.write_all(self.hash_seed.as_slice())?;
+        writer.write_all(self.filter.as_slice())?;
+        writer.write_all(self.num_hashes.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        SetFilter::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct AddFilter(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for AddFilter {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for AddFilter {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for AddFilter {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "filter", self.filter())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for AddFilter {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![12, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0];
+        AddFilter::new_unchecked(v.into())
+    }
+}
+impl AddFilter {
+    pub const FIELD_COUNT: usize = 1;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn filter(&self) -> Bytes {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[8..]) as usize;
+            Bytes::new_unchecked(self.0.slice(start..end))
+        } else {
+            Bytes::new_unchecked(self.0.slice(start..))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> AddFilterReader<'r> {
+        AddFilterReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for AddFilter {
+    type Builder = AddFilterBuilder;
+    const NAME: &'static str = "AddFilter";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        AddFilter(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        AddFilterReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        AddFilterReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder().filter(self.filter())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct AddFilterReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for AddFilterReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for AddFilterReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for AddFilterReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "filter", self.filter())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl<'r> AddFilterReader<'r> {
+    pub const FIELD_COUNT: usize = 1;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn filter(&self) -> BytesReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[8..]) as usize;
+            BytesReader::new_unchecked(&self.as_slice()[start..end])
+        } else {
+            BytesReader::new_unchecked(&self.as_slice()[start..])
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for AddFilterReader<'r> {
+    type Entity = AddFilter;
+    const NAME: &'static str = "AddFilterReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        AddFilterReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len == molecule::NUMBER_SIZE && Self::FIELD_COUNT == 0 {
+            return Ok(());
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        if slice_len < offset_first {
+            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
+        }
+        let field_count = offset_first / molecule::NUMBER_SIZE - 1;
+        if field_count < Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        } else if !compatible && field_count > Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        };
+        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
+            .chunks_exact(molecule::NUMBER_SIZE)
+            .map(|x| molecule::unpack_number(x) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        BytesReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct AddFilterBuilder {
+    pub(crate) filter: Bytes,
+}
+impl AddFilterBuilder {
+    pub const FIELD_COUNT: usize = 1;
+    pub fn filter(mut self, v: Bytes) -> Self {
+        self.filter = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for AddFilterBuilder {
+    type Entity = AddFilter;
+    const NAME: &'static str = "AddFilterBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1) + self.filter.as_slice().len()
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
+        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
+        offsets.push(total_size);
+        total_size += self.filter.as_slice().len();
+        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+        for offset in offsets.into_iter() {
+            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+        }
+        writer.write_all(self.filter.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        AddFilter::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct ClearFilter(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for ClearFilter {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for ClearFilter {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for ClearFilter {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ".. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for ClearFilter {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![4, 0, 0, 0];
+        ClearFilter::new_unchecked(v.into())
+    }
+}
+impl ClearFilter {
+    pub const FIELD_COUNT: usize = 0;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn as_reader<'r>(&'r self) -> ClearFilterReader<'r> {
+        ClearFilterReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for ClearFilter {
+    type Builder = ClearFilterBuilder;
+    const NAME: &'static str = "ClearFilter";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        ClearFilter(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        ClearFilterReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        ClearFilterReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder()
+    }
+}
+#[derive(Clone, Copy)]
+pub struct ClearFilterReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for ClearFilterReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for ClearFilterReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for ClearFilterReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ".. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl<'r> ClearFilterReader<'r> {
+    pub const FIELD_COUNT: usize = 0;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for ClearFilterReader<'r> {
+    type Entity = ClearFilter;
+    const NAME: &'static str = "ClearFilterReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        ClearFilterReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len > molecule::NUMBER_SIZE && !compatible {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, !0);
+        }
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct ClearFilterBuilder {}
+impl ClearFilterBuilder {
+    pub const FIELD_COUNT: usize = 0;
+}
+impl molecule::prelude::Builder for ClearFilterBuilder {
+    type Entity = ClearFilter;
+    const NAME: &'static str = "ClearFilterBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        writer.write_all(&molecule::pack_number(
+            molecule::NUMBER_SIZE as molecule::Number,
+        ))?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        ClearFilter::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct FilteredBlock(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for FilteredBlock {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for FilteredBlock {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for FilteredBlock {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "header", self.header())?;
+        write!(f, ", {}: {}", "transactions", self.transactions())?;
+        write!(f, ", {}: {}", "proof", self.proof())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for FilteredBlock {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![
+            248, 0, 0, 0, 16, 0, 0, 0, 224, 0, 0, 0, 228, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 20, 0,
+            0, 0, 12, 0, 0, 0, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ];
+        FilteredBlock::new_unchecked(v.into())
+    }
+}
+impl FilteredBlock {
+    pub const FIELD_COUNT: usize = 3;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn header(&self) -> Header {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        Header::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn transactions(&self) -> TransactionVec {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        TransactionVec::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn proof(&self) -> MerkleProof {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[16..]) as usize;
+            MerkleProof::new_unchecked(self.0.slice(start..end))
+        } else {
+            MerkleProof::new_unchecked(self.0.slice(start..))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> FilteredBlockReader<'r> {
+        FilteredBlockReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for FilteredBlock {
+    type Builder = FilteredBlockBuilder;
+    const NAME: &'static str = "FilteredBlock";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        FilteredBlock(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        FilteredBlockReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        FilteredBlockReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder()
+            .header(self.header())
+            .transactions(self.transactions())
+            .proof(self.proof())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct FilteredBlockReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for FilteredBlockReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for FilteredBlockReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for FilteredBlockReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "header", self.header())?;
+        write!(f, ", {}: {}", "transactions", self.transactions())?;
+        write!(f, ", {}: {}", "proof", self.proof())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl<'r> FilteredBlockReader<'r> {
+    pub const FIELD_COUNT: usize = 3;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn header(&self) -> HeaderReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        HeaderReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn transactions(&self) -> TransactionVecReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        TransactionVecReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn proof(&self) -> MerkleProofReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[16..]) as usize;
+            MerkleProofReader::new_unchecked(&self.as_slice()[start..end])
+        } else {
+            MerkleProofReader::new_unchecked(&self.as_slice()[start..])
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for FilteredBlockReader<'r> {
+    type Entity = FilteredBlock;
+    const NAME: &'static str = "FilteredBlockReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        FilteredBlockReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len == molecule::NUMBER_SIZE && Self::FIELD_COUNT == 0 {
+            return Ok(());
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        if slice_len < offset_first {
+            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
+        }
+        let field_count = offset_first / molecule::NUMBER_SIZE - 1;
+        if field_count < Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        } else if !compatible && field_count > Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        };
+        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
+            .chunks_exact(molecule::NUMBER_SIZE)
+            .map(|x| molecule::unpack_number(x) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        HeaderReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        TransactionVecReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        MerkleProofReader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct FilteredBlockBuilder {
+    pub(crate) header: Header,
+    pub(crate) transactions: TransactionVec,
+    pub(crate) proof: MerkleProof,
+}
+impl FilteredBlockBuilder {
+    pub const FIELD_COUNT: usize = 3;
+    pub fn header(mut self, v: Header) -> Self {
+        self.header = v;
+        self
+    }
+    pub fn transactions(mut self, v: TransactionVec) -> Self {
+        self.transactions = v;
+        self
+    }
+    pub fn proof(mut self, v: MerkleProof) -> Self {
+        self.proof = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for FilteredBlockBuilder {
+    type Entity = FilteredBlock;
+    const NAME: &'static str = "FilteredBlockBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
+            + self.header.as_slice().len()
+            + self.transactions.as_slice().len()
+            + self.proof.as_slice().len()
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
+        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
+        offsets.push(total_size);
+        total_size += self.header.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.transactions.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.proof.as_slice().len();
+        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+        for offset in offsets.into_iter() {
+            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+        }
+        writer.write_all(self.header.as_slice())?;
+        writer.write_all(self.transactions.as_slice())?;
+        writer.write_all(self.proof.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        FilteredBlock::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct MerkleProof(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for MerkleProof {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for MerkleProof {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for MerkleProof {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "indices", self.indices())?;
+        write!(f, ", {}: {}", "lemmas", self.lemmas())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for MerkleProof {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![
+            20, 0, 0, 0, 12, 0, 0, 0, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ];
+        MerkleProof::new_unchecked(v.into())
+    }
+}
+impl MerkleProof {
+    pub const FIELD_COUNT: usize = 2;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn indices(&self) -> Uint32Vec {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        Uint32Vec::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn lemmas(&self) -> Byte32Vec {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[12..]) as usize;
+            Byte32Vec::new_unchecked(self.0.slice(start..end))
+        } else {
+            Byte32Vec::new_unchecked(self.0.slice(start..))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> MerkleProofReader<'r> {
+        MerkleProofReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for MerkleProof {
+    type Builder = MerkleProofBuilder;
+    const NAME: &'static str = "MerkleProof";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        MerkleProof(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        MerkleProofReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        MerkleProofReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder()
+            .indices(self.indices())
+            .lemmas(self.lemmas())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct MerkleProofReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for MerkleProofReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for MerkleProofReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for MerkleProofReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "indices", self.indices())?;
+        write!(f, ", {}: {}", "lemmas", self.lemmas())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl<'r> MerkleProofReader<'r> {
+    pub const FIELD_COUNT: usize = 2;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn indices(&self) -> Uint32VecReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        Uint32VecReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn lemmas(&self) -> Byte32VecReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[12..]) as usize;
+            Byte32VecReader::new_unchecked(&self.as_slice()[start..end])
+        } else {
+            Byte32VecReader::new_unchecked(&self.as_slice()[start..])
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for MerkleProofReader<'r> {
+    type Entity = MerkleProof;
+    const NAME: &'static str = "MerkleProofReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        MerkleProofReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len == molecule::NUMBER_SIZE && Self::FIELD_COUNT == 0 {
+            return Ok(());
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        if slice_len < offset_first {
+            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
+        }
+        let field_count = offset_first / molecule::NUMBER_SIZE - 1;
+        if field_count < Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        } else if !compatible && field_count > Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        };
+        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
+            .chunks_exact(molecule::NUMBER_SIZE)
+            .map(|x| molecule::unpack_number(x) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        Uint32VecReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        Byte32VecReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct MerkleProofBuilder {
+    pub(crate) indices: Uint32Vec,
+    pub(crate) lemmas: Byte32Vec,
+}
+impl MerkleProofBuilder {
+    pub const FIELD_COUNT: usize = 2;
+    pub fn indices(mut self, v: Uint32Vec) -> Self {
+        self.indices = v;
+        self
+    }
+    pub fn lemmas(mut self, v: Byte32Vec) -> Self {
+        self.lemmas = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for MerkleProofBuilder {
+    type Entity = MerkleProof;
+    const NAME: &'static str = "MerkleProofBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
+            + self.indices.as_slice().len()
+            + self.lemmas.as_slice().len()
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
+        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
+        offsets.push(total_size);
+        total_size += self.indices.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.lemmas.as_slice().len();
+        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+        for offset in offsets.into_iter() {
+            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+        }
+        writer.write_all(self.indices.as_slice())?;
+        writer.write_all(self.lemmas.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        MerkleProof::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct InIBD(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for InIBD {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for InIBD {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for InIBD {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ".. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for InIBD {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![4, 0, 0, 0];
+        InIBD::new_unchecked(v.into())
+    }
+}
+impl InIBD {
+    pub const FIELD_COUNT: usize = 0;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn as_reader<'r>(&'r self) -> InIBDReader<'r> {
+        InIBDReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for InIBD {
+    type Builder = InIBDBuilder;
+    const NAME: &'static str = "InIBD";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        InIBD(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        InIBDReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        InIBDReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder()
+    }
+}
+#[derive(Clone, Copy)]
+pub struct InIBDReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for InIBDReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for InIBDReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for InIBDReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ".. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl<'r> InIBDReader<'r> {
+    pub const FIELD_COUNT: usize = 0;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for InIBDReader<'r> {
+    type Entity = InIBD;
+    const NAME: &'static str = "InIBDReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        InIBDReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len > molecule::NUMBER_SIZE && !compatible {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, !0);
+        }
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct InIBDBuilder {}
+impl InIBDBuilder {
+    pub const FIELD_COUNT: usize = 0;
+}
+impl molecule::prelude::Builder for InIBDBuilder {
+    type Entity = InIBD;
+    const NAME: &'static str = "InIBDBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        writer.write_all(&molecule::pack_number(
+            molecule::NUMBER_SIZE as molecule::Number,
+        ))?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        InIBD::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct Time(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for Time {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for Time {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for Time {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "timestamp", self.timestamp())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for Time {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![16, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0];
+        Time::new_unchecked(v.into())
+    }
+}
+impl Time {
+    pub const FIELD_COUNT: usize = 1;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn timestamp(&self) -> Uint64 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[8..]) as usize;
+            Uint64::new_unchecked(self.0.slice(start..end))
+        } else {
+            Uint64::new_unchecked(self.0.slice(start..))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> TimeReader<'r> {
+        TimeReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for Time {
+    type Builder = TimeBuilder;
+    const NAME: &'static str = "Time";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        Time(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        TimeReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        TimeReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder().timestamp(self.timestamp())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct TimeReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for TimeReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for TimeReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for TimeReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "timestamp", self.timestamp())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl<'r> TimeReader<'r> {
+    pub const FIELD_COUNT: usize = 1;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn timestamp(&self) -> Uint64Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[8..]) as usize;
+            Uint64Reader::new_unchecked(&self.as_slice()[start..end])
+        } else {
+            Uint64Reader::new_unchecked(&self.as_slice()[start..])
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for TimeReader<'r> {
+    type Entity = Time;
+    const NAME: &'static str = "TimeReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        TimeReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len == molecule::NUMBER_SIZE && Self::FIELD_COUNT == 0 {
+            return Ok(());
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        if slice_len < offset_first {
+            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
+        }
+        let field_count = offset_first / molecule::NUMBER_SIZE - 1;
+        if field_count < Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        } else if !compatible && field_count > Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        };
+        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
+            .chunks_exact(molecule::NUMBER_SIZE)
+            .map(|x| molecule::unpack_number(x) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        Uint64Reader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct TimeBuilder {
+    pub(crate) timestamp: Uint64,
+}
+impl TimeBuilder {
+    pub const FIELD_COUNT: usize = 1;
+    pub fn timestamp(mut self, v: Uint64) -> Self {
+        self.timestamp = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for TimeBuilder {
+    type Entity = Time;
+    const NAME: &'static str = "TimeBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1) + self.timestamp.as_slice().len()
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
+        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
+        offsets.push(total_size);
+        total_size += self.timestamp.as_slice().len();
+        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+        for offset in offsets.into_iter() {
+            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+        }
+        writer.write_all(self.timestamp.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        Time::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct RawAlert(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for RawAlert {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for RawAlert {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for RawAlert {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "notice_until", self.notice_until())?;
+        write!(f, ", {}: {}", "id", self.id())?;
+        write!(f, ", {}: {}", "cancel", self.cancel())?;
+        write!(f, ", {}: {}", "priority", self.priority())?;
+        write!(f, ", {}: {}", "message", self.message())?;
+        write!(f, ", {}: {}", "min_version", self.min_version())?;
+        write!(f, ", {}: {}", "max_version", self.max_version())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for RawAlert {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![
+            56, 0, 0, 0, 32, 0, 0, 0, 40, 0, 0, 0, 44, 0, 0, 0, 48, 0, 0, 0, 52, 0, 0, 0, 56, 0, 0,
+            0, 56, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ];
+        RawAlert::new_unchecked(v.into())
+    }
+}
+impl RawAlert {
+    pub const FIELD_COUNT: usize = 7;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn notice_until(&self) -> Uint64 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        Uint64::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn id(&self) -> Uint32 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        Uint32::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn cancel(&self) -> Uint32 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        Uint32::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn priority(&self) -> Uint32 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
+        let end = molecule::unpack_number(&slice[20..]) as usize;
+        Uint32::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn message(&self) -> Bytes {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[20..]) as usize;
+        let end = molecule::unpack_number(&slice[24..]) as usize;
+        Bytes::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn min_version(&self) -> BytesOpt {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[24..]) as usize;
+        let end = molecule::unpack_number(&slice[28..]) as usize;
+        BytesOpt::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn max_version(&self) -> BytesOpt {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[28..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[32..]) as usize;
+            BytesOpt::new_unchecked(self.0.slice(start..end))
+        } else {
+            BytesOpt::new_unchecked(self.0.slice(start..))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> RawAlertReader<'r> {
+        RawAlertReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for RawAlert {
+    type Builder = RawAlertBuilder;
+    const NAME: &'static str = "RawAlert";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        RawAlert(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        RawAlertReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        RawAlertReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder()
+            .notice_until(self.notice_until())
+            .id(self.id())
+            .cancel(self.cancel())
+            .priority(self.priority())
+            .message(self.message())
+            .min_version(self.min_version())
+            .max_version(self.max_version())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct RawAlertReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for RawAlertReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for RawAlertReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for RawAlertReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "notice_until", self.notice_until())?;
+        write!(f, ", {}: {}", "id", self.id())?;
+        write!(f, ", {}: {}", "cancel", self.cancel())?;
+        write!(f, ", {}: {}", "priority", self.priority())?;
+        write!(f, ", {}: {}", "message", self.message())?;
+        write!(f, ", {}: {}", "min_version", self.min_version())?;
+        write!(f, ", {}: {}", "max_version", self.max_version())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl<'r> RawAlertReader<'r> {
+    pub const FIELD_COUNT: usize = 7;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn notice_until(&self) -> Uint64Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        Uint64Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn id(&self) -> Uint32Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        Uint32Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn cancel(&self) -> Uint32Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        Uint32Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn priority(&self) -> Uint32Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
+        let end = molecule::unpack_number(&slice[20..]) as usize;
+        Uint32Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn message(&self) -> BytesReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[20..]) as usize;
+        let end = molecule::unpack_number(&slice[24..]) as usize;
+        BytesReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn min_version(&self) -> BytesOptReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[24..]) as usize;
+        let end = molecule::unpack_number(&slice[28..]) as usize;
+        BytesOptReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn max_version(&self) -> BytesOptReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[28..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[32..]) as usize;
+            BytesOptReader::new_unchecked(&self.as_slice()[start..end])
+        } else {
+            BytesOptReader::new_unchecked(&self.as_slice()[start..])
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for RawAlertReader<'r> {
+    type Entity = RawAlert;
+    const NAME: &'static str = "RawAlertReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        RawAlertReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len == molecule::NUMBER_SIZE && Self::FIELD_COUNT == 0 {
+            return Ok(());
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        if slice_len < offset_first {
+            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
+        }
+        let field_count = offset_first / molecule::NUMBER_SIZE - 1;
+        if field_count < Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        } else if !compatible && field_count > Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        };
+        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
+            .chunks_exact(molecule::NUMBER_SIZE)
+            .map(|x| molecule::unpack_number(x) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        Uint64Reader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        Uint32Reader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        Uint32Reader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
+        Uint32Reader::verify(&slice[offsets[3]..offsets[4]], compatible)?;
+        BytesReader::verify(&slice[offsets[4]..offsets[5]], compatible)?;
+        BytesOptReader::verify(&slice[offsets[5]..offsets[6]], compatible)?;
+        BytesOptReader::verify(&slice[offsets[6]..offsets[7]], compatible)?;
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct RawAlertBuilder {
+    pub(crate) notice_until: Uint64,
+    pub(crate) id: Uint32,
+    pub(crate) cancel: Uint32,
+    pub(crate) priority: Uint32,
+    pub(crate) message: Bytes,
+    pub(crate) min_version: BytesOpt,
+    pub(crate) max_version: BytesOpt,
+}
+impl RawAlertBuilder {
+    pub const FIELD_COUNT: usize = 7;
+    pub fn notice_until(mut self, v: Uint64) -> Self {
+        self.notice_until = v;
+        self
+    }
+    pub fn id(mut self, v: Uint32) -> Self {
+        self.id = v;
+        self
+    }
+    pub fn cancel(mut self, v: Uint32) -> Self {
+        self.cancel = v;
+        self
+    }
+    pub fn priority(mut self, v: Uint32) -> Self {
+        self.priority = v;
+        self
+    }
+    pub fn message(mut self, v: Bytes) -> Self {
+        self.message = v;
+        self
+    }
+    pub fn min_version(mut self, v: BytesOpt) -> Self {
+        self.min_version = v;
+        self
+    }
+    pub fn max_version(mut self, v: BytesOpt) -> Self {
+        self.max_version = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for RawAlertBuilder {
+    type Entity = RawAlert;
+    const NAME: &'static str = "RawAlertBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
+            + self.notice_until.as_slice().len()
+            + self.id.as_slice().len()
+            + self.cancel.as_slice().len()
+            + self.priority.as_slice().len()
+            + self.message.as_slice().len()
+            + self.min_version.as_slice().len()
+            + self.max_version.as_slice().len()
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
+        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
+        offsets.push(total_size);
+        total_size += self.notice_until.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.id.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.cancel.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.priority.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.message.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.min_version.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.max_version.as_slice().len();
+        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+        for offset in offsets.into_iter() {
+            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+        }
+        writer.write_all(self.notice_until.as_slice())?;
+        writer.write_all(self.id.as_slice())?;
+        writer.write_all(self.cancel.as_slice())?;
+        writer.write_all(self.priority.as_slice())?;
+        writer.write_all(self.message.as_slice())?;
+        writer.write_all(self.min_version.as_slice())?;
+        writer.write_all(self.max_version.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        RawAlert::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct Alert(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for Alert {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::convert::From<SetFilter> for SyncMessageUnion {
-    fn from(item: SetFilter) -> Self {
-        SyncMessageUnion::SetFilter(item)
+impl ::core::fmt::Debug for Alert {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::convert::From<AddFilter> for SyncMessageUnion {
-    fn from(item: AddFilter) -> Self {
-        SyncMessageUnion::AddFilter(item)
+impl ::core::fmt::Display for Alert {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "raw", self.raw())?;
+        write!(f, ", {}: {}", "signatures", self.signatures())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
     }
 }
-impl ::core::convert::From<ClearFilter> for SyncMessageUnion {
-    fn from(item: ClearFilter) -> Self {
-        SyncMessageUnion::ClearFilter(item)
+impl ::core::default::Default for Alert {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![
+            72, 0, 0, 0, 12, 0, 0, 0, 68, 0, 0, 0, 56, 0, 0, 0, 32, 0, 0, 0, 40, 0, 0, 0, 44, 0, 0,
+            0, 48, 0, 0, 0, 52, 0, 0, 0, 56, 0, 0, 0, 56, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0,
+        ];
+        Alert::new_unchecked(v.into())
     }
 }
-impl ::core::convert::From<FilteredBlock> for SyncMessageUnion {
-    fn from(item: FilteredBlock) -> Self {
-        SyncMessageUnion::FilteredBlock(item)
+impl Alert {
+    pub const FIELD_COUNT: usize = 2;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn raw(&self) -> RawAlert {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        RawAlert::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn signatures(&self) -> BytesVec {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[12..]) as usize;
+            BytesVec::new_unchecked(self.0.slice(start..end))
+        } else {
+            BytesVec::new_unchecked(self.0.slice(start..))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> AlertReader<'r> {
+        AlertReader::new_unchecked(self.as_slice())
     }
 }
-impl ::core::convert::From<InIBD> for SyncMessageUnion {
-    fn from(item: InIBD) -> Self {
-        SyncMessageUnion::InIBD(item)
+impl molecule::prelude::Entity for Alert {
+    type Builder = AlertBuilder;
+    const NAME: &'static str = "Alert";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        Alert(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        AlertReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        AlertReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder()
+            .raw(self.raw())
+            .signatures(self.signatures())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct AlertReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for AlertReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for AlertReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for AlertReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "raw", self.raw())?;
+        write!(f, ", {}: {}", "signatures", self.signatures())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl<'r> AlertReader<'r> {
+    pub const FIELD_COUNT: usize = 2;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn raw(&self) -> RawAlertReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        RawAlertReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn signatures(&self) -> BytesVecReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[12..]) as usize;
+            BytesVecReader::new_unchecked(&self.as_slice()[start..end])
+        } else {
+            BytesVecReader::new_unchecked(&self.as_slice()[start..])
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for AlertReader<'r> {
+    type Entity = Alert;
+    const NAME: &'static str = "AlertReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        AlertReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len == molecule::NUMBER_SIZE && Self::FIELD_COUNT == 0 {
+            return Ok(());
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        if slice_len < offset_first {
+            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
+        }
+        let field_count = offset_first / molecule::NUMBER_SIZE - 1;
+        if field_count < Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        } else if !compatible && field_count > Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        };
+        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
+            .chunks_exact(molecule::NUMBER_SIZE)
+            .map(|x| molecule::unpack_number(x) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        RawAlertReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        BytesVecReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct AlertBuilder {
+    pub(crate) raw: RawAlert,
+    pub(crate) signatures: BytesVec,
+}
+impl AlertBuilder {
+    pub const FIELD_COUNT: usize = 2;
+    pub fn raw(mut self, v: RawAlert) -> Self {
+        self.raw = v;
+        self
     }
-}
-impl<'r> ::core::convert::From<GetHeadersReader<'r>> for SyncMessageUnionReader<'r> {
-    fn from(item: GetHeadersReader<'r>) -> Self {
-        SyncMessageUnionReader::GetHeaders(item)
+    pub fn signatures(mut self, v: BytesVec) -> Self {
+        self.signatures = v;
+        self
     }
 }
-impl<'r> ::core::convert::From<SendHeadersReader<'r>> for SyncMessageUnionReader<'r> {
-    fn from(item: SendHeadersReader<'r>) -> Self {
-        SyncMessageUnionReader::SendHeaders(item)
+impl molecule::prelude::Builder for AlertBuilder {
+    type Entity = Alert;
+    const NAME: &'static str = "AlertBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
+            + self.raw.as_slice().len()
+            + self.signatures.as_slice().len()
     }
-}
-impl<'r> ::core::convert::From<GetBlocksReader<'r>> for SyncMessageUnionReader<'r> {
-    fn from(item: GetBlocksReader<'r>) -> Self {
-        SyncMessageUnionReader::GetBlocks(item)
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
+        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
+        offsets.push(total_size);
+        total_size += self.raw.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.signatures.as_slice().len();
+        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+        for offset in offsets.into_iter() {
+            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+        }
+        writer.write_all(self.raw.as_slice())?;
+        writer.write_all(self.signatures.as_slice())?;
+        Ok(())
     }
-}
-impl<'r> ::core::convert::From<SendBlockReader<'r>> for SyncMessageUnionReader<'r> {
-    fn from(item: SendBlockReader<'r>) -> Self {
-        SyncMessageUnionReader::SendBlock(item)
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        Alert::new_unchecked(inner.into())
     }
 }
-impl<'r> ::core::convert::From<SetFilterReader<'r>> for SyncMessageUnionReader<'r> {
-    fn from(item: SetFilterReader<'r>) -> Self {
-        SyncMessageUnionReader::SetFilter(item)
+#[derive(Clone)]
+pub struct Identify(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for Identify {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl<'r> ::core::convert::From<AddFilterReader<'r>> for SyncMessageUnionReader<'r> {
-    fn from(item: AddFilterReader<'r>) -> Self {
-        SyncMessageUnionReader::AddFilter(item)
+impl ::core::fmt::Debug for Identify {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::core::convert::From<ClearFilterReader<'r>> for SyncMessageUnionReader<'r> {
-    fn from(item: ClearFilterReader<'r>) -> Self {
-        SyncMessageUnionReader::ClearFilter(item)
+impl ::core::fmt::Display for Identify {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "flag", self.flag())?;
+        write!(f, ", {}: {}", "name", self.name())?;
+        write!(f, ", {}: {}", "client_version", self.client_version())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
     }
 }
-impl<'r> ::core::convert::From<FilteredBlockReader<'r>> for SyncMessageUnionReader<'r> {
-    fn from(item: FilteredBlockReader<'r>) -> Self {
-        SyncMessageUnionReader::FilteredBlock(item)
+impl ::core::default::Default for Identify {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![
+            32, 0, 0, 0, 16, 0, 0, 0, 24, 0, 0, 0, 28, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0,
+        ];
+        Identify::new_unchecked(v.into())
     }
 }
-impl<'r> ::core::convert::From<InIBDReader<'r>> for SyncMessageUnionReader<'r> {
-    fn from(item: InIBDReader<'r>) -> Self {
-        SyncMessageUnionReader::InIBD(item)
+impl Identify {
+    pub const FIELD_COUNT: usize = 3;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
     }
-}
-impl SyncMessageUnion {
-    pub const NAME: &'static str = "SyncMessageUnion";
-    pub fn as_bytes(&self) -> molecule::bytes::Bytes {
-        match self {
-            SyncMessageUnion::GetHeaders(item) => item.as_bytes(),
-            SyncMessageUnion::SendHeaders(item) => item.as_bytes(),
-            SyncMessageUnion::GetBlocks(item) => item.as_bytes(),
-            SyncMessageUnion::SendBlock(item) => item.as_bytes(),
-            SyncMessageUnion::SetFilter(item) => item.as_bytes(),
-            SyncMessageUnion::AddFilter(item) => item.as_bytes(),
-            SyncMessageUnion::ClearFilter(item) => item.as_bytes(),
-            SyncMessageUnion::FilteredBlock(item) => item.as_bytes(),
-            SyncMessageUnion::InIBD(item) => item.as_bytes(),
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
         }
     }
-    pub fn as_slice(&self) -> &[u8] {
-        match self {
-            SyncMessageUnion::GetHeaders(item) => item.as_slice(),
-            SyncMessageUnion::SendHeaders(item) => item.as_slice(),
-            SyncMessageUnion::GetBlocks(item) => item.as_slice(),
-            SyncMessageUnion::SendBlock(item) => item.as_slice(),
-            SyncMessageUnion::SetFilter(item) => item.as_slice(),
-            SyncMessageUnion::AddFilter(item) => item.as_slice(),
-            SyncMessageUnion::ClearFilter(item) => item.as_slice(),
-            SyncMessageUnion::FilteredBlock(item) => item.as_slice(),
-            SyncMessageUnion::InIBD(item) => item.as_slice(),
-        }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
     }
-    pub fn item_id(&self) -> molecule::Number {
-        match self {
-            SyncMessageUnion::GetHeaders(_) => 0,
-            SyncMessageUnion::SendHeaders(_) => 1,
-            SyncMessageUnion::GetBlocks(_) => 2,
-            SyncMessageUnion::SendBlock(_) => 3,
-            SyncMessageUnion::SetFilter(_) => 4,
-            SyncMessageUnion::AddFilter(_) => 5,
-            SyncMessageUnion::ClearFilter(_) => 6,
-            SyncMessageUnion::FilteredBlock(_) => 7,
-            SyncMessageUnion::InIBD(_) => 8,
-        }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
     }
-    pub fn item_name(&self) -> &str {
-        match self {
-            SyncMessageUnion::GetHeaders(_) => "GetHeaders",
-            SyncMessageUnion::SendHeaders(_) => "SendHeaders",
-            SyncMessageUnion::GetBlocks(_) => "GetBlocks",
-            SyncMessageUnion::SendBlock(_) => "SendBlock",
-            SyncMessageUnion::SetFilter(_) => "SetFilter",
-            SyncMessageUnion::AddFilter(_) => "AddFilter",
-            SyncMessageUnion::ClearFilter(_) => "ClearFilter",
-            SyncMessageUnion::FilteredBlock(_) => "FilteredBlock",
-            SyncMessageUnion::InIBD(_) => "InIBD",
-        }
+    pub fn flag(&self) -> Uint64 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        Uint64::new_unchecked(self.0.slice(start..end))
     }
-    pub fn as_reader<'r>(&'r self) -> SyncMessageUnionReader<'r> {
-        match self {
-            SyncMessageUnion::GetHeaders(item) => item.as_reader().into(),
-            SyncMessageUnion::SendHeaders(item) => item.as_reader().into(),
-            SyncMessageUnion::GetBlocks(item) => item.as_reader().into(),
-            SyncMessageUnion::SendBlock(item) => item.as_reader().into(),
-            SyncMessageUnion::SetFilter(item) => item.as_reader().into(),
-            SyncMessageUnion::AddFilter(item) => item.as_reader().into(),
-            SyncMessageUnion::ClearFilter(item) => item.as_reader().into(),
-            SyncMessageUnion::FilteredBlock(item) => item.as_reader().into(),
-            SyncMessageUnion::InIBD(item) => item.as_reader().into(),
+    pub fn name(&self) -> Bytes {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        Bytes::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn client_version(&self) -> Bytes {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[16..]) as usize;
+            Bytes::new_unchecked(self.0.slice(start..end))
+        } else {
+            Bytes::new_unchecked(self.0.slice(start..))
         }
     }
+    pub fn as_reader<'r>(&'r self) -> IdentifyReader<'r> {
+        IdentifyReader::new_unchecked(self.as_slice())
+    }
 }
-impl<'r> SyncMessageUnionReader<'r> {
-    pub const NAME: &'r str = "SyncMessageUnionReader";
-    pub fn as_slice(&self) -> &'r [u8] {
-        match self {
-            SyncMessageUnionReader::GetHeaders(item) => item.as_slice(),
-            SyncMessageUnionReader::SendHeaders(item) => item.as_slice(),
-            SyncMessageUnionReader::GetBlocks(item) => item.as_slice(),
-            SyncMessageUnionReader::SendBlock(item) => item.as_slice(),
-            SyncMessageUnionReader::SetFilter(item) => item.as_slice(),
-            SyncMessageUnionReader::AddFilter(item) => item.as_slice(),
-            SyncMessageUnionReader::ClearFilter(item) => item.as_slice(),
-            SyncMessageUnionReader::FilteredBlock(item) => item.as_slice(),
-            SyncMessageUnionReader::InIBD(item) => item.as_slice(),
-        }
+impl molecule::prelude::Entity for Identify {
+    type Builder = IdentifyBuilder;
+    const NAME: &'static str = "Identify";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        Identify(data)
     }
-    pub fn item_id(&self) -> molecule::Number {
-        match self {
-            SyncMessageUnionReader::GetHeaders(_) => 0,
-            SyncMessageUnionReader::SendHeaders(_) => 1,
-            SyncMessageUnionReader::GetBlocks(_) => 2,
-            SyncMessageUnionReader::SendBlock(_) => 3,
-            SyncMessageUnionReader::SetFilter(_) => 4,
-            SyncMessageUnionReader::AddFilter(_) => 5,
-            SyncMessageUnionReader::ClearFilter(_) => 6,
-            SyncMessageUnionReader::FilteredBlock(_) => 7,
-            SyncMessageUnionReader::InIBD(_) => 8,
-        }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
     }
-    pub fn item_name(&self) -> &str {
-        match self {
-            SyncMessageUnionReader::GetHeaders(_) => "GetHeaders",
-            SyncMessageUnionReader::SendHeaders(_) => "SendHeaders",
-            SyncMessageUnionReader::GetBlocks(_) => "GetBlocks",
-            SyncMessageUnionReader::SendBlock(_) => "SendBlock",
-            SyncMessageUnionReader::SetFilter(_) => "SetFilter",
-            SyncMessageUnionReader::AddFilter(_) => "AddFilter",
-            SyncMessageUnionReader::ClearFilter(_) => "ClearFilter",
-            SyncMessageUnionReader::FilteredBlock(_) => "FilteredBlock",
-            SyncMessageUnionReader::InIBD(_) => "InIBD",
-        }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        IdentifyReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        IdentifyReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder()
+            .flag(self.flag())
+            .name(self.name())
+            .client_version(self.client_version())
     }
 }
-#[derive(Clone)]
-pub struct GetHeaders(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for GetHeaders {
+#[derive(Clone, Copy)]
+pub struct IdentifyReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for IdentifyReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -9504,21 +14089,17 @@ impl ::core::fmt::LowerHex for GetHeaders {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Debug for GetHeaders {
+impl<'r> ::core::fmt::Debug for IdentifyReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::fmt::Display for GetHeaders {
+impl<'r> ::core::fmt::Display for IdentifyReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "hash_stop", self.hash_stop())?;
-        write!(
-            f,
-            ", {}: {}",
-            "block_locator_hashes",
-            self.block_locator_hashes()
-        )?;
+        write!(f, "{}: {}", "flag", self.flag())?;
+        write!(f, ", {}: {}", "name", self.name())?;
+        write!(f, ", {}: {}", "client_version", self.client_version())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -9526,17 +14107,8 @@ impl ::core::fmt::Display for GetHeaders {
         write!(f, " }}")
     }
 }
-impl ::core::default::Default for GetHeaders {
-    fn default() -> Self {
-        let v: Vec<u8> = vec![
-            48, 0, 0, 0, 12, 0, 0, 0, 44, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        ];
-        GetHeaders::new_unchecked(v.into())
-    }
-}
-impl GetHeaders {
-    pub const FIELD_COUNT: usize = 2;
+impl<'r> IdentifyReader<'r> {
+    pub const FIELD_COUNT: usize = 3;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -9553,31 +14125,192 @@ impl GetHeaders {
     pub fn has_extra_fields(&self) -> bool {
         Self::FIELD_COUNT != self.field_count()
     }
-    pub fn hash_stop(&self) -> Byte32 {
+    pub fn flag(&self) -> Uint64Reader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[4..]) as usize;
         let end = molecule::unpack_number(&slice[8..]) as usize;
-        Byte32::new_unchecked(self.0.slice(start..end))
+        Uint64Reader::new_unchecked(&self.as_slice()[start..end])
     }
-    pub fn block_locator_hashes(&self) -> Byte32Vec {
+    pub fn name(&self) -> BytesReader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        BytesReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn client_version(&self) -> BytesReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[12..]) as usize;
-            Byte32Vec::new_unchecked(self.0.slice(start..end))
+            let end = molecule::unpack_number(&slice[16..]) as usize;
+            BytesReader::new_unchecked(&self.as_slice()[start..end])
         } else {
-            Byte32Vec::new_unchecked(self.0.slice(start..))
+            BytesReader::new_unchecked(&self.as_slice()[start..])
         }
     }
-    pub fn as_reader<'r>(&'r self) -> GetHeadersReader<'r> {
-        GetHeadersReader::new_unchecked(self.as_slice())
+}
+impl<'r> molecule::prelude::Reader<'r> for IdentifyReader<'r> {
+    type Entity = Identify;
+    const NAME: &'static str = "IdentifyReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        IdentifyReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len == molecule::NUMBER_SIZE && Self::FIELD_COUNT == 0 {
+            return Ok(());
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        if slice_len < offset_first {
+            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
+        }
+        let field_count = offset_first / molecule::NUMBER_SIZE - 1;
+        if field_count < Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        } else if !compatible && field_count > Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        };
+        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
+            .chunks_exact(molecule::NUMBER_SIZE)
+            .map(|x| molecule::unpack_number(x) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        Uint64Reader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        BytesReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        BytesReader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
+        Ok(())
     }
 }
-impl molecule::prelude::Entity for GetHeaders {
-    type Builder = GetHeadersBuilder;
-    const NAME: &'static str = "GetHeaders";
+#[derive(Debug, Default)]
+pub struct IdentifyBuilder {
+    pub(crate) flag: Uint64,
+    pub(crate) name: Bytes,
+    pub(crate) client_version: Bytes,
+}
+impl IdentifyBuilder {
+    pub const FIELD_COUNT: usize = 3;
+    pub fn flag(mut self, v: Uint64) -> Self {
+        self.flag = v;
+        self
+    }
+    pub fn name(mut self, v: Bytes) -> Self {
+        self.name = v;
+        self
+    }
+    pub fn client_version(mut self, v: Bytes) -> Self {
+        self.client_version = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for IdentifyBuilder {
+    type Entity = Identify;
+    const NAME: &'static str = "IdentifyBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
+            + self.flag.as_slice().len()
+            + self.name.as_slice().len()
+            + self.client_version.as_slice().len()
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
+        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
+        offsets.push(total_size);
+        total_size += self.flag.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.name.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.client_version.as_slice().len();
+        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+        for offset in offsets.into_iter() {
+            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+        }
+        writer.write_all(self.flag.as_slice())?;
+        writer.write_all(self.name.as_slice())?;
+        writer.write_all(self.client_version.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        Identify::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct BlockFilterMessage(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for BlockFilterMessage {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for BlockFilterMessage {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for BlockFilterMessage {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}(", Self::NAME)?;
+        self.to_enum().display_inner(f)?;
+        write!(f, ")")
+    }
+}
+impl ::core::default::Default for BlockFilterMessage {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![0, 0, 0, 0, 16, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0];
+        BlockFilterMessage::new_unchecked(v.into())
+    }
+}
+impl BlockFilterMessage {
+    pub const ITEMS_COUNT: usize = 4;
+    pub fn item_id(&self) -> molecule::Number {
+        molecule::unpack_number(self.as_slice())
+    }
+    pub fn to_enum(&self) -> BlockFilterMessageUnion {
+        let inner = self.0.slice(molecule::NUMBER_SIZE..);
+        match self.item_id() {
+            0 => GetBlockFilters::new_unchecked(inner).into(),
+            1 => BlockFilters::new_unchecked(inner).into(),
+            2 => GetBlockFilterHashes::new_unchecked(inner).into(),
+            3 => BlockFilterHashes::new_unchecked(inner).into(),
+            _ => panic!("{}: invalid data", Self::NAME),
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> BlockFilterMessageReader<'r> {
+        BlockFilterMessageReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for BlockFilterMessage {
+    type Builder = BlockFilterMessageBuilder;
+    const NAME: &'static str = "BlockFilterMessage";
     fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        GetHeaders(data)
+        BlockFilterMessage(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -9586,23 +14319,21 @@ impl molecule::prelude::Entity for GetHeaders {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        GetHeadersReader::from_slice(slice).map(|reader| reader.to_entity())
+        BlockFilterMessageReader::from_slice(slice).map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        GetHeadersReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+        BlockFilterMessageReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
         ::core::default::Default::default()
     }
     fn as_builder(self) -> Self::Builder {
-        Self::new_builder()
-            .hash_stop(self.hash_stop())
-            .block_locator_hashes(self.block_locator_hashes())
+        Self::new_builder().set(self.to_enum())
     }
 }
 #[derive(Clone, Copy)]
-pub struct GetHeadersReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for GetHeadersReader<'r> {
+pub struct BlockFilterMessageReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for BlockFilterMessageReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -9611,71 +14342,42 @@ impl<'r> ::core::fmt::LowerHex for GetHeadersReader<'r> {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl<'r> ::core::fmt::Debug for GetHeadersReader<'r> {
+impl<'r> ::core::fmt::Debug for BlockFilterMessageReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::core::fmt::Display for GetHeadersReader<'r> {
+impl<'r> ::core::fmt::Display for BlockFilterMessageReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "hash_stop", self.hash_stop())?;
-        write!(
-            f,
-            ", {}: {}",
-            "block_locator_hashes",
-            self.block_locator_hashes()
-        )?;
-        let extra_count = self.count_extra_fields();
-        if extra_count != 0 {
-            write!(f, ", .. ({} fields)", extra_count)?;
-        }
-        write!(f, " }}")
+        write!(f, "{}(", Self::NAME)?;
+        self.to_enum().display_inner(f)?;
+        write!(f, ")")
     }
 }
-impl<'r> GetHeadersReader<'r> {
-    pub const FIELD_COUNT: usize = 2;
-    pub fn total_size(&self) -> usize {
-        molecule::unpack_number(self.as_slice()) as usize
-    }
-    pub fn field_count(&self) -> usize {
-        if self.total_size() == molecule::NUMBER_SIZE {
-            0
-        } else {
-            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
-        }
-    }
-    pub fn count_extra_fields(&self) -> usize {
-        self.field_count() - Self::FIELD_COUNT
-    }
-    pub fn has_extra_fields(&self) -> bool {
-        Self::FIELD_COUNT != self.field_count()
-    }
-    pub fn hash_stop(&self) -> Byte32Reader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[4..]) as usize;
-        let end = molecule::unpack_number(&slice[8..]) as usize;
-        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
+impl<'r> BlockFilterMessageReader<'r> {
+    pub const ITEMS_COUNT: usize = 4;
+    pub fn item_id(&self) -> molecule::Number {
+        molecule::unpack_number(self.as_slice())
     }
-    pub fn block_locator_hashes(&self) -> Byte32VecReader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[8..]) as usize;
-        if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[12..]) as usize;
-            Byte32VecReader::new_unchecked(&self.as_slice()[start..end])
-        } else {
-            Byte32VecReader::new_unchecked(&self.as_slice()[start..])
+    pub fn to_enum(&self) -> BlockFilterMessageUnionReader<'r> {
+        let inner = &self.as_slice()[molecule::NUMBER_SIZE..];
+        match self.item_id() {
+            0 => GetBlockFiltersReader::new_unchecked(inner).into(),
+            1 => BlockFiltersReader::new_unchecked(inner).into(),
+            2 => GetBlockFilterHashesReader::new_unchecked(inner).into(),
+            3 => BlockFilterHashesReader::new_unchecked(inner).into(),
+            _ => panic!("{}: invalid data", Self::NAME),
         }
     }
-}
-impl<'r> molecule::prelude::Reader<'r> for GetHeadersReader<'r> {
-    type Entity = GetHeaders;
-    const NAME: &'static str = "GetHeadersReader";
+}
+impl<'r> molecule::prelude::Reader<'r> for BlockFilterMessageReader<'r> {
+    type Entity = BlockFilterMessage;
+    const NAME: &'static str = "BlockFilterMessageReader";
     fn to_entity(&self) -> Self::Entity {
         Self::Entity::new_unchecked(self.as_slice().to_owned().into())
     }
     fn new_unchecked(slice: &'r [u8]) -> Self {
-        GetHeadersReader(slice)
+        BlockFilterMessageReader(slice)
     }
     fn as_slice(&self) -> &'r [u8] {
         self.0
@@ -9686,91 +14388,249 @@ impl<'r> molecule::prelude::Reader<'r> for GetHeadersReader<'r> {
         if slice_len < molecule::NUMBER_SIZE {
             return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
         }
-        let total_size = molecule::unpack_number(slice) as usize;
-        if slice_len != total_size {
-            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
-        }
-        if slice_len == molecule::NUMBER_SIZE && Self::FIELD_COUNT == 0 {
-            return Ok(());
-        }
-        if slice_len < molecule::NUMBER_SIZE * 2 {
-            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
-        }
-        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
-        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
-            return ve!(Self, OffsetsNotMatch);
-        }
-        if slice_len < offset_first {
-            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
-        }
-        let field_count = offset_first / molecule::NUMBER_SIZE - 1;
-        if field_count < Self::FIELD_COUNT {
-            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
-        } else if !compatible && field_count > Self::FIELD_COUNT {
-            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
-        };
-        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
-            .chunks_exact(molecule::NUMBER_SIZE)
-            .map(|x| molecule::unpack_number(x) as usize)
-            .collect();
-        offsets.push(total_size);
-        if offsets.windows(2).any(|i| i[0] > i[1]) {
-            return ve!(Self, OffsetsNotMatch);
-        }
-        Byte32Reader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
-        Byte32VecReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        let item_id = molecule::unpack_number(slice);
+        let inner_slice = &slice[molecule::NUMBER_SIZE..];
+        match item_id {
+            0 => GetBlockFiltersReader::verify(inner_slice, compatible),
+            1 => BlockFiltersReader::verify(inner_slice, compatible),
+            2 => GetBlockFilterHashesReader::verify(inner_slice, compatible),
+            3 => BlockFilterHashesReader::verify(inner_slice, compatible),
+            _ => ve!(Self, UnknownItem, Self::ITEMS_COUNT, item_id),
+        }?;
         Ok(())
     }
 }
 #[derive(Debug, Default)]
-pub struct GetHeadersBuilder {
-    pub(crate) hash_stop: Byte32,
-    pub(crate) block_locator_hashes: Byte32Vec,
-}
-impl GetHeadersBuilder {
-    pub const FIELD_COUNT: usize = 2;
-    pub fn hash_stop(mut self, v: Byte32) -> Self {
-        self.hash_stop = v;
-        self
-    }
-    pub fn block_locator_hashes(mut self, v: Byte32Vec) -> Self {
-        self.block_locator_hashes = v;
+pub struct BlockFilterMessageBuilder(pub(crate) BlockFilterMessageUnion);
+impl BlockFilterMessageBuilder {
+    pub const ITEMS_COUNT: usize = 4;
+    pub fn set<I>(mut self, v: I) -> Self
+    where
+        I: ::core::convert::Into<BlockFilterMessageUnion>,
+    {
+        self.0 = v.into();
         self
     }
 }
-impl molecule::prelude::Builder for GetHeadersBuilder {
-    type Entity = GetHeaders;
-    const NAME: &'static str = "GetHeadersBuilder";
+impl molecule::prelude::Builder for BlockFilterMessageBuilder {
+    type Entity = BlockFilterMessage;
+    const NAME: &'static str = "BlockFilterMessageBuilder";
     fn expected_length(&self) -> usize {
-        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
-            + self.hash_stop.as_slice().len()
-            + self.block_locator_hashes.as_slice().len()
+        molecule::NUMBER_SIZE + self.0.as_slice().len()
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
-        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
-        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
-        offsets.push(total_size);
-        total_size += self.hash_stop.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.block_locator_hashes.as_slice().len();
-        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
-        for offset in offsets.into_iter() {
-            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
-        }
-        writer.write_all(self.hash_stop.as_slice())?;
-        writer.write_all(self.block_locator_hashes.as_slice())?;
-        Ok(())
+        writer.write_all(&molecule::pack_number(self.0.item_id()))?;
+        writer.write_all(self.0.as_slice())
     }
     fn build(&self) -> Self::Entity {
         let mut inner = Vec::with_capacity(self.expected_length());
         self.write(&mut inner)
             .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        GetHeaders::new_unchecked(inner.into())
+        BlockFilterMessage::new_unchecked(inner.into())
+    }
+}
+#[derive(Debug, Clone)]
+pub enum BlockFilterMessageUnion {
+    GetBlockFilters(GetBlockFilters),
+    BlockFilters(BlockFilters),
+    GetBlockFilterHashes(GetBlockFilterHashes),
+    BlockFilterHashes(BlockFilterHashes),
+}
+#[derive(Debug, Clone, Copy)]
+pub enum BlockFilterMessageUnionReader<'r> {
+    GetBlockFilters(GetBlockFiltersReader<'r>),
+    BlockFilters(BlockFiltersReader<'r>),
+    GetBlockFilterHashes(GetBlockFilterHashesReader<'r>),
+    BlockFilterHashes(BlockFilterHashesReader<'r>),
+}
+impl ::core::default::Default for BlockFilterMessageUnion {
+    fn default() -> Self {
+        BlockFilterMessageUnion::GetBlockFilters(::core::default::Default::default())
+    }
+}
+impl ::core::fmt::Display for BlockFilterMessageUnion {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        match self {
+            BlockFilterMessageUnion::GetBlockFilters(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, GetBlockFilters::NAME, item)
+            }
+            BlockFilterMessageUnion::BlockFilters(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, BlockFilters::NAME, item)
+            }
+            BlockFilterMessageUnion::GetBlockFilterHashes(ref item) => {
+                write!(
+                    f,
+                    "{}::{}({})",
+                    Self::NAME,
+                    GetBlockFilterHashes::NAME,
+                    item
+                )
+            }
+            BlockFilterMessageUnion::BlockFilterHashes(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, BlockFilterHashes::NAME, item)
+            }
+        }
+    }
+}
+impl<'r> ::core::fmt::Display for BlockFilterMessageUnionReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        match self {
+            BlockFilterMessageUnionReader::GetBlockFilters(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, GetBlockFilters::NAME, item)
+            }
+            BlockFilterMessageUnionReader::BlockFilters(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, BlockFilters::NAME, item)
+            }
+            BlockFilterMessageUnionReader::GetBlockFilterHashes(ref item) => {
+                write!(
+                    f,
+                    "{}::{}({})",
+                    Self::NAME,
+                    GetBlockFilterHashes::NAME,
+                    item
+                )
+            }
+            BlockFilterMessageUnionReader::BlockFilterHashes(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, BlockFilterHashes::NAME, item)
+            }
+        }
+    }
+}
+impl BlockFilterMessageUnion {
+    pub(crate) fn display_inner(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        match self {
+            BlockFilterMessageUnion::GetBlockFilters(ref item) => write!(f, "{}", item),
+            BlockFilterMessageUnion::BlockFilters(ref item) => write!(f, "{}", item),
+            BlockFilterMessageUnion::GetBlockFilterHashes(ref item) => write!(f, "{}", item),
+            BlockFilterMessageUnion::BlockFilterHashes(ref item) => write!(f, "{}", item),
+        }
+    }
+}
+impl<'r> BlockFilterMessageUnionReader<'r> {
+    pub(crate) fn display_inner(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        match self {
+            BlockFilterMessageUnionReader::GetBlockFilters(ref item) => write!(f, "{}", item),
+            BlockFilterMessageUnionReader::BlockFilters(ref item) => write!(f, "{}", item),
+            BlockFilterMessageUnionReader::GetBlockFilterHashes(ref item) => write!(f, "{}", item),
+            BlockFilterMessageUnionReader::BlockFilterHashes(ref item) => write!(f, "{}", item),
+        }
+    }
+}
+impl ::core::convert::From<GetBlockFilters> for BlockFilterMessageUnion {
+    fn from(item: GetBlockFilters) -> Self {
+        BlockFilterMessageUnion::GetBlockFilters(item)
+    }
+}
+impl ::core::convert::From<BlockFilters> for BlockFilterMessageUnion {
+    fn from(item: BlockFilters) -> Self {
+        BlockFilterMessageUnion::BlockFilters(item)
+    }
+}
+impl ::core::convert::From<GetBlockFilterHashes> for BlockFilterMessageUnion {
+    fn from(item: GetBlockFilterHashes) -> Self {
+        BlockFilterMessageUnion::GetBlockFilterHashes(item)
+    }
+}
+impl ::core::convert::From<BlockFilterHashes> for BlockFilterMessageUnion {
+    fn from(item: BlockFilterHashes) -> Self {
+        BlockFilterMessageUnion::BlockFilterHashes(item)
+    }
+}
+impl<'r> ::core::convert::From<GetBlockFiltersReader<'r>> for BlockFilterMessageUnionReader<'r> {
+    fn from(item: GetBlockFiltersReader<'r>) -> Self {
+        BlockFilterMessageUnionReader::GetBlockFilters(item)
+    }
+}
+impl<'r> ::core::convert::From<BlockFiltersReader<'r>> for BlockFilterMessageUnionReader<'r> {
+    fn from(item: BlockFiltersReader<'r>) -> Self {
+        BlockFilterMessageUnionReader::BlockFilters(item)
+    }
+}
+impl<'r> ::core::convert::From<GetBlockFilterHashesReader<'r>>
+    for BlockFilterMessageUnionReader<'r>
+{
+    fn from(item: GetBlockFilterHashesReader<'r>) -> Self {
+        BlockFilterMessageUnionReader::GetBlockFilterHashes(item)
+    }
+}
+impl<'r> ::core::convert::From<BlockFilterHashesReader<'r>> for BlockFilterMessageUnionReader<'r> {
+    fn from(item: BlockFilterHashesReader<'r>) -> Self {
+        BlockFilterMessageUnionReader::BlockFilterHashes(item)
+    }
+}
+impl BlockFilterMessageUnion {
+    pub const NAME: &'static str = "BlockFilterMessageUnion";
+    pub fn as_bytes(&self) -> molecule::bytes::Bytes {
+        match self {
+            BlockFilterMessageUnion::GetBlockFilters(item) => item.as_bytes(),
+            BlockFilterMessageUnion::BlockFilters(item) => item.as_bytes(),
+            BlockFilterMessageUnion::GetBlockFilterHashes(item) => item.as_bytes(),
+            BlockFilterMessageUnion::BlockFilterHashes(item) => item.as_bytes(),
+        }
+    }
+    pub fn as_slice(&self) -> &[u8] {
+        match self {
+            BlockFilterMessageUnion::GetBlockFilters(item) => item.as_slice(),
+            BlockFilterMessageUnion::BlockFilters(item) => item.as_slice(),
+            BlockFilterMessageUnion::GetBlockFilterHashes(item) => item.as_slice(),
+            BlockFilterMessageUnion::BlockFilterHashes(item) => item.as_slice(),
+        }
+    }
+    pub fn item_id(&self) -> molecule::Number {
+        match self {
+            BlockFilterMessageUnion::GetBlockFilters(_) => 0,
+            BlockFilterMessageUnion::BlockFilters(_) => 1,
+            BlockFilterMessageUnion::GetBlockFilterHashes(_) => 2,
+            BlockFilterMessageUnion::BlockFilterHashes(_) => 3,
+        }
+    }
+    pub fn item_name(&self) -> &str {
+        match self {
+            BlockFilterMessageUnion::GetBlockFilters(_) => "GetBlockFilters",
+            BlockFilterMessageUnion::BlockFilters(_) => "BlockFilters",
+            BlockFilterMessageUnion::GetBlockFilterHashes(_) => "GetBlockFilterHashes",
+            BlockFilterMessageUnion::BlockFilterHashes(_) => "BlockFilterHashes",
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> BlockFilterMessageUnionReader<'r> {
+        match self {
+            BlockFilterMessageUnion::GetBlockFilters(item) => item.as_reader().into(),
+            BlockFilterMessageUnion::BlockFilters(item) => item.as_reader().into(),
+            BlockFilterMessageUnion::GetBlockFilterHashes(item) => item.as_reader().into(),
+            BlockFilterMessageUnion::BlockFilterHashes(item) => item.as_reader().into(),
+        }
+    }
+}
+impl<'r> BlockFilterMessageUnionReader<'r> {
+    pub const NAME: &'r str = "BlockFilterMessageUnionReader";
+    pub fn as_slice(&self) -> &'r [u8] {
+        match self {
+            BlockFilterMessageUnionReader::GetBlockFilters(item) => item.as_slice(),
+            BlockFilterMessageUnionReader::BlockFilters(item) => item.as_slice(),
+            BlockFilterMessageUnionReader::GetBlockFilterHashes(item) => item.as_slice(),
+            BlockFilterMessageUnionReader::BlockFilterHashes(item) => item.as_slice(),
+        }
+    }
+    pub fn item_id(&self) -> molecule::Number {
+        match self {
+            BlockFilterMessageUnionReader::GetBlockFilters(_) => 0,
+            BlockFilterMessageUnionReader::BlockFilters(_) => 1,
+            BlockFilterMessageUnionReader::GetBlockFilterHashes(_) => 2,
+            BlockFilterMessageUnionReader::BlockFilterHashes(_) => 3,
+        }
+    }
+    pub fn item_name(&self) -> &str {
+        match self {
+            BlockFilterMessageUnionReader::GetBlockFilters(_) => "GetBlockFilters",
+            BlockFilterMessageUnionReader::BlockFilters(_) => "BlockFilters",
+            BlockFilterMessageUnionReader::GetBlockFilterHashes(_) => "GetBlockFilterHashes",
+            BlockFilterMessageUnionReader::BlockFilterHashes(_) => "BlockFilterHashes",
+        }
     }
 }
 #[derive(Clone)]
-pub struct GetBlocks(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for GetBlocks {
+pub struct GetBlockFilters(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for GetBlockFilters {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -9779,15 +14639,15 @@ impl ::core::fmt::LowerHex for GetBlocks {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Debug for GetBlocks {
+impl ::core::fmt::Debug for GetBlockFilters {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::fmt::Display for GetBlocks {
+impl ::core::fmt::Display for GetBlockFilters {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "block_hashes", self.block_hashes())?;
+        write!(f, "{}: {}", "start_number", self.start_number())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -9795,13 +14655,13 @@ impl ::core::fmt::Display for GetBlocks {
         write!(f, " }}")
     }
 }
-impl ::core::default::Default for GetBlocks {
+impl ::core::default::Default for GetBlockFilters {
     fn default() -> Self {
-        let v: Vec<u8> = vec![12, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0];
-        GetBlocks::new_unchecked(v.into())
+        let v: Vec<u8> = vec![16, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0];
+        GetBlockFilters::new_unchecked(v.into())
     }
 }
-impl GetBlocks {
+impl GetBlockFilters {
     pub const FIELD_COUNT: usize = 1;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
@@ -9819,25 +14679,25 @@ impl GetBlocks {
     pub fn has_extra_fields(&self) -> bool {
         Self::FIELD_COUNT != self.field_count()
     }
-    pub fn block_hashes(&self) -> Byte32Vec {
+    pub fn start_number(&self) -> Uint64 {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[4..]) as usize;
         if self.has_extra_fields() {
             let end = molecule::unpack_number(&slice[8..]) as usize;
-            Byte32Vec::new_unchecked(self.0.slice(start..end))
+            Uint64::new_unchecked(self.0.slice(start..end))
         } else {
-            Byte32Vec::new_unchecked(self.0.slice(start..))
+            Uint64::new_unchecked(self.0.slice(start..))
         }
     }
-    pub fn as_reader<'r>(&'r self) -> GetBlocksReader<'r> {
-        GetBlocksReader::new_unchecked(self.as_slice())
+    pub fn as_reader<'r>(&'r self) -> GetBlockFiltersReader<'r> {
+        GetBlockFiltersReader::new_unchecked(self.as_slice())
     }
 }
-impl molecule::prelude::Entity for GetBlocks {
-    type Builder = GetBlocksBuilder;
-    const NAME: &'static str = "GetBlocks";
+impl molecule::prelude::Entity for GetBlockFilters {
+    type Builder = GetBlockFiltersBuilder;
+    const NAME: &'static str = "GetBlockFilters";
     fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        GetBlocks(data)
+        GetBlockFilters(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -9846,21 +14706,21 @@ impl molecule::prelude::Entity for GetBlocks {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        GetBlocksReader::from_slice(slice).map(|reader| reader.to_entity())
+        GetBlockFiltersReader::from_slice(slice).map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        GetBlocksReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+        GetBlockFiltersReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
         ::core::default::Default::default()
     }
     fn as_builder(self) -> Self::Builder {
-        Self::new_builder().block_hashes(self.block_hashes())
+        Self::new_builder().start_number(self.start_number())
     }
 }
 #[derive(Clone, Copy)]
-pub struct GetBlocksReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for GetBlocksReader<'r> {
+pub struct GetBlockFiltersReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for GetBlockFiltersReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -9869,15 +14729,15 @@ impl<'r> ::core::fmt::LowerHex for GetBlocksReader<'r> {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl<'r> ::core::fmt::Debug for GetBlocksReader<'r> {
+impl<'r> ::core::fmt::Debug for GetBlockFiltersReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::core::fmt::Display for GetBlocksReader<'r> {
+impl<'r> ::core::fmt::Display for GetBlockFiltersReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "block_hashes", self.block_hashes())?;
+        write!(f, "{}: {}", "start_number", self.start_number())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -9885,7 +14745,7 @@ impl<'r> ::core::fmt::Display for GetBlocksReader<'r> {
         write!(f, " }}")
     }
 }
-impl<'r> GetBlocksReader<'r> {
+impl<'r> GetBlockFiltersReader<'r> {
     pub const FIELD_COUNT: usize = 1;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
@@ -9903,25 +14763,25 @@ impl<'r> GetBlocksReader<'r> {
     pub fn has_extra_fields(&self) -> bool {
         Self::FIELD_COUNT != self.field_count()
     }
-    pub fn block_hashes(&self) -> Byte32VecReader<'r> {
+    pub fn start_number(&self) -> Uint64Reader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[4..]) as usize;
         if self.has_extra_fields() {
             let end = molecule::unpack_number(&slice[8..]) as usize;
-            Byte32VecReader::new_unchecked(&self.as_slice()[start..end])
+            Uint64Reader::new_unchecked(&self.as_slice()[start..end])
         } else {
-            Byte32VecReader::new_unchecked(&self.as_slice()[start..])
+            Uint64Reader::new_unchecked(&self.as_slice()[start..])
         }
     }
 }
-impl<'r> molecule::prelude::Reader<'r> for GetBlocksReader<'r> {
-    type Entity = GetBlocks;
-    const NAME: &'static str = "GetBlocksReader";
+impl<'r> molecule::prelude::Reader<'r> for GetBlockFiltersReader<'r> {
+    type Entity = GetBlockFilters;
+    const NAME: &'static str = "GetBlockFiltersReader";
     fn to_entity(&self) -> Self::Entity {
         Self::Entity::new_unchecked(self.as_slice().to_owned().into())
     }
     fn new_unchecked(slice: &'r [u8]) -> Self {
-        GetBlocksReader(slice)
+        GetBlockFiltersReader(slice)
     }
     fn as_slice(&self) -> &'r [u8] {
         self.0
@@ -9963,49 +14823,49 @@ impl<'r> molecule::prelude::Reader<'r> for GetBlocksReader<'r> {
         if offsets.windows(2).any(|i| i[0] > i[1]) {
             return ve!(Self, OffsetsNotMatch);
         }
-        Byte32VecReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        Uint64Reader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
         Ok(())
     }
 }
 #[derive(Debug, Default)]
-pub struct GetBlocksBuilder {
-    pub(crate) block_hashes: Byte32Vec,
+pub struct GetBlockFiltersBuilder {
+    pub(crate) start_number: Uint64,
 }
-impl GetBlocksBuilder {
+impl GetBlockFiltersBuilder {
     pub const FIELD_COUNT: usize = 1;
-    pub fn block_hashes(mut self, v: Byte32Vec) -> Self {
-        self.block_hashes = v;
+    pub fn start_number(mut self, v: Uint64) -> Self {
+        self.start_number = v;
         self
     }
 }
-impl molecule::prelude::Builder for GetBlocksBuilder {
-    type Entity = GetBlocks;
-    const NAME: &'static str = "GetBlocksBuilder";
+impl molecule::prelude::Builder for GetBlockFiltersBuilder {
+    type Entity = GetBlockFilters;
+    const NAME: &'static str = "GetBlockFiltersBuilder";
     fn expected_length(&self) -> usize {
-        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1) + self.block_hashes.as_slice().len()
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1) + self.start_number.as_slice().len()
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
         let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
         let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
         offsets.push(total_size);
-        total_size += self.block_hashes.as_slice().len();
+        total_size += self.start_number.as_slice().len();
         writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
         for offset in offsets.into_iter() {
             writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
         }
-        writer.write_all(self.block_hashes.as_slice())?;
+        writer.write_all(self.start_number.as_slice())?;
         Ok(())
     }
     fn build(&self) -> Self::Entity {
         let mut inner = Vec::with_capacity(self.expected_length());
         self.write(&mut inner)
             .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        GetBlocks::new_unchecked(inner.into())
+        GetBlockFilters::new_unchecked(inner.into())
     }
 }
 #[derive(Clone)]
-pub struct SendHeaders(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for SendHeaders {
+pub struct BlockFilters(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for BlockFilters {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -10014,15 +14874,17 @@ impl ::core::fmt::LowerHex for SendHeaders {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Debug for SendHeaders {
+impl ::core::fmt::Debug for BlockFilters {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::fmt::Display for SendHeaders {
+impl ::core::fmt::Display for BlockFilters {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "headers", self.headers())?;
+        write!(f, "{}: {}", "start_number", self.start_number())?;
+        write!(f, ", {}: {}", "block_hashes", self.block_hashes())?;
+        write!(f, ", {}: {}", "filters", self.filters())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -10030,14 +14892,17 @@ impl ::core::fmt::Display for SendHeaders {
         write!(f, " }}")
     }
 }
-impl ::core::default::Default for SendHeaders {
+impl ::core::default::Default for BlockFilters {
     fn default() -> Self {
-        let v: Vec<u8> = vec![12, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0];
-        SendHeaders::new_unchecked(v.into())
+        let v: Vec<u8> = vec![
+            32, 0, 0, 0, 16, 0, 0, 0, 24, 0, 0, 0, 28, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            4, 0, 0, 0,
+        ];
+        BlockFilters::new_unchecked(v.into())
     }
 }
-impl SendHeaders {
-    pub const FIELD_COUNT: usize = 1;
+impl BlockFilters {
+    pub const FIELD_COUNT: usize = 3;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -10054,25 +14919,37 @@ impl SendHeaders {
     pub fn has_extra_fields(&self) -> bool {
         Self::FIELD_COUNT != self.field_count()
     }
-    pub fn headers(&self) -> HeaderVec {
+    pub fn start_number(&self) -> Uint64 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        Uint64::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn block_hashes(&self) -> Byte32Vec {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        Byte32Vec::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn filters(&self) -> BytesVec {
         let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let start = molecule::unpack_number(&slice[12..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[8..]) as usize;
-            HeaderVec::new_unchecked(self.0.slice(start..end))
+            let end = molecule::unpack_number(&slice[16..]) as usize;
+            BytesVec::new_unchecked(self.0.slice(start..end))
         } else {
-            HeaderVec::new_unchecked(self.0.slice(start..))
+            BytesVec::new_unchecked(self.0.slice(start..))
         }
     }
-    pub fn as_reader<'r>(&'r self) -> SendHeadersReader<'r> {
-        SendHeadersReader::new_unchecked(self.as_slice())
+    pub fn as_reader<'r>(&'r self) -> BlockFiltersReader<'r> {
+        BlockFiltersReader::new_unchecked(self.as_slice())
     }
 }
-impl molecule::prelude::Entity for SendHeaders {
-    type Builder = SendHeadersBuilder;
-    const NAME: &'static str = "SendHeaders";
+impl molecule::prelude::Entity for BlockFilters {
+    type Builder = BlockFiltersBuilder;
+    const NAME: &'static str = "BlockFilters";
     fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        SendHeaders(data)
+        BlockFilters(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -10081,21 +14958,24 @@ impl molecule::prelude::Entity for SendHeaders {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        SendHeadersReader::from_slice(slice).map(|reader| reader.to_entity())
+        BlockFiltersReader::from_slice(slice).map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        SendHeadersReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+        BlockFiltersReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
         ::core::default::Default::default()
     }
     fn as_builder(self) -> Self::Builder {
-        Self::new_builder().headers(self.headers())
+        Self::new_builder()
+            .start_number(self.start_number())
+            .block_hashes(self.block_hashes())
+            .filters(self.filters())
     }
 }
 #[derive(Clone, Copy)]
-pub struct SendHeadersReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for SendHeadersReader<'r> {
+pub struct BlockFiltersReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for BlockFiltersReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -10104,15 +14984,17 @@ impl<'r> ::core::fmt::LowerHex for SendHeadersReader<'r> {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl<'r> ::core::fmt::Debug for SendHeadersReader<'r> {
+impl<'r> ::core::fmt::Debug for BlockFiltersReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::core::fmt::Display for SendHeadersReader<'r> {
+impl<'r> ::core::fmt::Display for BlockFiltersReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "headers", self.headers())?;
+        write!(f, "{}: {}", "start_number", self.start_number())?;
+        write!(f, ", {}: {}", "block_hashes", self.block_hashes())?;
+        write!(f, ", {}: {}", "filters", self.filters())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -10120,8 +15002,8 @@ impl<'r> ::core::fmt::Display for SendHeadersReader<'r> {
         write!(f, " }}")
     }
 }
-impl<'r> SendHeadersReader<'r> {
-    pub const FIELD_COUNT: usize = 1;
+impl<'r> BlockFiltersReader<'r> {
+    pub const FIELD_COUNT: usize = 3;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -10138,25 +15020,37 @@ impl<'r> SendHeadersReader<'r> {
     pub fn has_extra_fields(&self) -> bool {
         Self::FIELD_COUNT != self.field_count()
     }
-    pub fn headers(&self) -> HeaderVecReader<'r> {
+    pub fn start_number(&self) -> Uint64Reader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        Uint64Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn block_hashes(&self) -> Byte32VecReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        Byte32VecReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn filters(&self) -> BytesVecReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[8..]) as usize;
-            HeaderVecReader::new_unchecked(&self.as_slice()[start..end])
+            let end = molecule::unpack_number(&slice[16..]) as usize;
+            BytesVecReader::new_unchecked(&self.as_slice()[start..end])
         } else {
-            HeaderVecReader::new_unchecked(&self.as_slice()[start..])
+            BytesVecReader::new_unchecked(&self.as_slice()[start..])
         }
     }
 }
-impl<'r> molecule::prelude::Reader<'r> for SendHeadersReader<'r> {
-    type Entity = SendHeaders;
-    const NAME: &'static str = "SendHeadersReader";
+impl<'r> molecule::prelude::Reader<'r> for BlockFiltersReader<'r> {
+    type Entity = BlockFilters;
+    const NAME: &'static str = "BlockFiltersReader";
     fn to_entity(&self) -> Self::Entity {
         Self::Entity::new_unchecked(self.as_slice().to_owned().into())
     }
     fn new_unchecked(slice: &'r [u8]) -> Self {
-        SendHeadersReader(slice)
+        BlockFiltersReader(slice)
     }
     fn as_slice(&self) -> &'r [u8] {
         self.0
@@ -10198,49 +15092,70 @@ impl<'r> molecule::prelude::Reader<'r> for SendHeadersReader<'r> {
         if offsets.windows(2).any(|i| i[0] > i[1]) {
             return ve!(Self, OffsetsNotMatch);
         }
-        HeaderVecReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        Uint64Reader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        Byte32VecReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        BytesVecReader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
         Ok(())
     }
 }
 #[derive(Debug, Default)]
-pub struct SendHeadersBuilder {
-    pub(crate) headers: HeaderVec,
+pub struct BlockFiltersBuilder {
+    pub(crate) start_number: Uint64,
+    pub(crate) block_hashes: Byte32Vec,
+    pub(crate) filters: BytesVec,
 }
-impl SendHeadersBuilder {
-    pub const FIELD_COUNT: usize = 1;
-    pub fn headers(mut self, v: HeaderVec) -> Self {
-        self.headers = v;
+impl BlockFiltersBuilder {
+    pub const FIELD_COUNT: usize = 3;
+    pub fn start_number(mut self, v: Uint64) -> Self {
+        self.start_number = v;
+        self
+    }
+    pub fn block_hashes(mut self, v: Byte32Vec) -> Self {
+        self.block_hashes = v;
+        self
+    }
+    pub fn filters(mut self, v: BytesVec) -> Self {
+        self.filters = v;
         self
     }
 }
-impl molecule::prelude::Builder for SendHeadersBuilder {
-    type Entity = SendHeaders;
-    const NAME: &'static str = "SendHeadersBuilder";
+impl molecule::prelude::Builder for BlockFiltersBuilder {
+    type Entity = BlockFilters;
+    const NAME: &'static str = "BlockFiltersBuilder";
     fn expected_length(&self) -> usize {
-        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1) + self.headers.as_slice().len()
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
+            + self.start_number.as_slice().len()
+            + self.block_hashes.as_slice().len()
+            + self.filters.as_slice().len()
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
         let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
         let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
         offsets.push(total_size);
-        total_size += self.headers.as_slice().len();
+        total_size += self.start_number.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.block_hashes.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.filters.as_slice().len();
         writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
         for offset in offsets.into_iter() {
             writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
         }
-        writer.write_all(self.headers.as_slice())?;
+        writer.write_all(self.start_number.as_slice())?;
+        writer.write_all(self.block_hashes.as_slice())?;
+        writer.write_all(self.filters.as_slice())?;
         Ok(())
     }
     fn build(&self) -> Self::Entity {
         let mut inner = Vec::with_capacity(self.expected_length());
         self.write(&mut inner)
             .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        SendHeaders::new_unchecked(inner.into())
+        BlockFilters::new_unchecked(inner.into())
     }
 }
 #[derive(Clone)]
-pub struct SendBlock(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for SendBlock {
+pub struct GetBlockFilterHashes(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for GetBlockFilterHashes {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -10249,15 +15164,15 @@ impl ::core::fmt::LowerHex for SendBlock {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Debug for SendBlock {
+impl ::core::fmt::Debug for GetBlockFilterHashes {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::fmt::Display for SendBlock {
+impl ::core::fmt::Display for GetBlockFilterHashes {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "block", self.block())?;
+        write!(f, "{}: {}", "start_number", self.start_number())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -10265,23 +15180,13 @@ impl ::core::fmt::Display for SendBlock {
         write!(f, " }}")
     }
 }
-impl ::core::default::Default for SendBlock {
+impl ::core::default::Default for GetBlockFilterHashes {
     fn default() -> Self {
-        let v: Vec<u8> = vec![
-            248, 0, 0, 0, 8, 0, 0, 0, 240, 0, 0, 0, 20, 0, 0, 0, 228, 0, 0, 0, 232, 0, 0, 0, 236,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0,
-        ];
-        SendBlock::new_unchecked(v.into())
+        let v: Vec<u8> = vec![16, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0];
+        GetBlockFilterHashes::new_unchecked(v.into())
     }
 }
-impl SendBlock {
+impl GetBlockFilterHashes {
     pub const FIELD_COUNT: usize = 1;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
@@ -10299,25 +15204,25 @@ impl SendBlock {
     pub fn has_extra_fields(&self) -> bool {
         Self::FIELD_COUNT != self.field_count()
     }
-    pub fn block(&self) -> Block {
+    pub fn start_number(&self) -> Uint64 {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[4..]) as usize;
         if self.has_extra_fields() {
             let end = molecule::unpack_number(&slice[8..]) as usize;
-            Block::new_unchecked(self.0.slice(start..end))
+            Uint64::new_unchecked(self.0.slice(start..end))
         } else {
-            Block::new_unchecked(self.0.slice(start..))
+            Uint64::new_unchecked(self.0.slice(start..))
         }
     }
-    pub fn as_reader<'r>(&'r self) -> SendBlockReader<'r> {
-        SendBlockReader::new_unchecked(self.as_slice())
+    pub fn as_reader<'r>(&'r self) -> GetBlockFilterHashesReader<'r> {
+        GetBlockFilterHashesReader::new_unchecked(self.as_slice())
     }
 }
-impl molecule::prelude::Entity for SendBlock {
-    type Builder = SendBlockBuilder;
-    const NAME: &'static str = "SendBlock";
+impl molecule::prelude::Entity for GetBlockFilterHashes {
+    type Builder = GetBlockFilterHashesBuilder;
+    const NAME: &'static str = "GetBlockFilterHashes";
     fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        SendBlock(data)
+        GetBlockFilterHashes(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -10326,21 +15231,21 @@ impl molecule::prelude::Entity for SendBlock {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        SendBlockReader::from_slice(slice).map(|reader| reader.to_entity())
+        GetBlockFilterHashesReader::from_slice(slice).map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        SendBlockReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+        GetBlockFilterHashesReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
         ::core::default::Default::default()
     }
     fn as_builder(self) -> Self::Builder {
-        Self::new_builder().block(self.block())
+        Self::new_builder().start_number(self.start_number())
     }
 }
 #[derive(Clone, Copy)]
-pub struct SendBlockReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for SendBlockReader<'r> {
+pub struct GetBlockFilterHashesReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for GetBlockFilterHashesReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -10349,15 +15254,15 @@ impl<'r> ::core::fmt::LowerHex for SendBlockReader<'r> {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl<'r> ::core::fmt::Debug for SendBlockReader<'r> {
+impl<'r> ::core::fmt::Debug for GetBlockFilterHashesReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::core::fmt::Display for SendBlockReader<'r> {
+impl<'r> ::core::fmt::Display for GetBlockFilterHashesReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "block", self.block())?;
+        write!(f, "{}: {}", "start_number", self.start_number())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -10365,7 +15270,7 @@ impl<'r> ::core::fmt::Display for SendBlockReader<'r> {
         write!(f, " }}")
     }
 }
-impl<'r> SendBlockReader<'r> {
+impl<'r> GetBlockFilterHashesReader<'r> {
     pub const FIELD_COUNT: usize = 1;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
@@ -10383,25 +15288,25 @@ impl<'r> SendBlockReader<'r> {
     pub fn has_extra_fields(&self) -> bool {
         Self::FIELD_COUNT != self.field_count()
     }
-    pub fn block(&self) -> BlockReader<'r> {
+    pub fn start_number(&self) -> Uint64Reader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[4..]) as usize;
         if self.has_extra_fields() {
             let end = molecule::unpack_number(&slice[8..]) as usize;
-            BlockReader::new_unchecked(&self.as_slice()[start..end])
+            Uint64Reader::new_unchecked(&self.as_slice()[start..end])
         } else {
-            BlockReader::new_unchecked(&self.as_slice()[start..])
+            Uint64Reader::new_unchecked(&self.as_slice()[start..])
         }
     }
 }
-impl<'r> molecule::prelude::Reader<'r> for SendBlockReader<'r> {
-    type Entity = SendBlock;
-    const NAME: &'static str = "SendBlockReader";
+impl<'r> molecule::prelude::Reader<'r> for GetBlockFilterHashesReader<'r> {
+    type Entity = GetBlockFilterHashes;
+    const NAME: &'static str = "GetBlockFilterHashesReader";
     fn to_entity(&self) -> Self::Entity {
         Self::Entity::new_unchecked(self.as_slice().to_owned().into())
     }
     fn new_unchecked(slice: &'r [u8]) -> Self {
-        SendBlockReader(slice)
+        GetBlockFilterHashesReader(slice)
     }
     fn as_slice(&self) -> &'r [u8] {
         self.0
@@ -10443,49 +15348,49 @@ impl<'r> molecule::prelude::Reader<'r> for SendBlockReader<'r> {
         if offsets.windows(2).any(|i| i[0] > i[1]) {
             return ve!(Self, OffsetsNotMatch);
         }
-        BlockReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        Uint64Reader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
         Ok(())
     }
 }
 #[derive(Debug, Default)]
-pub struct SendBlockBuilder {
-    pub(crate) block: Block,
+pub struct GetBlockFilterHashesBuilder {
+    pub(crate) start_number: Uint64,
 }
-impl SendBlockBuilder {
+impl GetBlockFilterHashesBuilder {
     pub const FIELD_COUNT: usize = 1;
-    pub fn block(mut self, v: Block) -> Self {
-        self.block = v;
+    pub fn start_number(mut self, v: Uint64) -> Self {
+        self.start_number = v;
         self
     }
 }
-impl molecule::prelude::Builder for SendBlockBuilder {
-    type Entity = SendBlock;
-    const NAME: &'static str = "SendBlockBuilder";
+impl molecule::prelude::Builder for GetBlockFilterHashesBuilder {
+    type Entity = GetBlockFilterHashes;
+    const NAME: &'static str = "GetBlockFilterHashesBuilder";
     fn expected_length(&self) -> usize {
-        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1) + self.block.as_slice().len()
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1) + self.start_number.as_slice().len()
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
         let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
         let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
         offsets.push(total_size);
-        total_size += self.block.as_slice().len();
+        total_size += self.start_number.as_slice().len();
         writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
         for offset in offsets.into_iter() {
             writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
         }
-        writer.write_all(self.block.as_slice())?;
+        writer.write_all(self.start_number.as_slice())?;
         Ok(())
     }
     fn build(&self) -> Self::Entity {
         let mut inner = Vec::with_capacity(self.expected_length());
         self.write(&mut inner)
             .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        SendBlock::new_unchecked(inner.into())
+        GetBlockFilterHashes::new_unchecked(inner.into())
     }
 }
 #[derive(Clone)]
-pub struct SetFilter(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for SetFilter {
+pub struct BlockFilterHashes(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for BlockFilterHashes {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -10494,17 +15399,27 @@ impl ::core::fmt::LowerHex for SetFilter {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Debug for SetFilter {
+impl ::core::fmt::Debug for BlockFilterHashes {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::fmt::Display for SetFilter {
+impl ::core::fmt::Display for BlockFilterHashes {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "hash_seed", self.hash_seed())?;
-        write!(f, ", {}: {}", "filter", self.filter())?;
-        write!(f, ", {}: {}", "num_hashes", self.num_hashes())?;
+        write!(f, "{}: {}", "start_number", self.start_number())?;
+        write!(
+            f,
+            ", {}: {}",
+            "parent_block_filter_hash",
+            self.parent_block_filter_hash()
+        )?;
+        write!(
+            f,
+            ", {}: {}",
+            "block_filter_hashes",
+            self.block_filter_hashes()
+        )?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -10512,15 +15427,17 @@ impl ::core::fmt::Display for SetFilter {
         write!(f, " }}")
     }
 }
-impl ::core::default::Default for SetFilter {
+impl ::core::default::Default for BlockFilterHashes {
     fn default() -> Self {
         let v: Vec<u8> = vec![
-            25, 0, 0, 0, 16, 0, 0, 0, 20, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            60, 0, 0, 0, 16, 0, 0, 0, 24, 0, 0, 0, 56, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0,
         ];
-        SetFilter::new_unchecked(v.into())
+        BlockFilterHashes::new_unchecked(v.into())
     }
 }
-impl SetFilter {
+impl BlockFilterHashes {
     pub const FIELD_COUNT: usize = 3;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
@@ -10538,37 +15455,37 @@ impl SetFilter {
     pub fn has_extra_fields(&self) -> bool {
         Self::FIELD_COUNT != self.field_count()
     }
-    pub fn hash_seed(&self) -> Uint32 {
+    pub fn start_number(&self) -> Uint64 {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[4..]) as usize;
         let end = molecule::unpack_number(&slice[8..]) as usize;
-        Uint32::new_unchecked(self.0.slice(start..end))
+        Uint64::new_unchecked(self.0.slice(start..end))
     }
-    pub fn filter(&self) -> Bytes {
+    pub fn parent_block_filter_hash(&self) -> Byte32 {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[8..]) as usize;
         let end = molecule::unpack_number(&slice[12..]) as usize;
-        Bytes::new_unchecked(self.0.slice(start..end))
+        Byte32::new_unchecked(self.0.slice(start..end))
     }
-    pub fn num_hashes(&self) -> Byte {
+    pub fn block_filter_hashes(&self) -> Byte32Vec {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[12..]) as usize;
         if self.has_extra_fields() {
             let end = molecule::unpack_number(&slice[16..]) as usize;
-            Byte::new_unchecked(self.0.slice(start..end))
+            Byte32Vec::new_unchecked(self.0.slice(start..end))
         } else {
-            Byte::new_unchecked(self.0.slice(start..))
+            Byte32Vec::new_unchecked(self.0.slice(start..))
         }
     }
-    pub fn as_reader<'r>(&'r self) -> SetFilterReader<'r> {
-        SetFilterReader::new_unchecked(self.as_slice())
+    pub fn as_reader<'r>(&'r self) -> BlockFilterHashesReader<'r> {
+        BlockFilterHashesReader::new_unchecked(self.as_slice())
     }
 }
-impl molecule::prelude::Entity for SetFilter {
-    type Builder = SetFilterBuilder;
-    const NAME: &'static str = "SetFilter";
+impl molecule::prelude::Entity for BlockFilterHashes {
+    type Builder = BlockFilterHashesBuilder;
+    const NAME: &'static str = "BlockFilterHashes";
     fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        SetFilter(data)
+        BlockFilterHashes(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -10577,24 +15494,24 @@ impl molecule::prelude::Entity for SetFilter {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        SetFilterReader::from_slice(slice).map(|reader| reader.to_entity())
+        BlockFilterHashesReader::from_slice(slice).map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        SetFilterReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+        BlockFilterHashesReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
         ::core::default::Default::default()
     }
     fn as_builder(self) -> Self::Builder {
         Self::new_builder()
-            .hash_seed(self.hash_seed())
-            .filter(self.filter())
-            .num_hashes(self.num_hashes())
+            .start_number(self.start_number())
+            .parent_block_filter_hash(self.parent_block_filter_hash())
+            .block_filter_hashes(self.block_filter_hashes())
     }
 }
 #[derive(Clone, Copy)]
-pub struct SetFilterReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for SetFilterReader<'r> {
+pub struct BlockFilterHashesReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for BlockFilterHashesReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -10603,17 +15520,27 @@ impl<'r> ::core::fmt::LowerHex for SetFilterReader<'r> {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl<'r> ::core::fmt::Debug for SetFilterReader<'r> {
+impl<'r> ::core::fmt::Debug for BlockFilterHashesReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::core::fmt::Display for SetFilterReader<'r> {
+impl<'r> ::core::fmt::Display for BlockFilterHashesReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "hash_seed", self.hash_seed())?;
-        write!(f, ", {}: {}", "filter", self.filter())?;
-        write!(f, ", {}: {}", "num_hashes", self.num_hashes())?;
+        write!(f, "{}: {}", "start_number", self.start_number())?;
+        write!(
+            f,
+            ", {}: {}",
+            "parent_block_filter_hash",
+            self.parent_block_filter_hash()
+        )?;
+        write!(
+            f,
+            ", {}: {}",
+            "block_filter_hashes",
+            self.block_filter_hashes()
+        )?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -10621,7 +15548,7 @@ impl<'r> ::core::fmt::Display for SetFilterReader<'r> {
         write!(f, " }}")
     }
 }
-impl<'r> SetFilterReader<'r> {
+impl<'r> BlockFilterHashesReader<'r> {
     pub const FIELD_COUNT: usize = 3;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
@@ -10639,37 +15566,37 @@ impl<'r> SetFilterReader<'r> {
     pub fn has_extra_fields(&self) -> bool {
         Self::FIELD_COUNT != self.field_count()
     }
-    pub fn hash_seed(&self) -> Uint32Reader<'r> {
+    pub fn start_number(&self) -> Uint64Reader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[4..]) as usize;
         let end = molecule::unpack_number(&slice[8..]) as usize;
-        Uint32Reader::new_unchecked(&self.as_slice()[start..end])
+        Uint64Reader::new_unchecked(&self.as_slice()[start..end])
     }
-    pub fn filter(&self) -> BytesReader<'r> {
+    pub fn parent_block_filter_hash(&self) -> Byte32Reader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[8..]) as usize;
         let end = molecule::unpack_number(&slice[12..]) as usize;
-        BytesReader::new_unchecked(&self.as_slice()[start..end])
+        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
     }
-    pub fn num_hashes(&self) -> ByteReader<'r> {
+    pub fn block_filter_hashes(&self) -> Byte32VecReader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[12..]) as usize;
         if self.has_extra_fields() {
             let end = molecule::unpack_number(&slice[16..]) as usize;
-            ByteReader::new_unchecked(&self.as_slice()[start..end])
+            Byte32VecReader::new_unchecked(&self.as_slice()[start..end])
         } else {
-            ByteReader::new_unchecked(&self.as_slice()[start..])
+            Byte32VecReader::new_unchecked(&self.as_slice()[start..])
         }
     }
 }
-impl<'r> molecule::prelude::Reader<'r> for SetFilterReader<'r> {
-    type Entity = SetFilter;
-    const NAME: &'static str = "SetFilterReader";
+impl<'r> molecule::prelude::Reader<'r> for BlockFilterHashesReader<'r> {
+    type Entity = BlockFilterHashes;
+    const NAME: &'static str = "BlockFilterHashesReader";
     fn to_entity(&self) -> Self::Entity {
         Self::Entity::new_unchecked(self.as_slice().to_owned().into())
     }
     fn new_unchecked(slice: &'r [u8]) -> Self {
-        SetFilterReader(slice)
+        BlockFilterHashesReader(slice)
     }
     fn as_slice(&self) -> &'r [u8] {
         self.0
@@ -10711,70 +15638,70 @@ impl<'r> molecule::prelude::Reader<'r> for SetFilterReader<'r> {
         if offsets.windows(2).any(|i| i[0] > i[1]) {
             return ve!(Self, OffsetsNotMatch);
         }
-        Uint32Reader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
-        BytesReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
-        ByteReader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
+        Uint64Reader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        Byte32Reader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        Byte32VecReader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
         Ok(())
     }
 }
 #[derive(Debug, Default)]
-pub struct SetFilterBuilder {
-    pub(crate) hash_seed: Uint32,
-    pub(crate) filter: Bytes,
-    pub(crate) num_hashes: Byte,
+pub struct BlockFilterHashesBuilder {
+    pub(crate) start_number: Uint64,
+    pub(crate) parent_block_filter_hash: Byte32,
+    pub(crate) block_filter_hashes: Byte32Vec,
 }
-impl SetFilterBuilder {
+impl BlockFilterHashesBuilder {
     pub const FIELD_COUNT: usize = 3;
-    pub fn hash_seed(mut self, v: Uint32) -> Self {
-        self.hash_seed = v;
+    pub fn start_number(mut self, v: Uint64) -> Self {
+        self.start_number = v;
         self
     }
-    pub fn filter(mut self, v: Bytes) -> Self {
-        self.filter = v;
+    pub fn parent_block_filter_hash(mut self, v: Byte32) -> Self {
+        self.parent_block_filter_hash = v;
         self
     }
-    pub fn num_hashes(mut self, v: Byte) -> Self {
-        self.num_hashes = v;
+    pub fn block_filter_hashes(mut self, v: Byte32Vec) -> Self {
+        self.block_filter_hashes = v;
         self
     }
-}
-impl molecule::prelude::Builder for SetFilterBuilder {
-    type Entity = SetFilter;
-    const NAME: &'static str = "SetFilterBuilder";
+}
+impl molecule::prelude::Builder for BlockFilterHashesBuilder {
+    type Entity = BlockFilterHashes;
+    const NAME: &'static str = "BlockFilterHashesBuilder";
     fn expected_length(&self) -> usize {
         molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
-            + self.hash_seed.as_slice().len()
-            + self.filter.as_slice().len()
-            + self.num_hashes.as_slice().len()
+            + self.start_number.as_slice().len()
+            + self.parent_block_filter_hash.as_slice().len()
+            + self.block_filter_hashes.as_slice().len()
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
         let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
         let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
         offsets.push(total_size);
-        total_size += self.hash_seed.as_slice().len();
+        total_size += self.start_number.as_slice().len();
         offsets.push(total_size);
-        total_size += self.filter.as_slice().len();
+        total_size += self.parent_block_filter_hash.as_slice().len();
         offsets.push(total_size);
-        total_size += self.num_hashes.as_slice().len();
+        total_size += self.block_filter_hashes.as_slice().len();
         writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
         for offset in offsets.into_iter() {
             writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
         }
-        writer.write_all(self.hash_seed.as_slice())?;
-        writer.write_all(self.filter.as_slice())?;
-        writer.write_all(self.num_hashes.as_slice())?;
+        writer.write_all(self.start_number.as_slice())?;
+        writer.write_all(self.parent_block_filter_hash.as_slice())?;
+        writer.write_all(self.block_filter_hashes.as_slice())?;
         Ok(())
     }
     fn build(&self) -> Self::Entity {
         let mut inner = Vec::with_capacity(self.expected_length());
         self.write(&mut inner)
             .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        SetFilter::new_unchecked(inner.into())
+        BlockFilterHashes::new_unchecked(inner.into())
     }
 }
 #[derive(Clone)]
-pub struct AddFilter(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for AddFilter {
+pub struct LightClientMessage(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for LightClientMessage {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -10783,65 +15710,50 @@ impl ::core::fmt::LowerHex for AddFilter {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Debug for AddFilter {
+impl ::core::fmt::Debug for LightClientMessage {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::fmt::Display for AddFilter {
+impl ::core::fmt::Display for LightClientMessage {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "filter", self.filter())?;
-        let extra_count = self.count_extra_fields();
-        if extra_count != 0 {
-            write!(f, ", .. ({} fields)", extra_count)?;
-        }
-        write!(f, " }}")
+        write!(f, "{}(", Self::NAME)?;
+        self.to_enum().display_inner(f)?;
+        write!(f, ")")
     }
 }
-impl ::core::default::Default for AddFilter {
+impl ::core::default::Default for LightClientMessage {
     fn default() -> Self {
-        let v: Vec<u8> = vec![12, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0];
-        AddFilter::new_unchecked(v.into())
+        let v: Vec<u8> = vec![0, 0, 0, 0, 4, 0, 0, 0];
+        LightClientMessage::new_unchecked(v.into())
     }
 }
-impl AddFilter {
-    pub const FIELD_COUNT: usize = 1;
-    pub fn total_size(&self) -> usize {
-        molecule::unpack_number(self.as_slice()) as usize
-    }
-    pub fn field_count(&self) -> usize {
-        if self.total_size() == molecule::NUMBER_SIZE {
-            0
-        } else {
-            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
-        }
-    }
-    pub fn count_extra_fields(&self) -> usize {
-        self.field_count() - Self::FIELD_COUNT
-    }
-    pub fn has_extra_fields(&self) -> bool {
-        Self::FIELD_COUNT != self.field_count()
+impl LightClientMessage {
+    pub const ITEMS_COUNT: usize = 6;
+    pub fn item_id(&self) -> molecule::Number {
+        molecule::unpack_number(self.as_slice())
     }
-    pub fn filter(&self) -> Bytes {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[4..]) as usize;
-        if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[8..]) as usize;
-            Bytes::new_unchecked(self.0.slice(start..end))
-        } else {
-            Bytes::new_unchecked(self.0.slice(start..))
+    pub fn to_enum(&self) -> LightClientMessageUnion {
+        let inner = self.0.slice(molecule::NUMBER_SIZE..);
+        match self.item_id() {
+            0 => GetLastState::new_unchecked(inner).into(),
+            1 => SendLastState::new_unchecked(inner).into(),
+            2 => GetBlockHeaders::new_unchecked(inner).into(),
+            3 => SendBlockHeaders::new_unchecked(inner).into(),
+            4 => GetTransactionsProof::new_unchecked(inner).into(),
+            5 => SendTransactionsProof::new_unchecked(inner).into(),
+            _ => panic!("{}: invalid data", Self::NAME),
         }
     }
-    pub fn as_reader<'r>(&'r self) -> AddFilterReader<'r> {
-        AddFilterReader::new_unchecked(self.as_slice())
+    pub fn as_reader<'r>(&'r self) -> LightClientMessageReader<'r> {
+        LightClientMessageReader::new_unchecked(self.as_slice())
     }
 }
-impl molecule::prelude::Entity for AddFilter {
-    type Builder = AddFilterBuilder;
-    const NAME: &'static str = "AddFilter";
+impl molecule::prelude::Entity for LightClientMessage {
+    type Builder = LightClientMessageBuilder;
+    const NAME: &'static str = "LightClientMessage";
     fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        AddFilter(data)
+        LightClientMessage(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -10850,21 +15762,21 @@ impl molecule::prelude::Entity for AddFilter {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        AddFilterReader::from_slice(slice).map(|reader| reader.to_entity())
+        LightClientMessageReader::from_slice(slice).map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        AddFilterReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+        LightClientMessageReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
         ::core::default::Default::default()
     }
     fn as_builder(self) -> Self::Builder {
-        Self::new_builder().filter(self.filter())
+        Self::new_builder().set(self.to_enum())
     }
 }
 #[derive(Clone, Copy)]
-pub struct AddFilterReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for AddFilterReader<'r> {
+pub struct LightClientMessageReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for LightClientMessageReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -10873,59 +15785,44 @@ impl<'r> ::core::fmt::LowerHex for AddFilterReader<'r> {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl<'r> ::core::fmt::Debug for AddFilterReader<'r> {
+impl<'r> ::core::fmt::Debug for LightClientMessageReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::core::fmt::Display for AddFilterReader<'r> {
+impl<'r> ::core::fmt::Display for LightClientMessageReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "filter", self.filter())?;
-        let extra_count = self.count_extra_fields();
-        if extra_count != 0 {
-            write!(f, ", .. ({} fields)", extra_count)?;
-        }
-        write!(f, " }}")
+        write!(f, "{}(", Self::NAME)?;
+        self.to_enum().display_inner(f)?;
+        write!(f, ")")
     }
 }
-impl<'r> AddFilterReader<'r> {
-    pub const FIELD_COUNT: usize = 1;
-    pub fn total_size(&self) -> usize {
-        molecule::unpack_number(self.as_slice()) as usize
-    }
-    pub fn field_count(&self) -> usize {
-        if self.total_size() == molecule::NUMBER_SIZE {
-            0
-        } else {
-            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
-        }
-    }
-    pub fn count_extra_fields(&self) -> usize {
-        self.field_count() - Self::FIELD_COUNT
-    }
-    pub fn has_extra_fields(&self) -> bool {
-        Self::FIELD_COUNT != self.field_count()
+impl<'r> LightClientMessageReader<'r> {
+    pub const ITEMS_COUNT: usize = 6;
+    pub fn item_id(&self) -> molecule::Number {
+        molecule::unpack_number(self.as_slice())
     }
-    pub fn filter(&self) -> BytesReader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[4..]) as usize;
-        if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[8..]) as usize;
-            BytesReader::new_unchecked(&self.as_slice()[start..end])
-        } else {
-            BytesReader::new_unchecked(&self.as_slice()[start..])
+    pub fn to_enum(&self) -> LightClientMessageUnionReader<'r> {
+        let inner = &self.as_slice()[molecule::NUMBER_SIZE..];
+        match self.item_id() {
+            0 => GetLastStateReader::new_unchecked(inner).into(),
+            1 => SendLastStateReader::new_unchecked(inner).into(),
+            2 => GetBlockHeadersReader::new_unchecked(inner).into(),
+            3 => SendBlockHeadersReader::new_unchecked(inner).into(),
+            4 => GetTransactionsProofReader::new_unchecked(inner).into(),
+            5 => SendTransactionsProofReader::new_unchecked(inner).into(),
+            _ => panic!("{}: invalid data", Self::NAME),
         }
     }
 }
-impl<'r> molecule::prelude::Reader<'r> for AddFilterReader<'r> {
-    type Entity = AddFilter;
-    const NAME: &'static str = "AddFilterReader";
+impl<'r> molecule::prelude::Reader<'r> for LightClientMessageReader<'r> {
+    type Entity = LightClientMessage;
+    const NAME: &'static str = "LightClientMessageReader";
     fn to_entity(&self) -> Self::Entity {
         Self::Entity::new_unchecked(self.as_slice().to_owned().into())
     }
     fn new_unchecked(slice: &'r [u8]) -> Self {
-        AddFilterReader(slice)
+        LightClientMessageReader(slice)
     }
     fn as_slice(&self) -> &'r [u8] {
         self.0
@@ -10936,80 +15833,321 @@ impl<'r> molecule::prelude::Reader<'r> for AddFilterReader<'r> {
         if slice_len < molecule::NUMBER_SIZE {
             return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
         }
-        let total_size = molecule::unpack_number(slice) as usize;
-        if slice_len != total_size {
-            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
-        }
-        if slice_len == molecule::NUMBER_SIZE && Self::FIELD_COUNT == 0 {
-            return Ok(());
+        let item_id = molecule::unpack_number(slice);
+        let inner_slice = &slice[molecule::NUMBER_SIZE..];
+        match item_id {
+            0 => GetLastStateReader::verify(inner_slice, compatible),
+            1 => SendLastStateReader::verify(inner_slice, compatible),
+            2 => GetBlockHeadersReader::verify(inner_slice, compatible),
+            3 => SendBlockHeadersReader::verify(inner_slice, compatible),
+            4 => GetTransactionsProofReader::verify(inner_slice, compatible),
+            5 => SendTransactionsProofReader::verify(inner_slice, compatible),
+            _ => ve!(Self, UnknownItem, Self::ITEMS_COUNT, item_id),
+        }?;
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct LightClientMessageBuilder(pub(crate) LightClientMessageUnion);
+impl LightClientMessageBuilder {
+    pub const ITEMS_COUNT: usize = 6;
+    pub fn set<I>(mut self, v: I) -> Self
+    where
+        I: ::core::convert::Into<LightClientMessageUnion>,
+    {
+        self.0 = v.into();
+        self
+    }
+}
+impl molecule::prelude::Builder for LightClientMessageBuilder {
+    type Entity = LightClientMessage;
+    const NAME: &'static str = "LightClientMessageBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE + self.0.as_slice().len()
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        writer.write_all(&molecule::pack_number(self.0.item_id()))?;
+        writer.write_all(self.0.as_slice())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        LightClientMessage::new_unchecked(inner.into())
+    }
+}
+#[derive(Debug, Clone)]
+pub enum LightClientMessageUnion {
+    GetLastState(GetLastState),
+    SendLastState(SendLastState),
+    GetBlockHeaders(GetBlockHeaders),
+    SendBlockHeaders(SendBlockHeaders),
+    GetTransactionsProof(GetTransactionsProof),
+    SendTransactionsProof(SendTransactionsProof),
+}
+#[derive(Debug, Clone, Copy)]
+pub enum LightClientMessageUnionReader<'r> {
+    GetLastState(GetLastStateReader<'r>),
+    SendLastState(SendLastStateReader<'r>),
+    GetBlockHeaders(GetBlockHeadersReader<'r>),
+    SendBlockHeaders(SendBlockHeadersReader<'r>),
+    GetTransactionsProof(GetTransactionsProofReader<'r>),
+    SendTransactionsProof(SendTransactionsProofReader<'r>),
+}
+impl ::core::default::Default for LightClientMessageUnion {
+    fn default() -> Self {
+        LightClientMessageUnion::GetLastState(::core::default::Default::default())
+    }
+}
+impl ::core::fmt::Display for LightClientMessageUnion {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        match self {
+            LightClientMessageUnion::GetLastState(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, GetLastState::NAME, item)
+            }
+            LightClientMessageUnion::SendLastState(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, SendLastState::NAME, item)
+            }
+            LightClientMessageUnion::GetBlockHeaders(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, GetBlockHeaders::NAME, item)
+            }
+            LightClientMessageUnion::SendBlockHeaders(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, SendBlockHeaders::NAME, item)
+            }
+            LightClientMessageUnion::GetTransactionsProof(ref item) => {
+                write!(
+                    f,
+                    "{}::{}({})",
+                    Self::NAME,
+                    GetTransactionsProof::NAME,
+                    item
+                )
+            }
+            LightClientMessageUnion::SendTransactionsProof(ref item) => {
+                write!(
+                    f,
+                    "{}::{}({})",
+                    Self::NAME,
+                    SendTransactionsProof::NAME,
+                    item
+                )
+            }
         }
-        if slice_len < molecule::NUMBER_SIZE * 2 {
-            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
+    }
+}
+impl<'r> ::core::fmt::Display for LightClientMessageUnionReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        match self {
+            LightClientMessageUnionReader::GetLastState(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, GetLastState::NAME, item)
+            }
+            LightClientMessageUnionReader::SendLastState(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, SendLastState::NAME, item)
+            }
+            LightClientMessageUnionReader::GetBlockHeaders(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, GetBlockHeaders::NAME, item)
+            }
+            LightClientMessageUnionReader::SendBlockHeaders(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, SendBlockHeaders::NAME, item)
+            }
+            LightClientMessageUnionReader::GetTransactionsProof(ref item) => {
+                write!(
+                    f,
+                    "{}::{}({})",
+                    Self::NAME,
+                    GetTransactionsProof::NAME,
+                    item
+                )
+            }
+            LightClientMessageUnionReader::SendTransactionsProof(ref item) => {
+                write!(
+                    f,
+                    "{}::{}({})",
+                    Self::NAME,
+                    SendTransactionsProof::NAME,
+                    item
+                )
+            }
         }
-        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
-        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
-            return ve!(Self, OffsetsNotMatch);
+    }
+}
+impl LightClientMessageUnion {
+    pub(crate) fn display_inner(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        match self {
+            LightClientMessageUnion::GetLastState(ref item) => write!(f, "{}", item),
+            LightClientMessageUnion::SendLastState(ref item) => write!(f, "{}", item),
+            LightClientMessageUnion::GetBlockHeaders(ref item) => write!(f, "{}", item),
+            LightClientMessageUnion::SendBlockHeaders(ref item) => write!(f, "{}", item),
+            LightClientMessageUnion::GetTransactionsProof(ref item) => write!(f, "{}", item),
+            LightClientMessageUnion::SendTransactionsProof(ref item) => write!(f, "{}", item),
         }
-        if slice_len < offset_first {
-            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
+    }
+}
+impl<'r> LightClientMessageUnionReader<'r> {
+    pub(crate) fn display_inner(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        match self {
+            LightClientMessageUnionReader::GetLastState(ref item) => write!(f, "{}", item),
+            LightClientMessageUnionReader::SendLastState(ref item) => write!(f, "{}", item),
+            LightClientMessageUnionReader::GetBlockHeaders(ref item) => write!(f, "{}", item),
+            LightClientMessageUnionReader::SendBlockHeaders(ref item) => write!(f, "{}", item),
+            LightClientMessageUnionReader::GetTransactionsProof(ref item) => write!(f, "{}", item),
+            LightClientMessageUnionReader::SendTransactionsProof(ref item) => write!(f, "{}", item),
         }
-        let field_count = offset_first / molecule::NUMBER_SIZE - 1;
-        if field_count < Self::FIELD_COUNT {
-            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
-        } else if !compatible && field_count > Self::FIELD_COUNT {
-            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
-        };
-        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
-            .chunks_exact(molecule::NUMBER_SIZE)
-            .map(|x| molecule::unpack_number(x) as usize)
-            .collect();
-        offsets.push(total_size);
-        if offsets.windows(2).any(|i| i[0] > i[1]) {
-            return ve!(Self, OffsetsNotMatch);
+    }
+}
+impl ::core::convert::From<GetLastState> for LightClientMessageUnion {
+    fn from(item: GetLastState) -> Self {
+        LightClientMessageUnion::GetLastState(item)
+    }
+}
+impl ::core::convert::From<SendLastState> for LightClientMessageUnion {
+    fn from(item: SendLastState) -> Self {
+        LightClientMessageUnion::SendLastState(item)
+    }
+}
+impl ::core::convert::From<GetBlockHeaders> for LightClientMessageUnion {
+    fn from(item: GetBlockHeaders) -> Self {
+        LightClientMessageUnion::GetBlockHeaders(item)
+    }
+}
+impl ::core::convert::From<SendBlockHeaders> for LightClientMessageUnion {
+    fn from(item: SendBlockHeaders) -> Self {
+        LightClientMessageUnion::SendBlockHeaders(item)
+    }
+}
+impl ::core::convert::From<GetTransactionsProof> for LightClientMessageUnion {
+    fn from(item: GetTransactionsProof) -> Self {
+        LightClientMessageUnion::GetTransactionsProof(item)
+    }
+}
+impl ::core::convert::From<SendTransactionsProof> for LightClientMessageUnion {
+    fn from(item: SendTransactionsProof) -> Self {
+        LightClientMessageUnion::SendTransactionsProof(item)
+    }
+}
+impl<'r> ::core::convert::From<GetLastStateReader<'r>> for LightClientMessageUnionReader<'r> {
+    fn from(item: GetLastStateReader<'r>) -> Self {
+        LightClientMessageUnionReader::GetLastState(item)
+    }
+}
+impl<'r> ::core::convert::From<SendLastStateReader<'r>> for LightClientMessageUnionReader<'r> {
+    fn from(item: SendLastStateReader<'r>) -> Self {
+        LightClientMessageUnionReader::SendLastState(item)
+    }
+}
+impl<'r> ::core::convert::From<GetBlockHeadersReader<'r>> for LightClientMessageUnionReader<'r> {
+    fn from(item: GetBlockHeadersReader<'r>) -> Self {
+        LightClientMessageUnionReader::GetBlockHeaders(item)
+    }
+}
+impl<'r> ::core::convert::From<SendBlockHeadersReader<'r>> for LightClientMessageUnionReader<'r> {
+    fn from(item: SendBlockHeadersReader<'r>) -> Self {
+        LightClientMessageUnionReader::SendBlockHeaders(item)
+    }
+}
+impl<'r> ::core::convert::From<GetTransactionsProofReader<'r>>
+    for LightClientMessageUnionReader<'r>
+{
+    fn from(item: GetTransactionsProofReader<'r>) -> Self {
+        LightClientMessageUnionReader::GetTransactionsProof(item)
+    }
+}
+impl<'r> ::core::convert::From<SendTransactionsProofReader<'r>>
+    for LightClientMessageUnionReader<'r>
+{
+    fn from(item: SendTransactionsProofReader<'r>) -> Self {
+        LightClientMessageUnionReader::SendTransactionsProof(item)
+    }
+}
+impl LightClientMessageUnion {
+    pub const NAME: &'static str = "LightClientMessageUnion";
+    pub fn as_bytes(&self) -> molecule::bytes::Bytes {
+        match self {
+            LightClientMessageUnion::GetLastState(item) => item.as_bytes(),
+            LightClientMessageUnion::SendLastState(item) => item.as_bytes(),
+            LightClientMessageUnion::GetBlockHeaders(item) => item.as_bytes(),
+            LightClientMessageUnion::SendBlockHeaders(item) => item.as_bytes(),
+            LightClientMessageUnion::GetTransactionsProof(item) => item.as_bytes(),
+            LightClientMessageUnion::SendTransactionsProof(item) => item.as_bytes(),
         }
-        BytesReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
-        Ok(())
     }
-}
-#[derive(Debug, Default)]
-pub struct AddFilterBuilder {
-    pub(crate) filter: Bytes,
-}
-impl AddFilterBuilder {
-    pub const FIELD_COUNT: usize = 1;
-    pub fn filter(mut self, v: Bytes) -> Self {
-        self.filter = v;
-        self
+    pub fn as_slice(&self) -> &[u8] {
+        match self {
+            LightClientMessageUnion::GetLastState(item) => item.as_slice(),
+            LightClientMessageUnion::SendLastState(item) => item.as_slice(),
+            LightClientMessageUnion::GetBlockHeaders(item) => item.as_slice(),
+            LightClientMessageUnion::SendBlockHeaders(item) => item.as_slice(),
+            LightClientMessageUnion::GetTransactionsProof(item) => item.as_slice(),
+            LightClientMessageUnion::SendTransactionsProof(item) => item.as_slice(),
+        }
+    }
+    pub fn item_id(&self) -> molecule::Number {
+        match self {
+            LightClientMessageUnion::GetLastState(_) => 0,
+            LightClientMessageUnion::SendLastState(_) => 1,
+            LightClientMessageUnion::GetBlockHeaders(_) => 2,
+            LightClientMessageUnion::SendBlockHeaders(_) => 3,
+            LightClientMessageUnion::GetTransactionsProof(_) => 4,
+            LightClientMessageUnion::SendTransactionsProof(_) => 5,
+        }
+    }
+    pub fn item_name(&self) -> &str {
+        match self {
+            LightClientMessageUnion::GetLastState(_) => "GetLastState",
+            LightClientMessageUnion::SendLastState(_) => "SendLastState",
+            LightClientMessageUnion::GetBlockHeaders(_) => "GetBlockHeaders",
+            LightClientMessageUnion::SendBlockHeaders(_) => "SendBlockHeaders",
+            LightClientMessageUnion::GetTransactionsProof(_) => "GetTransactionsProof",
+            LightClientMessageUnion::SendTransactionsProof(_) => "SendTransactionsProof",
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> LightClientMessageUnionReader<'r> {
+        match self {
+            LightClientMessageUnion::GetLastState(item) => item.as_reader().into(),
+            LightClientMessageUnion::SendLastState(item) => item.as_reader().into(),
+            LightClientMessageUnion::GetBlockHeaders(item) => item.as_reader().into(),
+            LightClientMessageUnion::SendBlockHeaders(item) => item.as_reader().into(),
+            LightClientMessageUnion::GetTransactionsProof(item) => item.as_reader().into(),
+            LightClientMessageUnion::SendTransactionsProof(item) => item.as_reader().into(),
+        }
     }
 }
-impl molecule::prelude::Builder for AddFilterBuilder {
-    type Entity = AddFilter;
-    const NAME: &'static str = "AddFilterBuilder";
-    fn expected_length(&self) -> usize {
-        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1) + self.filter.as_slice().len()
+impl<'r> LightClientMessageUnionReader<'r> {
+    pub const NAME: &'r str = "LightClientMessageUnionReader";
+    pub fn as_slice(&self) -> &'r [u8] {
+        match self {
+            LightClientMessageUnionReader::GetLastState(item) => item.as_slice(),
+            LightClientMessageUnionReader::SendLastState(item) => item.as_slice(),
+            LightClientMessageUnionReader::GetBlockHeaders(item) => item.as_slice(),
+            LightClientMessageUnionReader::SendBlockHeaders(item) => item.as_slice(),
+            LightClientMessageUnionReader::GetTransactionsProof(item) => item.as_slice(),
+            LightClientMessageUnionReader::SendTransactionsProof(item) => item.as_slice(),
+        }
     }
-    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
-        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
-        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
-        offsets.push(total_size);
-        total_size += self.filter.as_slice().len();
-        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
-        for offset in offsets.into_iter() {
-            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+    pub fn item_id(&self) -> molecule::Number {
+        match self {
+            LightClientMessageUnionReader::GetLastState(_) => 0,
+            LightClientMessageUnionReader::SendLastState(_) => 1,
+            LightClientMessageUnionReader::GetBlockHeaders(_) => 2,
+            LightClientMessageUnionReader::SendBlockHeaders(_) => 3,
+            LightClientMessageUnionReader::GetTransactionsProof(_) => 4,
+            LightClientMessageUnionReader::SendTransactionsProof(_) => 5,
         }
-        writer.write_all(self.filter.as_slice())?;
-        Ok(())
     }
-    fn build(&self) -> Self::Entity {
-        let mut inner = Vec::with_capacity(self.expected_length());
-        self.write(&mut inner)
-            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        AddFilter::new_unchecked(inner.into())
+    pub fn item_name(&self) -> &str {
+        match self {
+            LightClientMessageUnionReader::GetLastState(_) => "GetLastState",
+            LightClientMessageUnionReader::SendLastState(_) => "SendLastState",
+            LightClientMessageUnionReader::GetBlockHeaders(_) => "GetBlockHeaders",
+            LightClientMessageUnionReader::SendBlockHeaders(_) => "SendBlockHeaders",
+            LightClientMessageUnionReader::GetTransactionsProof(_) => "GetTransactionsProof",
+            LightClientMessageUnionReader::SendTransactionsProof(_) => "SendTransactionsProof",
+        }
     }
 }
 #[derive(Clone)]
-pub struct ClearFilter(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for ClearFilter {
+pub struct GetLastState(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for GetLastState {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -11018,12 +16156,12 @@ impl ::core::fmt::LowerHex for ClearFilter {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Debug for ClearFilter {
+impl ::core::fmt::Debug for GetLastState {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::fmt::Display for ClearFilter {
+impl ::core::fmt::Display for GetLastState {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
         let extra_count = self.count_extra_fields();
@@ -11033,13 +16171,13 @@ impl ::core::fmt::Display for ClearFilter {
         write!(f, " }}")
     }
 }
-impl ::core::default::Default for ClearFilter {
+impl ::core::default::Default for GetLastState {
     fn default() -> Self {
         let v: Vec<u8> = vec![4, 0, 0, 0];
-        ClearFilter::new_unchecked(v.into())
+        GetLastState::new_unchecked(v.into())
     }
 }
-impl ClearFilter {
+impl GetLastState {
     pub const FIELD_COUNT: usize = 0;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
@@ -11057,15 +16195,15 @@ impl ClearFilter {
     pub fn has_extra_fields(&self) -> bool {
         Self::FIELD_COUNT != self.field_count()
     }
-    pub fn as_reader<'r>(&'r self) -> ClearFilterReader<'r> {
-        ClearFilterReader::new_unchecked(self.as_slice())
+    pub fn as_reader<'r>(&'r self) -> GetLastStateReader<'r> {
+        GetLastStateReader::new_unchecked(self.as_slice())
     }
 }
-impl molecule::prelude::Entity for ClearFilter {
-    type Builder = ClearFilterBuilder;
-    const NAME: &'static str = "ClearFilter";
+impl molecule::prelude::Entity for GetLastState {
+    type Builder = GetLastStateBuilder;
+    const NAME: &'static str = "GetLastState";
     fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        ClearFilter(data)
+        GetLastState(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -11074,10 +16212,10 @@ impl molecule::prelude::Entity for ClearFilter {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        ClearFilterReader::from_slice(slice).map(|reader| reader.to_entity())
+        GetLastStateReader::from_slice(slice).map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        ClearFilterReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+        GetLastStateReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
         ::core::default::Default::default()
@@ -11087,8 +16225,8 @@ impl molecule::prelude::Entity for ClearFilter {
     }
 }
 #[derive(Clone, Copy)]
-pub struct ClearFilterReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for ClearFilterReader<'r> {
+pub struct GetLastStateReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for GetLastStateReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -11097,12 +16235,12 @@ impl<'r> ::core::fmt::LowerHex for ClearFilterReader<'r> {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl<'r> ::core::fmt::Debug for ClearFilterReader<'r> {
+impl<'r> ::core::fmt::Debug for GetLastStateReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::core::fmt::Display for ClearFilterReader<'r> {
+impl<'r> ::core::fmt::Display for GetLastStateReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
         let extra_count = self.count_extra_fields();
@@ -11112,7 +16250,7 @@ impl<'r> ::core::fmt::Display for ClearFilterReader<'r> {
         write!(f, " }}")
     }
 }
-impl<'r> ClearFilterReader<'r> {
+impl<'r> GetLastStateReader<'r> {
     pub const FIELD_COUNT: usize = 0;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
@@ -11131,14 +16269,14 @@ impl<'r> ClearFilterReader<'r> {
         Self::FIELD_COUNT != self.field_count()
     }
 }
-impl<'r> molecule::prelude::Reader<'r> for ClearFilterReader<'r> {
-    type Entity = ClearFilter;
-    const NAME: &'static str = "ClearFilterReader";
+impl<'r> molecule::prelude::Reader<'r> for GetLastStateReader<'r> {
+    type Entity = GetLastState;
+    const NAME: &'static str = "GetLastStateReader";
     fn to_entity(&self) -> Self::Entity {
         Self::Entity::new_unchecked(self.as_slice().to_owned().into())
     }
     fn new_unchecked(slice: &'r [u8]) -> Self {
-        ClearFilterReader(slice)
+        GetLastStateReader(slice)
     }
     fn as_slice(&self) -> &'r [u8] {
         self.0
@@ -11160,13 +16298,13 @@ impl<'r> molecule::prelude::Reader<'r> for ClearFilterReader<'r> {
     }
 }
 #[derive(Debug, Default)]
-pub struct ClearFilterBuilder {}
-impl ClearFilterBuilder {
+pub struct GetLastStateBuilder {}
+impl GetLastStateBuilder {
     pub const FIELD_COUNT: usize = 0;
 }
-impl molecule::prelude::Builder for ClearFilterBuilder {
-    type Entity = ClearFilter;
-    const NAME: &'static str = "ClearFilterBuilder";
+impl molecule::prelude::Builder for GetLastStateBuilder {
+    type Entity = GetLastState;
+    const NAME: &'static str = "GetLastStateBuilder";
     fn expected_length(&self) -> usize {
         molecule::NUMBER_SIZE
     }
@@ -11180,12 +16318,12 @@ impl molecule::prelude::Builder for ClearFilterBuilder {
         let mut inner = Vec::with_capacity(self.expected_length());
         self.write(&mut inner)
             .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        ClearFilter::new_unchecked(inner.into())
+        GetLastState::new_unchecked(inner.into())
     }
 }
 #[derive(Clone)]
-pub struct FilteredBlock(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for FilteredBlock {
+pub struct SendLastState(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for SendLastState {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -11194,17 +16332,16 @@ impl ::core::fmt::LowerHex for FilteredBlock {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Debug for FilteredBlock {
+impl ::core::fmt::Debug for SendLastState {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::fmt::Display for FilteredBlock {
+impl ::core::fmt::Display for SendLastState {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "header", self.header())?;
-        write!(f, ", {}: {}", "transactions", self.transactions())?;
-        write!(f, ", {}: {}", "proof", self.proof())?;
+        write!(f, "{}: {}", "tip_header", self.tip_header())?;
+        write!(f, ", {}: {}", "total_difficulty", self.total_difficulty())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -11212,24 +16349,24 @@ impl ::core::fmt::Display for FilteredBlock {
         write!(f, " }}")
     }
 }
-impl ::core::default::Default for FilteredBlock {
+impl ::core::default::Default for SendLastState {
     fn default() -> Self {
         let v: Vec<u8> = vec![
-            248, 0, 0, 0, 16, 0, 0, 0, 224, 0, 0, 0, 228, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            252, 0, 0, 0, 12, 0, 0, 0, 220, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
             0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
             0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
             0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
             0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
             0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
             0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 20, 0,
-            0, 0, 12, 0, 0, 0, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         ];
-        FilteredBlock::new_unchecked(v.into())
+        SendLastState::new_unchecked(v.into())
     }
 }
-impl FilteredBlock {
-    pub const FIELD_COUNT: usize = 3;
+impl SendLastState {
+    pub const FIELD_COUNT: usize = 2;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -11246,37 +16383,31 @@ impl FilteredBlock {
     pub fn has_extra_fields(&self) -> bool {
         Self::FIELD_COUNT != self.field_count()
     }
-    pub fn header(&self) -> Header {
+    pub fn tip_header(&self) -> Header {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[4..]) as usize;
         let end = molecule::unpack_number(&slice[8..]) as usize;
         Header::new_unchecked(self.0.slice(start..end))
     }
-    pub fn transactions(&self) -> TransactionVec {
+    pub fn total_difficulty(&self) -> Uint256 {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[8..]) as usize;
-        let end = molecule::unpack_number(&slice[12..]) as usize;
-        TransactionVec::new_unchecked(self.0.slice(start..end))
-    }
-    pub fn proof(&self) -> MerkleProof {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[12..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[16..]) as usize;
-            MerkleProof::new_unchecked(self.0.slice(start..end))
+            let end = molecule::unpack_number(&slice[12..]) as usize;
+            Uint256::new_unchecked(self.0.slice(start..end))
         } else {
-            MerkleProof::new_unchecked(self.0.slice(start..))
+            Uint256::new_unchecked(self.0.slice(start..))
         }
     }
-    pub fn as_reader<'r>(&'r self) -> FilteredBlockReader<'r> {
-        FilteredBlockReader::new_unchecked(self.as_slice())
+    pub fn as_reader<'r>(&'r self) -> SendLastStateReader<'r> {
+        SendLastStateReader::new_unchecked(self.as_slice())
     }
 }
-impl molecule::prelude::Entity for FilteredBlock {
-    type Builder = FilteredBlockBuilder;
-    const NAME: &'static str = "FilteredBlock";
+impl molecule::prelude::Entity for SendLastState {
+    type Builder = SendLastStateBuilder;
+    const NAME: &'static str = "SendLastState";
     fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        FilteredBlock(data)
+        SendLastState(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -11285,24 +16416,23 @@ impl molecule::prelude::Entity for FilteredBlock {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        FilteredBlockReader::from_slice(slice).map(|reader| reader.to_entity())
+        SendLastStateReader::from_slice(slice).map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        FilteredBlockReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+        SendLastStateReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
         ::core::default::Default::default()
     }
     fn as_builder(self) -> Self::Builder {
         Self::new_builder()
-            .header(self.header())
-            .transactions(self.transactions())
-            .proof(self.proof())
+            .tip_header(self.tip_header())
+            .total_difficulty(self.total_difficulty())
     }
 }
 #[derive(Clone, Copy)]
-pub struct FilteredBlockReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for FilteredBlockReader<'r> {
+pub struct SendLastStateReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for SendLastStateReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -11311,17 +16441,16 @@ impl<'r> ::core::fmt::LowerHex for FilteredBlockReader<'r> {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl<'r> ::core::fmt::Debug for FilteredBlockReader<'r> {
+impl<'r> ::core::fmt::Debug for SendLastStateReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::core::fmt::Display for FilteredBlockReader<'r> {
+impl<'r> ::core::fmt::Display for SendLastStateReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "header", self.header())?;
-        write!(f, ", {}: {}", "transactions", self.transactions())?;
-        write!(f, ", {}: {}", "proof", self.proof())?;
+        write!(f, "{}: {}", "tip_header", self.tip_header())?;
+        write!(f, ", {}: {}", "total_difficulty", self.total_difficulty())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -11329,8 +16458,8 @@ impl<'r> ::core::fmt::Display for FilteredBlockReader<'r> {
         write!(f, " }}")
     }
 }
-impl<'r> FilteredBlockReader<'r> {
-    pub const FIELD_COUNT: usize = 3;
+impl<'r> SendLastStateReader<'r> {
+    pub const FIELD_COUNT: usize = 2;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -11347,37 +16476,31 @@ impl<'r> FilteredBlockReader<'r> {
     pub fn has_extra_fields(&self) -> bool {
         Self::FIELD_COUNT != self.field_count()
     }
-    pub fn header(&self) -> HeaderReader<'r> {
+    pub fn tip_header(&self) -> HeaderReader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[4..]) as usize;
         let end = molecule::unpack_number(&slice[8..]) as usize;
         HeaderReader::new_unchecked(&self.as_slice()[start..end])
     }
-    pub fn transactions(&self) -> TransactionVecReader<'r> {
+    pub fn total_difficulty(&self) -> Uint256Reader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[8..]) as usize;
-        let end = molecule::unpack_number(&slice[12..]) as usize;
-        TransactionVecReader::new_unchecked(&self.as_slice()[start..end])
-    }
-    pub fn proof(&self) -> MerkleProofReader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[12..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[16..]) as usize;
-            MerkleProofReader::new_unchecked(&self.as_slice()[start..end])
+            let end = molecule::unpack_number(&slice[12..]) as usize;
+            Uint256Reader::new_unchecked(&self.as_slice()[start..end])
         } else {
-            MerkleProofReader::new_unchecked(&self.as_slice()[start..])
+            Uint256Reader::new_unchecked(&self.as_slice()[start..])
         }
     }
 }
-impl<'r> molecule::prelude::Reader<'r> for FilteredBlockReader<'r> {
-    type Entity = FilteredBlock;
-    const NAME: &'static str = "FilteredBlockReader";
+impl<'r> molecule::prelude::Reader<'r> for SendLastStateReader<'r> {
+    type Entity = SendLastState;
+    const NAME: &'static str = "SendLastStateReader";
     fn to_entity(&self) -> Self::Entity {
         Self::Entity::new_unchecked(self.as_slice().to_owned().into())
     }
     fn new_unchecked(slice: &'r [u8]) -> Self {
-        FilteredBlockReader(slice)
+        SendLastStateReader(slice)
     }
     fn as_slice(&self) -> &'r [u8] {
         self.0
@@ -11420,69 +16543,59 @@ impl<'r> molecule::prelude::Reader<'r> for FilteredBlockReader<'r> {
             return ve!(Self, OffsetsNotMatch);
         }
         HeaderReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
-        TransactionVecReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
-        MerkleProofReader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
+        Uint256Reader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
         Ok(())
     }
 }
 #[derive(Debug, Default)]
-pub struct FilteredBlockBuilder {
-    pub(crate) header: Header,
-    pub(crate) transactions: TransactionVec,
-    pub(crate) proof: MerkleProof,
+pub struct SendLastStateBuilder {
+    pub(crate) tip_header: Header,
+    pub(crate) total_difficulty: Uint256,
 }
-impl FilteredBlockBuilder {
-    pub const FIELD_COUNT: usize = 3;
-    pub fn header(mut self, v: Header) -> Self {
-        self.header = v;
-        self
-    }
-    pub fn transactions(mut self, v: TransactionVec) -> Self {
-        self.transactions = v;
+impl SendLastStateBuilder {
+    pub const FIELD_COUNT: usize = 2;
+    pub fn tip_header(mut self, v: Header) -> Self {
+        self.tip_header = v;
         self
     }
-    pub fn proof(mut self, v: MerkleProof) -> Self {
-        self.proof = v;
+    pub fn total_difficulty(mut self, v: Uint256) -> Self {
+        self.total_difficulty = v;
         self
     }
 }
-impl molecule::prelude::Builder for FilteredBlockBuilder {
-    type Entity = FilteredBlock;
-    const NAME: &'static str = "FilteredBlockBuilder";
+impl molecule::prelude::Builder for SendLastStateBuilder {
+    type Entity = SendLastState;
+    const NAME: &'static str = "SendLastStateBuilder";
     fn expected_length(&self) -> usize {
         molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
-            + self.header.as_slice().len()
-            + self.transactions.as_slice().len()
-            + self.proof.as_slice().len()
+            + self.tip_header.as_slice().len()
+            + self.total_difficulty.as_slice().len()
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
         let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
         let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
         offsets.push(total_size);
-        total_size += self.header.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.transactions.as_slice().len();
+        total_size += self.tip_header.as_slice().len();
         offsets.push(total_size);
-        total_size += self.proof.as_slice().len();
+        total_size += self.total_difficulty.as_slice().len();
         writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
         for offset in offsets.into_iter() {
             writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
         }
-        writer.write_all(self.header.as_slice())?;
-        writer.write_all(self.transactions.as_slice())?;
-        writer.write_all(self.proof.as_slice())?;
+        writer.write_all(self.tip_header.as_slice())?;
+        writer.write_all(self.total_difficulty.as_slice())?;
         Ok(())
     }
     fn build(&self) -> Self::Entity {
         let mut inner = Vec::with_capacity(self.expected_length());
         self.write(&mut inner)
             .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        FilteredBlock::new_unchecked(inner.into())
+        SendLastState::new_unchecked(inner.into())
     }
 }
 #[derive(Clone)]
-pub struct MerkleProof(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for MerkleProof {
+pub struct GetBlockHeaders(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for GetBlockHeaders {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -11491,16 +16604,16 @@ impl ::core::fmt::LowerHex for MerkleProof {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Debug for MerkleProof {
+impl ::core::fmt::Debug for GetBlockHeaders {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::fmt::Display for MerkleProof {
+impl ::core::fmt::Display for GetBlockHeaders {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "indices", self.indices())?;
-        write!(f, ", {}: {}", "lemmas", self.lemmas())?;
+        write!(f, "{}: {}", "start_number", self.start_number())?;
+        write!(f, ", {}: {}", "count", self.count())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -11508,15 +16621,15 @@ impl ::core::fmt::Display for MerkleProof {
         write!(f, " }}")
     }
 }
-impl ::core::default::Default for MerkleProof {
+impl ::core::default::Default for GetBlockHeaders {
     fn default() -> Self {
         let v: Vec<u8> = vec![
-            20, 0, 0, 0, 12, 0, 0, 0, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            24, 0, 0, 0, 12, 0, 0, 0, 20, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         ];
-        MerkleProof::new_unchecked(v.into())
+        GetBlockHeaders::new_unchecked(v.into())
     }
 }
-impl MerkleProof {
+impl GetBlockHeaders {
     pub const FIELD_COUNT: usize = 2;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
@@ -11534,31 +16647,31 @@ impl MerkleProof {
     pub fn has_extra_fields(&self) -> bool {
         Self::FIELD_COUNT != self.field_count()
     }
-    pub fn indices(&self) -> Uint32Vec {
+    pub fn start_number(&self) -> Uint64 {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[4..]) as usize;
         let end = molecule::unpack_number(&slice[8..]) as usize;
-        Uint32Vec::new_unchecked(self.0.slice(start..end))
+        Uint64::new_unchecked(self.0.slice(start..end))
     }
-    pub fn lemmas(&self) -> Byte32Vec {
+    pub fn count(&self) -> Uint32 {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[8..]) as usize;
         if self.has_extra_fields() {
             let end = molecule::unpack_number(&slice[12..]) as usize;
-            Byte32Vec::new_unchecked(self.0.slice(start..end))
+            Uint32::new_unchecked(self.0.slice(start..end))
         } else {
-            Byte32Vec::new_unchecked(self.0.slice(start..))
+            Uint32::new_unchecked(self.0.slice(start..))
         }
     }
-    pub fn as_reader<'r>(&'r self) -> MerkleProofReader<'r> {
-        MerkleProofReader::new_unchecked(self.as_slice())
+    pub fn as_reader<'r>(&'r self) -> GetBlockHeadersReader<'r> {
+        GetBlockHeadersReader::new_unchecked(self.as_slice())
     }
 }
-impl molecule::prelude::Entity for MerkleProof {
-    type Builder = MerkleProofBuilder;
-    const NAME: &'static str = "MerkleProof";
+impl molecule::prelude::Entity for GetBlockHeaders {
+    type Builder = GetBlockHeadersBuilder;
+    const NAME: &'static str = "GetBlockHeaders";
     fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        MerkleProof(data)
+        GetBlockHeaders(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -11567,23 +16680,23 @@ impl molecule::prelude::Entity for MerkleProof {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        MerkleProofReader::from_slice(slice).map(|reader| reader.to_entity())
+        GetBlockHeadersReader::from_slice(slice).map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        MerkleProofReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+        GetBlockHeadersReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
         ::core::default::Default::default()
     }
     fn as_builder(self) -> Self::Builder {
         Self::new_builder()
-            .indices(self.indices())
-            .lemmas(self.lemmas())
+            .start_number(self.start_number())
+            .count(self.count())
     }
 }
 #[derive(Clone, Copy)]
-pub struct MerkleProofReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for MerkleProofReader<'r> {
+pub struct GetBlockHeadersReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for GetBlockHeadersReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -11592,16 +16705,16 @@ impl<'r> ::core::fmt::LowerHex for MerkleProofReader<'r> {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl<'r> ::core::fmt::Debug for MerkleProofReader<'r> {
+impl<'r> ::core::fmt::Debug for GetBlockHeadersReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::core::fmt::Display for MerkleProofReader<'r> {
+impl<'r> ::core::fmt::Display for GetBlockHeadersReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "indices", self.indices())?;
-        write!(f, ", {}: {}", "lemmas", self.lemmas())?;
+        write!(f, "{}: {}", "start_number", self.start_number())?;
+        write!(f, ", {}: {}", "count", self.count())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -11609,7 +16722,7 @@ impl<'r> ::core::fmt::Display for MerkleProofReader<'r> {
         write!(f, " }}")
     }
 }
-impl<'r> MerkleProofReader<'r> {
+impl<'r> GetBlockHeadersReader<'r> {
     pub const FIELD_COUNT: usize = 2;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
@@ -11627,31 +16740,31 @@ impl<'r> MerkleProofReader<'r> {
     pub fn has_extra_fields(&self) -> bool {
         Self::FIELD_COUNT != self.field_count()
     }
-    pub fn indices(&self) -> Uint32VecReader<'r> {
+    pub fn start_number(&self) -> Uint64Reader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[4..]) as usize;
         let end = molecule::unpack_number(&slice[8..]) as usize;
-        Uint32VecReader::new_unchecked(&self.as_slice()[start..end])
+        Uint64Reader::new_unchecked(&self.as_slice()[start..end])
     }
-    pub fn lemmas(&self) -> Byte32VecReader<'r> {
+    pub fn count(&self) -> Uint32Reader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[8..]) as usize;
         if self.has_extra_fields() {
             let end = molecule::unpack_number(&slice[12..]) as usize;
-            Byte32VecReader::new_unchecked(&self.as_slice()[start..end])
+            Uint32Reader::new_unchecked(&self.as_slice()[start..end])
         } else {
-            Byte32VecReader::new_unchecked(&self.as_slice()[start..])
+            Uint32Reader::new_unchecked(&self.as_slice()[start..])
         }
     }
 }
-impl<'r> molecule::prelude::Reader<'r> for MerkleProofReader<'r> {
-    type Entity = MerkleProof;
-    const NAME: &'static str = "MerkleProofReader";
+impl<'r> molecule::prelude::Reader<'r> for GetBlockHeadersReader<'r> {
+    type Entity = GetBlockHeaders;
+    const NAME: &'static str = "GetBlockHeadersReader";
     fn to_entity(&self) -> Self::Entity {
         Self::Entity::new_unchecked(self.as_slice().to_owned().into())
     }
     fn new_unchecked(slice: &'r [u8]) -> Self {
-        MerkleProofReader(slice)
+        GetBlockHeadersReader(slice)
     }
     fn as_slice(&self) -> &'r [u8] {
         self.0
@@ -11693,60 +16806,60 @@ impl<'r> molecule::prelude::Reader<'r> for MerkleProofReader<'r> {
         if offsets.windows(2).any(|i| i[0] > i[1]) {
             return ve!(Self, OffsetsNotMatch);
         }
-        Uint32VecReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
-        Byte32VecReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        Uint64Reader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        Uint32Reader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
         Ok(())
     }
 }
 #[derive(Debug, Default)]
-pub struct MerkleProofBuilder {
-    pub(crate) indices: Uint32Vec,
-    pub(crate) lemmas: Byte32Vec,
+pub struct GetBlockHeadersBuilder {
+    pub(crate) start_number: Uint64,
+    pub(crate) count: Uint32,
 }
-impl MerkleProofBuilder {
+impl GetBlockHeadersBuilder {
     pub const FIELD_COUNT: usize = 2;
-    pub fn indices(mut self, v: Uint32Vec) -> Self {
-        self.indices = v;
+    pub fn start_number(mut self, v: Uint64) -> Self {
+        self.start_number = v;
         self
     }
-    pub fn lemmas(mut self, v: Byte32Vec) -> Self {
-        self.lemmas = v;
+    pub fn count(mut self, v: Uint32) -> Self {
+        self.count = v;
         self
     }
 }
-impl molecule::prelude::Builder for MerkleProofBuilder {
-    type Entity = MerkleProof;
-    const NAME: &'static str = "MerkleProofBuilder";
+impl molecule::prelude::Builder for GetBlockHeadersBuilder {
+    type Entity = GetBlockHeaders;
+    const NAME: &'static str = "GetBlockHeadersBuilder";
     fn expected_length(&self) -> usize {
-        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
-            + self.indices.as_slice().len()
-            + self.lemmas.as_slice().len()
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
+            + self.start_number.as_slice().len()
+            + self.count.as_slice().len()
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
         let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
         let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
         offsets.push(total_size);
-        total_size += self.indices.as_slice().len();
+        total_size += self.start_number.as_slice().len();
         offsets.push(total_size);
-        total_size += self.lemmas.as_slice().len();
+        total_size += self.count.as_slice().len();
         writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
         for offset in offsets.into_iter() {
             writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
         }
-        writer.write_all(self.indices.as_slice())?;
-        writer.write_all(self.lemmas.as_slice())?;
+        writer.write_all(self.start_number.as_slice())?;
+        writer.write_all(self.count.as_slice())?;
         Ok(())
     }
     fn build(&self) -> Self::Entity {
         let mut inner = Vec::with_capacity(self.expected_length());
         self.write(&mut inner)
             .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        MerkleProof::new_unchecked(inner.into())
+        GetBlockHeaders::new_unchecked(inner.into())
     }
 }
 #[derive(Clone)]
-pub struct InIBD(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for InIBD {
+pub struct SendBlockHeaders(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for SendBlockHeaders {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -11755,29 +16868,30 @@ impl ::core::fmt::LowerHex for InIBD {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Debug for InIBD {
+impl ::core::fmt::Debug for SendBlockHeaders {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::fmt::Display for InIBD {
+impl ::core::fmt::Display for SendBlockHeaders {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "headers", self.headers())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
-            write!(f, ".. ({} fields)", extra_count)?;
+            write!(f, ", .. ({} fields)", extra_count)?;
         }
         write!(f, " }}")
     }
 }
-impl ::core::default::Default for InIBD {
+impl ::core::default::Default for SendBlockHeaders {
     fn default() -> Self {
-        let v: Vec<u8> = vec![4, 0, 0, 0];
-        InIBD::new_unchecked(v.into())
+        let v: Vec<u8> = vec![12, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0];
+        SendBlockHeaders::new_unchecked(v.into())
     }
 }
-impl InIBD {
-    pub const FIELD_COUNT: usize = 0;
+impl SendBlockHeaders {
+    pub const FIELD_COUNT: usize = 1;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -11794,15 +16908,25 @@ impl InIBD {
     pub fn has_extra_fields(&self) -> bool {
         Self::FIELD_COUNT != self.field_count()
     }
-    pub fn as_reader<'r>(&'r self) -> InIBDReader<'r> {
-        InIBDReader::new_unchecked(self.as_slice())
+    pub fn headers(&self) -> HeaderVec {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[8..]) as usize;
+            HeaderVec::new_unchecked(self.0.slice(start..end))
+        } else {
+            HeaderVec::new_unchecked(self.0.slice(start..))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> SendBlockHeadersReader<'r> {
+        SendBlockHeadersReader::new_unchecked(self.as_slice())
     }
 }
-impl molecule::prelude::Entity for InIBD {
-    type Builder = InIBDBuilder;
-    const NAME: &'static str = "InIBD";
+impl molecule::prelude::Entity for SendBlockHeaders {
+    type Builder = SendBlockHeadersBuilder;
+    const NAME: &'static str = "SendBlockHeaders";
     fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        InIBD(data)
+        SendBlockHeaders(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -11811,21 +16935,21 @@ impl molecule::prelude::Entity for InIBD {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        InIBDReader::from_slice(slice).map(|reader| reader.to_entity())
+        SendBlockHeadersReader::from_slice(slice).map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        InIBDReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+        SendBlockHeadersReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
         ::core::default::Default::default()
     }
     fn as_builder(self) -> Self::Builder {
-        Self::new_builder()
+        Self::new_builder().headers(self.headers())
     }
 }
 #[derive(Clone, Copy)]
-pub struct InIBDReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for InIBDReader<'r> {
+pub struct SendBlockHeadersReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for SendBlockHeadersReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -11834,23 +16958,24 @@ impl<'r> ::core::fmt::LowerHex for InIBDReader<'r> {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl<'r> ::core::fmt::Debug for InIBDReader<'r> {
+impl<'r> ::core::fmt::Debug for SendBlockHeadersReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::core::fmt::Display for InIBDReader<'r> {
+impl<'r> ::core::fmt::Display for SendBlockHeadersReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "headers", self.headers())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
-            write!(f, ".. ({} fields)", extra_count)?;
+            write!(f, ", .. ({} fields)", extra_count)?;
         }
         write!(f, " }}")
     }
 }
-impl<'r> InIBDReader<'r> {
-    pub const FIELD_COUNT: usize = 0;
+impl<'r> SendBlockHeadersReader<'r> {
+    pub const FIELD_COUNT: usize = 1;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -11867,15 +16992,25 @@ impl<'r> InIBDReader<'r> {
     pub fn has_extra_fields(&self) -> bool {
         Self::FIELD_COUNT != self.field_count()
     }
+    pub fn headers(&self) -> HeaderVecReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[8..]) as usize;
+            HeaderVecReader::new_unchecked(&self.as_slice()[start..end])
+        } else {
+            HeaderVecReader::new_unchecked(&self.as_slice()[start..])
+        }
+    }
 }
-impl<'r> molecule::prelude::Reader<'r> for InIBDReader<'r> {
-    type Entity = InIBD;
-    const NAME: &'static str = "InIBDReader";
+impl<'r> molecule::prelude::Reader<'r> for SendBlockHeadersReader<'r> {
+    type Entity = SendBlockHeaders;
+    const NAME: &'static str = "SendBlockHeadersReader";
     fn to_entity(&self) -> Self::Entity {
         Self::Entity::new_unchecked(self.as_slice().to_owned().into())
     }
     fn new_unchecked(slice: &'r [u8]) -> Self {
-        InIBDReader(slice)
+        SendBlockHeadersReader(slice)
     }
     fn as_slice(&self) -> &'r [u8] {
         self.0
@@ -11890,39 +17025,76 @@ impl<'r> molecule::prelude::Reader<'r> for InIBDReader<'r> {
         if slice_len != total_size {
             return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
         }
-        if slice_len > molecule::NUMBER_SIZE && !compatible {
-            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, !0);
+        if slice_len == molecule::NUMBER_SIZE && Self::FIELD_COUNT == 0 {
+            return Ok(());
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        if slice_len < offset_first {
+            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
+        }
+        let field_count = offset_first / molecule::NUMBER_SIZE - 1;
+        if field_count < Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        } else if !compatible && field_count > Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        };
+        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
+            .chunks_exact(molecule::NUMBER_SIZE)
+            .map(|x| molecule::unpack_number(x) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
         }
+        HeaderVecReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
         Ok(())
     }
 }
 #[derive(Debug, Default)]
-pub struct InIBDBuilder {}
-impl InIBDBuilder {
-    pub const FIELD_COUNT: usize = 0;
+pub struct SendBlockHeadersBuilder {
+    pub(crate) headers: HeaderVec,
 }
-impl molecule::prelude::Builder for InIBDBuilder {
-    type Entity = InIBD;
-    const NAME: &'static str = "InIBDBuilder";
+impl SendBlockHeadersBuilder {
+    pub const FIELD_COUNT: usize = 1;
+    pub fn headers(mut self, v: HeaderVec) -> Self {
+        self.headers = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for SendBlockHeadersBuilder {
+    type Entity = SendBlockHeaders;
+    const NAME: &'static str = "SendBlockHeadersBuilder";
     fn expected_length(&self) -> usize {
-        molecule::NUMBER_SIZE
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1) + self.headers.as_slice().len()
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
-        writer.write_all(&molecule::pack_number(
-            molecule::NUMBER_SIZE as molecule::Number,
-        ))?;
+        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
+        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
+        offsets.push(total_size);
+        total_size += self.headers.as_slice().len();
+        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+        for offset in offsets.into_iter() {
+            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+        }
+        writer.write_all(self.headers.as_slice())?;
         Ok(())
     }
     fn build(&self) -> Self::Entity {
         let mut inner = Vec::with_capacity(self.expected_length());
         self.write(&mut inner)
             .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        InIBD::new_unchecked(inner.into())
+        SendBlockHeaders::new_unchecked(inner.into())
     }
 }
 #[derive(Clone)]
-pub struct Time(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for Time {
+pub struct GetTransactionsProof(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for GetTransactionsProof {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -11931,15 +17103,15 @@ impl ::core::fmt::LowerHex for Time {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Debug for Time {
+impl ::core::fmt::Debug for GetTransactionsProof {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::fmt::Display for Time {
+impl ::core::fmt::Display for GetTransactionsProof {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "timestamp", self.timestamp())?;
+        write!(f, "{}: {}", "tx_hashes", self.tx_hashes())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -11947,13 +17119,13 @@ impl ::core::fmt::Display for Time {
         write!(f, " }}")
     }
 }
-impl ::core::default::Default for Time {
+impl ::core::default::Default for GetTransactionsProof {
     fn default() -> Self {
-        let v: Vec<u8> = vec![16, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0];
-        Time::new_unchecked(v.into())
+        let v: Vec<u8> = vec![12, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0];
+        GetTransactionsProof::new_unchecked(v.into())
     }
 }
-impl Time {
+impl GetTransactionsProof {
     pub const FIELD_COUNT: usize = 1;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
@@ -11971,25 +17143,25 @@ impl Time {
     pub fn has_extra_fields(&self) -> bool {
         Self::FIELD_COUNT != self.field_count()
     }
-    pub fn timestamp(&self) -> Uint64 {
+    pub fn tx_hashes(&self) -> Byte32Vec {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[4..]) as usize;
         if self.has_extra_fields() {
             let end = molecule::unpack_number(&slice[8..]) as usize;
-            Uint64::new_unchecked(self.0.slice(start..end))
+            Byte32Vec::new_unchecked(self.0.slice(start..end))
         } else {
-            Uint64::new_unchecked(self.0.slice(start..))
+            Byte32Vec::new_unchecked(self.0.slice(start..))
         }
     }
-    pub fn as_reader<'r>(&'r self) -> TimeReader<'r> {
-        TimeReader::new_unchecked(self.as_slice())
+    pub fn as_reader<'r>(&'r self) -> GetTransactionsProofReader<'r> {
+        GetTransactionsProofReader::new_unchecked(self.as_slice())
     }
 }
-impl molecule::prelude::Entity for Time {
-    type Builder = TimeBuilder;
-    const NAME: &'static str = "Time";
+impl molecule::prelude::Entity for GetTransactionsProof {
+    type Builder = GetTransactionsProofBuilder;
+    const NAME: &'static str = "GetTransactionsProof";
     fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        Time(data)
+        GetTransactionsProof(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -11998,21 +17170,21 @@ impl molecule::prelude::Entity for Time {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        TimeReader::from_slice(slice).map(|reader| reader.to_entity())
+        GetTransactionsProofReader::from_slice(slice).map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        TimeReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+        GetTransactionsProofReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
         ::core::default::Default::default()
     }
     fn as_builder(self) -> Self::Builder {
-        Self::new_builder().timestamp(self.timestamp())
+        Self::new_builder().tx_hashes(self.tx_hashes())
     }
 }
 #[derive(Clone, Copy)]
-pub struct TimeReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for TimeReader<'r> {
+pub struct GetTransactionsProofReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for GetTransactionsProofReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -12021,15 +17193,15 @@ impl<'r> ::core::fmt::LowerHex for TimeReader<'r> {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl<'r> ::core::fmt::Debug for TimeReader<'r> {
+impl<'r> ::core::fmt::Debug for GetTransactionsProofReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::core::fmt::Display for TimeReader<'r> {
+impl<'r> ::core::fmt::Display for GetTransactionsProofReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "timestamp", self.timestamp())?;
+        write!(f, "{}: {}", "tx_hashes", self.tx_hashes())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -12037,7 +17209,7 @@ impl<'r> ::core::fmt::Display for TimeReader<'r> {
         write!(f, " }}")
     }
 }
-impl<'r> TimeReader<'r> {
+impl<'r> GetTransactionsProofReader<'r> {
     pub const FIELD_COUNT: usize = 1;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
@@ -12055,25 +17227,25 @@ impl<'r> TimeReader<'r> {
     pub fn has_extra_fields(&self) -> bool {
         Self::FIELD_COUNT != self.field_count()
     }
-    pub fn timestamp(&self) -> Uint64Reader<'r> {
+    pub fn tx_hashes(&self) -> Byte32VecReader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[4..]) as usize;
         if self.has_extra_fields() {
             let end = molecule::unpack_number(&slice[8..]) as usize;
-            Uint64Reader::new_unchecked(&self.as_slice()[start..end])
+            Byte32VecReader::new_unchecked(&self.as_slice()[start..end])
         } else {
-            Uint64Reader::new_unchecked(&self.as_slice()[start..])
+            Byte32VecReader::new_unchecked(&self.as_slice()[start..])
         }
     }
 }
-impl<'r> molecule::prelude::Reader<'r> for TimeReader<'r> {
-    type Entity = Time;
-    const NAME: &'static str = "TimeReader";
+impl<'r> molecule::prelude::Reader<'r> for GetTransactionsProofReader<'r> {
+    type Entity = GetTransactionsProof;
+    const NAME: &'static str = "GetTransactionsProofReader";
     fn to_entity(&self) -> Self::Entity {
         Self::Entity::new_unchecked(self.as_slice().to_owned().into())
     }
     fn new_unchecked(slice: &'r [u8]) -> Self {
-        TimeReader(slice)
+        GetTransactionsProofReader(slice)
     }
     fn as_slice(&self) -> &'r [u8] {
         self.0
@@ -12115,49 +17287,49 @@ impl<'r> molecule::prelude::Reader<'r> for TimeReader<'r> {
         if offsets.windows(2).any(|i| i[0] > i[1]) {
             return ve!(Self, OffsetsNotMatch);
         }
-        Uint64Reader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        Byte32VecReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
         Ok(())
     }
 }
 #[derive(Debug, Default)]
-pub struct TimeBuilder {
-    pub(crate) timestamp: Uint64,
+pub struct GetTransactionsProofBuilder {
+    pub(crate) tx_hashes: Byte32Vec,
 }
-impl TimeBuilder {
+impl GetTransactionsProofBuilder {
     pub const FIELD_COUNT: usize = 1;
-    pub fn timestamp(mut self, v: Uint64) -> Self {
-        self.timestamp = v;
+    pub fn tx_hashes(mut self, v: Byte32Vec) -> Self {
+        self.tx_hashes = v;
         self
     }
 }
-impl molecule::prelude::Builder for TimeBuilder {
-    type Entity = Time;
-    const NAME: &'static str = "TimeBuilder";
+impl molecule::prelude::Builder for GetTransactionsProofBuilder {
+    type Entity = GetTransactionsProof;
+    const NAME: &'static str = "GetTransactionsProofBuilder";
     fn expected_length(&self) -> usize {
-        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1) + self.timestamp.as_slice().len()
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1) + self.tx_hashes.as_slice().len()
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
         let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
         let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
         offsets.push(total_size);
-        total_size += self.timestamp.as_slice().len();
+        total_size += self.tx_hashes.as_slice().len();
         writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
         for offset in offsets.into_iter() {
             writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
         }
-        writer.write_all(self.timestamp.as_slice())?;
+        writer.write_all(self.tx_hashes.as_slice())?;
         Ok(())
     }
     fn build(&self) -> Self::Entity {
         let mut inner = Vec::with_capacity(self.expected_length());
         self.write(&mut inner)
             .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        Time::new_unchecked(inner.into())
+        GetTransactionsProof::new_unchecked(inner.into())
     }
 }
 #[derive(Clone)]
-pub struct RawAlert(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for RawAlert {
+pub struct TransactionsProof(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for TransactionsProof {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -12166,21 +17338,18 @@ impl ::core::fmt::LowerHex for RawAlert {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Debug for RawAlert {
+impl ::core::fmt::Debug for TransactionsProof {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::fmt::Display for RawAlert {
+impl ::core::fmt::Display for TransactionsProof {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "notice_until", self.notice_until())?;
-        write!(f, ", {}: {}", "id", self.id())?;
-        write!(f, ", {}: {}", "cancel", self.cancel())?;
-        write!(f, ", {}: {}", "priority", self.priority())?;
-        write!(f, ", {}: {}", "message", self.message())?;
-        write!(f, ", {}: {}", "min_version", self.min_version())?;
-        write!(f, ", {}: {}", "max_version", self.max_version())?;
+        write!(f, "{}: {}", "header", self.header())?;
+        write!(f, ", {}: {}", "witnesses_root", self.witnesses_root())?;
+        write!(f, ", {}: {}", "transactions", self.transactions())?;
+        write!(f, ", {}: {}", "proof", self.proof())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -12188,17 +17357,25 @@ impl ::core::fmt::Display for RawAlert {
         write!(f, " }}")
     }
 }
-impl ::core::default::Default for RawAlert {
+impl ::core::default::Default for TransactionsProof {
     fn default() -> Self {
         let v: Vec<u8> = vec![
-            56, 0, 0, 0, 32, 0, 0, 0, 40, 0, 0, 0, 44, 0, 0, 0, 48, 0, 0, 0, 52, 0, 0, 0, 56, 0, 0,
-            0, 56, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            28, 1, 0, 0, 20, 0, 0, 0, 228, 0, 0, 0, 4, 1, 0, 0, 8, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            4, 0, 0, 0, 20, 0, 0, 0, 12, 0, 0, 0, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         ];
-        RawAlert::new_unchecked(v.into())
+        TransactionsProof::new_unchecked(v.into())
     }
 }
-impl RawAlert {
-    pub const FIELD_COUNT: usize = 7;
+impl TransactionsProof {
+    pub const FIELD_COUNT: usize = 4;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -12215,61 +17392,43 @@ impl RawAlert {
     pub fn has_extra_fields(&self) -> bool {
         Self::FIELD_COUNT != self.field_count()
     }
-    pub fn notice_until(&self) -> Uint64 {
+    pub fn header(&self) -> Header {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[4..]) as usize;
         let end = molecule::unpack_number(&slice[8..]) as usize;
-        Uint64::new_unchecked(self.0.slice(start..end))
+        Header::new_unchecked(self.0.slice(start..end))
     }
-    pub fn id(&self) -> Uint32 {
+    pub fn witnesses_root(&self) -> Byte32 {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[8..]) as usize;
         let end = molecule::unpack_number(&slice[12..]) as usize;
-        Uint32::new_unchecked(self.0.slice(start..end))
+        Byte32::new_unchecked(self.0.slice(start..end))
     }
-    pub fn cancel(&self) -> Uint32 {
+    pub fn transactions(&self) -> TransactionVec {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[12..]) as usize;
         let end = molecule::unpack_number(&slice[16..]) as usize;
-        Uint32::new_unchecked(self.0.slice(start..end))
+        TransactionVec::new_unchecked(self.0.slice(start..end))
     }
-    pub fn priority(&self) -> Uint32 {
+    pub fn proof(&self) -> MerkleProof {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[16..]) as usize;
-        let end = molecule::unpack_number(&slice[20..]) as usize;
-        Uint32::new_unchecked(self.0.slice(start..end))
-    }
-    pub fn message(&self) -> Bytes {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[20..]) as usize;
-        let end = molecule::unpack_number(&slice[24..]) as usize;
-        Bytes::new_unchecked(self.0.slice(start..end))
-    }
-    pub fn min_version(&self) -> BytesOpt {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[24..]) as usize;
-        let end = molecule::unpack_number(&slice[28..]) as usize;
-        BytesOpt::new_unchecked(self.0.slice(start..end))
-    }
-    pub fn max_version(&self) -> BytesOpt {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[28..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[32..]) as usize;
-            BytesOpt::new_unchecked(self.0.slice(start..end))
+            let end = molecule::unpack_number(&slice[20..]) as usize;
+            MerkleProof::new_unchecked(self.0.slice(start..end))
         } else {
-            BytesOpt::new_unchecked(self.0.slice(start..))
+            MerkleProof::new_unchecked(self.0.slice(start..))
         }
     }
-    pub fn as_reader<'r>(&'r self) -> RawAlertReader<'r> {
-        RawAlertReader::new_unchecked(self.as_slice())
+    pub fn as_reader<'r>(&'r self) -> TransactionsProofReader<'r> {
+        TransactionsProofReader::new_unchecked(self.as_slice())
     }
 }
-impl molecule::prelude::Entity for RawAlert {
-    type Builder = RawAlertBuilder;
-    const NAME: &'static str = "RawAlert";
+impl molecule::prelude::Entity for TransactionsProof {
+    type Builder = TransactionsProofBuilder;
+    const NAME: &'static str = "TransactionsProof";
     fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        RawAlert(data)
+        TransactionsProof(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -12278,28 +17437,25 @@ impl molecule::prelude::Entity for RawAlert {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        RawAlertReader::from_slice(slice).map(|reader| reader.to_entity())
+        TransactionsProofReader::from_slice(slice).map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        RawAlertReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+        TransactionsProofReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
         ::core::default::Default::default()
     }
     fn as_builder(self) -> Self::Builder {
         Self::new_builder()
-            .notice_until(self.notice_until())
-            .id(self.id())
-            .cancel(self.cancel())
-            .priority(self.priority())
-            .message(self.message())
-            .min_version(self.min_version())
-            .max_version(self.max_version())
+            .header(self.header())
+            .witnesses_root(self.witnesses_root())
+            .transactions(self.transactions())
+            .proof(self.proof())
     }
 }
 #[derive(Clone, Copy)]
-pub struct RawAlertReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for RawAlertReader<'r> {
+pub struct TransactionsProofReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for TransactionsProofReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -12308,21 +17464,18 @@ impl<'r> ::core::fmt::LowerHex for RawAlertReader<'r> {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl<'r> ::core::fmt::Debug for RawAlertReader<'r> {
+impl<'r> ::core::fmt::Debug for TransactionsProofReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::core::fmt::Display for RawAlertReader<'r> {
+impl<'r> ::core::fmt::Display for TransactionsProofReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "notice_until", self.notice_until())?;
-        write!(f, ", {}: {}", "id", self.id())?;
-        write!(f, ", {}: {}", "cancel", self.cancel())?;
-        write!(f, ", {}: {}", "priority", self.priority())?;
-        write!(f, ", {}: {}", "message", self.message())?;
-        write!(f, ", {}: {}", "min_version", self.min_version())?;
-        write!(f, ", {}: {}", "max_version", self.max_version())?;
+        write!(f, "{}: {}", "header", self.header())?;
+        write!(f, ", {}: {}", "witnesses_root", self.witnesses_root())?;
+        write!(f, ", {}: {}", "transactions", self.transactions())?;
+        write!(f, ", {}: {}", "proof", self.proof())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -12330,8 +17483,8 @@ impl<'r> ::core::fmt::Display for RawAlertReader<'r> {
         write!(f, " }}")
     }
 }
-impl<'r> RawAlertReader<'r> {
-    pub const FIELD_COUNT: usize = 7;
+impl<'r> TransactionsProofReader<'r> {
+    pub const FIELD_COUNT: usize = 4;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -12348,61 +17501,43 @@ impl<'r> RawAlertReader<'r> {
     pub fn has_extra_fields(&self) -> bool {
         Self::FIELD_COUNT != self.field_count()
     }
-    pub fn notice_until(&self) -> Uint64Reader<'r> {
+    pub fn header(&self) -> HeaderReader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[4..]) as usize;
         let end = molecule::unpack_number(&slice[8..]) as usize;
-        Uint64Reader::new_unchecked(&self.as_slice()[start..end])
+        HeaderReader::new_unchecked(&self.as_slice()[start..end])
     }
-    pub fn id(&self) -> Uint32Reader<'r> {
+    pub fn witnesses_root(&self) -> Byte32Reader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[8..]) as usize;
         let end = molecule::unpack_number(&slice[12..]) as usize;
-        Uint32Reader::new_unchecked(&self.as_slice()[start..end])
+        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
     }
-    pub fn cancel(&self) -> Uint32Reader<'r> {
+    pub fn transactions(&self) -> TransactionVecReader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[12..]) as usize;
         let end = molecule::unpack_number(&slice[16..]) as usize;
-        Uint32Reader::new_unchecked(&self.as_slice()[start..end])
+        TransactionVecReader::new_unchecked(&self.as_slice()[start..end])
     }
-    pub fn priority(&self) -> Uint32Reader<'r> {
+    pub fn proof(&self) -> MerkleProofReader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[16..]) as usize;
-        let end = molecule::unpack_number(&slice[20..]) as usize;
-        Uint32Reader::new_unchecked(&self.as_slice()[start..end])
-    }
-    pub fn message(&self) -> BytesReader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[20..]) as usize;
-        let end = molecule::unpack_number(&slice[24..]) as usize;
-        BytesReader::new_unchecked(&self.as_slice()[start..end])
-    }
-    pub fn min_version(&self) -> BytesOptReader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[24..]) as usize;
-        let end = molecule::unpack_number(&slice[28..]) as usize;
-        BytesOptReader::new_unchecked(&self.as_slice()[start..end])
-    }
-    pub fn max_version(&self) -> BytesOptReader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[28..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[32..]) as usize;
-            BytesOptReader::new_unchecked(&self.as_slice()[start..end])
+            let end = molecule::unpack_number(&slice[20..]) as usize;
+            MerkleProofReader::new_unchecked(&self.as_slice()[start..end])
         } else {
-            BytesOptReader::new_unchecked(&self.as_slice()[start..])
+            MerkleProofReader::new_unchecked(&self.as_slice()[start..])
         }
     }
 }
-impl<'r> molecule::prelude::Reader<'r> for RawAlertReader<'r> {
-    type Entity = RawAlert;
-    const NAME: &'static str = "RawAlertReader";
+impl<'r> molecule::prelude::Reader<'r> for TransactionsProofReader<'r> {
+    type Entity = TransactionsProof;
+    const NAME: &'static str = "TransactionsProofReader";
     fn to_entity(&self) -> Self::Entity {
         Self::Entity::new_unchecked(self.as_slice().to_owned().into())
     }
     fn new_unchecked(slice: &'r [u8]) -> Self {
-        RawAlertReader(slice)
+        TransactionsProofReader(slice)
     }
     fn as_slice(&self) -> &'r [u8] {
         self.0
@@ -12444,110 +17579,80 @@ impl<'r> molecule::prelude::Reader<'r> for RawAlertReader<'r> {
         if offsets.windows(2).any(|i| i[0] > i[1]) {
             return ve!(Self, OffsetsNotMatch);
         }
-        Uint64Reader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
-        Uint32Reader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
-        Uint32Reader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
-        Uint32Reader::verify(&slice[offsets[3]..offsets[4]], compatible)?;
-        BytesReader::verify(&slice[offsets[4]..offsets[5]], compatible)?;
-        BytesOptReader::verify(&slice[offsets[5]..offsets[6]], compatible)?;
-        BytesOptReader::verify(&slice[offsets[6]..offsets[7]], compatible)?;
+        HeaderReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        Byte32Reader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        TransactionVecReader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
+        MerkleProofReader::verify(&slice[offsets[3]..offsets[4]], compatible)?;
         Ok(())
     }
 }
 #[derive(Debug, Default)]
-pub struct RawAlertBuilder {
-    pub(crate) notice_until: Uint64,
-    pub(crate) id: Uint32,
-    pub(crate) cancel: Uint32,
-    pub(crate) priority: Uint32,
-    pub(crate) message: Bytes,
-    pub(crate) min_version: BytesOpt,
-    pub(crate) max_version: BytesOpt,
+pub struct TransactionsProofBuilder {
+    pub(crate) header: Header,
+    pub(crate) witnesses_root: Byte32,
+    pub(crate) transactions: TransactionVec,
+    pub(crate) proof: MerkleProof,
 }
-impl RawAlertBuilder {
-    pub const FIELD_COUNT: usize = 7;
-    pub fn notice_until(mut self, v: Uint64) -> Self {
-        self.notice_until = v;
-        self
-    }
-    pub fn id(mut self, v: Uint32) -> Self {
-        self.id = v;
-        self
-    }
-    pub fn cancel(mut self, v: Uint32) -> Self {
-        self.cancel = v;
-        self
-    }
-    pub fn priority(mut self, v: Uint32) -> Self {
-        self.priority = v;
+impl TransactionsProofBuilder {
+    pub const FIELD_COUNT: usize = 4;
+    pub fn header(mut self, v: Header) -> Self {
+        self.header = v;
         self
     }
-    pub fn message(mut self, v: Bytes) -> Self {
-        self.message = v;
+    pub fn witnesses_root(mut self, v: Byte32) -> Self {
+        self.witnesses_root = v;
         self
     }
-    pub fn min_version(mut self, v: BytesOpt) -> Self {
-        self.min_version = v;
+    pub fn transactions(mut self, v: TransactionVec) -> Self {
+        self.transactions = v;
         self
     }
-    pub fn max_version(mut self, v: BytesOpt) -> Self {
-        self.max_version = v;
+    pub fn proof(mut self, v: MerkleProof) -> Self {
+        self.proof = v;
         self
     }
 }
-impl molecule::prelude::Builder for RawAlertBuilder {
-    type Entity = RawAlert;
-    const NAME: &'static str = "RawAlertBuilder";
+impl molecule::prelude::Builder for TransactionsProofBuilder {
+    type Entity = TransactionsProof;
+    const NAME: &'static str = "TransactionsProofBuilder";
     fn expected_length(&self) -> usize {
         molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
-            + self.notice_until.as_slice().len()
-            + self.id.as_slice().len()
-            + self.cancel.as_slice().len()
-            + self.priority.as_slice().len()
-            + self.message.as_slice().len()
-            + self.min_version.as_slice().len()
-            + self.max_version.as_slice().len()
+            + self.header.as_slice().len()
+            + self.witnesses_root.as_slice().len()
+            + self.transactions.as_slice().len()
+            + self.proof.as_slice().len()
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
         let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
         let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
         offsets.push(total_size);
-        total_size += self.notice_until.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.id.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.cancel.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.priority.as_slice().len();
+        total_size += self.header.as_slice().len();
         offsets.push(total_size);
-        total_size += self.message.as_slice().len();
+        total_size += self.witnesses_root.as_slice().len();
         offsets.push(total_size);
-        total_size += self.min_version.as_slice().len();
+        total_size += self.transactions.as_slice().len();
         offsets.push(total_size);
-        total_size += self.max_version.as_slice().len();
+        total_size += self.proof.as_slice().len();
         writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
         for offset in offsets.into_iter() {
             writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
         }
-        writer.write_all(self.notice_until.as_slice())?;
-        writer.write_all(self.id.as_slice())?;
-        writer.write_all(self.cancel.as_slice())?;
-        writer.write_all(self.priority.as_slice())?;
-        writer.write_all(self.message.as_slice())?;
-        writer.write_all(self.min_version.as_slice())?;
-        writer.write_all(self.max_version.as_slice())?;
+        writer.write_all(self.header.as_slice())?;
+        writer.write_all(self.witnesses_root.as_slice())?;
+        writer.write_all(self.transactions.as_slice())?;
+        writer.write_all(self.proof.as_slice())?;
         Ok(())
     }
     fn build(&self) -> Self::Entity {
         let mut inner = Vec::with_capacity(self.expected_length());
         self.write(&mut inner)
             .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        RawAlert::new_unchecked(inner.into())
+        TransactionsProof::new_unchecked(inner.into())
     }
 }
 #[derive(Clone)]
-pub struct Alert(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for Alert {
+pub struct TransactionsProofVec(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for TransactionsProofVec {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -12556,76 +17661,75 @@ impl ::core::fmt::LowerHex for Alert {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Debug for Alert {
+impl ::core::fmt::Debug for TransactionsProofVec {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::fmt::Display for Alert {
+impl ::core::fmt::Display for TransactionsProofVec {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "raw", self.raw())?;
-        write!(f, ", {}: {}", "signatures", self.signatures())?;
-        let extra_count = self.count_extra_fields();
-        if extra_count != 0 {
-            write!(f, ", .. ({} fields)", extra_count)?;
+        write!(f, "{} [", Self::NAME)?;
+        for i in 0..self.len() {
+            if i == 0 {
+                write!(f, "{}", self.get_unchecked(i))?;
+            } else {
+                write!(f, ", {}", self.get_unchecked(i))?;
+            }
         }
-        write!(f, " }}")
+        write!(f, "]")
     }
 }
-impl ::core::default::Default for Alert {
+impl ::core::default::Default for TransactionsProofVec {
     fn default() -> Self {
-        let v: Vec<u8> = vec![
-            72, 0, 0, 0, 12, 0, 0, 0, 68, 0, 0, 0, 56, 0, 0, 0, 32, 0, 0, 0, 40, 0, 0, 0, 44, 0, 0,
-            0, 48, 0, 0, 0, 52, 0, 0, 0, 56, 0, 0, 0, 56, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0,
-        ];
-        Alert::new_unchecked(v.into())
+        let v: Vec<u8> = vec![4, 0, 0, 0];
+        TransactionsProofVec::new_unchecked(v.into())
     }
 }
-impl Alert {
-    pub const FIELD_COUNT: usize = 2;
+impl TransactionsProofVec {
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
-    pub fn field_count(&self) -> usize {
+    pub fn item_count(&self) -> usize {
         if self.total_size() == molecule::NUMBER_SIZE {
             0
         } else {
             (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
         }
     }
-    pub fn count_extra_fields(&self) -> usize {
-        self.field_count() - Self::FIELD_COUNT
+    pub fn len(&self) -> usize {
+        self.item_count()
     }
-    pub fn has_extra_fields(&self) -> bool {
-        Self::FIELD_COUNT != self.field_count()
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
     }
-    pub fn raw(&self) -> RawAlert {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[4..]) as usize;
-        let end = molecule::unpack_number(&slice[8..]) as usize;
-        RawAlert::new_unchecked(self.0.slice(start..end))
+    pub fn get(&self, idx: usize) -> Option<TransactionsProof> {
+        if idx >= self.len() {
+            None
+        } else {
+            Some(self.get_unchecked(idx))
+        }
     }
-    pub fn signatures(&self) -> BytesVec {
+    pub fn get_unchecked(&self, idx: usize) -> TransactionsProof {
         let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[8..]) as usize;
-        if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[12..]) as usize;
-            BytesVec::new_unchecked(self.0.slice(start..end))
+        let start_idx = molecule::NUMBER_SIZE * (1 + idx);
+        let start = molecule::unpack_number(&slice[start_idx..]) as usize;
+        if idx == self.len() - 1 {
+            TransactionsProof::new_unchecked(self.0.slice(start..))
         } else {
-            BytesVec::new_unchecked(self.0.slice(start..))
+            let end_idx = start_idx + molecule::NUMBER_SIZE;
+            let end = molecule::unpack_number(&slice[end_idx..]) as usize;
+            TransactionsProof::new_unchecked(self.0.slice(start..end))
         }
     }
-    pub fn as_reader<'r>(&'r self) -> AlertReader<'r> {
-        AlertReader::new_unchecked(self.as_slice())
+    pub fn as_reader<'r>(&'r self) -> TransactionsProofVecReader<'r> {
+        TransactionsProofVecReader::new_unchecked(self.as_slice())
     }
 }
-impl molecule::prelude::Entity for Alert {
-    type Builder = AlertBuilder;
-    const NAME: &'static str = "Alert";
+impl molecule::prelude::Entity for TransactionsProofVec {
+    type Builder = TransactionsProofVecBuilder;
+    const NAME: &'static str = "TransactionsProofVec";
     fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        Alert(data)
+        TransactionsProofVec(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -12634,23 +17738,21 @@ impl molecule::prelude::Entity for Alert {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        AlertReader::from_slice(slice).map(|reader| reader.to_entity())
+        TransactionsProofVecReader::from_slice(slice).map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        AlertReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+        TransactionsProofVecReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
         ::core::default::Default::default()
     }
     fn as_builder(self) -> Self::Builder {
-        Self::new_builder()
-            .raw(self.raw())
-            .signatures(self.signatures())
+        Self::new_builder().extend(self.into_iter())
     }
 }
 #[derive(Clone, Copy)]
-pub struct AlertReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for AlertReader<'r> {
+pub struct TransactionsProofVecReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for TransactionsProofVecReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -12659,66 +17761,69 @@ impl<'r> ::core::fmt::LowerHex for AlertReader<'r> {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl<'r> ::core::fmt::Debug for AlertReader<'r> {
+impl<'r> ::core::fmt::Debug for TransactionsProofVecReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::core::fmt::Display for AlertReader<'r> {
+impl<'r> ::core::fmt::Display for TransactionsProofVecReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "raw", self.raw())?;
-        write!(f, ", {}: {}", "signatures", self.signatures())?;
-        let extra_count = self.count_extra_fields();
-        if extra_count != 0 {
-            write!(f, ", .. ({} fields)", extra_count)?;
+        write!(f, "{} [", Self::NAME)?;
+        for i in 0..self.len() {
+            if i == 0 {
+                write!(f, "{}", self.get_unchecked(i))?;
+            } else {
+                write!(f, ", {}", self.get_unchecked(i))?;
+            }
         }
-        write!(f, " }}")
+        write!(f, "]")
     }
 }
-impl<'r> AlertReader<'r> {
-    pub const FIELD_COUNT: usize = 2;
+impl<'r> TransactionsProofVecReader<'r> {
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
-    pub fn field_count(&self) -> usize {
+    pub fn item_count(&self) -> usize {
         if self.total_size() == molecule::NUMBER_SIZE {
             0
         } else {
             (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
         }
     }
-    pub fn count_extra_fields(&self) -> usize {
-        self.field_count() - Self::FIELD_COUNT
+    pub fn len(&self) -> usize {
+        self.item_count()
     }
-    pub fn has_extra_fields(&self) -> bool {
-        Self::FIELD_COUNT != self.field_count()
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
     }
-    pub fn raw(&self) -> RawAlertReader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[4..]) as usize;
-        let end = molecule::unpack_number(&slice[8..]) as usize;
-        RawAlertReader::new_unchecked(&self.as_slice()[start..end])
+    pub fn get(&self, idx: usize) -> Option<TransactionsProofReader<'r>> {
+        if idx >= self.len() {
+            None
+        } else {
+            Some(self.get_unchecked(idx))
+        }
     }
-    pub fn signatures(&self) -> BytesVecReader<'r> {
+    pub fn get_unchecked(&self, idx: usize) -> TransactionsProofReader<'r> {
         let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[8..]) as usize;
-        if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[12..]) as usize;
-            BytesVecReader::new_unchecked(&self.as_slice()[start..end])
+        let start_idx = molecule::NUMBER_SIZE * (1 + idx);
+        let start = molecule::unpack_number(&slice[start_idx..]) as usize;
+        if idx == self.len() - 1 {
+            TransactionsProofReader::new_unchecked(&self.as_slice()[start..])
         } else {
-            BytesVecReader::new_unchecked(&self.as_slice()[start..])
+            let end_idx = start_idx + molecule::NUMBER_SIZE;
+            let end = molecule::unpack_number(&slice[end_idx..]) as usize;
+            TransactionsProofReader::new_unchecked(&self.as_slice()[start..end])
         }
     }
 }
-impl<'r> molecule::prelude::Reader<'r> for AlertReader<'r> {
-    type Entity = Alert;
-    const NAME: &'static str = "AlertReader";
+impl<'r> molecule::prelude::Reader<'r> for TransactionsProofVecReader<'r> {
+    type Entity = TransactionsProofVec;
+    const NAME: &'static str = "TransactionsProofVecReader";
     fn to_entity(&self) -> Self::Entity {
         Self::Entity::new_unchecked(self.as_slice().to_owned().into())
     }
     fn new_unchecked(slice: &'r [u8]) -> Self {
-        AlertReader(slice)
+        TransactionsProofVecReader(slice)
     }
     fn as_slice(&self) -> &'r [u8] {
         self.0
@@ -12733,11 +17838,16 @@ impl<'r> molecule::prelude::Reader<'r> for AlertReader<'r> {
         if slice_len != total_size {
             return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
         }
-        if slice_len == molecule::NUMBER_SIZE && Self::FIELD_COUNT == 0 {
+        if slice_len == molecule::NUMBER_SIZE {
             return Ok(());
         }
         if slice_len < molecule::NUMBER_SIZE * 2 {
-            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
+            return ve!(
+                Self,
+                TotalSizeNotMatch,
+                molecule::NUMBER_SIZE * 2,
+                slice_len
+            );
         }
         let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
         if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
@@ -12746,12 +17856,6 @@ impl<'r> molecule::prelude::Reader<'r> for AlertReader<'r> {
         if slice_len < offset_first {
             return ve!(Self, HeaderIsBroken, offset_first, slice_len);
         }
-        let field_count = offset_first / molecule::NUMBER_SIZE - 1;
-        if field_count < Self::FIELD_COUNT {
-            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
-        } else if !compatible && field_count > Self::FIELD_COUNT {
-            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
-        };
         let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
             .chunks_exact(molecule::NUMBER_SIZE)
             .map(|x| molecule::unpack_number(x) as usize)
@@ -12760,60 +17864,141 @@ impl<'r> molecule::prelude::Reader<'r> for AlertReader<'r> {
         if offsets.windows(2).any(|i| i[0] > i[1]) {
             return ve!(Self, OffsetsNotMatch);
         }
-        RawAlertReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
-        BytesVecReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        for pair in offsets.windows(2) {
+            let start = pair[0];
+            let end = pair[1];
+            TransactionsProofReader::verify(&slice[start..end], compatible)?;
+        }
         Ok(())
     }
 }
 #[derive(Debug, Default)]
-pub struct AlertBuilder {
-    pub(crate) raw: RawAlert,
-    pub(crate) signatures: BytesVec,
-}
-impl AlertBuilder {
-    pub const FIELD_COUNT: usize = 2;
-    pub fn raw(mut self, v: RawAlert) -> Self {
-        self.raw = v;
+pub struct TransactionsProofVecBuilder(pub(crate) Vec<TransactionsProof>);
+impl TransactionsProofVecBuilder {
+    pub fn set(mut self, v: Vec<TransactionsProof>) -> Self {
+        self.0 = v;
         self
     }
-    pub fn signatures(mut self, v: BytesVec) -> Self {
-        self.signatures = v;
+    pub fn push(mut self, v: TransactionsProof) -> Self {
+        self.0.push(v);
+        self
+    }
+    pub fn extend<T: ::core::iter::IntoIterator<Item = TransactionsProof>>(
+        mut self,
+        iter: T,
+    ) -> Self {
+        for elem in iter {
+            self.0.push(elem);
+        }
         self
     }
+    pub fn replace(&mut self, index: usize, v: TransactionsProof) -> Option<TransactionsProof> {
+        self.0
+            .get_mut(index)
+            .map(|item| ::core::mem::replace(item, v))
+    }
 }
-impl molecule::prelude::Builder for AlertBuilder {
-    type Entity = Alert;
-    const NAME: &'static str = "AlertBuilder";
+impl molecule::prelude::Builder for TransactionsProofVecBuilder {
+    type Entity = TransactionsProofVec;
+    const NAME: &'static str = "TransactionsProofVecBuilder";
     fn expected_length(&self) -> usize {
-        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
-            + self.raw.as_slice().len()
-            + self.signatures.as_slice().len()
+        molecule::NUMBER_SIZE * (self.0.len() + 1)
+            + self
+                .0
+                .iter()
+                .map(|inner| inner.as_slice().len())
+                .sum::<usize>()
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
-        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
-        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
-        offsets.push(total_size);
-        total_size += self.raw.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.signatures.as_slice().len();
-        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
-        for offset in offsets.into_iter() {
-            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+        let item_count = self.0.len();
+        if item_count == 0 {
+            writer.write_all(&molecule::pack_number(
+                molecule::NUMBER_SIZE as molecule::Number,
+            ))?;
+        } else {
+            let (total_size, offsets) = self.0.iter().fold(
+                (
+                    molecule::NUMBER_SIZE * (item_count + 1),
+                    Vec::with_capacity(item_count),
+                ),
+                |(start, mut offsets), inner| {
+                    offsets.push(start);
+                    (start + inner.as_slice().len(), offsets)
+                },
+            );
+            writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+            for offset in offsets.into_iter() {
+                writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+            }
+            for inner in self.0.iter() {
+                writer.write_all(inner.as_slice())?;
+            }
         }
-        writer.write_all(self.raw.as_slice())?;
-        writer.write_all(self.signatures.as_slice())?;
         Ok(())
     }
     fn build(&self) -> Self::Entity {
         let mut inner = Vec::with_capacity(self.expected_length());
         self.write(&mut inner)
             .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        Alert::new_unchecked(inner.into())
+        TransactionsProofVec::new_unchecked(inner.into())
+    }
+}
+pub struct TransactionsProofVecIterator(TransactionsProofVec, usize, usize);
+impl ::core::iter::Iterator for TransactionsProofVecIterator {
+    type Item = TransactionsProof;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.1 >= self.2 {
+            None
+        } else {
+            let ret = self.0.get_unchecked(self.1);
+            self.1 += 1;
+            Some(ret)
+        }
+    }
+}
+impl ::core::iter::ExactSizeIterator for TransactionsProofVecIterator {
+    fn len(&self) -> usize {
+        self.2 - self.1
+    }
+}
+impl ::core::iter::IntoIterator for TransactionsProofVec {
+    type Item = TransactionsProof;
+    type IntoIter = TransactionsProofVecIterator;
+    fn into_iter(self) -> Self::IntoIter {
+        let len = self.len();
+        TransactionsProofVecIterator(self, 0, len)
+    }
+}
+impl<'r> TransactionsProofVecReader<'r> {
+    pub fn iter<'t>(&'t self) -> TransactionsProofVecReaderIterator<'t, 'r> {
+        TransactionsProofVecReaderIterator(&self, 0, self.len())
+    }
+}
+pub struct TransactionsProofVecReaderIterator<'t, 'r>(
+    &'t TransactionsProofVecReader<'r>,
+    usize,
+    usize,
+);
+impl<'t: 'r, 'r> ::core::iter::Iterator for TransactionsProofVecReaderIterator<'t, 'r> {
+    type Item = TransactionsProofReader<'t>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.1 >= self.2 {
+            None
+        } else {
+            let ret = self.0.get_unchecked(self.1);
+            self.1 += 1;
+            Some(ret)
+        }
+    }
+}
+impl<'t: 'r, 'r> ::core::iter::ExactSizeIterator for TransactionsProofVecReaderIterator<'t, 'r> {
+    fn len(&self) -> usize {
+        self.2 - self.1
     }
 }
 #[derive(Clone)]
-pub struct Identify(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for Identify {
+pub struct SendTransactionsProof(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for SendTransactionsProof {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -12822,17 +18007,16 @@ impl ::core::fmt::LowerHex for Identify {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Debug for Identify {
+impl ::core::fmt::Debug for SendTransactionsProof {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::fmt::Display for Identify {
+impl ::core::fmt::Display for SendTransactionsProof {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "flag", self.flag())?;
-        write!(f, ", {}: {}", "name", self.name())?;
-        write!(f, ", {}: {}", "client_version", self.client_version())?;
+        write!(f, "{}: {}", "proofs", self.proofs())?;
+        write!(f, ", {}: {}", "missing_tx_hashes", self.missing_tx_hashes())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -12840,17 +18024,16 @@ impl ::core::fmt::Display for Identify {
         write!(f, " }}")
     }
 }
-impl ::core::default::Default for Identify {
+impl ::core::default::Default for SendTransactionsProof {
     fn default() -> Self {
         let v: Vec<u8> = vec![
-            32, 0, 0, 0, 16, 0, 0, 0, 24, 0, 0, 0, 28, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0,
+            20, 0, 0, 0, 12, 0, 0, 0, 16, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0,
         ];
-        Identify::new_unchecked(v.into())
+        SendTransactionsProof::new_unchecked(v.into())
     }
 }
-impl Identify {
-    pub const FIELD_COUNT: usize = 3;
+impl SendTransactionsProof {
+    pub const FIELD_COUNT: usize = 2;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -12867,37 +18050,31 @@ impl Identify {
     pub fn has_extra_fields(&self) -> bool {
         Self::FIELD_COUNT != self.field_count()
     }
-    pub fn flag(&self) -> Uint64 {
+    pub fn proofs(&self) -> TransactionsProofVec {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[4..]) as usize;
         let end = molecule::unpack_number(&slice[8..]) as usize;
-        Uint64::new_unchecked(self.0.slice(start..end))
+        TransactionsProofVec::new_unchecked(self.0.slice(start..end))
     }
-    pub fn name(&self) -> Bytes {
+    pub fn missing_tx_hashes(&self) -> Byte32Vec {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[8..]) as usize;
-        let end = molecule::unpack_number(&slice[12..]) as usize;
-        Bytes::new_unchecked(self.0.slice(start..end))
-    }
-    pub fn client_version(&self) -> Bytes {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[12..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[16..]) as usize;
-            Bytes::new_unchecked(self.0.slice(start..end))
+            let end = molecule::unpack_number(&slice[12..]) as usize;
+            Byte32Vec::new_unchecked(self.0.slice(start..end))
         } else {
-            Bytes::new_unchecked(self.0.slice(start..))
+            Byte32Vec::new_unchecked(self.0.slice(start..))
         }
     }
-    pub fn as_reader<'r>(&'r self) -> IdentifyReader<'r> {
-        IdentifyReader::new_unchecked(self.as_slice())
+    pub fn as_reader<'r>(&'r self) -> SendTransactionsProofReader<'r> {
+        SendTransactionsProofReader::new_unchecked(self.as_slice())
     }
 }
-impl molecule::prelude::Entity for Identify {
-    type Builder = IdentifyBuilder;
-    const NAME: &'static str = "Identify";
+impl molecule::prelude::Entity for SendTransactionsProof {
+    type Builder = SendTransactionsProofBuilder;
+    const NAME: &'static str = "SendTransactionsProof";
     fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        Identify(data)
+        SendTransactionsProof(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -12906,24 +18083,23 @@ impl molecule::prelude::Entity for Identify {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        IdentifyReader::from_slice(slice).map(|reader| reader.to_entity())
+        SendTransactionsProofReader::from_slice(slice).map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        IdentifyReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+        SendTransactionsProofReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
         ::core::default::Default::default()
     }
     fn as_builder(self) -> Self::Builder {
         Self::new_builder()
-            .flag(self.flag())
-            .name(self.name())
-            .client_version(self.client_version())
+            .proofs(self.proofs())
+            .missing_tx_hashes(self.missing_tx_hashes())
     }
 }
 #[derive(Clone, Copy)]
-pub struct IdentifyReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for IdentifyReader<'r> {
+pub struct SendTransactionsProofReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for SendTransactionsProofReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -12932,17 +18108,16 @@ impl<'r> ::core::fmt::LowerHex for IdentifyReader<'r> {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl<'r> ::core::fmt::Debug for IdentifyReader<'r> {
+impl<'r> ::core::fmt::Debug for SendTransactionsProofReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::core::fmt::Display for IdentifyReader<'r> {
+impl<'r> ::core::fmt::Display for SendTransactionsProofReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "flag", self.flag())?;
-        write!(f, ", {}: {}", "name", self.name())?;
-        write!(f, ", {}: {}", "client_version", self.client_version())?;
+        write!(f, "{}: {}", "proofs", self.proofs())?;
+        write!(f, ", {}: {}", "missing_tx_hashes", self.missing_tx_hashes())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -12950,8 +18125,8 @@ impl<'r> ::core::fmt::Display for IdentifyReader<'r> {
         write!(f, " }}")
     }
 }
-impl<'r> IdentifyReader<'r> {
-    pub const FIELD_COUNT: usize = 3;
+impl<'r> SendTransactionsProofReader<'r> {
+    pub const FIELD_COUNT: usize = 2;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -12968,37 +18143,31 @@ impl<'r> IdentifyReader<'r> {
     pub fn has_extra_fields(&self) -> bool {
         Self::FIELD_COUNT != self.field_count()
     }
-    pub fn flag(&self) -> Uint64Reader<'r> {
+    pub fn proofs(&self) -> TransactionsProofVecReader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[4..]) as usize;
         let end = molecule::unpack_number(&slice[8..]) as usize;
-        Uint64Reader::new_unchecked(&self.as_slice()[start..end])
+        TransactionsProofVecReader::new_unchecked(&self.as_slice()[start..end])
     }
-    pub fn name(&self) -> BytesReader<'r> {
+    pub fn missing_tx_hashes(&self) -> Byte32VecReader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[8..]) as usize;
-        let end = molecule::unpack_number(&slice[12..]) as usize;
-        BytesReader::new_unchecked(&self.as_slice()[start..end])
-    }
-    pub fn client_version(&self) -> BytesReader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[12..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[16..]) as usize;
-            BytesReader::new_unchecked(&self.as_slice()[start..end])
+            let end = molecule::unpack_number(&slice[12..]) as usize;
+            Byte32VecReader::new_unchecked(&self.as_slice()[start..end])
         } else {
-            BytesReader::new_unchecked(&self.as_slice()[start..])
+            Byte32VecReader::new_unchecked(&self.as_slice()[start..])
         }
     }
 }
-impl<'r> molecule::prelude::Reader<'r> for IdentifyReader<'r> {
-    type Entity = Identify;
-    const NAME: &'static str = "IdentifyReader";
+impl<'r> molecule::prelude::Reader<'r> for SendTransactionsProofReader<'r> {
+    type Entity = SendTransactionsProof;
+    const NAME: &'static str = "SendTransactionsProofReader";
     fn to_entity(&self) -> Self::Entity {
         Self::Entity::new_unchecked(self.as_slice().to_owned().into())
     }
     fn new_unchecked(slice: &'r [u8]) -> Self {
-        IdentifyReader(slice)
+        SendTransactionsProofReader(slice)
     }
     fn as_slice(&self) -> &'r [u8] {
         self.0
@@ -13040,64 +18209,54 @@ impl<'r> molecule::prelude::Reader<'r> for IdentifyReader<'r> {
         if offsets.windows(2).any(|i| i[0] > i[1]) {
             return ve!(Self, OffsetsNotMatch);
         }
-        Uint64Reader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
-        BytesReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
-        BytesReader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
+        TransactionsProofVecReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        Byte32VecReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
         Ok(())
     }
 }
 #[derive(Debug, Default)]
-pub struct IdentifyBuilder {
-    pub(crate) flag: Uint64,
-    pub(crate) name: Bytes,
-    pub(crate) client_version: Bytes,
+pub struct SendTransactionsProofBuilder {
+    pub(crate) proofs: TransactionsProofVec,
+    pub(crate) missing_tx_hashes: Byte32Vec,
 }
-impl IdentifyBuilder {
-    pub const FIELD_COUNT: usize = 3;
-    pub fn flag(mut self, v: Uint64) -> Self {
-        self.flag = v;
-        self
-    }
-    pub fn name(mut self, v: Bytes) -> Self {
-        self.name = v;
+impl SendTransactionsProofBuilder {
+    pub const FIELD_COUNT: usize = 2;
+    pub fn proofs(mut self, v: TransactionsProofVec) -> Self {
+        self.proofs = v;
         self
     }
-    pub fn client_version(mut self, v: Bytes) -> Self {
-        self.client_version = v;
+    pub fn missing_tx_hashes(mut self, v: Byte32Vec) -> Self {
+        self.missing_tx_hashes = v;
         self
     }
 }
-impl molecule::prelude::Builder for IdentifyBuilder {
-    type Entity = Identify;
-    const NAME: &'static str = "IdentifyBuilder";
+impl molecule::prelude::Builder for SendTransactionsProofBuilder {
+    type Entity = SendTransactionsProof;
+    const NAME: &'static str = "SendTransactionsProofBuilder";
     fn expected_length(&self) -> usize {
         molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
-            + self.flag.as_slice().len()
-            + self.name.as_slice().len()
-            + self.client_version.as_slice().len()
+            + self.proofs.as_slice().len()
+            + self.missing_tx_hashes.as_slice().len()
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
         let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
         let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
         offsets.push(total_size);
-        total_size += self.flag.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.name.as_slice().len();
+        total_size += self.proofs.as_slice().len();
         offsets.push(total_size);
-        total_size += self.client_version.as_slice().len();
+        total_size += self.missing_tx_hashes.as_slice().len();
         writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
         for offset in offsets.into_iter() {
             writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
         }
-        writer.write_all(self.flag.as_slice())?;
-        writer.write_all(self.name.as_slice())?;
-        writer.write_all(self.client_version.as_slice())?;
+        writer.write_all(self.proofs.as_slice())?;
+        writer.write_all(self.missing_tx_hashes.as_slice())?;
         Ok(())
     }
     fn build(&self) -> Self::Entity {
         let mut inner = Vec::with_capacity(self.expected_length());
         self.write(&mut inner)
             .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        Identify::new_unchecked(inner.into())
+        SendTransactionsProof::new_unchecked(inner.into())
     }
 }
@@ -1,7 +1,7 @@
 use crate::error::{
     BlockError, BlockErrorKind, BlockTransactionsError, BlockVersionError, CellbaseError,
-    CommitError, EpochError, HeaderError, HeaderErrorKind, InvalidParentError, NumberError,
-    PowError, TimestampError, UnclesError, UnknownParentError,
+    CheckpointError, CommitError, EpochError, HeaderError, HeaderErrorKind, InvalidParentError,
+    NumberError, PowError, TimestampError, UnclesError, UnknownParentError,
 };
 use ckb_error::{
     impl_error_conversion_with_adaptor, impl_error_conversion_with_kind, Error, ErrorKind,
@@ -20,6 +20,7 @@ impl_error_conversion_with_kind!(PowError, HeaderErrorKind::Pow, HeaderError);
 impl_error_conversion_with_kind!(TimestampError, HeaderErrorKind::Timestamp, HeaderError);
 impl_error_conversion_with_kind!(NumberError, HeaderErrorKind::Number, HeaderError);
 impl_error_conversion_with_kind!(EpochError, HeaderErrorKind::Epoch, HeaderError);
+impl_error_conversion_with_kind!(CheckpointError, HeaderErrorKind::Checkpoint, HeaderError);
 
 impl_error_conversion_with_kind!(
     BlockTransactionsError,
@@ -41,6 +42,7 @@ impl_error_conversion_with_adaptor!(PowError, HeaderError, Error);
 impl_error_conversion_with_adaptor!(TimestampError, HeaderError, Error);
 impl_error_conversion_with_adaptor!(NumberError, HeaderError, Error);
 impl_error_conversion_with_adaptor!(EpochError, HeaderError, Error);
+impl_error_conversion_with_adaptor!(CheckpointError, HeaderError, Error);
 
 impl_error_conversion_with_adaptor!(BlockErrorKind, BlockError, Error);
 impl_error_conversion_with_adaptor!(HeaderErrorKind, HeaderError, Error);
@@ -40,6 +40,10 @@ pub enum HeaderErrorKind {
     ///
     /// [`BlockVersionError`]: ../ckb_verification/struct.BlockVersionError.html
     Version,
+    /// It indicates that the underlying error is [`CheckpointError`].
+    ///
+    /// [`CheckpointError`]: ../ckb_verification/enum.CheckpointError.html
+    Checkpoint,
 }
 
 def_error_base_on_kind!(
@@ -350,6 +354,37 @@ pub enum EpochError {
     },
 }
 
+/// Errors due to the fact that the chain does not go through a checkpoint.
+#[derive(Error, Debug, PartialEq, Eq, Clone)]
+pub enum CheckpointError {
+    /// The block hash conflicts with the checkpoint at the same number.
+    #[error("Mismatch(number: {number}, expected: {expected}, actual: {actual})")]
+    Mismatch {
+        /// The checkpoint block number.
+        number: u64,
+        /// The checkpoint block hash.
+        expected: Byte32,
+        /// The actual block hash.
+        actual: Byte32,
+    },
+
+    /// Switching to the fork detaches the checkpoint block.
+    #[error("ReorgBelowCheckpoint(number: {number})")]
+    ReorgBelowCheckpoint {
+        /// The checkpoint block number.
+        number: u64,
+    },
+
+    /// The block is below a known checkpoint block, but it isn't an ancestor of it.
+    #[error("ForkBelowCheckpoint(checkpoint: {checkpoint}, number: {number})")]
+    ForkBelowCheckpoint {
+        /// The checkpoint block number.
+        checkpoint: u64,
+        /// The block number.
+        number: u64,
+    },
+}
+
 impl HeaderError {
     /// Downcast `HeaderError` to `TimestampError` then check [`TimestampError::is_too_new`].
     ///
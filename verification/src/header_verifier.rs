@@ -1,6 +1,6 @@
 use crate::{
-    BlockVersionError, EpochError, NumberError, PowError, TimestampError, UnknownParentError,
-    ALLOWED_FUTURE_BLOCKTIME,
+    BlockVersionError, CheckpointError, EpochError, NumberError, PowError, TimestampError,
+    UnknownParentError, ALLOWED_FUTURE_BLOCKTIME,
 };
 use ckb_chain_spec::consensus::Consensus;
 use ckb_error::Error;
@@ -32,6 +32,7 @@ impl<'a, DL: HeaderProvider> Verifier for HeaderVerifier<'a, DL> {
     type Target = HeaderView;
     fn verify(&self, header: &Self::Target) -> Result<(), Error> {
         VersionVerifier::new(header, self.consensus.block_version()).verify()?;
+        CheckpointVerifier::new(self.data_loader, header, self.consensus).verify()?;
         // POW check first
         PowVerifier::new(header, self.consensus.pow_engine().as_ref()).verify()?;
         let parent = self
@@ -77,6 +78,49 @@ impl<'a> VersionVerifier<'a> {
     }
 }
 
+pub struct CheckpointVerifier<'a, DL> {
+    data_loader: &'a DL,
+    header: &'a HeaderView,
+    consensus: &'a Consensus,
+}
+
+impl<'a, DL: HeaderProvider> CheckpointVerifier<'a, DL> {
+    pub fn new(data_loader: &'a DL, header: &'a HeaderView, consensus: &'a Consensus) -> Self {
+        CheckpointVerifier {
+            data_loader,
+            header,
+            consensus,
+        }
+    }
+
+    pub fn verify(&self) -> Result<(), Error> {
+        let number = self.header.number();
+        match self.consensus.next_checkpoint(number) {
+            Some((checkpoint, expected)) if checkpoint == number => {
+                if expected != &self.header.hash() {
+                    return Err(CheckpointError::Mismatch {
+                        number,
+                        expected: expected.clone(),
+                        actual: self.header.hash(),
+                    }
+                    .into());
+                }
+            }
+            // The ancestors of a known checkpoint header are all known, so an unknown header
+            // below it is on another chain
+            Some((checkpoint, expected)) => {
+                if self.data_loader.get_header(expected).is_some()
+                    && self.data_loader.get_header(&self.header.hash()).is_none()
+                {
+                    return Err(CheckpointError::ForkBelowCheckpoint { checkpoint, number }.into());
+                }
+            }
+            None => {}
+        }
+        Ok(())
+    }
+}
+
 pub struct TimestampVerifier<'a, DL> {
     header: &'a HeaderView,
     data_loader: &'a DL,
@@ -15,8 +15,8 @@ mod tests;
 pub use crate::block_verifier::{BlockVerifier, NonContextualBlockTxsVerifier};
 pub use crate::error::{
     BlockError, BlockErrorKind, BlockTransactionsError, BlockVersionError, CellbaseError,
-    CommitError, EpochError, HeaderError, HeaderErrorKind, InvalidParentError, NumberError,
-    PowError, TimestampError, TransactionError, UnclesError, UnknownParentError,
+    CheckpointError, CommitError, EpochError, HeaderError, HeaderErrorKind, InvalidParentError,
+    NumberError, PowError, TimestampError, TransactionError, UnclesError, UnknownParentError,
 };
 pub use crate::genesis_verifier::GenesisVerifier;
 pub use crate::header_verifier::HeaderVerifier;
@@ -1,16 +1,19 @@
 use crate::header_verifier::{
-    EpochVerifier, NumberVerifier, PowVerifier, TimestampVerifier, VersionVerifier,
+    CheckpointVerifier, EpochVerifier, NumberVerifier, PowVerifier, TimestampVerifier,
+    VersionVerifier,
 };
 use crate::{
-    BlockVersionError, EpochError, NumberError, PowError, TimestampError, ALLOWED_FUTURE_BLOCKTIME,
+    BlockVersionError, CheckpointError, EpochError, NumberError, PowError, TimestampError,
+    ALLOWED_FUTURE_BLOCKTIME,
 };
+use ckb_chain_spec::consensus::ConsensusBuilder;
 use ckb_error::assert_error_eq;
 use ckb_pow::PowEngine;
 use ckb_test_chain_utils::{MockMedianTime, MOCK_MEDIAN_TIME_COUNT};
 use ckb_types::{
     constants::BLOCK_VERSION,
-    core::{EpochNumberWithFraction, HeaderBuilder},
-    packed::Header,
+    core::{EpochNumberWithFraction, HeaderBuilder, HeaderView},
+    packed::{Byte32, Header},
     prelude::*,
 };
 use faketime::unix_time_as_millis;
@@ -128,6 +131,80 @@ fn test_number() {
     );
 }
 
+#[test]
+fn test_checkpoint() {
+    let checkpoint = HeaderBuilder::default().number(10u64.pack()).build();
+    let consensus = ConsensusBuilder::default()
+        .checkpoints(vec![(10, checkpoint.hash())].into_iter().collect())
+        .build();
+    let unknown: Box<dyn Fn(Byte32) -> Option<HeaderView>> = Box::new(|_| None);
+    assert!(CheckpointVerifier::new(&unknown, &checkpoint, &consensus)
+        .verify()
+        .is_ok());
+
+    let other = HeaderBuilder::default().number(11u64.pack()).build();
+    assert!(CheckpointVerifier::new(&unknown, &other, &consensus)
+        .verify()
+        .is_ok());
+
+    let conflicting = HeaderBuilder::default()
+        .number(10u64.pack())
+        .timestamp(1u64.pack())
+        .build();
+    assert_error_eq!(
+        CheckpointVerifier::new(&unknown, &conflicting, &consensus)
+            .verify()
+            .unwrap_err(),
+        CheckpointError::Mismatch {
+            number: 10,
+            expected: checkpoint.hash(),
+            actual: conflicting.hash(),
+        },
+    );
+}
+
+#[test]
+fn test_fork_below_checkpoint() {
+    let ancestor = HeaderBuilder::default().number(5u64.pack()).build();
+    let checkpoint = HeaderBuilder::default()
+        .number(10u64.pack())
+        .parent_hash(ancestor.hash())
+        .build();
+    let consensus = ConsensusBuilder::default()
+        .checkpoints(vec![(10, checkpoint.hash())].into_iter().collect())
+        .build();
+    let fork = HeaderBuilder::default()
+        .number(5u64.pack())
+        .timestamp(1u64.pack())
+        .build();
+
+    // the headers below the checkpoint are not checked until the checkpoint header is known
+    let unknown: Box<dyn Fn(Byte32) -> Option<HeaderView>> = Box::new(|_| None);
+    assert!(CheckpointVerifier::new(&unknown, &fork, &consensus)
+        .verify()
+        .is_ok());
+
+    let known_headers = vec![ancestor.clone(), checkpoint];
+    let known: Box<dyn Fn(Byte32) -> Option<HeaderView>> = Box::new(move |hash| {
+        known_headers
+            .iter()
+            .find(|header| header.hash() == hash)
+            .cloned()
+    });
+    assert!(CheckpointVerifier::new(&known, &ancestor, &consensus)
+        .verify()
+        .is_ok());
+    assert_error_eq!(
+        CheckpointVerifier::new(&known, &fork, &consensus)
+            .verify()
+            .unwrap_err(),
+        CheckpointError::ForkBelowCheckpoint {
+            checkpoint: 10,
+            number: 5,
+        },
+    );
+}
+
 #[test]
 fn test_epoch() {
     {